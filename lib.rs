@@ -1,22 +1,963 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
+/// Merkle proof verification for the registration allowlist used by `register_with_proof`.
+/// Trees use BLAKE2-256 with sorted pairs: a parent is the hash of its two children in ascending
+/// byte order, so proofs need no left/right flags. A node without a sibling on its level is
+/// promoted to the next level unchanged.
+pub mod merkle;
+
 /// The `content_ownership` module defines a smart contract for managing digital content ownership.
 /// It allows users to register digital content, transfer ownership, and validate content using oracle data.
 #[ink::contract]
-mod content_ownership {
+pub mod content_ownership {
     use ink::storage::Mapping;
     use ink::prelude::string::String;
     use ink::prelude::collections::BTreeMap;
+    use ink::prelude::vec::Vec;
+
+    /// The maximum number of entries returned by a single paginated query.
+    pub const MAX_PAGE_SIZE: u32 = 100;
+
+    /// The maximum number of items accepted by a single batch message.
+    pub const MAX_BATCH_SIZE: u32 = 100;
+
+    /// The maximum number of entries accepted by a single `bulk_import` call.
+    pub const MAX_IMPORT_BATCH_SIZE: u32 = 50;
+
+    /// The maximum length, in bytes, of a transfer memo.
+    pub const MAX_MEMO_LENGTH: usize = 256;
+
+    /// The maximum length, in bytes, of a metadata title.
+    pub const MAX_TITLE_LENGTH: usize = 128;
+
+    /// The maximum length, in bytes, of a metadata description.
+    pub const MAX_DESCRIPTION_LENGTH: usize = 1024;
+
+    /// The maximum length, in bytes, of a metadata URI.
+    pub const MAX_URI_LENGTH: usize = 256;
+
+    /// The maximum length, in bytes, of a licensing terms digest.
+    pub const MAX_LICENSE_TERMS_LENGTH: usize = 128;
+
+    /// The maximum number of tags per content item.
+    pub const MAX_TAGS_PER_CONTENT: usize = 8;
+
+    /// The maximum length, in bytes, of a normalized tag.
+    pub const MAX_TAG_LENGTH: usize = 32;
+
+    /// The maximum length, in bytes, of oracle data or a single oracle prefix.
+    pub const MAX_ORACLE_DATA_LENGTH: usize = 256;
+
+    /// The maximum length, in bytes, of a content hash.
+    pub const MAX_CONTENT_HASH_LENGTH: usize = 128;
+
+    /// The maximum number of accepted oracle prefixes.
+    pub const MAX_ORACLE_PREFIXES: usize = 8;
+
+    /// The maximum number of oracle members voting on oracle data.
+    pub const MAX_ORACLE_MEMBERS: usize = 16;
+
+    /// The maximum number of items in a collection, so `transfer_collection` fits in one call.
+    pub const MAX_COLLECTION_SIZE: u32 = MAX_BATCH_SIZE;
+
+    /// The maximum length, in bytes, of a collection name.
+    pub const MAX_COLLECTION_NAME_LENGTH: usize = 64;
+
+    /// The maximum number of content IDs covered by a single `export_range` call.
+    pub const MAX_EXPORT_RANGE: u64 = 200;
+
+    /// The maximum number of recipients a royalty can be split among.
+    pub const MAX_ROYALTY_RECIPIENTS: usize = 10;
+
+    /// The maximum number of accounts holding a license on one content item, active or expired, so
+    /// granting an exclusive license can check all of them in one call.
+    pub const MAX_LICENSEES_PER_CONTENT: u32 = 100;
+
+    /// The number of most recent sales kept in each content item's sale history.
+    pub const MAX_SALE_HISTORY: u32 = 100;
+
+    /// The number of blocks after which a pending admin action can no longer be approved.
+    pub const ADMIN_ACTION_EXPIRY_BLOCKS: BlockNumber = 14_400;
+
+    /// The number of blocks a registration commitment must age before it can be revealed.
+    pub const MIN_REVEAL_DELAY_BLOCKS: BlockNumber = 1;
+
+    /// The number of blocks after which a registration commitment can no longer be revealed.
+    pub const COMMITMENT_EXPIRY_BLOCKS: BlockNumber = 14_400;
+
+    /// The default delay, in milliseconds, between scheduling and executing a forced transfer (7 days).
+    pub const DEFAULT_FORCED_TRANSFER_DELAY: Timestamp = 7 * 24 * 60 * 60 * 1000;
+
+    /// The default anti-sniping window, in milliseconds, of English auctions (5 minutes).
+    pub const DEFAULT_AUCTION_EXTENSION: Timestamp = 5 * 60 * 1000;
+
+    /// The basis points making up a whole sale price.
+    pub const BPS_DENOMINATOR: Balance = 10_000;
+
+    /// The highest platform fee, in basis points, an admin can take from sales (10%).
+    pub const MAX_PLATFORM_FEE_BPS: u16 = 1_000;
+
+    /// The royalty cap, in basis points of the sale price, until an admin changes it.
+    pub const DEFAULT_MAX_ROYALTY_BPS: u16 = 1_000;
+
+    /// The highest royalty cap an admin can set, so a royalty and the platform fee together never
+    /// exceed the sale price.
+    pub const MAX_ROYALTY_CAP_BPS: u16 = BPS_DENOMINATOR as u16 - MAX_PLATFORM_FEE_BPS;
+
+    /// The maximum length, in bytes, of the message attached to a tip.
+    pub const MAX_TIP_MESSAGE_LENGTH: usize = 256;
+
+    /// The length of one rental day, in milliseconds.
+    pub const RENTAL_DAY: Timestamp = 24 * 60 * 60 * 1000;
+
+    /// The domain tag prefixed to the payload signed for `permit`, so a permit signature
+    /// cannot be replayed as any other kind of signed message.
+    pub const PERMIT_DOMAIN: &[u8] = b"content_ownership::permit";
+
+    /// The domain tag prefixed to the payload signed for `execute_signed_transfer`.
+    pub const TRANSFER_DOMAIN: &[u8] = b"content_ownership::transfer";
+
+    /// The domain tag prefixed to the payload an attestor signs for `register_with_attestation`.
+    pub const ATTESTATION_DOMAIN: &[u8] = b"content_ownership::attestation";
+
+    /// The maximum size in bytes of a preimage hashed on-chain by `register_preimage`.
+    pub const MAX_PREIMAGE_SIZE: usize = 4096;
+
+    /// Domain separator for the message signed by `register_content_with_pop`.
+    pub const POP_DOMAIN: &[u8] = b"content_ownership::proof_of_possession";
+
+    /// The maximum number of siblings in an allowlist proof, enough for a tree of 2^32 hashes.
+    pub const MAX_PROOF_LENGTH: usize = 32;
+
+    /// The version of the contract logic, bumped on each release.
+    pub const CONTRACT_VERSION: u32 = 2;
+
+    /// Implemented by contracts that want to receive content through `safe_transfer_ownership`.
+    #[ink::trait_definition]
+    pub trait ContentReceiver {
+        /// Called after `content_id` was transferred from `from` to the implementing contract.
+        /// Returning `false` (or failing) reverts the transfer.
+        #[ink(message)]
+        fn on_content_received(&mut self, content_id: u64, from: AccountId, data: Vec<u8>) -> bool;
+    }
+
+    /// Implemented by external validator contracts set with `set_validator`, so validation rules can
+    /// be upgraded independently of the registry.
+    #[ink::trait_definition]
+    pub trait ContentValidator {
+        /// Returns whether `content_hash` may be registered. Failing counts as `false`.
+        #[ink(message)]
+        fn is_valid(&self, content_hash: String) -> bool;
+    }
+
+    /// The PSP22 fungible token standard's error type, returned by token contracts that listings
+    /// may be priced in.
+    #[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum PSP22Error {
+        /// A custom error type for implementation-specific errors.
+        Custom(String),
+        /// Returned when the sender's balance is below the transferred value.
+        InsufficientBalance,
+        /// Returned when the caller's allowance is below the transferred value.
+        InsufficientAllowance,
+        /// Returned when the recipient is the zero address.
+        ZeroRecipientAddress,
+        /// Returned when the sender is the zero address.
+        ZeroSenderAddress,
+        /// Returned when a safe transfer check fails.
+        SafeTransferCheckFailed(String),
+    }
+
+    /// The part of the PSP22 fungible token standard used to collect payments for listings priced
+    /// through `list_for_sale_in_token`.
+    #[ink::trait_definition]
+    pub trait PSP22 {
+        /// Transfers `value` tokens from `from` to `to` out of the caller's allowance.
+        #[ink(message)]
+        fn transfer_from(&mut self, from: AccountId, to: AccountId, value: u128, data: Vec<u8>) -> core::result::Result<(), PSP22Error>;
+    }
 
     /// Represents a digital content record stored on-chain.
     /// Each record contains:
     /// - `content_hash`: A unique identifier for the content (e.g., an IPFS hash).
     /// - `owner`: The AccountId of the current owner of the content.
+    /// - `creator`: The AccountId that owned the content at registration; never changed by transfers.
+    /// - `imported`: Whether the record was migrated through `bulk_import` without oracle validation.
+    /// - `renounced`: Whether the owner released the content through `renounce_ownership`.
+    /// - `soulbound`: Whether the content is permanently bound to its owner; fixed at registration.
+    /// - `status`: Where the content is in its lifecycle; only `Active` content can change hands.
+    /// - `scheme`: The format of `content_hash`, declared at registration.
+    /// - `parent`: The content this item is a derivative of (e.g. a remix or translation), if any.
     #[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq, Eq)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
     pub struct Content {
         content_hash: String,
         owner: AccountId,
+        creator: AccountId,
+        imported: bool,
+        renounced: bool,
+        soulbound: bool,
+        status: ContentStatus,
+        scheme: HashScheme,
+        parent: Option<u64>,
+    }
+
+    impl Content {
+        /// Creates a content record, e.g. to build fixtures outside the contract.
+        /// The owner is also recorded as the creator.
+        pub fn new(content_hash: String, owner: AccountId) -> Self {
+            Self {
+                content_hash,
+                owner,
+                creator: owner,
+                imported: false,
+                renounced: false,
+                soulbound: false,
+                status: ContentStatus::Active,
+                scheme: HashScheme::Other,
+                parent: None,
+            }
+        }
+
+        /// Returns whether the record was migrated through `bulk_import`.
+        pub fn is_imported(&self) -> bool {
+            self.imported
+        }
+
+        /// Returns the content hash of the record.
+        pub fn content_hash(&self) -> &str {
+            &self.content_hash
+        }
+
+        /// Returns the current owner of the record, or the last owner if it was renounced.
+        pub fn owner(&self) -> AccountId {
+            self.owner
+        }
+
+        /// Returns the original creator of the record, which transfers never change.
+        pub fn creator(&self) -> AccountId {
+            self.creator
+        }
+
+        /// Returns whether the ownership of the record was renounced, leaving it without an owner.
+        pub fn is_renounced(&self) -> bool {
+            self.renounced
+        }
+
+        /// Returns whether the record is soulbound, i.e. can never be transferred.
+        pub fn is_soulbound(&self) -> bool {
+            self.soulbound
+        }
+
+        /// Returns the lifecycle status of the record.
+        pub fn status(&self) -> ContentStatus {
+            self.status
+        }
+
+        /// Returns the format of the record's content hash.
+        pub fn scheme(&self) -> HashScheme {
+            self.scheme
+        }
+
+        /// Returns the content this record is a derivative of, if any.
+        pub fn parent(&self) -> Option<u64> {
+            self.parent
+        }
+    }
+
+    /// The format of a content hash, declared at registration.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum HashScheme {
+        /// A base58btc IPFS CIDv0: 46 characters starting with "Qm".
+        IpfsCidV0,
+        /// A base32 IPFS CIDv1: the multibase prefix "b" followed by lowercase base32, 59 characters or more.
+        IpfsCidV1,
+        /// A raw SHA-256 digest: 64 lowercase hex characters.
+        Sha256Hex,
+        /// Any other format, validated by the admin-configured `ValidationStrategy`.
+        #[default]
+        Other,
+        /// A raw BLAKE2-256 digest: 64 lowercase hex characters, as computed by `register_preimage`.
+        Blake2x256Hex,
+    }
+
+    /// How hashes declared as `HashScheme::Other` are validated, set by the admin with `set_validation_strategy`.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum ValidationStrategy {
+        /// The hash must start with one of the oracle prefixes.
+        #[default]
+        PrefixMatch,
+        /// The hash must be exactly this many bytes long.
+        ExactLength(u32),
+        /// The hash must be a non-empty string of hex digits, in either case.
+        CharsetHex,
+        /// Any hash is accepted.
+        Disabled,
+        /// Every enabled check must pass, in the order prefix, length, charset.
+        Combined {
+            /// Whether the hash must start with one of the oracle prefixes.
+            prefix: bool,
+            /// The exact length in bytes the hash must have, if any.
+            length: Option<u32>,
+            /// Whether the hash must consist of hex digits.
+            hex: bool,
+        },
+    }
+
+    impl ValidationStrategy {
+        /// Checks a hash against the strategy, given the accepted oracle prefixes.
+        /// Prefix matching rejects every hash when the list is empty.
+        pub fn check(self, content_hash: &str, oracle_prefixes: &[String]) -> core::result::Result<(), HashFormatError> {
+            let (prefix, length, hex) = match self {
+                ValidationStrategy::PrefixMatch => (true, None, false),
+                ValidationStrategy::ExactLength(length) => (false, Some(length), false),
+                ValidationStrategy::CharsetHex => (false, None, true),
+                ValidationStrategy::Disabled => return Ok(()),
+                ValidationStrategy::Combined { prefix, length, hex } => (prefix, length, hex),
+            };
+            if prefix && !oracle_prefixes.iter().any(|oracle_prefix| content_hash.starts_with(oracle_prefix.as_str())) {
+                return Err(HashFormatError::OracleMismatch);
+            }
+            if length.is_some_and(|length| content_hash.len() != length as usize) {
+                return Err(HashFormatError::WrongLength);
+            }
+            if hex && (content_hash.is_empty() || !content_hash.bytes().all(|c| c.is_ascii_hexdigit())) {
+                return Err(HashFormatError::InvalidCharacter);
+            }
+            Ok(())
+        }
+    }
+
+    /// Why a content hash does not match its declared `HashScheme`, reported by `check_hash_format`.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum HashFormatError {
+        /// The hash has the wrong length for the scheme.
+        WrongLength,
+        /// The hash does not start with the scheme's prefix.
+        WrongPrefix,
+        /// The hash contains a character outside the scheme's alphabet.
+        InvalidCharacter,
+        /// The hash does not start with any oracle prefix, under a prefix-matching `ValidationStrategy`.
+        OracleMismatch,
+        /// The external validator contract rejected the hash, or the call to it failed.
+        ValidatorRejected,
+    }
+
+    impl HashScheme {
+        /// Checks the structure of a hash against the scheme. `Other` has no structure to check.
+        pub fn check_structure(self, content_hash: &str) -> core::result::Result<(), HashFormatError> {
+            let bytes = content_hash.as_bytes();
+            let (valid_length, prefix, in_alphabet) = match self {
+                HashScheme::IpfsCidV0 => (
+                    bytes.len() == 46,
+                    "Qm",
+                    bytes.iter().all(|c| c.is_ascii_alphanumeric() && !b"0OIl".contains(c)),
+                ),
+                HashScheme::IpfsCidV1 => (
+                    bytes.len() >= 59,
+                    "b",
+                    bytes.iter().all(|c| c.is_ascii_lowercase() || (b'2'..=b'7').contains(c)),
+                ),
+                HashScheme::Sha256Hex | HashScheme::Blake2x256Hex => (
+                    bytes.len() == 64,
+                    "",
+                    bytes.iter().all(|c| c.is_ascii_digit() || (b'a'..=b'f').contains(c)),
+                ),
+                HashScheme::Other => return Ok(()),
+            };
+            if !valid_length {
+                return Err(HashFormatError::WrongLength);
+            }
+            if !content_hash.starts_with(prefix) {
+                return Err(HashFormatError::WrongPrefix);
+            }
+            if !in_alphabet {
+                return Err(HashFormatError::InvalidCharacter);
+            }
+            Ok(())
+        }
+    }
+
+    /// The lifecycle status of a content item.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum ContentStatus {
+        /// In good standing; the only status in which content can be transferred, approved, or burned.
+        #[default]
+        Active,
+        /// Under dispute; can return to `Active` or be revoked.
+        Disputed,
+        /// Invalidated through `revoke_content`. Terminal for `set_content_status`; only an appeal
+        /// through `unrevoke_content` returns it to `Active`.
+        Revoked,
+        /// Removed through `burn_content`. Never stored: burned content has no record, and
+        /// `status_of` reports this status for issued IDs without one. Terminal.
+        Burned,
+    }
+
+    impl ContentStatus {
+        /// Returns whether content may move from this status to `next` through `set_content_status`.
+        pub fn can_transition_to(self, next: ContentStatus) -> bool {
+            matches!(
+                (self, next),
+                (ContentStatus::Active, ContentStatus::Disputed)
+                    | (ContentStatus::Active, ContentStatus::Revoked)
+                    | (ContentStatus::Disputed, ContentStatus::Active)
+                    | (ContentStatus::Disputed, ContentStatus::Revoked)
+            )
+        }
+    }
+
+    /// Roles the admin can delegate to other accounts.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum Role {
+        /// May update the oracle data without holding the admin key.
+        OracleUpdater,
+        /// May register content on behalf of other accounts through `register_content_for`.
+        /// Unrelated to the registrar whitelist used by `RegistrationMode::WhitelistOnly`.
+        Registrar,
+        /// May revoke infringing content through `revoke_content` and lift revocations on appeal.
+        Moderator,
+    }
+
+    /// Who may register new content.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum RegistrationMode {
+        /// Any account that is not blacklisted may register.
+        #[default]
+        Open,
+        /// Only whitelisted registrars may register.
+        WhitelistOnly,
+    }
+
+    /// An operation that can be paused independently of the others.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum PausableOp {
+        /// New content registrations.
+        Registrations,
+        /// Ownership transfers of existing content.
+        Transfers,
+        /// Marketplace sales.
+        Sales,
+    }
+
+    /// The pause flag of each pausable operation.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct PauseState {
+        /// Whether new registrations are paused.
+        pub registrations: bool,
+        /// Whether ownership transfers are paused.
+        pub transfers: bool,
+        /// Whether marketplace sales are paused.
+        pub sales: bool,
+    }
+
+    impl PauseState {
+        /// Returns whether `op` is paused.
+        pub fn is_paused(&self, op: PausableOp) -> bool {
+            match op {
+                PausableOp::Registrations => self.registrations,
+                PausableOp::Transfers => self.transfers,
+                PausableOp::Sales => self.sales,
+            }
+        }
+
+        /// Sets the pause flag of `op`.
+        fn set(&mut self, op: PausableOp, paused: bool) {
+            match op {
+                PausableOp::Registrations => self.registrations = paused,
+                PausableOp::Transfers => self.transfers = paused,
+                PausableOp::Sales => self.sales = paused,
+            }
+        }
+
+        /// Returns a state with every flag set to `paused`.
+        fn all(paused: bool) -> Self {
+            Self {
+                registrations: paused,
+                transfers: paused,
+                sales: paused,
+            }
+        }
+    }
+
+    /// A sensitive admin action that requires `approval_threshold` admin approvals.
+    #[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum AdminAction {
+        /// Replaces the oracle data, like `update_oracle_data`. While the oracle delay is not zero,
+        /// executing it proposes the data like `propose_oracle_data` instead.
+        UpdateOracle(String),
+        /// Changes the number of admin approvals required to execute an action.
+        SetApprovalThreshold(u32),
+        /// Accepts an additional oracle prefix, like `add_oracle_prefix`. Timelocked like `UpdateOracle`.
+        AddOraclePrefix(String),
+        /// Stops accepting an oracle prefix, like `remove_oracle_prefix`. Timelocked like `UpdateOracle`.
+        RemoveOraclePrefix(String),
+        /// Sets or clears the oracle account, like `set_oracle_account`.
+        SetOracleAccount(Option<AccountId>),
+        /// Adds a voting oracle member, like `add_oracle_member`.
+        AddOracleMember(AccountId),
+        /// Removes a voting oracle member, like `remove_oracle_member`.
+        RemoveOracleMember(AccountId),
+        /// Changes the number of identical submissions needed to adopt oracle data, like `set_oracle_quorum`.
+        SetOracleQuorum(u32),
+        /// Changes the timelock on oracle data updates, like `set_oracle_delay`.
+        SetOracleDelay(Timestamp),
+        /// Sets or clears the external validator contract, like `set_validator`. Timelocked like `UpdateOracle`.
+        SetValidator(Option<AccountId>),
+        /// Switches the validation strategy, like `set_validation_strategy`. Timelocked like `UpdateOracle`.
+        SetValidationStrategy(ValidationStrategy),
+        /// Sets or clears the attestor public key, like `set_attestor`.
+        SetAttestor(Option<[u8; 33]>),
+        /// Replaces the allowlist Merkle root, like `set_allowlist_root`.
+        SetAllowlistRoot(Hash),
+        /// Changes the treasury account, like `set_treasury`.
+        SetTreasury(AccountId),
+        /// Sends collected fees to the treasury, like `withdraw_fees`.
+        WithdrawFees(Balance),
+        /// Adds an account to the admin set, like `add_admin`.
+        AddAdmin(AccountId),
+        /// Removes an account from the admin set, like `remove_admin`.
+        RemoveAdmin(AccountId),
+        /// Grants a role to an account, like `grant_role`.
+        GrantRole(Role, AccountId),
+        /// Revokes a role from an account, like `revoke_role`.
+        RevokeRole(Role, AccountId),
+        /// Sets the primary and secondary platform fees, like `set_fee_schedule`.
+        SetFeeSchedule(u16, u16),
+        /// Sets the fee every registration must carry, like `set_registration_fee`.
+        SetRegistrationFee(Balance),
+        /// Sets the cap on royalties, like `set_max_royalty_bps`.
+        SetMaxRoyalty(u16),
+        /// Sets the anti-sniping window of auctions, like `set_auction_extension`.
+        SetAuctionExtension(Timestamp),
+        /// Sets the delay applied to newly scheduled forced transfers, like `set_forced_transfer_delay`.
+        SetForcedTransferDelay(Timestamp),
+    }
+
+    /// A change to how content hashes are validated, subject to the oracle delay.
+    #[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub enum OracleChange {
+        /// Replaces all accepted oracle prefixes with one, like `update_oracle_data`.
+        ReplaceData(String),
+        /// Accepts an additional oracle prefix, like `add_oracle_prefix`.
+        AddPrefix(String),
+        /// Stops accepting an oracle prefix, like `remove_oracle_prefix`.
+        RemovePrefix(String),
+        /// Switches the validation strategy, like `set_validation_strategy`.
+        SetValidationStrategy(ValidationStrategy),
+        /// Sets or clears the external validator contract, like `set_validator`.
+        SetValidator(Option<AccountId>),
+    }
+
+    /// An oracle change waiting for its timelock, created by `propose_oracle_data` or `propose_oracle_change`.
+    #[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct PendingOracleData {
+        /// The proposed change.
+        pub change: OracleChange,
+        /// The account that proposed it.
+        pub proposed_by: AccountId,
+        /// The block timestamp from which `apply_oracle_data` can adopt it.
+        pub not_before: Timestamp,
+    }
+
+    /// The oracle voting round in progress, returned by `pending_oracle_round`.
+    #[derive(scale::Encode, scale::Decode, Clone, Debug, Default, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct OracleRound {
+        /// The round number, starting at zero and advancing when a round is settled.
+        pub round: u64,
+        /// The data submitted by each member so far, at most one entry per member.
+        pub submissions: Vec<(AccountId, String)>,
+    }
+
+    /// An admin action awaiting approvals, created by `propose_admin_action`.
+    #[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct PendingAction {
+        /// The action to execute once approved.
+        pub action: AdminAction,
+        /// The admins that approved the action so far, including the proposer.
+        pub approvers: Vec<AccountId>,
+        /// The last block at which the action can still be approved.
+        pub expires_at: BlockNumber,
+    }
+
+    /// A forced transfer scheduled by an admin through `admin_schedule_transfer`.
+    #[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct ScheduledTransfer {
+        /// The account that will receive the content.
+        pub new_owner: AccountId,
+        /// A hash of the off-chain document (e.g. a takedown order) justifying the transfer.
+        pub reason_hash: String,
+        /// The admin that scheduled the transfer.
+        pub scheduled_by: AccountId,
+        /// The block timestamp from which `admin_execute_transfer` may be called.
+        pub executable_at: Timestamp,
+    }
+
+    /// A per-content transfer approval granted through `approve` or `approve_until`.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct ContentApproval {
+        /// The account allowed to call `transfer_from`.
+        pub operator: AccountId,
+        /// The block timestamp from which the approval is no longer valid, if it expires.
+        pub expires_at: Option<Timestamp>,
+    }
+
+    /// A fixed-price sale created through `list_for_sale` or `list_for_sale_in_token`.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct Listing {
+        /// The asking price, in units of `currency`.
+        pub price: Balance,
+        /// The PSP22 token the price is paid in through `buy_with_token`, or `None` for native tokens
+        /// paid through `buy`.
+        pub currency: Option<AccountId>,
+    }
+
+    /// An escrowed purchase offer made through `make_offer`.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct PurchaseOffer {
+        /// The escrowed amount.
+        pub amount: Balance,
+        /// The block timestamp from which the offer can no longer be accepted and may be reclaimed.
+        pub expires_at: Timestamp,
+    }
+
+    /// An English auction started through `start_auction`.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct Auction {
+        /// The owner that started the auction and is credited the winning bid.
+        pub seller: AccountId,
+        /// The lowest winning bid; if the highest bid stays below it, the item is not sold.
+        pub reserve_price: Balance,
+        /// How much a bid must exceed the current highest bid by.
+        pub min_increment: Balance,
+        /// The block timestamp from which no more bids are accepted and the auction can be settled.
+        pub ends_at: Timestamp,
+        /// The current highest bidder, if any bid was placed.
+        pub highest_bidder: Option<AccountId>,
+        /// The escrowed highest bid, or zero before the first bid.
+        pub highest_bid: Balance,
+    }
+
+    /// A declining-price sale started through `start_dutch_auction`.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct DutchAuction {
+        /// The owner that started the sale.
+        pub seller: AccountId,
+        /// The price at `started_at`.
+        pub start_price: Balance,
+        /// The price the sale declines to at `ends_at`.
+        pub end_price: Balance,
+        /// The block timestamp the sale started at.
+        pub started_at: Timestamp,
+        /// The block timestamp from which the item can no longer be bought.
+        pub ends_at: Timestamp,
+    }
+
+    impl DutchAuction {
+        /// Returns the price at a block timestamp, declining linearly from `start_price` to
+        /// `end_price` and rounded up in the seller's favour, or `None` once the sale has ended.
+        pub fn price_at(&self, now: Timestamp) -> Option<Balance> {
+            if now >= self.ends_at {
+                return None;
+            }
+            let elapsed = Balance::from(now.saturating_sub(self.started_at));
+            let duration = Balance::from(self.ends_at.saturating_sub(self.started_at));
+            let drop = self.start_price.saturating_sub(self.end_price);
+            // Split the drop so `drop * elapsed / duration` cannot overflow.
+            let decrease = (drop / duration) * elapsed + (drop % duration) * elapsed / duration;
+            Some(self.start_price - decrease)
+        }
+    }
+
+    /// Several content items sold together at one price, listed through `list_bundle`.
+    #[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct Bundle {
+        /// The owner that listed the items and is credited the price.
+        pub seller: AccountId,
+        /// The items sold together, in listing order.
+        pub content_ids: Vec<u64>,
+        /// The price of the whole bundle, in native tokens.
+        pub price: Balance,
+    }
+
+    /// The terms on which an owner sells access to a content item through `purchase_access`.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct AccessTerms {
+        /// The price of one purchase.
+        pub price: Balance,
+        /// How long, in milliseconds, one purchase grants access for, or `None` for good.
+        pub duration: Option<u64>,
+    }
+
+    /// The terms on which an owner rents out the usage rights of a content item through `rent`.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct RentalTerms {
+        /// The price of one day of rental.
+        pub price_per_day: Balance,
+        /// The longest rental, in days, that can be taken out at once.
+        pub max_days: u32,
+    }
+
+    /// The usage rights of a content item rented out through `rent`.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct Rental {
+        /// The account holding the usage rights.
+        pub renter: AccountId,
+        /// The block timestamp from which the rental is over and the slot may be reclaimed.
+        pub expires_at: Timestamp,
+    }
+
+    /// A loan secured by a content item, offered through `create_loan_offer`.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct Loan {
+        /// The owner borrowing against the content.
+        pub borrower: AccountId,
+        /// The amount lent, credited to the borrower when the loan is funded.
+        pub principal: Balance,
+        /// The interest owed to the lender on top of the principal, however early the loan is repaid.
+        pub interest: Balance,
+        /// The block timestamp from which the offer can no longer be funded, the loan no longer be
+        /// repaid, and the lender may claim the content.
+        pub due_at: Timestamp,
+        /// The lender, once the loan is funded.
+        pub lender: Option<AccountId>,
+    }
+
+    /// The terms on which an owner sells standard licenses on a content item through `buy_license`.
+    #[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct LicenseOffer {
+        /// The price of one license.
+        pub price: Balance,
+        /// How long, in milliseconds, a bought license is active for.
+        pub duration: u64,
+        /// The digest of the license terms.
+        pub terms_hash: String,
+    }
+
+    /// A license on a content item held by one account, granted through `grant_license` or bought
+    /// through `buy_license`.
+    #[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct License {
+        /// The digest of the license terms, e.g. the hash of a license document.
+        pub terms_hash: String,
+        /// The owner that granted the license.
+        pub granted_by: AccountId,
+        /// The block timestamp at which the license was granted.
+        pub granted_at: Timestamp,
+        /// The block timestamp from which the license is no longer active, or `None` for good.
+        pub expires_at: Option<Timestamp>,
+        /// Whether no other account may be licensed while this license is active.
+        pub exclusive: bool,
+        /// Whether the licensee may move the license to another account through `transfer_license`.
+        pub transferable: bool,
+    }
+
+    /// The income a content item has earned its owners and creator, by source, as credited after the
+    /// platform fee and royalties.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, Default, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct RevenueStats {
+        /// Seller proceeds of `buy`, `buy_with_token`, `buy_dutch`, `buy_bundle`, `accept_offer`, and
+        /// `settle_auction`. Token proceeds are added in the token's units.
+        pub sales: Balance,
+        /// Tips sent through `tip`.
+        pub tips: Balance,
+        /// Proceeds of `purchase_access`.
+        pub access: Balance,
+        /// Proceeds of `rent`.
+        pub rentals: Balance,
+        /// Proceeds of `buy_license`.
+        pub licenses: Balance,
+        /// Royalties paid to the creator and split recipients on sales, in the sale's currency units.
+        pub royalties: Balance,
+    }
+
+    /// The source of an owner's income, as counted in `RevenueStats`.
+    #[derive(Clone, Copy, Debug)]
+    enum RevenueSource {
+        Sale,
+        Tip,
+        Access,
+        Rental,
+        License,
+        Royalty,
+    }
+
+    impl RevenueStats {
+        /// Returns the stats with `amount` added to the counter of `source`, or `None` on overflow.
+        fn add(mut self, source: RevenueSource, amount: Balance) -> Option<Self> {
+            let counter = match source {
+                RevenueSource::Sale => &mut self.sales,
+                RevenueSource::Tip => &mut self.tips,
+                RevenueSource::Access => &mut self.access,
+                RevenueSource::Rental => &mut self.rentals,
+                RevenueSource::License => &mut self.licenses,
+                RevenueSource::Royalty => &mut self.royalties,
+            };
+            *counter = counter.checked_add(amount)?;
+            Some(self)
+        }
+    }
+
+    /// The credits a completed sale pays out, computed by `sale_payout` before anything is written
+    /// and applied by `pay_out`.
+    #[derive(Clone, Debug)]
+    struct SalePayout {
+        /// The sold content.
+        content_id: u64,
+        /// The PSP22 token the price is paid in, or `None` for native tokens.
+        currency: Option<AccountId>,
+        /// The amount each paid account receives, one entry per account.
+        payees: Vec<(AccountId, Balance)>,
+        /// The credit of each paid account after a native-token sale, one entry per account; empty for
+        /// token sales, whose payees are paid directly.
+        credits: Vec<(AccountId, Balance)>,
+        /// The lifetime earnings of each paid account after the sale, one entry per account.
+        earnings: Vec<(AccountId, Balance)>,
+        /// The content's revenue after the sale.
+        revenue: RevenueStats,
+        /// The royalty taken from the price, by recipient; empty if none is owed.
+        royalties: Vec<(AccountId, Balance)>,
+        /// The lifetime royalty earnings of each royalty recipient after the sale.
+        royalties_earned: Vec<(AccountId, Balance)>,
+        /// The platform fee taken from the price.
+        fee: Balance,
+        /// The collected fees after the sale; unchanged by token sales, whose fee goes to the treasury.
+        collected_fees: Balance,
+    }
+
+    /// Optional descriptive metadata of a content item, stored separately from its record.
+    #[derive(scale::Encode, scale::Decode, Clone, Debug, Default, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct ContentMetadata {
+        /// The title, at most `MAX_TITLE_LENGTH` bytes.
+        pub title: String,
+        /// The description, at most `MAX_DESCRIPTION_LENGTH` bytes.
+        pub description: String,
+        /// A URI where the content can be found, at most `MAX_URI_LENGTH` bytes.
+        pub uri: String,
+    }
+
+    impl ContentMetadata {
+        /// Returns whether every field is within its byte limit.
+        pub fn is_within_limits(&self) -> bool {
+            self.title.len() <= MAX_TITLE_LENGTH
+                && self.description.len() <= MAX_DESCRIPTION_LENGTH
+                && self.uri.len() <= MAX_URI_LENGTH
+        }
+    }
+
+    /// When a content item was registered, recorded by every registration path.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct RegistrationInfo {
+        /// The block in which the content was registered.
+        pub registered_at_block: BlockNumber,
+        /// The block timestamp of the registration.
+        pub registered_at_time: Timestamp,
+        /// The oracle data version in force at registration; see `oracle_data_at_version`.
+        pub oracle_version: u32,
+    }
+
+    /// An owner-defined group of content items, e.g. an album or a photo set.
+    #[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct Collection {
+        /// The account that manages the collection and owns all of its items.
+        pub owner: AccountId,
+        /// The display name of the collection.
+        pub name: String,
+    }
+
+    /// One link in the chain of custody of a content item, recorded by every transfer.
+    #[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct ProvenanceEntry {
+        /// The owner before the transfer.
+        pub from: AccountId,
+        /// The owner after the transfer.
+        pub to: AccountId,
+        /// The block in which the transfer happened.
+        pub block: BlockNumber,
+        /// The block timestamp of the transfer.
+        pub timestamp: Timestamp,
+    }
+
+    /// One completed sale of a content item, recorded by every sale path.
+    #[derive(scale::Encode, scale::Decode, Clone, Copy, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout))]
+    pub struct SaleRecord {
+        /// The new owner.
+        pub buyer: AccountId,
+        /// The previous owner.
+        pub seller: AccountId,
+        /// The price paid, before the platform fee.
+        pub price: Balance,
+        /// The PSP22 token the price was paid in, or `None` for native tokens.
+        pub currency: Option<AccountId>,
+        /// The block timestamp of the sale.
+        pub timestamp: Timestamp,
+    }
+
+    /// A summary of the contract's configuration and state, returned by `contract_info`.
+    #[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ContractInfo {
+        /// The number of admins; `0` once the last admin has renounced.
+        pub admin_count: u32,
+        /// The version of the contract logic (`CONTRACT_VERSION`).
+        pub version: u32,
+        /// The number of registered content records.
+        pub total_supply: u64,
+        /// The first accepted oracle prefix, as returned by `get_oracle_data`.
+        pub oracle_data: String,
+    }
+
+    /// A flattened content record returned by `export_range` for off-chain re-indexing.
+    #[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct ContentExport {
+        /// The unique ID of the content.
+        pub content_id: u64,
+        /// The content hash.
+        pub content_hash: String,
+        /// The current owner, or the last owner if the ownership was renounced.
+        pub owner: AccountId,
+        /// Whether the ownership was renounced.
+        pub renounced: bool,
+        /// The lifecycle status of the content.
+        pub status: ContentStatus,
+        /// The block in which the content was registered, if recorded; see `registration_info`.
+        pub registered_at_block: Option<BlockNumber>,
+        /// The block timestamp of the registration, if recorded.
+        pub registered_at_time: Option<Timestamp>,
     }
 
     /// Defines custom error types for the contract.
@@ -34,344 +975,15972 @@ mod content_ownership {
         CounterOverflow = 3,
         /// Error returned when the content hash is deemed invalid by the oracle.
         InvalidContent = 4,
+        /// Error returned when an account's owned-content counter would overflow or underflow.
+        OwnedCountOverflow = 5,
+        /// Error returned when a batch exceeds `MAX_BATCH_SIZE` items.
+        BatchTooLarge = 6,
+        /// Error returned when an export range covers more than `MAX_EXPORT_RANGE` IDs.
+        RangeTooLarge = 7,
+        /// Error returned when a caller other than the pending admin attempts to accept the admin role.
+        NotPendingAdmin = 8,
+        /// Error returned when removing the last remaining admin.
+        LastAdmin = 9,
+        /// Error returned when an admin action must go through `propose_admin_action` instead.
+        ApprovalRequired = 10,
+        /// Error returned when a pending admin action ID is not found.
+        ActionNotFound = 11,
+        /// Error returned when an admin approves the same action twice.
+        AlreadyApproved = 12,
+        /// Error returned when approving an admin action past its expiry block.
+        ActionExpired = 13,
+        /// Error returned when an approval threshold is zero or exceeds the number of admins.
+        InvalidThreshold = 14,
+        /// Error returned when a state-changing operation is attempted while it is paused.
+        ContractPaused = 15,
+        /// Error returned when a blacklisted account attempts to register content.
+        AccountBlacklisted = 16,
+        /// Error returned when a non-whitelisted account registers content in `WhitelistOnly` mode.
+        NotWhitelisted = 17,
+        /// Error returned when executing a scheduled transfer before its delay has elapsed.
+        TransferNotReady = 18,
+        /// Error returned when no forced transfer is scheduled for a content ID.
+        TransferNotScheduled = 19,
+        /// Error returned when a content hash is already registered.
+        AlreadyRegistered = 20,
+        /// Error returned when accepting or cancelling a transfer offer that does not exist.
+        NoPendingTransfer = 21,
+        /// Error returned when an account other than the offered recipient accepts a transfer offer.
+        NotTransferRecipient = 22,
+        /// Error returned when the stored owner differs from the owner expected by the caller.
+        OwnerMismatch = 23,
+        /// Error returned when a transfer memo exceeds `MAX_MEMO_LENGTH` bytes.
+        MemoTooLong = 24,
+        /// Error returned when transferring content whose ownership was renounced.
+        OwnershipRenounced = 25,
+        /// Error returned when a receiving contract fails or declines `on_content_received`.
+        ReceiverRejected = 26,
+        /// Error returned when transferring content to its current owner.
+        SelfTransfer = 27,
+        /// Error returned when transferring content to the all-zero account.
+        ZeroAddressRecipient = 28,
+        /// Error returned when transferring soulbound content.
+        ContentSoulbound = 29,
+        /// Error returned when transferring, offering, or approving content its owner has locked.
+        ContentLocked = 30,
+        /// Error returned when `transfer_from` is called by an account that is neither the owner nor approved.
+        NotApproved = 31,
+        /// Error returned when an account approves itself as an operator.
+        SelfApproval = 32,
+        /// Error returned when `transfer_from` is called with an approval past its expiry.
+        ApprovalExpired = 33,
+        /// Error returned when a signed permit or transfer intent is submitted after its deadline.
+        PermitExpired = 34,
+        /// Error returned when a signature cannot be recovered or was not made by the expected account.
+        InvalidSignature = 35,
+        /// Error returned when an account's signature nonce would overflow.
+        NonceOverflow = 36,
+        /// Error returned when a signed message uses a nonce other than the signer's current one.
+        InvalidNonce = 37,
+        /// Error returned when content cannot be burned because a forced transfer is scheduled for it.
+        ForcedTransferPending = 38,
+        /// Error returned when an operation requires content whose status is `ContentStatus::Active`.
+        ContentNotActive = 39,
+        /// Error returned when a content status change is not an allowed lifecycle transition.
+        InvalidStatusTransition = 40,
+        /// Error returned when content is under a compliance hold set through `freeze_content`.
+        ContentFrozen = 41,
+        /// Error returned when a new version is registered for, or the owner burns, content that already
+        /// has a newer version.
+        AlreadySuperseded = 42,
+        /// Error returned when a metadata field exceeds its byte limit.
+        MetadataTooLarge = 43,
+        /// Error returned when changing metadata that was permanently locked through `lock_metadata`.
+        MetadataLocked = 44,
+        /// Error returned when more than `MAX_TAGS_PER_CONTENT` distinct tags are set on a content item.
+        TooManyTags = 45,
+        /// Error returned when a tag is empty or longer than `MAX_TAG_LENGTH` bytes after normalization.
+        InvalidTag = 46,
+        /// Error returned when a base URI or URI override exceeds `MAX_URI_LENGTH` bytes.
+        UriTooLong = 47,
+        /// Error returned when registering a derivative of content that requires consent the owner has not given.
+        DerivativeNotApproved = 48,
+        /// Error returned when a collection ID is not found.
+        CollectionNotFound = 49,
+        /// Error returned when adding content that already belongs to a collection.
+        AlreadyInCollection = 50,
+        /// Error returned when removing content that does not belong to the given collection.
+        NotInCollection = 51,
+        /// Error returned when a collection already holds `MAX_COLLECTION_SIZE` items.
+        CollectionFull = 52,
+        /// Error returned when a collection name exceeds `MAX_COLLECTION_NAME_LENGTH` bytes.
+        CollectionNameTooLong = 53,
+        /// Error returned when revealing a registration the caller never committed to, e.g. with the wrong salt.
+        CommitmentNotFound = 54,
+        /// Error returned when revealing a commitment younger than `MIN_REVEAL_DELAY_BLOCKS`.
+        CommitmentTooRecent = 55,
+        /// Error returned when revealing a commitment older than `COMMITMENT_EXPIRY_BLOCKS`.
+        CommitmentExpired = 56,
+        /// Error returned when the caller already has an unexpired commitment with the same value.
+        CommitmentExists = 57,
+        /// Error returned when adding an oracle prefix beyond `MAX_ORACLE_PREFIXES`.
+        TooManyOraclePrefixes = 58,
+        /// Error returned when removing an oracle prefix that is not accepted.
+        OraclePrefixNotFound = 59,
+        /// Error returned when adding an oracle prefix that is already accepted.
+        DuplicateOraclePrefix = 60,
+        /// Error returned when a caller other than the oracle account pushes oracle data.
+        NotOracle = 61,
+        /// Error returned when adding an account that is already an oracle member.
+        AlreadyOracleMember = 62,
+        /// Error returned when removing an account that is not an oracle member.
+        NotOracleMember = 63,
+        /// Error returned when adding an oracle member beyond `MAX_ORACLE_MEMBERS`.
+        TooManyOracleMembers = 64,
+        /// Error returned when changing oracle data, prefixes, the validation strategy, or the validator
+        /// immediately while an oracle delay is configured.
+        OracleDelayActive = 65,
+        /// Error returned when applying or cancelling an oracle proposal while none is pending.
+        NoPendingOracleData = 66,
+        /// Error returned when applying an oracle proposal before its not-before timestamp.
+        OracleDelayNotElapsed = 67,
+        /// Error returned when registering with an attestation past its expiry.
+        AttestationExpired = 68,
+        /// Error returned when registering with an attestation that was already used.
+        AttestationUsed = 69,
+        /// Error returned when registering with a proof while no allowlist root is set.
+        AllowlistNotSet = 70,
+        /// Error returned when an allowlist proof does not link the hash to the root.
+        InvalidProof = 71,
+        /// Error returned when a preimage exceeds `MAX_PREIMAGE_SIZE`.
+        PreimageTooLarge = 72,
+        /// Error returned when registering without a proof of possession while one is required.
+        ProofRequired = 73,
+        /// Error returned when a licensing terms digest exceeds `MAX_LICENSE_TERMS_LENGTH` bytes.
+        LicenseTermsTooLong = 74,
+        /// Error returned when changing or re-locking licensing terms that were locked.
+        LicenseTermsLocked = 75,
+        /// Error returned when locking licensing terms that were never set.
+        LicenseTermsNotSet = 76,
+        /// Error returned when oracle data exceeds `MAX_ORACLE_DATA_LENGTH` bytes.
+        OracleDataTooLong = 77,
+        /// Error returned when a content hash exceeds `MAX_CONTENT_HASH_LENGTH` bytes.
+        ContentHashTooLong = 78,
+        /// Error returned when a registration carries less value than the registration fee.
+        InsufficientFee = 79,
+        /// Error returned when the contract fails to transfer native tokens.
+        TransferFailed = 80,
+        /// Error returned when the fees or the contract balance do not cover a withdrawal.
+        InsufficientContractBalance = 81,
+        /// Error returned when withdrawing a zero amount, or while no fees are collected.
+        NothingToWithdraw = 82,
+        /// Error returned when a native-token amount would overflow.
+        BalanceOverflow = 83,
+        /// Error returned when buying or delisting content that is not listed for sale.
+        NotListed = 84,
+        /// Error returned when listing content for a price of zero, or paying in nothing where value is required.
+        InvalidPrice = 85,
+        /// Error returned when a purchase carries less value than the price.
+        InsufficientPayment = 86,
+        /// Error returned when no escrowed purchase offer exists for the content and buyer.
+        OfferNotFound = 87,
+        /// Error returned when accepting an expired offer, or making one that expires immediately.
+        OfferExpired = 88,
+        /// Error returned when reclaiming an offer that has not expired yet.
+        OfferNotExpired = 89,
+        /// Error returned when the content is being auctioned, or an auction is started on it twice.
+        AuctionActive = 90,
+        /// Error returned when no auction exists for the content.
+        NoAuction = 91,
+        /// Error returned when bidding on an auction past its deadline.
+        AuctionEnded = 92,
+        /// Error returned when settling an auction before its deadline.
+        AuctionNotEnded = 93,
+        /// Error returned when a bid is zero or does not exceed the highest bid by the minimum increment.
+        BidTooLow = 94,
+        /// Error returned when cancelling an auction that already received a bid.
+        AuctionHasBids = 95,
+        /// Error returned when starting an auction with a zero duration.
+        InvalidDuration = 96,
+        /// Error returned when setting a platform fee above `MAX_PLATFORM_FEE_BPS`.
+        FeeTooHigh = 97,
+        /// Error returned when a tip message exceeds `MAX_TIP_MESSAGE_LENGTH` bytes.
+        TipMessageTooLong = 98,
+        /// Error returned when purchasing access to content whose owner does not sell access.
+        AccessNotForSale = 99,
+        /// Error returned when the owner purchases access to, rents, or licenses their own content,
+        /// which they always have the use of.
+        OwnerHasAccess = 100,
+        /// Error returned when a PSP22 token transfer fails, e.g. for lack of balance or allowance.
+        TokenTransferFailed = 101,
+        /// Error returned when paying for a listing in a currency other than the one it is priced in.
+        WrongCurrency = 102,
+        /// Error returned when a bundle ID is not found.
+        BundleNotFound = 103,
+        /// Error returned when buying a bundle whose seller no longer owns every item, or one of whose
+        /// items can no longer change hands.
+        BundleInvalidated = 104,
+        /// Error returned when listing a bundle with no items or with the same item twice.
+        InvalidBundle = 105,
+        /// Error returned when renting content whose owner does not rent it out.
+        RentalNotAvailable = 106,
+        /// Error returned when renting content, reclaiming its rental slot, burning it, or reclaiming a
+        /// front-run registration of it while a rental runs.
+        RentalActive = 107,
+        /// Error returned when reclaiming the rental slot of content that is not rented.
+        NoRental = 108,
+        /// Error returned when content has no loan or loan offer.
+        NoLoan = 109,
+        /// Error returned when content that secures a loan or loan offer would change hands, be locked,
+        /// or be put up for sale.
+        ContentPledged = 110,
+        /// Error returned when funding a loan offer from its due time on.
+        LoanOfferExpired = 111,
+        /// Error returned when funding or cancelling a loan offer that was already funded.
+        LoanAlreadyFunded = 112,
+        /// Error returned when repaying or claiming the collateral of a loan that was never funded.
+        LoanNotFunded = 113,
+        /// Error returned when claiming the collateral of a loan before it is due.
+        LoanNotDue = 114,
+        /// Error returned when repaying a loan from its due time on.
+        LoanOverdue = 115,
+        /// Error returned when setting a royalty above the royalty cap, or a cap above `MAX_ROYALTY_CAP_BPS`.
+        RoyaltyTooHigh = 116,
+        /// Error returned when the caller is not the original creator of the content.
+        NotCreator = 117,
+        /// Error returned when a royalty split is empty, has more than `MAX_ROYALTY_RECIPIENTS` recipients,
+        /// repeats a recipient, has a zero share, or its shares do not sum to the royalty.
+        InvalidRoyaltySplit = 118,
+        /// Error returned when changing a royalty that was permanently locked through `lock_royalty`.
+        RoyaltyLocked = 119,
+        /// Error returned when transferring marketplace-only content other than through a sale,
+        /// to a recipient the creator did not exempt.
+        MarketplaceOnly = 120,
+        /// Error returned when making content marketplace-only after it has already changed hands.
+        AlreadyTransferred = 121,
+        /// Error returned when the account holds no license on the content.
+        NoLicense = 122,
+        /// Error returned when a license would expire, or has expired, by the current block timestamp.
+        LicenseExpired = 123,
+        /// Error returned when buying a license on content whose owner does not sell licenses.
+        LicenseNotOffered = 124,
+        /// Error returned when licensing content that another account holds an active exclusive
+        /// license on, or licensing it exclusively while another account holds an active license.
+        ExclusiveLicenseActive = 125,
+        /// Error returned when more than `MAX_LICENSEES_PER_CONTENT` accounts would hold a license on the content.
+        TooManyLicensees = 126,
+        /// Error returned when transferring a license that was not granted as transferable.
+        LicenseNotTransferable = 127,
+        /// Error returned when transferring a license to an account that already holds one on the content,
+        /// or buying a license while holding an active exclusive one.
+        AlreadyLicensed = 128,
+        /// Error returned when reclaiming a front-run registration whose content secures a funded loan.
+        LoanActive = 129,
     }
 
     /// A type alias for the contract's result type.
     /// It wraps the `Result` type with the contract's custom `Error` enum.
     pub type Result<T> = core::result::Result<T, Error>;
 
-    /// The `ContentOwnership` contract manages digital content and its ownership.
-    /// It provides functionality for:
-    /// - Registering new content.
-    /// - Transferring ownership of content.
-    /// - Validating content using oracle data.
-    #[ink(storage)]
-    pub struct ContentOwnership {
-        /// The administrator of the contract, typically the deployer.
-        admin: AccountId,
-        /// Oracle data used for validating content (e.g., a hash of licensing terms).
-        oracle_data: String,
-        /// A mapping of content IDs to their corresponding content records.
-        contents: Mapping<u64, Content>,
-        /// A counter for generating unique content IDs.
-        next_content_id: u64,
-        /// A mapping of content hashes to their corresponding content IDs.
-        content_hash_to_id: BTreeMap<String, u64>,
+    /// The recipients of a royalty and their shares in basis points of the sale price.
+    pub type RoyaltySplit = Vec<(AccountId, u16)>;
+
+    /// Emitted when a new content record is registered.
+    ///
+    /// Topics: `content_id`, `owner`.
+    #[ink(event)]
+    pub struct ContentRegistered {
+        /// The unique ID assigned to the content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The registered content hash.
+        content_hash: String,
+        /// The AccountId that owns the newly registered content.
+        #[ink(topic)]
+        owner: AccountId,
+        /// The AccountId that submitted the registration; equals `owner` unless registered on their behalf.
+        registrar: AccountId,
+        /// The block in which the content was registered.
+        registered_at_block: BlockNumber,
+        /// The block timestamp of the registration.
+        registered_at_time: Timestamp,
+        /// The contract-wide event sequence number.
+        seq: u64,
     }
 
-    //----------------------------------
-    // Default Implementation
-    //----------------------------------
+    /// Emitted whenever ownership of a content record changes hands.
+    /// Every transfer path goes through `apply_transfer`, so indexers only need to follow this event.
+    ///
+    /// Topics: `content_id`, `from`, `to`.
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        /// The unique ID of the transferred content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The previous owner.
+        #[ink(topic)]
+        from: AccountId,
+        /// The new owner.
+        #[ink(topic)]
+        to: AccountId,
+        /// The memo referencing an off-chain agreement, if the transfer carried one.
+        memo: Option<String>,
+        /// The account that submitted the owner's signed transfer intent, if the transfer was relayed.
+        relayed_by: Option<AccountId>,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when the admin updates the oracle data.
+    /// Emitted even if `new_data` equals `old_data`, so every admin call leaves an audit trace.
+    #[ink(event)]
+    pub struct OracleDataUpdated {
+        /// The account that performed the update.
+        updated_by: AccountId,
+        /// The first oracle prefix in force before the update, or an empty string if there was none.
+        old_data: String,
+        /// The only oracle prefix in force after the update.
+        new_data: String,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when the oracle account replaces the oracle data through `push_oracle_data`.
+    /// Admin updates emit `OracleDataUpdated` instead.
+    #[ink(event)]
+    pub struct OracleDataPushed {
+        /// The oracle account that pushed the data.
+        #[ink(topic)]
+        oracle: AccountId,
+        /// The first oracle prefix in force before the push, or an empty string if there was none.
+        old_data: String,
+        /// The only oracle prefix in force after the push.
+        new_data: String,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an oracle change is proposed and its timelock starts.
+    #[ink(event)]
+    pub struct OracleDataProposed {
+        /// The account that proposed the change.
+        #[ink(topic)]
+        proposed_by: AccountId,
+        /// The proposed change.
+        change: OracleChange,
+        /// The block timestamp from which the proposal can be applied.
+        not_before: Timestamp,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a timelocked oracle proposal is adopted through `apply_oracle_data`.
+    #[ink(event)]
+    pub struct OracleProposalApplied {
+        /// The account that applied the proposal.
+        applied_by: AccountId,
+        /// The account that proposed it.
+        #[ink(topic)]
+        proposed_by: AccountId,
+        /// The applied change.
+        change: OracleChange,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin cancels a pending oracle proposal.
+    #[ink(event)]
+    pub struct OracleProposalCancelled {
+        /// The admin that cancelled the proposal.
+        #[ink(topic)]
+        cancelled_by: AccountId,
+        /// The discarded change.
+        change: OracleChange,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin changes the oracle delay.
+    #[ink(event)]
+    pub struct OracleDelayChanged {
+        /// The admin that changed the delay.
+        by: AccountId,
+        /// The new delay, in milliseconds.
+        delay: Timestamp,
+        /// The block timestamp from which the new delay applies; later than now for a lower delay.
+        effective_at: Timestamp,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an oracle member submits data for the current round.
+    #[ink(event)]
+    pub struct OracleDataSubmitted {
+        /// The submitting member.
+        #[ink(topic)]
+        member: AccountId,
+        /// The round the submission counts towards.
+        round: u64,
+        /// The submitted data.
+        data: String,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when enough oracle members submitted identical data and it was adopted.
+    #[ink(event)]
+    pub struct OracleQuorumReached {
+        /// The settled round.
+        round: u64,
+        /// The adopted oracle data.
+        data: String,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when every oracle member submitted data for a round without reaching quorum.
+    /// The previous oracle data stays in force and a new round starts.
+    #[ink(event)]
+    pub struct OracleRoundFailed {
+        /// The settled round.
+        round: u64,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin adds or removes an oracle member.
+    #[ink(event)]
+    pub struct OracleMembershipChanged {
+        /// The admin that changed the membership.
+        by: AccountId,
+        /// The added or removed member.
+        #[ink(topic)]
+        member: AccountId,
+        /// `true` if the member was added, `false` if removed.
+        added: bool,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin changes the oracle quorum.
+    #[ink(event)]
+    pub struct OracleQuorumChanged {
+        /// The admin that changed the quorum.
+        by: AccountId,
+        /// The number of identical submissions now needed.
+        quorum: u32,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin sets or clears the oracle account.
+    #[ink(event)]
+    pub struct OracleAccountChanged {
+        /// The admin that changed the account.
+        #[ink(topic)]
+        by: AccountId,
+        /// The new oracle account, `None` if cleared.
+        account: Option<AccountId>,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin adds an accepted oracle prefix.
+    #[ink(event)]
+    pub struct OraclePrefixAdded {
+        /// The account that performed the update.
+        updated_by: AccountId,
+        /// The new prefix.
+        prefix: String,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin removes an accepted oracle prefix.
+    #[ink(event)]
+    pub struct OraclePrefixRemoved {
+        /// The account that performed the update.
+        updated_by: AccountId,
+        /// The removed prefix.
+        prefix: String,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin changes the base URI through `set_base_uri`.
+    #[ink(event)]
+    pub struct BaseUriUpdated {
+        /// The admin that performed the update.
+        updated_by: AccountId,
+        /// The base URI in force before the update.
+        old_uri: String,
+        /// The base URI in force after the update.
+        new_uri: String,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner sets or clears (`uri` is `None`) the URI override of a content item.
+    #[ink(event)]
+    pub struct ContentUriOverrideSet {
+        /// The content whose URI changed.
+        #[ink(topic)]
+        content_id: u64,
+        /// The new override, or `None` if the content now resolves through the base URI.
+        uri: Option<String>,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when the admin proposes a successor, or cancels a proposal (`proposed` is `None`).
+    #[ink(event)]
+    pub struct AdminProposed {
+        /// The admin making the proposal.
+        #[ink(topic)]
+        admin: AccountId,
+        /// The proposed successor, or `None` if the proposal was cancelled.
+        #[ink(topic)]
+        proposed: Option<AccountId>,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a proposed admin accepts the role, taking over the proposer's seat.
+    #[ink(event)]
+    pub struct AdminChanged {
+        /// The admin that proposed the handover and left the admin set.
+        #[ink(topic)]
+        old_admin: AccountId,
+        /// The new admin.
+        #[ink(topic)]
+        new_admin: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when the admin grants a role to an account.
+    #[ink(event)]
+    pub struct RoleGranted {
+        /// The granted role.
+        #[ink(topic)]
+        role: Role,
+        /// The account receiving the role.
+        #[ink(topic)]
+        account: AccountId,
+        /// The admin that granted the role.
+        granted_by: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when the admin revokes a role from an account.
+    #[ink(event)]
+    pub struct RoleRevoked {
+        /// The revoked role.
+        #[ink(topic)]
+        role: Role,
+        /// The account losing the role.
+        #[ink(topic)]
+        account: AccountId,
+        /// The admin that revoked the role.
+        revoked_by: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin proposes a sensitive admin action.
+    #[ink(event)]
+    pub struct AdminActionProposed {
+        /// The ID of the pending action.
+        #[ink(topic)]
+        action_id: u64,
+        /// The admin that proposed the action.
+        #[ink(topic)]
+        proposer: AccountId,
+        /// The proposed action.
+        action: AdminAction,
+        /// The last block at which the action can still be approved.
+        expires_at: BlockNumber,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin approves a pending admin action.
+    #[ink(event)]
+    pub struct AdminActionApproved {
+        /// The ID of the pending action.
+        #[ink(topic)]
+        action_id: u64,
+        /// The approving admin.
+        #[ink(topic)]
+        approver: AccountId,
+        /// The number of current admins that have approved the action.
+        approvals: u32,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a pending admin action reaches the approval threshold and is executed.
+    #[ink(event)]
+    pub struct AdminActionExecuted {
+        /// The ID of the executed action.
+        #[ink(topic)]
+        action_id: u64,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin pauses or unpauses a single operation through `set_paused`.
+    #[ink(event)]
+    pub struct PauseStateChanged {
+        /// The admin that changed the pause state.
+        #[ink(topic)]
+        account: AccountId,
+        /// The affected operation.
+        op: PausableOp,
+        /// Whether the operation is now paused.
+        paused: bool,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin pauses every operation.
+    #[ink(event)]
+    pub struct Paused {
+        /// The admin that paused the contract.
+        #[ink(topic)]
+        account: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin unpauses every operation.
+    #[ink(event)]
+    pub struct Unpaused {
+        /// The admin that unpaused the contract.
+        #[ink(topic)]
+        account: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin blacklists an account from registering content.
+    #[ink(event)]
+    pub struct Blacklisted {
+        /// The blacklisted account.
+        #[ink(topic)]
+        account: AccountId,
+        /// The admin that blacklisted it.
+        by: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin removes an account from the blacklist.
+    #[ink(event)]
+    pub struct Unblacklisted {
+        /// The account removed from the blacklist.
+        #[ink(topic)]
+        account: AccountId,
+        /// The admin that removed it.
+        by: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin switches the registration mode.
+    #[ink(event)]
+    pub struct RegistrationModeChanged {
+        /// The admin that changed the mode.
+        #[ink(topic)]
+        by: AccountId,
+        /// The new registration mode.
+        mode: RegistrationMode,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin changes the registration fee.
+    #[ink(event)]
+    pub struct RegistrationFeeChanged {
+        /// The admin that changed the fee.
+        #[ink(topic)]
+        by: AccountId,
+        /// The new fee per registration; zero makes registration free.
+        fee: Balance,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin changes the platform fees taken from sales.
+    #[ink(event)]
+    pub struct PlatformFeeChanged {
+        /// The admin that changed the fees.
+        #[ink(topic)]
+        by: AccountId,
+        /// The new fee on the first sale of a content item, in basis points of the sale price.
+        primary_bps: u16,
+        /// The new fee on every later sale and on other payments, in basis points of the price.
+        secondary_bps: u16,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin changes the cap on royalties.
+    #[ink(event)]
+    pub struct MaxRoyaltyChanged {
+        /// The admin that changed the cap.
+        #[ink(topic)]
+        by: AccountId,
+        /// The new cap in basis points of the sale price.
+        bps: u16,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a creator sets the resale royalty of a content item through `set_royalty`.
+    #[ink(event)]
+    pub struct RoyaltySet {
+        /// The content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The creator.
+        #[ink(topic)]
+        creator: AccountId,
+        /// The new royalty in basis points of the sale price, zero for none.
+        bps: u16,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a creator splits the royalty of a content item through `set_royalty_split`.
+    #[ink(event)]
+    pub struct RoyaltySplitSet {
+        /// The content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The creator.
+        #[ink(topic)]
+        creator: AccountId,
+        /// The recipients and their shares in basis points of the sale price.
+        recipients: RoyaltySplit,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a creator permanently locks the royalty of a content item through `lock_royalty`.
+    #[ink(event)]
+    pub struct RoyaltyLocked {
+        /// The content whose royalty was locked.
+        #[ink(topic)]
+        content_id: u64,
+        /// The creator that locked it.
+        locked_by: AccountId,
+        /// The royalty locked in, in basis points of the sale price.
+        bps: u16,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a creator turns marketplace-only transfers of a content item on or off.
+    #[ink(event)]
+    pub struct MarketplaceOnlyChanged {
+        /// The content.
+        #[ink(topic)]
+        content_id: u64,
+        /// Whether ownership can now only change through a sale.
+        enforced: bool,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a creator exempts a recipient from marketplace-only transfers, or lifts the exemption.
+    #[ink(event)]
+    pub struct TransferExemptionChanged {
+        /// The content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The recipient.
+        #[ink(topic)]
+        account: AccountId,
+        /// Whether plain transfers to the recipient are now allowed.
+        exempt: bool,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted for each recipient a sale pays a royalty to, before the event of the sale itself.
+    #[ink(event)]
+    pub struct RoyaltyPaid {
+        /// The sold content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The account credited with the royalty, or paid it in the token of a PSP22 sale.
+        #[ink(topic)]
+        recipient: AccountId,
+        /// The royalty paid.
+        amount: Balance,
+        /// The PSP22 token the royalty was paid in, or `None` for native tokens.
+        currency: Option<AccountId>,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin changes the treasury account that receives withdrawn fees.
+    #[ink(event)]
+    pub struct TreasuryChanged {
+        /// The admin that changed the treasury.
+        #[ink(topic)]
+        by: AccountId,
+        /// The new treasury account.
+        treasury: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when collected fees are sent to the treasury.
+    #[ink(event)]
+    pub struct FeesWithdrawn {
+        /// The admin that withdrew the fees.
+        #[ink(topic)]
+        by: AccountId,
+        /// The treasury account that received them.
+        #[ink(topic)]
+        treasury: AccountId,
+        /// The amount withdrawn.
+        amount: Balance,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin turns the proof-of-possession requirement on or off.
+    #[ink(event)]
+    pub struct PopRequirementChanged {
+        /// The admin that changed the requirement.
+        #[ink(topic)]
+        by: AccountId,
+        /// Whether registrations must now carry a proof of possession.
+        required: bool,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin changes how `HashScheme::Other` hashes are validated.
+    #[ink(event)]
+    pub struct ValidationStrategyChanged {
+        /// The admin that changed the strategy.
+        #[ink(topic)]
+        by: AccountId,
+        /// The new validation strategy.
+        strategy: ValidationStrategy,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin turns re-validation at transfer time on or off.
+    #[ink(event)]
+    pub struct RevalidateOnTransferChanged {
+        /// The admin that changed the setting.
+        #[ink(topic)]
+        by: AccountId,
+        /// Whether transfers now re-validate the content hash.
+        enabled: bool,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin sets or clears the external validator contract.
+    #[ink(event)]
+    pub struct ValidatorChanged {
+        /// The admin that changed the validator.
+        #[ink(topic)]
+        by: AccountId,
+        /// The new validator contract, `None` if cleared.
+        validator: Option<AccountId>,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin replaces the allowlist Merkle root.
+    #[ink(event)]
+    pub struct AllowlistRootUpdated {
+        /// The admin that replaced the root.
+        #[ink(topic)]
+        by: AccountId,
+        /// The new Merkle root.
+        root: Hash,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin sets or clears the attestor public key.
+    #[ink(event)]
+    pub struct AttestorChanged {
+        /// The admin that changed the attestor.
+        #[ink(topic)]
+        by: AccountId,
+        /// The new compressed ECDSA public key, `None` if cleared.
+        attestor: Option<[u8; 33]>,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin adds an account to the registrar whitelist.
+    #[ink(event)]
+    pub struct RegistrarAdded {
+        /// The whitelisted account.
+        #[ink(topic)]
+        account: AccountId,
+        /// The admin that added it.
+        by: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin removes an account from the registrar whitelist.
+    #[ink(event)]
+    pub struct RegistrarRemoved {
+        /// The account removed from the whitelist.
+        #[ink(topic)]
+        account: AccountId,
+        /// The admin that removed it.
+        by: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin adds another account to the admin set.
+    #[ink(event)]
+    pub struct AdminAdded {
+        /// The account added to the admin set.
+        #[ink(topic)]
+        admin: AccountId,
+        /// The admin that added it.
+        added_by: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin removes another account from the admin set.
+    #[ink(event)]
+    pub struct AdminRemoved {
+        /// The account removed from the admin set.
+        #[ink(topic)]
+        admin: AccountId,
+        /// The admin that removed it.
+        removed_by: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin gives up their seat through `renounce_admin`.
+    #[ink(event)]
+    pub struct AdminRenounced {
+        /// The admin that renounced the role.
+        #[ink(topic)]
+        admin: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin schedules a forced transfer, giving the current owner advance warning.
+    #[ink(event)]
+    pub struct TransferScheduled {
+        /// The content to be transferred.
+        #[ink(topic)]
+        content_id: u64,
+        /// The owner at scheduling time.
+        #[ink(topic)]
+        owner: AccountId,
+        /// The account that will receive the content.
+        new_owner: AccountId,
+        /// A hash of the document justifying the transfer.
+        reason_hash: String,
+        /// The admin that scheduled the transfer.
+        scheduled_by: AccountId,
+        /// The block timestamp from which the transfer may be executed.
+        executable_at: Timestamp,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin cancels a scheduled forced transfer.
+    #[ink(event)]
+    pub struct ScheduledTransferCancelled {
+        /// The content whose scheduled transfer was cancelled.
+        #[ink(topic)]
+        content_id: u64,
+        /// The admin that cancelled it.
+        cancelled_by: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a scheduled forced transfer is executed, right after `OwnershipTransferred`.
+    #[ink(event)]
+    pub struct ScheduledTransferExecuted {
+        /// The content that was transferred.
+        #[ink(topic)]
+        content_id: u64,
+        /// The admin that executed the transfer.
+        executed_by: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin changes the delay applied to newly scheduled forced transfers.
+    #[ink(event)]
+    pub struct ForcedTransferDelayChanged {
+        /// The admin that changed the delay.
+        #[ink(topic)]
+        by: AccountId,
+        /// The new delay in milliseconds.
+        delay: Timestamp,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner releases a content item through `renounce_ownership`.
+    #[ink(event)]
+    pub struct ContentRenounced {
+        /// The renounced content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The owner that renounced it.
+        #[ink(topic)]
+        former_owner: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner burns a content item through `burn_content`.
+    #[ink(event)]
+    pub struct ContentBurned {
+        /// The burned content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The owner that burned it.
+        #[ink(topic)]
+        owner: AccountId,
+        /// The content hash that became available for registration again.
+        content_hash: String,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner replaces the content hash of a content item through `update_content_hash`.
+    #[ink(event)]
+    pub struct ContentHashUpdated {
+        /// The content whose hash changed.
+        #[ink(topic)]
+        content_id: u64,
+        /// The replaced hash, now the latest entry of `hash_history`.
+        old_hash: String,
+        /// The new hash.
+        new_hash: String,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when `register_new_version` links a newly registered content item to its previous version,
+    /// right after `ContentRegistered`.
+    #[ink(event)]
+    pub struct NewVersionRegistered {
+        /// The new version.
+        #[ink(topic)]
+        content_id: u64,
+        /// The version it supersedes.
+        #[ink(topic)]
+        supersedes: u64,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when the metadata of a content item is set at registration or through `set_metadata`.
+    #[ink(event)]
+    pub struct MetadataUpdated {
+        /// The content whose metadata changed.
+        #[ink(topic)]
+        content_id: u64,
+        /// The new metadata.
+        metadata: ContentMetadata,
+        /// The account that set it.
+        updated_by: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner permanently locks the metadata of a content item through `lock_metadata`.
+    #[ink(event)]
+    pub struct MetadataLocked {
+        /// The content whose metadata was locked.
+        #[ink(topic)]
+        content_id: u64,
+        /// The owner that locked it.
+        locked_by: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner sets or clears the licensing terms of a content item through `set_license_terms`.
+    #[ink(event)]
+    pub struct LicenseTermsUpdated {
+        /// The content whose licensing terms changed.
+        #[ink(topic)]
+        content_id: u64,
+        /// The new terms digest, or `None` if cleared.
+        terms_hash: Option<String>,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner permanently locks the licensing terms of a content item through `lock_license_terms`.
+    #[ink(event)]
+    pub struct LicenseTermsLocked {
+        /// The content whose licensing terms were locked.
+        #[ink(topic)]
+        content_id: u64,
+        /// The owner that locked them.
+        locked_by: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner replaces the tags of a content item through `set_tags`.
+    #[ink(event)]
+    pub struct TagsUpdated {
+        /// The content whose tags changed.
+        #[ink(topic)]
+        content_id: u64,
+        /// The new, normalized tags.
+        tags: Vec<String>,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when `register_derivative` links a newly registered content item to its parent,
+    /// right after `ContentRegistered`.
+    #[ink(event)]
+    pub struct DerivativeRegistered {
+        /// The derivative.
+        #[ink(topic)]
+        content_id: u64,
+        /// The content it derives from.
+        #[ink(topic)]
+        parent_id: u64,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner requires or stops requiring consent for derivatives of a content item.
+    #[ink(event)]
+    pub struct DerivativeConsentChanged {
+        /// The parent content.
+        #[ink(topic)]
+        content_id: u64,
+        /// Whether derivatives now require `approve_derivative`.
+        required: bool,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner allows an account to register one derivative of a content item.
+    #[ink(event)]
+    pub struct DerivativeApproved {
+        /// The parent content.
+        #[ink(topic)]
+        parent_id: u64,
+        /// The account allowed to register a derivative.
+        #[ink(topic)]
+        account: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an account commits to a future registration with `commit_registration`.
+    #[ink(event)]
+    pub struct RegistrationCommitted {
+        /// The committing account.
+        #[ink(topic)]
+        committer: AccountId,
+        /// The commitment, hiding the content hash.
+        commitment: Hash,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when `reveal_registration` registers, or reclaims from a front-runner, a content item.
+    #[ink(event)]
+    pub struct RegistrationRevealed {
+        /// The revealed content item.
+        #[ink(topic)]
+        content_id: u64,
+        /// The committer, now owning the content.
+        #[ink(topic)]
+        owner: AccountId,
+        /// The account that had registered the hash after the commitment, if it was reclaimed.
+        reclaimed_from: Option<AccountId>,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an account creates a collection.
+    #[ink(event)]
+    pub struct CollectionCreated {
+        /// The new collection.
+        #[ink(topic)]
+        collection_id: u64,
+        /// The account that owns the collection.
+        #[ink(topic)]
+        owner: AccountId,
+        /// The collection name.
+        name: String,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a content item is added to a collection.
+    #[ink(event)]
+    pub struct CollectionItemAdded {
+        /// The collection.
+        #[ink(topic)]
+        collection_id: u64,
+        /// The added content item.
+        #[ink(topic)]
+        content_id: u64,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a content item leaves a collection, either through `remove_from_collection` or
+    /// automatically when it is transferred away, renounced, or burned on its own.
+    #[ink(event)]
+    pub struct CollectionItemRemoved {
+        /// The collection.
+        #[ink(topic)]
+        collection_id: u64,
+        /// The removed content item.
+        #[ink(topic)]
+        content_id: u64,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when `transfer_collection` moves a collection, after the `OwnershipTransferred`
+    /// events of its items.
+    #[ink(event)]
+    pub struct CollectionTransferred {
+        /// The collection.
+        #[ink(topic)]
+        collection_id: u64,
+        /// The previous owner.
+        #[ink(topic)]
+        from: AccountId,
+        /// The new owner.
+        #[ink(topic)]
+        to: AccountId,
+        /// The number of items moved with the collection.
+        items: u32,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin changes the lifecycle status of a content item.
+    #[ink(event)]
+    pub struct ContentStatusChanged {
+        /// The content whose status changed.
+        #[ink(topic)]
+        content_id: u64,
+        /// The status before the change.
+        old_status: ContentStatus,
+        /// The status after the change.
+        new_status: ContentStatus,
+        /// The admin that changed it.
+        changed_by: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin or moderator revokes a content item through `revoke_content`.
+    #[ink(event)]
+    pub struct ContentRevoked {
+        /// The revoked content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The hash of the off-chain reason for the revocation, e.g. an infringement report.
+        reason_hash: String,
+        /// The account that revoked it.
+        revoked_by: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a revocation is lifted through `unrevoke_content`.
+    #[ink(event)]
+    pub struct ContentUnrevoked {
+        /// The reinstated content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The account that lifted the revocation.
+        unrevoked_by: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin or moderator freezes or unfreezes a content item.
+    #[ink(event)]
+    pub struct ContentFreezeChanged {
+        /// The content whose freeze changed.
+        #[ink(topic)]
+        content_id: u64,
+        /// Whether the content is now frozen.
+        frozen: bool,
+        /// The account that changed the freeze.
+        changed_by: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner locks or unlocks a content item.
+    #[ink(event)]
+    pub struct ContentLockChanged {
+        /// The content whose lock changed.
+        #[ink(topic)]
+        content_id: u64,
+        /// The block timestamp until which the content is locked (`Timestamp::MAX` for no expiry),
+        /// or `None` once unlocked.
+        locked_until: Option<Timestamp>,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner approves an operator for a content item, or revokes the approval.
+    #[ink(event)]
+    pub struct Approval {
+        /// The content the approval applies to.
+        #[ink(topic)]
+        content_id: u64,
+        /// The owner granting or revoking the approval.
+        #[ink(topic)]
+        owner: AccountId,
+        /// The approved operator, or `None` once revoked.
+        #[ink(topic)]
+        operator: Option<AccountId>,
+        /// The block timestamp from which the approval is no longer valid, if it expires.
+        expires_at: Option<Timestamp>,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner approves or revokes an operator for all of their content.
+    #[ink(event)]
+    pub struct ApprovalForAll {
+        /// The owner granting or revoking the approval.
+        #[ink(topic)]
+        owner: AccountId,
+        /// The operator concerned.
+        #[ink(topic)]
+        operator: AccountId,
+        /// Whether the operator is now approved.
+        approved: bool,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner offers a content item to a recipient through `offer_transfer`.
+    #[ink(event)]
+    pub struct TransferOffered {
+        /// The content on offer.
+        #[ink(topic)]
+        content_id: u64,
+        /// The current owner making the offer.
+        #[ink(topic)]
+        from: AccountId,
+        /// The recipient that may accept the offer.
+        #[ink(topic)]
+        to: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner withdraws a transfer offer through `cancel_transfer_offer`.
+    #[ink(event)]
+    pub struct TransferOfferCancelled {
+        /// The content whose offer was withdrawn.
+        #[ink(topic)]
+        content_id: u64,
+        /// The recipient of the withdrawn offer.
+        to: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a recipient accepts a transfer offer, right after `OwnershipTransferred`.
+    #[ink(event)]
+    pub struct TransferOfferAccepted {
+        /// The content that was transferred.
+        #[ink(topic)]
+        content_id: u64,
+        /// The recipient that accepted the offer.
+        #[ink(topic)]
+        to: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner lists a content item for sale, or changes its price.
+    #[ink(event)]
+    pub struct ContentListed {
+        /// The listed content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The owner selling it.
+        #[ink(topic)]
+        seller: AccountId,
+        /// The asking price.
+        price: Balance,
+        /// The PSP22 token the price is in, or `None` for native tokens.
+        currency: Option<AccountId>,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner withdraws a content item from sale through `delist`.
+    #[ink(event)]
+    pub struct ContentDelisted {
+        /// The delisted content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a listed content item is bought through `buy`, or a Dutch auction through
+    /// `buy_dutch`, right after `OwnershipTransferred`.
+    #[ink(event)]
+    pub struct ContentSold {
+        /// The sold content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The previous owner, credited with the price minus the platform fee.
+        #[ink(topic)]
+        seller: AccountId,
+        /// The new owner.
+        #[ink(topic)]
+        buyer: AccountId,
+        /// The price paid.
+        price: Balance,
+        /// The PSP22 token the price was paid in, or `None` for native tokens.
+        currency: Option<AccountId>,
+        /// The platform fee taken from the price.
+        fee: Balance,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a buyer escrows a purchase offer through `make_offer`, or tops one up.
+    #[ink(event)]
+    pub struct OfferMade {
+        /// The content the offer is for.
+        #[ink(topic)]
+        content_id: u64,
+        /// The buyer making the offer.
+        #[ink(topic)]
+        buyer: AccountId,
+        /// The total escrowed amount of the buyer's offer.
+        amount: Balance,
+        /// The block timestamp from which the offer expires.
+        expires_at: Timestamp,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a buyer withdraws a purchase offer and is credited its escrow.
+    #[ink(event)]
+    pub struct OfferWithdrawn {
+        /// The content the offer was for.
+        #[ink(topic)]
+        content_id: u64,
+        /// The buyer that withdrew the offer.
+        #[ink(topic)]
+        buyer: AccountId,
+        /// The escrowed amount credited back.
+        amount: Balance,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an expired purchase offer is swept through `reclaim_expired_offer` and the buyer
+    /// is credited its escrow.
+    #[ink(event)]
+    pub struct OfferReclaimed {
+        /// The content the offer was for.
+        #[ink(topic)]
+        content_id: u64,
+        /// The buyer credited with the escrow.
+        #[ink(topic)]
+        buyer: AccountId,
+        /// The escrowed amount credited back.
+        amount: Balance,
+        /// The account that swept the offer.
+        reclaimed_by: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner rejects a purchase offer and the buyer is credited its escrow.
+    #[ink(event)]
+    pub struct OfferRejected {
+        /// The content the offer was for.
+        #[ink(topic)]
+        content_id: u64,
+        /// The buyer whose offer was rejected.
+        #[ink(topic)]
+        buyer: AccountId,
+        /// The escrowed amount credited back.
+        amount: Balance,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner accepts a purchase offer, right after `OwnershipTransferred`.
+    #[ink(event)]
+    pub struct OfferAccepted {
+        /// The sold content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The previous owner, credited with the escrow minus the platform fee.
+        #[ink(topic)]
+        seller: AccountId,
+        /// The buyer, now the owner.
+        #[ink(topic)]
+        buyer: AccountId,
+        /// The price paid.
+        amount: Balance,
+        /// The platform fee taken from the price.
+        fee: Balance,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an account tips the owner of a content item through `tip`.
+    #[ink(event)]
+    pub struct Tipped {
+        /// The tipped content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The account that sent the tip.
+        #[ink(topic)]
+        from: AccountId,
+        /// The owner credited with the tip.
+        #[ink(topic)]
+        to: AccountId,
+        /// The tipped amount.
+        amount: Balance,
+        /// The message attached to the tip, if any.
+        message: Option<String>,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner changes the terms on which access to a content item is sold.
+    #[ink(event)]
+    pub struct AccessPriceChanged {
+        /// The content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The new terms, or `None` if access is no longer sold.
+        terms: Option<AccessTerms>,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an account purchases access to a content item through `purchase_access`.
+    #[ink(event)]
+    pub struct AccessPurchased {
+        /// The content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The account granted access.
+        #[ink(topic)]
+        buyer: AccountId,
+        /// The owner, credited with the price minus the platform fee.
+        owner: AccountId,
+        /// The price paid.
+        price: Balance,
+        /// The platform fee taken from the price.
+        fee: Balance,
+        /// The block timestamp from which access ends, `Timestamp::MAX` for good.
+        expires_at: Timestamp,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner sets or clears the rental terms of a content item.
+    #[ink(event)]
+    pub struct RentalTermsChanged {
+        /// The content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The new terms, or `None` if the content is no longer rented out.
+        terms: Option<RentalTerms>,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an account rents a content item through `rent`.
+    #[ink(event)]
+    pub struct ContentRented {
+        /// The rented content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The new renter.
+        #[ink(topic)]
+        renter: AccountId,
+        /// The owner, credited with the price minus the platform fee.
+        owner: AccountId,
+        /// The price paid for all rented days.
+        price: Balance,
+        /// The platform fee taken from the price.
+        fee: Balance,
+        /// The block timestamp from which the rental is over.
+        expires_at: Timestamp,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an expired rental slot is cleared through `reclaim_rental`.
+    #[ink(event)]
+    pub struct RentalReclaimed {
+        /// The content that was rented.
+        #[ink(topic)]
+        content_id: u64,
+        /// The former renter.
+        #[ink(topic)]
+        renter: AccountId,
+        /// The account that cleared the slot.
+        reclaimed_by: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an account withdraws its credit through `withdraw`.
+    #[ink(event)]
+    pub struct CreditWithdrawn {
+        /// The account that withdrew.
+        #[ink(topic)]
+        account: AccountId,
+        /// The amount transferred out.
+        amount: Balance,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner starts an English auction through `start_auction`.
+    #[ink(event)]
+    pub struct AuctionStarted {
+        /// The auctioned content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The owner selling the content.
+        #[ink(topic)]
+        seller: AccountId,
+        /// The lowest winning bid.
+        reserve_price: Balance,
+        /// How much a bid must exceed the highest bid by.
+        min_increment: Balance,
+        /// The block timestamp the auction ends at.
+        ends_at: Timestamp,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a bid becomes the highest bid of an auction.
+    #[ink(event)]
+    pub struct BidPlaced {
+        /// The auctioned content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The new highest bidder.
+        #[ink(topic)]
+        bidder: AccountId,
+        /// The escrowed bid.
+        amount: Balance,
+        /// The block timestamp the auction ends at, possibly extended by this bid.
+        ends_at: Timestamp,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an auction is settled through `settle_auction`, after `OwnershipTransferred`
+    /// if the item was sold.
+    #[ink(event)]
+    pub struct AuctionSettled {
+        /// The auctioned content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The owner that started the auction.
+        #[ink(topic)]
+        seller: AccountId,
+        /// The new owner, or `None` if the item stayed with the seller and the highest bid, if any,
+        /// was credited back to its bidder.
+        winner: Option<AccountId>,
+        /// The highest bid.
+        amount: Balance,
+        /// The platform fee taken from the winning bid, zero if the item was not sold.
+        fee: Balance,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner starts a declining-price sale through `start_dutch_auction`.
+    #[ink(event)]
+    pub struct DutchAuctionStarted {
+        /// The content for sale.
+        #[ink(topic)]
+        content_id: u64,
+        /// The owner selling the content.
+        #[ink(topic)]
+        seller: AccountId,
+        /// The price at the start.
+        start_price: Balance,
+        /// The price at the end.
+        end_price: Balance,
+        /// The block timestamp the sale ends at.
+        ends_at: Timestamp,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner cancels a declining-price sale.
+    #[ink(event)]
+    pub struct DutchAuctionCancelled {
+        /// The content that was for sale.
+        #[ink(topic)]
+        content_id: u64,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner lists several content items for sale together through `list_bundle`.
+    #[ink(event)]
+    pub struct BundleListed {
+        /// The new bundle.
+        #[ink(topic)]
+        bundle_id: u64,
+        /// The owner selling it.
+        #[ink(topic)]
+        seller: AccountId,
+        /// The items in the bundle.
+        content_ids: Vec<u64>,
+        /// The price of the whole bundle.
+        price: Balance,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a bundle is bought through `buy_bundle`, after the `OwnershipTransferred` events
+    /// of its items.
+    #[ink(event)]
+    pub struct BundleSold {
+        /// The sold bundle.
+        #[ink(topic)]
+        bundle_id: u64,
+        /// The previous owner, credited with the price minus the platform fees.
+        #[ink(topic)]
+        seller: AccountId,
+        /// The new owner of every item.
+        #[ink(topic)]
+        buyer: AccountId,
+        /// The price paid.
+        price: Balance,
+        /// The platform fees taken from the price, summed over the items.
+        fee: Balance,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a seller withdraws a bundle through `cancel_bundle`.
+    #[ink(event)]
+    pub struct BundleCancelled {
+        /// The withdrawn bundle.
+        #[ink(topic)]
+        bundle_id: u64,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner offers to pledge a content item for a loan through `create_loan_offer`.
+    #[ink(event)]
+    pub struct LoanOffered {
+        /// The pledged content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The owner borrowing against it.
+        #[ink(topic)]
+        borrower: AccountId,
+        /// The amount to borrow.
+        principal: Balance,
+        /// The interest owed on top of the principal.
+        interest: Balance,
+        /// The block timestamp the loan is due at.
+        due_at: Timestamp,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a lender funds a loan offer through `fund_loan`.
+    #[ink(event)]
+    pub struct LoanFunded {
+        /// The pledged content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The lender.
+        #[ink(topic)]
+        lender: AccountId,
+        /// The borrower, credited with the principal.
+        borrower: AccountId,
+        /// The principal lent.
+        principal: Balance,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a borrower repays a loan through `repay_loan`, releasing the content.
+    #[ink(event)]
+    pub struct LoanRepaid {
+        /// The released content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The borrower.
+        #[ink(topic)]
+        borrower: AccountId,
+        /// The lender, credited with the principal and interest.
+        lender: AccountId,
+        /// The principal and interest repaid.
+        amount: Balance,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when the lender of a defaulted loan takes the content through `claim_collateral`,
+    /// after its `OwnershipTransferred` event.
+    #[ink(event)]
+    pub struct CollateralClaimed {
+        /// The claimed content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The lender, now the owner.
+        #[ink(topic)]
+        lender: AccountId,
+        /// The borrower that defaulted.
+        borrower: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an unfunded loan offer is withdrawn, by the borrower through `cancel_loan_offer` or
+    /// by an admin executing a forced transfer.
+    #[ink(event)]
+    pub struct LoanOfferCancelled {
+        /// The released content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner grants or renews a license on a content item through `grant_license`.
+    #[ink(event)]
+    pub struct LicenseGranted {
+        /// The licensed content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The licensee.
+        #[ink(topic)]
+        licensee: AccountId,
+        /// The digest of the license terms.
+        terms_hash: String,
+        /// The block timestamp from which the license is no longer active, or `None` for good.
+        expires_at: Option<Timestamp>,
+        /// Whether the license is exclusive.
+        exclusive: bool,
+        /// Whether the licensee may transfer the license.
+        transferable: bool,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner sets or withdraws the license offer of a content item.
+    #[ink(event)]
+    pub struct LicenseOfferChanged {
+        /// The content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The new offer, or `None` if licenses are no longer sold.
+        offer: Option<LicenseOffer>,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an account buys a license on a content item through `buy_license`.
+    #[ink(event)]
+    pub struct LicensePurchased {
+        /// The licensed content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The licensee.
+        #[ink(topic)]
+        licensee: AccountId,
+        /// The owner, credited with the price minus the platform fee.
+        owner: AccountId,
+        /// The price paid.
+        price: Balance,
+        /// The platform fee taken from the price.
+        fee: Balance,
+        /// The block timestamp from which the license is no longer active.
+        expires_at: Timestamp,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner revokes a license through `revoke_license`.
+    #[ink(event)]
+    pub struct LicenseRevoked {
+        /// The licensed content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The former licensee.
+        #[ink(topic)]
+        licensee: AccountId,
+        /// The owner that revoked the license.
+        revoked_by: AccountId,
+        /// The reason given for the revocation.
+        reason: String,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when a licensee moves their license on a content item to another account through
+    /// `transfer_license`.
+    #[ink(event)]
+    pub struct LicenseTransferred {
+        /// The licensed content.
+        #[ink(topic)]
+        content_id: u64,
+        /// The former licensee.
+        #[ink(topic)]
+        from: AccountId,
+        /// The new licensee.
+        #[ink(topic)]
+        to: AccountId,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an owner cancels an auction without bids, or an admin executing a forced transfer
+    /// cancels an auction after crediting back its highest bid.
+    #[ink(event)]
+    pub struct AuctionCancelled {
+        /// The content that was auctioned.
+        #[ink(topic)]
+        content_id: u64,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// Emitted when an admin changes the anti-sniping window of auctions.
+    #[ink(event)]
+    pub struct AuctionExtensionChanged {
+        /// The admin that changed the window.
+        #[ink(topic)]
+        by: AccountId,
+        /// The new window in milliseconds.
+        window: Timestamp,
+        /// The contract-wide event sequence number.
+        seq: u64,
+    }
+
+    /// The `ContentOwnership` contract manages digital content and its ownership.
+    /// It provides functionality for:
+    /// - Registering new content.
+    /// - Transferring ownership of content.
+    /// - Validating content using oracle data.
+    #[ink(storage)]
+    pub struct ContentOwnership {
+        /// The set of administrators, mapped to their position in `admin_list`; initially just the deployer.
+        admins: Mapping<AccountId, u32>,
+        /// An index of the administrators, keyed by position. Positions are dense in `0..admin_count`;
+        /// removal swaps the last entry into the gap.
+        admin_list: Mapping<u32, AccountId>,
+        /// The number of accounts in `admins`. Once it reaches `0` admin-only messages are disabled forever.
+        admin_count: u32,
+        /// A pending admin handover as `(proposer, successor)`; the successor must call `accept_admin`.
+        pending_admin: Option<(AccountId, AccountId)>,
+        /// The number of admin approvals required to execute an `AdminAction`.
+        /// While it is `1`, admins may also update the oracle data directly.
+        approval_threshold: u32,
+        /// A mapping of action IDs to admin actions awaiting approval.
+        pending_actions: Mapping<u64, PendingAction>,
+        /// A counter for generating unique admin action IDs.
+        next_action_id: u64,
+        /// Which operations are currently suspended.
+        pause_state: PauseState,
+        /// Accounts barred from registering new content. They can still transfer content they own.
+        blacklist: Mapping<AccountId, ()>,
+        /// Who may register new content.
+        registration_mode: RegistrationMode,
+        /// Whether self-service registrations must go through `register_content_with_pop`.
+        pop_required: bool,
+        /// The native-token fee charged per registration; zero makes registration free.
+        registration_fee: Balance,
+        /// The account that receives withdrawn fees; the deployer until changed.
+        treasury: AccountId,
+        /// Fees collected and not yet withdrawn. Only this part of the contract balance can be
+        /// withdrawn as fees; the rest belongs to users.
+        collected_fees: Balance,
+        /// The platform fee, in basis points, taken from the price of the first sale of each content
+        /// item into `collected_fees`.
+        primary_fee_bps: u16,
+        /// The platform fee, in basis points, taken from the price of every later sale and of access
+        /// purchases, rentals, and licenses into `collected_fees`.
+        secondary_fee_bps: u16,
+        /// The highest royalty, in basis points, a creator can set.
+        max_royalty_bps: u16,
+        /// A mapping of content IDs to the resale royalty, in basis points, set by their creator.
+        royalty_bps: Mapping<u64, u16>,
+        /// A mapping of content IDs to the recipients their royalty is split among, if not all paid to the creator.
+        royalty_splits: Mapping<u64, RoyaltySplit>,
+        /// Content whose royalty and royalty split can no longer change. Survives transfers.
+        royalty_locks: Mapping<u64, ()>,
+        /// Content whose ownership can only change through a sale, so resales pay the royalty.
+        marketplace_only: Mapping<u64, ()>,
+        /// The recipients each marketplace-only content item may still be transferred to without a sale,
+        /// mapped to the creator who exempted them. Exemptions lapse once that account is no longer the creator.
+        transfer_exemptions: Mapping<(u64, AccountId), AccountId>,
+        /// How hashes declared as `HashScheme::Other` are validated.
+        validation_strategy: ValidationStrategy,
+        /// Whether transfers re-check the content hash against the current validation rules.
+        revalidate_on_transfer: bool,
+        /// The external `ContentValidator` contract replacing `validation_strategy`, if any.
+        validator: Option<AccountId>,
+        /// The compressed ECDSA public key whose attestations `register_with_attestation` accepts.
+        attestor: Option<[u8; 33]>,
+        /// Attestation message hashes already used, so an attestation registers at most once.
+        used_attestations: Mapping<[u8; 32], ()>,
+        /// The Merkle root of pre-approved content hashes accepted by `register_with_proof`.
+        allowlist_root: Option<Hash>,
+        /// Accounts allowed to register while `registration_mode` is `WhitelistOnly`.
+        whitelist: Mapping<AccountId, ()>,
+        /// The set of `(role, account)` pairs granted by the admin.
+        roles: Mapping<(Role, AccountId), ()>,
+        /// The delay, in milliseconds, applied to newly scheduled forced transfers.
+        forced_transfer_delay: Timestamp,
+        /// A mapping of content IDs to forced transfers awaiting their delay.
+        scheduled_transfers: Mapping<u64, ScheduledTransfer>,
+        /// The transfer history of each content item, keyed by `(content_id, index)`.
+        /// Indices are dense in `0..transfer_count(content_id)`, oldest first.
+        provenance: Mapping<(u64, u32), ProvenanceEntry>,
+        /// A mapping of content IDs to the number of entries in their transfer history.
+        transfer_counts: Mapping<u64, u32>,
+        /// The latest sales of each content item, keyed by `(content_id, index % MAX_SALE_HISTORY)`,
+        /// so each new sale past the bound overwrites the oldest one.
+        sale_records: Mapping<(u64, u32), SaleRecord>,
+        /// A mapping of content IDs to the number of sales ever recorded for them.
+        sale_counts: Mapping<u64, u32>,
+        /// The hashes each content item had before `update_content_hash`, keyed by `(content_id, index)`.
+        /// Indices are dense in `0..hash_history_length(content_id)`, oldest first; entries are never removed.
+        hash_history: Mapping<(u64, u32), String>,
+        /// A mapping of content IDs to the number of entries in their hash history.
+        hash_history_lengths: Mapping<u64, u32>,
+        /// A mapping of content IDs to their metadata. Content registered without metadata has no entry.
+        metadata: Mapping<u64, ContentMetadata>,
+        /// Content whose metadata can no longer change. Survives transfers.
+        metadata_locks: Mapping<u64, ()>,
+        /// A mapping of content IDs to the digest of their licensing terms. Survives transfers.
+        license_terms: Mapping<u64, String>,
+        /// Content whose licensing terms can no longer change. Survives transfers.
+        license_terms_locks: Mapping<u64, ()>,
+        /// A mapping of content IDs to their normalized tags. Untagged content has no entry.
+        content_tags: Mapping<u64, Vec<String>>,
+        /// A mapping of tags to the number of content items carrying them.
+        tag_count: Mapping<String, u32>,
+        /// A per-tag index of content IDs, keyed by `(tag, position)`.
+        /// Positions are dense in `0..tag_count`; removal swaps the last entry into the gap.
+        tagged_contents: Mapping<(String, u32), u64>,
+        /// A mapping of `(tag, content_id)` to the content's position in the tag's `tagged_contents` index.
+        tagged_content_position: Mapping<(String, u64), u32>,
+        /// A mapping of content IDs to the number of existing derivatives registered against them.
+        derivative_count: Mapping<u64, u32>,
+        /// A per-parent index of derivative content IDs, keyed by `(parent_id, position)`.
+        /// Positions are dense in `0..derivative_count`; removal swaps the last entry into the gap.
+        derivatives: Mapping<(u64, u32), u64>,
+        /// A mapping of derivative content IDs to their position in the parent's `derivatives` index.
+        derivative_position: Mapping<u64, u32>,
+        /// Content whose owner requires `approve_derivative` before derivatives can be registered.
+        derivative_consent_required: Mapping<u64, ()>,
+        /// A mapping of `(parent_id, account)` to the owner that allowed the account to register a
+        /// derivative. Only valid while that owner still owns the parent; consumed on use.
+        derivative_approvals: Mapping<(u64, AccountId), AccountId>,
+        /// A mapping of `(committer, commitment)` to the block in which the commitment was made.
+        commitments: Mapping<(AccountId, Hash), BlockNumber>,
+        /// A mapping of content IDs registered through `reveal_registration` to their commitment
+        /// block, which takes precedence over the registration block when priority is compared.
+        commitment_blocks: Mapping<u64, BlockNumber>,
+        /// A counter for generating unique collection IDs.
+        next_collection_id: u64,
+        /// A mapping of collection IDs to their records.
+        collections: Mapping<u64, Collection>,
+        /// A mapping of collection IDs to the number of items they hold.
+        collection_size: Mapping<u64, u32>,
+        /// A per-collection index of content IDs, keyed by `(collection_id, position)`.
+        /// Positions are dense in `0..collection_size`; removal swaps the last entry into the gap.
+        collection_items: Mapping<(u64, u32), u64>,
+        /// A mapping of content IDs to the collection holding them and their position in it.
+        /// A content item belongs to at most one collection.
+        item_collection: Mapping<u64, (u64, u32)>,
+        /// A mapping of content IDs to the version that superseded them.
+        superseded_by: Mapping<u64, u64>,
+        /// A mapping of content IDs to the version they supersede.
+        supersedes: Mapping<u64, u64>,
+        /// A mapping of versions other than the first to the first version of their chain.
+        version_roots: Mapping<u64, u64>,
+        /// A mapping of first versions to the latest version of their chain, for chains with several versions.
+        latest_versions: Mapping<u64, u64>,
+        /// A mapping of content IDs to the approval allowing an operator to transfer them through
+        /// `transfer_from`. Cleared by any ownership change.
+        approvals: Mapping<u64, ContentApproval>,
+        /// A mapping of owners to the number of their content items with a per-content approval.
+        approved_count: Mapping<AccountId, u32>,
+        /// The index of content IDs with a per-content approval, keyed by `(owner, position)`.
+        /// Positions are dense in `0..approved_count`; removal swaps the last entry into the gap.
+        approved_contents: Mapping<(AccountId, u32), u64>,
+        /// A mapping of approved content IDs to their position in the owner's `approved_contents` index.
+        approved_content_position: Mapping<u64, u32>,
+        /// The set of `(owner, operator)` pairs where the operator may transfer all of the owner's content,
+        /// mapped to the operator's position in the owner's `operators` index.
+        operator_approvals: Mapping<(AccountId, AccountId), u32>,
+        /// A mapping of owners to the number of operators approved for all of their content.
+        operator_count: Mapping<AccountId, u32>,
+        /// The index of operators approved for all of an owner's content, keyed by `(owner, position)`.
+        /// Positions are dense in `0..operator_count`; removal swaps the last entry into the gap.
+        operators: Mapping<(AccountId, u32), AccountId>,
+        /// A mapping of accounts to the nonce their next signed message must use.
+        nonces: Mapping<AccountId, u64>,
+        /// A mapping of content IDs to the block timestamp until which their owner locked them.
+        /// Cleared by any ownership change.
+        locks: Mapping<u64, Timestamp>,
+        /// A mapping of frozen content IDs to the block timestamp at which they were frozen.
+        frozen: Mapping<u64, Timestamp>,
+        /// A mapping of content IDs to the memo of their latest transfer.
+        /// Removed by a transfer without a memo, so it always describes the latest transfer.
+        transfer_memos: Mapping<u64, String>,
+        /// A mapping of content IDs to the recipient of their pending transfer offer.
+        /// Cleared by any ownership change.
+        transfer_offers: Mapping<u64, AccountId>,
+        /// A mapping of content IDs to their fixed-price listing while listed for sale.
+        /// Cleared by any ownership change.
+        listings: Mapping<u64, Listing>,
+        /// Native tokens the contract owes each account, e.g. sale proceeds, paid out by `withdraw`.
+        credits: Mapping<AccountId, Balance>,
+        /// A mapping of content IDs to the income they earned their owners over their lifetime.
+        /// Kept across ownership changes and burns.
+        revenue: Mapping<u64, RevenueStats>,
+        /// The total income each account was credited as an owner or royalty recipient, excluding refunds.
+        earnings: Mapping<AccountId, Balance>,
+        /// The total royalties each account was credited across all content.
+        royalty_earnings: Mapping<AccountId, Balance>,
+        /// A mapping of content IDs to the terms on which their owner sells access.
+        /// Cleared by any ownership change.
+        access_terms: Mapping<u64, AccessTerms>,
+        /// The block timestamp from which each account's purchased access to a content item ends,
+        /// keyed by `(content_id, account)`. Grants survive ownership changes and price changes.
+        access_grants: Mapping<(u64, AccountId), Timestamp>,
+        /// A mapping of content IDs to the terms on which their owner rents them out.
+        /// Cleared by any ownership change.
+        rental_terms: Mapping<u64, RentalTerms>,
+        /// A mapping of content IDs to their current or expired rental. Rentals survive ownership
+        /// changes until they expire, and are removed by burns.
+        rentals: Mapping<u64, Rental>,
+        /// Escrowed purchase offers, keyed by content ID and buyer. Offers outlive burned content,
+        /// so buyers can still withdraw them.
+        purchase_offers: Mapping<(u64, AccountId), PurchaseOffer>,
+        /// A mapping of content IDs to the number of open purchase offers.
+        offer_count: Mapping<u64, u32>,
+        /// The buyers with an open offer on each content item, keyed by `(content_id, position)`.
+        /// Positions are dense in `0..offer_count`; removal swaps the last entry into the gap.
+        offer_buyers: Mapping<(u64, u32), AccountId>,
+        /// The position of each open offer in `offer_buyers`, keyed by `(content_id, buyer)`.
+        offer_position: Mapping<(u64, AccountId), u32>,
+        /// A mapping of content IDs to their running English auction. The content cannot change
+        /// hands, be locked, or be removed until the auction is settled or cancelled.
+        auctions: Mapping<u64, Auction>,
+        /// How long, in milliseconds, before an auction ends a bid extends it, to that long after the bid.
+        auction_extension: Timestamp,
+        /// A mapping of content IDs to their declining-price sale. Cleared by any ownership change.
+        dutch_auctions: Mapping<u64, DutchAuction>,
+        /// A mapping of bundle IDs to their listed bundle. Kept when items change hands on their own,
+        /// which only makes the bundle unbuyable.
+        bundles: Mapping<u64, Bundle>,
+        /// The ID the next bundle will receive.
+        next_bundle_id: u64,
+        /// A mapping of content IDs to the loan they secure, funded or still on offer. Removed on
+        /// repayment, claim, or cancellation; kept while the content stays pledged.
+        loans: Mapping<u64, Loan>,
+        /// A mapping of `(content_id, licensee)` pairs to their license, active or expired. Survives
+        /// transfers; removed on revocation.
+        licenses: Mapping<(u64, AccountId), License>,
+        /// A mapping of content IDs to the license offer of their owner. Cleared by any ownership change.
+        license_offers: Mapping<u64, LicenseOffer>,
+        /// A mapping of `(content_id, position)` pairs to the accounts holding a license on the content.
+        licensees: Mapping<(u64, u32), AccountId>,
+        /// The position of each `(content_id, licensee)` pair in `licensees`.
+        licensee_positions: Mapping<(u64, AccountId), u32>,
+        /// The number of accounts holding a license on each content item.
+        licensee_counts: Mapping<u64, u32>,
+        /// A mapping of content IDs to the account holding an exclusive license on them, active or expired.
+        exclusive_licensees: Mapping<u64, AccountId>,
+        /// The oracle prefixes accepted when validating content, at most `MAX_ORACLE_PREFIXES`,
+        /// e.g. one per storage network content may be pinned on.
+        oracle_prefixes: Vec<String>,
+        /// The off-chain oracle service allowed to call `push_oracle_data`, if any.
+        oracle_account: Option<AccountId>,
+        /// The block timestamp of the last change to the oracle prefixes.
+        oracle_updated_at: Timestamp,
+        /// The version of the oracle prefixes, bumped on every change.
+        oracle_version: u32,
+        /// A mapping of oracle versions to the prefixes in force under them.
+        oracle_history: Mapping<u32, Vec<String>>,
+        /// The minimum time, in milliseconds, between proposing and applying oracle data.
+        oracle_delay: Timestamp,
+        /// A lower oracle delay and the block timestamp from which it replaces `oracle_delay`.
+        pending_oracle_delay: Option<(Timestamp, Timestamp)>,
+        /// The oracle data update waiting for its timelock, if any.
+        pending_oracle_data: Option<PendingOracleData>,
+        /// The accounts voting on oracle data through `submit_oracle_data`, at most `MAX_ORACLE_MEMBERS`.
+        oracle_members: Vec<AccountId>,
+        /// The number of identical submissions needed to adopt oracle data.
+        oracle_quorum: u32,
+        /// The oracle voting round in progress.
+        oracle_round: OracleRound,
+        /// The prefix that `content_uri` prepends to content hashes, e.g. a gateway URL.
+        base_uri: String,
+        /// A mapping of content IDs to the URI their owner set in place of the base URI.
+        uri_overrides: Mapping<u64, String>,
+        /// A mapping of content IDs to their corresponding content records.
+        contents: Mapping<u64, Content>,
+        /// A mapping of content IDs to when they were registered. Kept apart from `contents` so the
+        /// layout of existing records is unchanged; content registered before it existed has no entry.
+        registrations: Mapping<u64, RegistrationInfo>,
+        /// A mapping of content IDs to their current owners.
+        /// Mirrors `Content::owner` so ownership queries don't decode the content hash.
+        /// Renounced content has no entry.
+        owners: Mapping<u64, AccountId>,
+        /// A mapping of accounts to the number of content records they own.
+        owned_count: Mapping<AccountId, u32>,
+        /// A per-owner index of content IDs, keyed by `(owner, position)`.
+        /// Positions are dense in `0..owned_count`; removal swaps the last entry into the gap.
+        owned_contents: Mapping<(AccountId, u32), u64>,
+        /// A mapping of content IDs to their position in the owner's `owned_contents` index.
+        owned_content_position: Mapping<u64, u32>,
+        /// A mapping of accounts to the number of existing content records they created.
+        created_count: Mapping<AccountId, u32>,
+        /// A per-creator index of content IDs, keyed by `(creator, position)`.
+        /// Positions are dense in `0..created_count`; removal swaps the last entry into the gap.
+        created_contents: Mapping<(AccountId, u32), u64>,
+        /// A mapping of content IDs to their position in the creator's `created_contents` index.
+        created_content_position: Mapping<u64, u32>,
+        /// A global index of existing content IDs, keyed by dense position in `0..total_supply()`.
+        /// Removal swaps the last entry into the gap.
+        all_contents: Mapping<u64, u64>,
+        /// A mapping of content IDs to their position in the `all_contents` index.
+        all_content_position: Mapping<u64, u64>,
+        /// The number of existing content records.
+        content_count: u64,
+        /// A counter for generating unique content IDs. IDs of burned content are never reused.
+        next_content_id: u64,
+        /// A mapping of content hashes to their corresponding content IDs.
+        content_hash_to_id: BTreeMap<String, u64>,
+        /// The sequence number of the most recently emitted event (0 if none).
+        /// Every event carries its sequence number so indexers can detect gaps.
+        last_event_seq: u64,
+    }
+
+    //----------------------------------
+    // Default Implementation
+    //----------------------------------
 
     /// Provides default initialization values for the contract.
     /// This is primarily used for testing or demonstration purposes.
     impl Default for ContentOwnership {
         fn default() -> Self {
             Self {
-                admin: AccountId::from([0u8; 32]),
-                oracle_data: String::from("default_oracle"),
+                admins: Mapping::default(),
+                admin_list: Mapping::default(),
+                admin_count: 0,
+                pending_admin: None,
+                approval_threshold: 1,
+                pending_actions: Mapping::default(),
+                next_action_id: 1,
+                pause_state: PauseState::default(),
+                blacklist: Mapping::default(),
+                registration_mode: RegistrationMode::default(),
+                pop_required: false,
+                registration_fee: 0,
+                treasury: AccountId::from([0u8; 32]),
+                collected_fees: 0,
+                primary_fee_bps: 0,
+                secondary_fee_bps: 0,
+                max_royalty_bps: DEFAULT_MAX_ROYALTY_BPS,
+                royalty_bps: Mapping::default(),
+                royalty_splits: Mapping::default(),
+                royalty_locks: Mapping::default(),
+                marketplace_only: Mapping::default(),
+                transfer_exemptions: Mapping::default(),
+                validation_strategy: ValidationStrategy::default(),
+                revalidate_on_transfer: false,
+                validator: None,
+                attestor: None,
+                used_attestations: Mapping::default(),
+                allowlist_root: None,
+                whitelist: Mapping::default(),
+                roles: Mapping::default(),
+                forced_transfer_delay: DEFAULT_FORCED_TRANSFER_DELAY,
+                scheduled_transfers: Mapping::default(),
+                transfer_offers: Mapping::default(),
+                listings: Mapping::default(),
+                credits: Mapping::default(),
+                revenue: Mapping::default(),
+                earnings: Mapping::default(),
+                royalty_earnings: Mapping::default(),
+                access_terms: Mapping::default(),
+                access_grants: Mapping::default(),
+                rental_terms: Mapping::default(),
+                rentals: Mapping::default(),
+                purchase_offers: Mapping::default(),
+                offer_count: Mapping::default(),
+                offer_buyers: Mapping::default(),
+                offer_position: Mapping::default(),
+                auctions: Mapping::default(),
+                auction_extension: DEFAULT_AUCTION_EXTENSION,
+                dutch_auctions: Mapping::default(),
+                bundles: Mapping::default(),
+                next_bundle_id: 1,
+                loans: Mapping::default(),
+                licenses: Mapping::default(),
+                license_offers: Mapping::default(),
+                licensees: Mapping::default(),
+                licensee_positions: Mapping::default(),
+                licensee_counts: Mapping::default(),
+                exclusive_licensees: Mapping::default(),
+                transfer_memos: Mapping::default(),
+                locks: Mapping::default(),
+                frozen: Mapping::default(),
+                approvals: Mapping::default(),
+                approved_count: Mapping::default(),
+                approved_contents: Mapping::default(),
+                approved_content_position: Mapping::default(),
+                operator_approvals: Mapping::default(),
+                operator_count: Mapping::default(),
+                operators: Mapping::default(),
+                nonces: Mapping::default(),
+                provenance: Mapping::default(),
+                transfer_counts: Mapping::default(),
+                sale_records: Mapping::default(),
+                sale_counts: Mapping::default(),
+                hash_history: Mapping::default(),
+                hash_history_lengths: Mapping::default(),
+                metadata: Mapping::default(),
+                metadata_locks: Mapping::default(),
+                license_terms: Mapping::default(),
+                license_terms_locks: Mapping::default(),
+                content_tags: Mapping::default(),
+                tag_count: Mapping::default(),
+                tagged_contents: Mapping::default(),
+                tagged_content_position: Mapping::default(),
+                derivative_count: Mapping::default(),
+                derivatives: Mapping::default(),
+                derivative_position: Mapping::default(),
+                derivative_consent_required: Mapping::default(),
+                derivative_approvals: Mapping::default(),
+                commitments: Mapping::default(),
+                commitment_blocks: Mapping::default(),
+                next_collection_id: 1,
+                collections: Mapping::default(),
+                collection_size: Mapping::default(),
+                collection_items: Mapping::default(),
+                item_collection: Mapping::default(),
+                superseded_by: Mapping::default(),
+                supersedes: Mapping::default(),
+                version_roots: Mapping::default(),
+                latest_versions: Mapping::default(),
+                oracle_prefixes: ink::prelude::vec![String::from("default_oracle")],
+                oracle_account: None,
+                oracle_updated_at: 0,
+                oracle_version: 0,
+                oracle_history: Mapping::default(),
+                oracle_delay: 0,
+                pending_oracle_delay: None,
+                pending_oracle_data: None,
+                oracle_members: Vec::new(),
+                oracle_quorum: 1,
+                oracle_round: OracleRound::default(),
+                base_uri: String::new(),
+                uri_overrides: Mapping::default(),
                 contents: Mapping::default(),
+                registrations: Mapping::default(),
+                owners: Mapping::default(),
+                owned_count: Mapping::default(),
+                owned_contents: Mapping::default(),
+                owned_content_position: Mapping::default(),
+                created_count: Mapping::default(),
+                created_contents: Mapping::default(),
+                created_content_position: Mapping::default(),
+                all_contents: Mapping::default(),
+                all_content_position: Mapping::default(),
+                content_count: 0,
                 next_content_id: 1,
                 content_hash_to_id: BTreeMap::new(),
+                last_event_seq: 0,
+            }
+        }
+    }
+
+    //----------------------------------
+    // Contract Implementation
+    //----------------------------------
+
+    impl ContentOwnership {
+        /// Constructor: Initializes the contract with the deployer as the admin and sets the initial oracle data.
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut contract = Self::default();
+            contract.add_to_admins(Self::env().caller());
+            contract.treasury = Self::env().caller();
+            contract.oracle_updated_at = Self::env().block_timestamp();
+            contract.oracle_history.insert(0, &contract.oracle_prefixes);
+            contract
+        }
+
+        /// Updates the oracle data stored in the contract, replacing all accepted prefixes with `new_data`.
+        /// Only an admin or an account holding `Role::OracleUpdater` can call this function, and only
+        /// while `oracle_delay` is zero; otherwise updates go through `propose_oracle_data`.
+        /// When the approval threshold is above one, admins must use `propose_admin_action` instead;
+        /// oracle updaters, having been delegated explicitly, are unaffected.
+        /// Emits `OracleDataUpdated`, even when the new value equals the current one.
+        ///
+        /// # Arguments
+        /// - `new_data`: The new oracle data to be stored.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither an admin nor an oracle updater.
+        /// - Returns `Error::ApprovalRequired` if an admin calls while the approval threshold is above one.
+        /// - Returns `Error::OracleDelayActive` if the oracle delay is not zero.
+        /// - Returns `Error::OracleDataTooLong` if the data exceeds `MAX_ORACLE_DATA_LENGTH` bytes.
+        #[ink(message)]
+        pub fn update_oracle_data(&mut self, new_data: String) -> Result<()> {
+            let caller = self.ensure_oracle_updater()?;
+            Self::ensure_oracle_data_within_bounds(&new_data)?;
+            if self.oracle_delay() > 0 {
+                return Err(Error::OracleDelayActive);
+            }
+            self.set_oracle_data(caller, new_data);
+            Ok(())
+        }
+
+        /// Checks that the caller may change the oracle data directly or through a proposal.
+        ///
+        /// # Returns
+        /// - The caller's AccountId.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither an admin nor an oracle updater.
+        /// - Returns `Error::ApprovalRequired` if an admin calls while the approval threshold is above one.
+        fn ensure_oracle_updater(&self) -> Result<AccountId> {
+            let caller = self.ensure_admin_or_role(Role::OracleUpdater)?;
+            if self.approval_threshold > 1 && !self.has_role(Role::OracleUpdater, caller) {
+                return Err(Error::ApprovalRequired);
+            }
+            Ok(caller)
+        }
+
+        /// Checks that oracle data, or a single prefix, is short enough to store and check cheaply.
+        ///
+        /// # Errors
+        /// - Returns `Error::OracleDataTooLong` if the data exceeds `MAX_ORACLE_DATA_LENGTH` bytes.
+        fn ensure_oracle_data_within_bounds(data: &str) -> Result<()> {
+            if data.len() > MAX_ORACLE_DATA_LENGTH {
+                return Err(Error::OracleDataTooLong);
+            }
+            Ok(())
+        }
+
+        /// Proposes new oracle data, which `apply_oracle_data` can adopt once `oracle_delay` has passed,
+        /// giving the community time to react to a compromised key. A new proposal replaces a pending
+        /// one and restarts the delay. Same authorization as `update_oracle_data`. Emits `OracleDataProposed`.
+        ///
+        /// # Arguments
+        /// - `new_data`: The proposed oracle data.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither an admin nor an oracle updater.
+        /// - Returns `Error::ApprovalRequired` if an admin calls while the approval threshold is above one.
+        /// - Returns `Error::OracleDataTooLong` if the data exceeds `MAX_ORACLE_DATA_LENGTH` bytes.
+        #[ink(message)]
+        pub fn propose_oracle_data(&mut self, new_data: String) -> Result<()> {
+            self.propose_oracle_change(OracleChange::ReplaceData(new_data))
+        }
+
+        /// Proposes any oracle change, which `apply_oracle_data` can adopt once `oracle_delay` has passed,
+        /// like `propose_oracle_data`. While the delay is not zero, this is the only way to change the
+        /// oracle prefixes, the validation strategy, or the validator. Each change needs the same
+        /// authorization as the message it stands in for. Emits `OracleDataProposed`.
+        ///
+        /// # Arguments
+        /// - `change`: The proposed change.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller may not make the change.
+        /// - Returns `Error::ApprovalRequired` if an admin proposes a change that needs M-of-N approval
+        ///   while the approval threshold is above one; see `AdminAction`.
+        /// - Returns `Error::OracleDataTooLong` if the data or prefix exceeds `MAX_ORACLE_DATA_LENGTH` bytes.
+        #[ink(message)]
+        pub fn propose_oracle_change(&mut self, change: OracleChange) -> Result<()> {
+            let caller = match change {
+                OracleChange::ReplaceData(_) => self.ensure_oracle_updater()?,
+                _ => {
+                    let caller = self.ensure_admin()?;
+                    if self.approval_threshold > 1 {
+                        return Err(Error::ApprovalRequired);
+                    }
+                    caller
+                }
+            };
+            Self::ensure_oracle_change_within_bounds(&change)?;
+            self.schedule_oracle_change(caller, change);
+            Ok(())
+        }
+
+        /// Adopts the pending oracle proposal once its not-before timestamp is reached. Anyone can call
+        /// this function. Emits the event of the applied change, then `OracleProposalApplied`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NoPendingOracleData` if no proposal is pending.
+        /// - Returns `Error::OracleDelayNotElapsed` if the proposal's not-before timestamp is in the future.
+        /// - Returns the errors of the change itself, such as `Error::DuplicateOraclePrefix`.
+        #[ink(message)]
+        pub fn apply_oracle_data(&mut self) -> Result<()> {
+            let pending = self.pending_oracle_data.clone().ok_or(Error::NoPendingOracleData)?;
+            if self.env().block_timestamp() < pending.not_before {
+                return Err(Error::OracleDelayNotElapsed);
+            }
+            self.apply_oracle_change(pending.proposed_by, pending.change.clone())?;
+            self.pending_oracle_data = None;
+            let seq = self.next_event_seq();
+            self.env().emit_event(OracleProposalApplied {
+                applied_by: self.env().caller(),
+                proposed_by: pending.proposed_by,
+                change: pending.change,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Discards the pending oracle proposal. Only an admin can call this function, even under
+        /// M-of-N approval, so any single admin can stop a malicious update. Emits `OracleProposalCancelled`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::NoPendingOracleData` if no proposal is pending.
+        #[ink(message)]
+        pub fn cancel_oracle_proposal(&mut self) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            let pending = self.pending_oracle_data.take().ok_or(Error::NoPendingOracleData)?;
+            let seq = self.next_event_seq();
+            self.env().emit_event(OracleProposalCancelled {
+                cancelled_by: caller,
+                change: pending.change,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Checks that the data or prefix an oracle change carries is within bounds.
+        ///
+        /// # Errors
+        /// - Returns `Error::OracleDataTooLong` if the data or prefix exceeds `MAX_ORACLE_DATA_LENGTH` bytes.
+        fn ensure_oracle_change_within_bounds(change: &OracleChange) -> Result<()> {
+            match change {
+                OracleChange::ReplaceData(data) | OracleChange::AddPrefix(data) | OracleChange::RemovePrefix(data) => {
+                    Self::ensure_oracle_data_within_bounds(data)
+                }
+                _ => Ok(()),
+            }
+        }
+
+        /// Stores an oracle change as the pending proposal, replacing any other, and emits
+        /// `OracleDataProposed`. Authorization is the caller's responsibility.
+        fn schedule_oracle_change(&mut self, proposed_by: AccountId, change: OracleChange) {
+            let not_before = self.env().block_timestamp().saturating_add(self.oracle_delay());
+            self.pending_oracle_data = Some(PendingOracleData {
+                change: change.clone(),
+                proposed_by,
+                not_before,
+            });
+            let seq = self.next_event_seq();
+            self.env().emit_event(OracleDataProposed {
+                proposed_by,
+                change,
+                not_before,
+                seq,
+            });
+        }
+
+        /// Applies an oracle change at once, emitting its event. Authorization and the oracle delay
+        /// are the caller's responsibility.
+        fn apply_oracle_change(&mut self, by: AccountId, change: OracleChange) -> Result<()> {
+            match change {
+                OracleChange::ReplaceData(data) => self.set_oracle_data(by, data),
+                OracleChange::AddPrefix(prefix) => self.insert_oracle_prefix(by, prefix)?,
+                OracleChange::RemovePrefix(prefix) => self.delete_oracle_prefix(by, prefix)?,
+                OracleChange::SetValidationStrategy(strategy) => self.apply_validation_strategy(by, strategy),
+                OracleChange::SetValidator(validator) => self.apply_validator(by, validator),
+            }
+            Ok(())
+        }
+
+        /// Applies an oracle change approved through `AdminAction` at once while the oracle delay is
+        /// zero, and otherwise proposes it, so M-of-N approval never skips the timelock.
+        fn apply_or_schedule_oracle_change(&mut self, by: AccountId, change: OracleChange) -> Result<()> {
+            if self.oracle_delay() > 0 {
+                self.schedule_oracle_change(by, change);
+                return Ok(());
+            }
+            self.apply_oracle_change(by, change)
+        }
+
+        /// Returns the oracle change waiting for its timelock, if any.
+        #[ink(message)]
+        pub fn pending_oracle_data(&self) -> Option<PendingOracleData> {
+            self.pending_oracle_data.clone()
+        }
+
+        /// Sets the minimum time between proposing and applying oracle data. A higher delay applies
+        /// immediately; a lower one only after the current delay has passed, so a compromised key
+        /// cannot drop the timelock and update at once. Only an admin can call this function; when
+        /// the approval threshold is above one, it must go through `AdminAction::SetOracleDelay`.
+        /// Emits `OracleDelayChanged`.
+        ///
+        /// # Arguments
+        /// - `delay`: The new delay, in milliseconds; `0` re-enables `update_oracle_data`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        #[ink(message)]
+        pub fn set_oracle_delay(&mut self, delay: Timestamp) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            self.apply_oracle_delay(caller, delay);
+            Ok(())
+        }
+
+        /// Returns the oracle delay currently in force, in milliseconds.
+        #[ink(message)]
+        pub fn oracle_delay(&self) -> Timestamp {
+            match self.pending_oracle_delay {
+                Some((delay, effective_at)) if self.env().block_timestamp() >= effective_at => delay,
+                _ => self.oracle_delay,
+            }
+        }
+
+        /// Stores or schedules a new oracle delay and emits `OracleDelayChanged`.
+        /// Authorization is the caller's responsibility.
+        fn apply_oracle_delay(&mut self, by: AccountId, delay: Timestamp) {
+            let current = self.oracle_delay();
+            let now = self.env().block_timestamp();
+            let effective_at = if delay >= current {
+                self.oracle_delay = delay;
+                self.pending_oracle_delay = None;
+                now
+            } else {
+                let effective_at = now.saturating_add(current);
+                self.oracle_delay = current;
+                self.pending_oracle_delay = Some((delay, effective_at));
+                effective_at
+            };
+            let seq = self.next_event_seq();
+            self.env().emit_event(OracleDelayChanged { by, delay, effective_at, seq });
+        }
+
+        /// Replaces the oracle prefixes with `new_data` and emits `OracleDataUpdated`.
+        /// Authorization is the caller's responsibility.
+        fn set_oracle_data(&mut self, updated_by: AccountId, new_data: String) {
+            let old_data = self.replace_oracle_prefixes(new_data.clone());
+            let seq = self.next_event_seq();
+            self.env().emit_event(OracleDataUpdated {
+                updated_by,
+                old_data,
+                new_data,
+                seq,
+            });
+        }
+
+        /// Replaces the oracle prefixes with a single one and records the update time.
+        ///
+        /// # Returns
+        /// - The first prefix in force before the update, or an empty string if there was none.
+        fn replace_oracle_prefixes(&mut self, new_data: String) -> String {
+            let old_data = self.get_oracle_data();
+            self.oracle_prefixes = ink::prelude::vec![new_data];
+            self.record_oracle_change();
+            old_data
+        }
+
+        /// Records a change to the oracle prefixes: bumps the oracle version, archives the new
+        /// prefixes under it, and sets the update time. Every change to `oracle_prefixes` must call this.
+        fn record_oracle_change(&mut self) {
+            self.oracle_version = self.oracle_version.saturating_add(1);
+            self.oracle_history.insert(self.oracle_version, &self.oracle_prefixes);
+            self.oracle_updated_at = self.env().block_timestamp();
+        }
+
+        /// Returns the version of the oracle data, bumped on every change.
+        #[ink(message)]
+        pub fn current_oracle_version(&self) -> u32 {
+            self.oracle_version
+        }
+
+        /// Returns the oracle prefixes that were in force under an oracle version, for audits.
+        ///
+        /// # Returns
+        /// - `None` if the version does not exist yet.
+        #[ink(message)]
+        pub fn oracle_data_at_version(&self, version: u32) -> Option<Vec<String>> {
+            self.oracle_history.get(version)
+        }
+
+        /// Replaces the oracle data like `update_oracle_data`, on behalf of the off-chain oracle service.
+        /// Only the account set with `set_oracle_account` can call this function; admins keep
+        /// `update_oracle_data` as an override. Emits `OracleDataPushed`.
+        ///
+        /// # Arguments
+        /// - `new_data`: The new oracle data to be stored.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotOracle` if the caller is not the oracle account, or none is set.
+        /// - Returns `Error::OracleDataTooLong` if the data exceeds `MAX_ORACLE_DATA_LENGTH` bytes.
+        #[ink(message)]
+        pub fn push_oracle_data(&mut self, new_data: String) -> Result<()> {
+            let caller = self.env().caller();
+            if self.oracle_account != Some(caller) {
+                return Err(Error::NotOracle);
+            }
+            Self::ensure_oracle_data_within_bounds(&new_data)?;
+            let old_data = self.replace_oracle_prefixes(new_data.clone());
+            let seq = self.next_event_seq();
+            self.env().emit_event(OracleDataPushed {
+                oracle: caller,
+                old_data,
+                new_data,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Sets or clears the account allowed to call `push_oracle_data`, so an off-chain oracle
+        /// service does not need the admin key. Only an admin can call this function; when the
+        /// approval threshold is above one, it must go through `AdminAction::SetOracleAccount`.
+        /// Emits `OracleAccountChanged`.
+        ///
+        /// # Arguments
+        /// - `account`: The new oracle account, or `None` to disable pushes.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        #[ink(message)]
+        pub fn set_oracle_account(&mut self, account: Option<AccountId>) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            self.apply_oracle_account(caller, account);
+            Ok(())
+        }
+
+        /// Stores the oracle account and emits `OracleAccountChanged`. Authorization is the caller's responsibility.
+        fn apply_oracle_account(&mut self, by: AccountId, account: Option<AccountId>) {
+            self.oracle_account = account;
+            let seq = self.next_event_seq();
+            self.env().emit_event(OracleAccountChanged { by, account, seq });
+        }
+
+        /// Returns the account allowed to call `push_oracle_data`, if any.
+        #[ink(message)]
+        pub fn oracle_account(&self) -> Option<AccountId> {
+            self.oracle_account
+        }
+
+        /// Returns the block timestamp of the last change to the oracle data, by the oracle account
+        /// or an admin, or the deployment time if it never changed. Consumers can compare it with
+        /// the current time to detect a dead oracle.
+        #[ink(message)]
+        pub fn oracle_updated_at(&self) -> Timestamp {
+            self.oracle_updated_at
+        }
+
+        /// Submits oracle data for the current round. Each member has one submission per round, which a
+        /// later call replaces. Once `oracle_quorum` members submitted the identical string, it replaces
+        /// the oracle data like `update_oracle_data` and a new round starts. If every member submitted
+        /// without reaching quorum, the previous data stays in force and a new round starts.
+        /// Emits `OracleDataSubmitted`, then `OracleQuorumReached` or `OracleRoundFailed` if the round settles.
+        ///
+        /// # Arguments
+        /// - `data`: The proposed oracle data.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotOracle` if the caller is not an oracle member.
+        /// - Returns `Error::OracleDataTooLong` if the data exceeds `MAX_ORACLE_DATA_LENGTH` bytes.
+        #[ink(message)]
+        pub fn submit_oracle_data(&mut self, data: String) -> Result<()> {
+            let caller = self.env().caller();
+            if !self.oracle_members.contains(&caller) {
+                return Err(Error::NotOracle);
+            }
+            Self::ensure_oracle_data_within_bounds(&data)?;
+            let submissions = &mut self.oracle_round.submissions;
+            match submissions.iter_mut().find(|(member, _)| *member == caller) {
+                Some((_, submitted)) => *submitted = data.clone(),
+                None => submissions.push((caller, data.clone())),
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(OracleDataSubmitted {
+                member: caller,
+                round: self.oracle_round.round,
+                data,
+                seq,
+            });
+            self.settle_oracle_round();
+            Ok(())
+        }
+
+        /// Adds an account to the oracle members voting through `submit_oracle_data`.
+        /// Only an admin can call this function; when the approval threshold is above one, it must go
+        /// through `AdminAction::AddOracleMember`. Emits `OracleMembershipChanged`.
+        ///
+        /// # Arguments
+        /// - `member`: The account to add.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        /// - Returns `Error::AlreadyOracleMember` if the account is already a member.
+        /// - Returns `Error::TooManyOracleMembers` if `MAX_ORACLE_MEMBERS` members exist already.
+        #[ink(message)]
+        pub fn add_oracle_member(&mut self, member: AccountId) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            self.insert_oracle_member(caller, member)
+        }
+
+        /// Removes an oracle member, discarding their submission for the current round, which may
+        /// settle it. Only an admin can call this function; when the approval threshold is above one,
+        /// it must go through `AdminAction::RemoveOracleMember`. Emits `OracleMembershipChanged`.
+        ///
+        /// # Arguments
+        /// - `member`: The account to remove.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        /// - Returns `Error::NotOracleMember` if the account is not a member.
+        /// - Returns `Error::InvalidThreshold` if fewer members than the quorum would remain, unless none remain.
+        #[ink(message)]
+        pub fn remove_oracle_member(&mut self, member: AccountId) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            self.delete_oracle_member(caller, member)
+        }
+
+        /// Sets the number of identical submissions needed to adopt oracle data, which may settle the
+        /// current round. Only an admin can call this function; when the approval threshold is above
+        /// one, it must go through `AdminAction::SetOracleQuorum`. Emits `OracleQuorumChanged`.
+        ///
+        /// # Arguments
+        /// - `quorum`: The new quorum, between one and the number of members.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        /// - Returns `Error::InvalidThreshold` if `quorum` is zero or exceeds the number of members.
+        #[ink(message)]
+        pub fn set_oracle_quorum(&mut self, quorum: u32) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            self.apply_oracle_quorum(caller, quorum)
+        }
+
+        /// Returns the oracle members, in the order they were added.
+        #[ink(message)]
+        pub fn oracle_members(&self) -> Vec<AccountId> {
+            self.oracle_members.clone()
+        }
+
+        /// Returns the number of identical submissions needed to adopt oracle data.
+        #[ink(message)]
+        pub fn oracle_quorum(&self) -> u32 {
+            self.oracle_quorum
+        }
+
+        /// Returns the oracle voting round in progress and its submissions so far.
+        #[ink(message)]
+        pub fn pending_oracle_round(&self) -> OracleRound {
+            self.oracle_round.clone()
+        }
+
+        /// Adds an oracle member and emits `OracleMembershipChanged`. Authorization is the caller's responsibility.
+        fn insert_oracle_member(&mut self, by: AccountId, member: AccountId) -> Result<()> {
+            if self.oracle_members.contains(&member) {
+                return Err(Error::AlreadyOracleMember);
+            }
+            if self.oracle_members.len() >= MAX_ORACLE_MEMBERS {
+                return Err(Error::TooManyOracleMembers);
+            }
+            self.oracle_members.push(member);
+            let seq = self.next_event_seq();
+            self.env().emit_event(OracleMembershipChanged { by, member, added: true, seq });
+            Ok(())
+        }
+
+        /// Removes an oracle member and their pending submission, emits `OracleMembershipChanged`,
+        /// and settles the round if it can be. Authorization is the caller's responsibility.
+        fn delete_oracle_member(&mut self, by: AccountId, member: AccountId) -> Result<()> {
+            let position = self.oracle_members
+                .iter()
+                .position(|existing| *existing == member)
+                .ok_or(Error::NotOracleMember)?;
+            let remaining = self.oracle_members.len().saturating_sub(1);
+            if remaining > 0 && remaining < self.oracle_quorum as usize {
+                return Err(Error::InvalidThreshold);
+            }
+            self.oracle_members.remove(position);
+            self.oracle_round.submissions.retain(|(submitter, _)| *submitter != member);
+            let seq = self.next_event_seq();
+            self.env().emit_event(OracleMembershipChanged { by, member, added: false, seq });
+            self.settle_oracle_round();
+            Ok(())
+        }
+
+        /// Stores the oracle quorum, emits `OracleQuorumChanged`, and settles the round if it can be.
+        /// Authorization is the caller's responsibility.
+        fn apply_oracle_quorum(&mut self, by: AccountId, quorum: u32) -> Result<()> {
+            if quorum == 0 || quorum as usize > self.oracle_members.len() {
+                return Err(Error::InvalidThreshold);
+            }
+            self.oracle_quorum = quorum;
+            let seq = self.next_event_seq();
+            self.env().emit_event(OracleQuorumChanged { by, quorum, seq });
+            self.settle_oracle_round();
+            Ok(())
+        }
+
+        /// Adopts the data submitted by at least `oracle_quorum` members, or fails the round if every
+        /// member has submitted without agreement. Either way a new round starts; otherwise nothing changes.
+        fn settle_oracle_round(&mut self) {
+            let submissions = &self.oracle_round.submissions;
+            let agreed = submissions
+                .iter()
+                .map(|(_, data)| data)
+                .find(|data| {
+                    let votes = submissions.iter().filter(|(_, other)| other == *data).count();
+                    votes >= self.oracle_quorum as usize
+                })
+                .cloned();
+            let round = self.oracle_round.round;
+            if let Some(data) = agreed {
+                self.replace_oracle_prefixes(data.clone());
+                let seq = self.next_event_seq();
+                self.env().emit_event(OracleQuorumReached { round, data, seq });
+            } else if !submissions.is_empty() && submissions.len() >= self.oracle_members.len() {
+                let seq = self.next_event_seq();
+                self.env().emit_event(OracleRoundFailed { round, seq });
+            } else {
+                return;
+            }
+            self.oracle_round = OracleRound {
+                round: round.saturating_add(1),
+                submissions: Vec::new(),
+            };
+        }
+
+        /// Accepts an additional oracle prefix, so content hashes starting with any accepted prefix
+        /// pass prefix validation. Only an admin can call this function; when the approval threshold
+        /// is above one, it must go through `AdminAction::AddOraclePrefix`, and while the oracle delay
+        /// is not zero, through `propose_oracle_change`. Emits `OraclePrefixAdded`.
+        ///
+        /// # Arguments
+        /// - `prefix`: The prefix to accept.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        /// - Returns `Error::OracleDelayActive` if the oracle delay is not zero.
+        /// - Returns `Error::DuplicateOraclePrefix` if the prefix is already accepted.
+        /// - Returns `Error::TooManyOraclePrefixes` if `MAX_ORACLE_PREFIXES` prefixes are accepted already.
+        /// - Returns `Error::OracleDataTooLong` if the prefix exceeds `MAX_ORACLE_DATA_LENGTH` bytes.
+        #[ink(message)]
+        pub fn add_oracle_prefix(&mut self, prefix: String) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            if self.oracle_delay() > 0 {
+                return Err(Error::OracleDelayActive);
+            }
+            self.insert_oracle_prefix(caller, prefix)
+        }
+
+        /// Stops accepting an oracle prefix. Removing the last prefix makes prefix validation reject
+        /// every hash. Only an admin can call this function; when the approval threshold is above one,
+        /// it must go through `AdminAction::RemoveOraclePrefix`, and while the oracle delay is not zero,
+        /// through `propose_oracle_change`. Emits `OraclePrefixRemoved`.
+        ///
+        /// # Arguments
+        /// - `prefix`: The prefix to remove.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        /// - Returns `Error::OracleDelayActive` if the oracle delay is not zero.
+        /// - Returns `Error::OraclePrefixNotFound` if the prefix is not accepted.
+        #[ink(message)]
+        pub fn remove_oracle_prefix(&mut self, prefix: String) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            if self.oracle_delay() > 0 {
+                return Err(Error::OracleDelayActive);
+            }
+            self.delete_oracle_prefix(caller, prefix)
+        }
+
+        /// Returns the accepted oracle prefixes, in the order they were added.
+        #[ink(message)]
+        pub fn get_oracle_prefixes(&self) -> Vec<String> {
+            self.oracle_prefixes.clone()
+        }
+
+        /// Appends an oracle prefix and emits `OraclePrefixAdded`. Authorization is the caller's responsibility.
+        fn insert_oracle_prefix(&mut self, updated_by: AccountId, prefix: String) -> Result<()> {
+            Self::ensure_oracle_data_within_bounds(&prefix)?;
+            if self.oracle_prefixes.contains(&prefix) {
+                return Err(Error::DuplicateOraclePrefix);
+            }
+            if self.oracle_prefixes.len() >= MAX_ORACLE_PREFIXES {
+                return Err(Error::TooManyOraclePrefixes);
+            }
+            self.oracle_prefixes.push(prefix.clone());
+            self.record_oracle_change();
+            let seq = self.next_event_seq();
+            self.env().emit_event(OraclePrefixAdded { updated_by, prefix, seq });
+            Ok(())
+        }
+
+        /// Removes an oracle prefix and emits `OraclePrefixRemoved`. Authorization is the caller's responsibility.
+        fn delete_oracle_prefix(&mut self, updated_by: AccountId, prefix: String) -> Result<()> {
+            let position = self.oracle_prefixes
+                .iter()
+                .position(|accepted| *accepted == prefix)
+                .ok_or(Error::OraclePrefixNotFound)?;
+            self.oracle_prefixes.remove(position);
+            self.record_oracle_change();
+            let seq = self.next_event_seq();
+            self.env().emit_event(OraclePrefixRemoved { updated_by, prefix, seq });
+            Ok(())
+        }
+
+        /// Sets the number of admin approvals required to execute an `AdminAction`.
+        /// Can only be called directly while the threshold is one; afterwards it must be
+        /// changed through `AdminAction::SetApprovalThreshold`.
+        ///
+        /// # Arguments
+        /// - `threshold`: The number of approvals required, between one and the number of admins.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the current threshold is above one.
+        /// - Returns `Error::InvalidThreshold` if `threshold` is zero or exceeds the number of admins.
+        #[ink(message)]
+        pub fn set_approval_threshold(&mut self, threshold: u32) -> Result<()> {
+            self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            self.apply_approval_threshold(threshold)
+        }
+
+        /// Validates and stores a new approval threshold.
+        ///
+        /// # Errors
+        /// - Returns `Error::InvalidThreshold` if `threshold` is zero or exceeds the number of admins.
+        fn apply_approval_threshold(&mut self, threshold: u32) -> Result<()> {
+            if threshold == 0 || threshold > self.admin_count {
+                return Err(Error::InvalidThreshold);
+            }
+            self.approval_threshold = threshold;
+            Ok(())
+        }
+
+        /// Returns the number of admin approvals required to execute an `AdminAction`.
+        #[ink(message)]
+        pub fn approval_threshold(&self) -> u32 {
+            self.approval_threshold
+        }
+
+        /// Proposes a sensitive admin action, counting as the proposer's approval.
+        /// The action executes as soon as enough admins have approved it, which is immediately if the
+        /// threshold is one. It can be approved until `ADMIN_ACTION_EXPIRY_BLOCKS` blocks have passed.
+        ///
+        /// # Arguments
+        /// - `action`: The action to execute once approved.
+        ///
+        /// # Returns
+        /// - The ID of the pending action.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::OracleDataTooLong` if an `UpdateOracle` action carries oversized data.
+        /// - Returns `Error::CounterOverflow` if the action ID counter overflows.
+        /// - Returns any error produced by executing the action.
+        #[ink(message)]
+        pub fn propose_admin_action(&mut self, action: AdminAction) -> Result<u64> {
+            let caller = self.ensure_admin()?;
+            if let AdminAction::UpdateOracle(new_data) = &action {
+                Self::ensure_oracle_data_within_bounds(new_data)?;
+            }
+            let action_id = self.next_action_id;
+            self.next_action_id = self.next_action_id
+                .checked_add(1)
+                .ok_or(Error::CounterOverflow)?;
+            let expires_at = self.env().block_number().saturating_add(ADMIN_ACTION_EXPIRY_BLOCKS);
+            let pending = PendingAction {
+                action: action.clone(),
+                approvers: ink::prelude::vec![caller],
+                expires_at,
+            };
+            let seq = self.next_event_seq();
+            self.env().emit_event(AdminActionProposed {
+                action_id,
+                proposer: caller,
+                action,
+                expires_at,
+                seq,
+            });
+            self.record_approval(action_id, pending, caller)?;
+            Ok(action_id)
+        }
+
+        /// Approves a pending admin action, executing it if the approval threshold is reached.
+        ///
+        /// # Arguments
+        /// - `action_id`: The ID of the pending action.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ActionNotFound` if no pending action has this ID (including executed ones).
+        /// - Returns `Error::ActionExpired` if the action's expiry block has passed.
+        /// - Returns `Error::AlreadyApproved` if the caller already approved the action.
+        /// - Returns any error produced by executing the action.
+        #[ink(message)]
+        pub fn approve_action(&mut self, action_id: u64) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            let mut pending = self.pending_actions.get(action_id).ok_or(Error::ActionNotFound)?;
+            if self.env().block_number() > pending.expires_at {
+                return Err(Error::ActionExpired);
+            }
+            if pending.approvers.contains(&caller) {
+                return Err(Error::AlreadyApproved);
+            }
+            pending.approvers.push(caller);
+            self.record_approval(action_id, pending, caller)
+        }
+
+        /// Returns a pending admin action.
+        ///
+        /// # Arguments
+        /// - `action_id`: The ID of the pending action.
+        ///
+        /// # Returns
+        /// - An `Option` containing the pending action, or `None` if it is unknown or already executed.
+        #[ink(message)]
+        pub fn get_pending_action(&self, action_id: u64) -> Option<PendingAction> {
+            self.pending_actions.get(action_id)
+        }
+
+        /// Emits `AdminActionApproved` for a new approval, then either executes the action or stores it.
+        /// Only approvers that are still admins count. The threshold is capped by the number of admins,
+        /// so removing admins can never make a pending action impossible to execute.
+        fn record_approval(&mut self, action_id: u64, pending: PendingAction, approver: AccountId) -> Result<()> {
+            let approvals = pending.approvers
+                .iter()
+                .filter(|account| self.is_admin(**account))
+                .count() as u32;
+            let seq = self.next_event_seq();
+            self.env().emit_event(AdminActionApproved {
+                action_id,
+                approver,
+                approvals,
+                seq,
+            });
+            if approvals < self.approval_threshold.min(self.admin_count) {
+                self.pending_actions.insert(action_id, &pending);
+                return Ok(());
+            }
+            self.pending_actions.remove(action_id);
+            match pending.action {
+                AdminAction::UpdateOracle(new_data) => {
+                    self.apply_or_schedule_oracle_change(approver, OracleChange::ReplaceData(new_data))?
+                }
+                AdminAction::SetApprovalThreshold(threshold) => self.apply_approval_threshold(threshold)?,
+                AdminAction::AddOraclePrefix(prefix) => {
+                    self.apply_or_schedule_oracle_change(approver, OracleChange::AddPrefix(prefix))?
+                }
+                AdminAction::RemoveOraclePrefix(prefix) => {
+                    self.apply_or_schedule_oracle_change(approver, OracleChange::RemovePrefix(prefix))?
+                }
+                AdminAction::SetOracleAccount(account) => self.apply_oracle_account(approver, account),
+                AdminAction::AddOracleMember(member) => self.insert_oracle_member(approver, member)?,
+                AdminAction::RemoveOracleMember(member) => self.delete_oracle_member(approver, member)?,
+                AdminAction::SetOracleQuorum(quorum) => self.apply_oracle_quorum(approver, quorum)?,
+                AdminAction::SetOracleDelay(delay) => self.apply_oracle_delay(approver, delay),
+                AdminAction::SetValidator(validator) => {
+                    self.apply_or_schedule_oracle_change(approver, OracleChange::SetValidator(validator))?
+                }
+                AdminAction::SetValidationStrategy(strategy) => {
+                    self.apply_or_schedule_oracle_change(approver, OracleChange::SetValidationStrategy(strategy))?
+                }
+                AdminAction::SetAttestor(attestor) => self.apply_attestor(approver, attestor),
+                AdminAction::SetAllowlistRoot(root) => self.apply_allowlist_root(approver, root),
+                AdminAction::SetTreasury(treasury) => self.apply_treasury(approver, treasury),
+                AdminAction::WithdrawFees(amount) => self.send_fees(approver, amount)?,
+                AdminAction::AddAdmin(account) => self.insert_admin(approver, account),
+                AdminAction::RemoveAdmin(account) => self.delete_admin(approver, account)?,
+                AdminAction::GrantRole(role, account) => self.insert_role(approver, role, account),
+                AdminAction::RevokeRole(role, account) => self.delete_role(approver, role, account),
+                AdminAction::SetFeeSchedule(primary_bps, secondary_bps) => {
+                    self.apply_fee_schedule(approver, primary_bps, secondary_bps)?
+                }
+                AdminAction::SetRegistrationFee(fee) => self.apply_registration_fee(approver, fee),
+                AdminAction::SetMaxRoyalty(bps) => self.apply_max_royalty(approver, bps)?,
+                AdminAction::SetAuctionExtension(window) => self.apply_auction_extension(approver, window),
+                AdminAction::SetForcedTransferDelay(delay) => self.apply_forced_transfer_delay(approver, delay),
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(AdminActionExecuted { action_id, seq });
+            Ok(())
+        }
+
+        /// Pauses every operation until `unpause` is called.
+        /// Read-only queries keep working. Any single admin may pause, even under M-of-N approval,
+        /// so the switch stays usable in an emergency.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            self.pause_state = PauseState::all(true);
+            let seq = self.next_event_seq();
+            self.env().emit_event(Paused { account: caller, seq });
+            Ok(())
+        }
+
+        /// Unpauses every operation.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        #[ink(message)]
+        pub fn unpause(&mut self) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            self.pause_state = PauseState::all(false);
+            let seq = self.next_event_seq();
+            self.env().emit_event(Unpaused { account: caller, seq });
+            Ok(())
+        }
+
+        /// Pauses or unpauses a single operation, leaving the others unchanged.
+        ///
+        /// # Arguments
+        /// - `op`: The operation to change.
+        /// - `paused`: Whether the operation should be paused.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        #[ink(message)]
+        pub fn set_paused(&mut self, op: PausableOp, paused: bool) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            self.pause_state.set(op, paused);
+            let seq = self.next_event_seq();
+            self.env().emit_event(PauseStateChanged {
+                account: caller,
+                op,
+                paused,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Returns whether any operation is paused.
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.pause_state != PauseState::default()
+        }
+
+        /// Returns the pause flag of every operation.
+        #[ink(message)]
+        pub fn pause_state(&self) -> PauseState {
+            self.pause_state
+        }
+
+        /// Checks that an operation is not paused.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if `op` is paused.
+        fn ensure_not_paused(&self, op: PausableOp) -> Result<()> {
+            if self.pause_state.is_paused(op) {
+                return Err(Error::ContractPaused);
+            }
+            Ok(())
+        }
+
+        /// Bars an account from registering new content. Content it already owns is unaffected
+        /// and can still be transferred.
+        ///
+        /// # Arguments
+        /// - `account`: The AccountId to blacklist.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        #[ink(message)]
+        pub fn blacklist_account(&mut self, account: AccountId) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            self.blacklist.insert(account, &());
+            let seq = self.next_event_seq();
+            self.env().emit_event(Blacklisted { account, by: caller, seq });
+            Ok(())
+        }
+
+        /// Removes an account from the blacklist.
+        ///
+        /// # Arguments
+        /// - `account`: The AccountId to remove from the blacklist.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        #[ink(message)]
+        pub fn unblacklist_account(&mut self, account: AccountId) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            self.blacklist.remove(account);
+            let seq = self.next_event_seq();
+            self.env().emit_event(Unblacklisted { account, by: caller, seq });
+            Ok(())
+        }
+
+        /// Checks whether an account is blacklisted from registering content.
+        #[ink(message)]
+        pub fn is_blacklisted(&self, account: AccountId) -> bool {
+            self.blacklist.contains(account)
+        }
+
+        /// Switches who may register new content.
+        /// Already registered content and transfers are unaffected by the mode.
+        ///
+        /// # Arguments
+        /// - `mode`: The new registration mode.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        #[ink(message)]
+        pub fn set_registration_mode(&mut self, mode: RegistrationMode) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            self.registration_mode = mode;
+            let seq = self.next_event_seq();
+            self.env().emit_event(RegistrationModeChanged { by: caller, mode, seq });
+            Ok(())
+        }
+
+        /// Returns the current registration mode.
+        #[ink(message)]
+        pub fn registration_mode(&self) -> RegistrationMode {
+            self.registration_mode
+        }
+
+        /// Turns the proof-of-possession requirement on or off. While it is on, self-service
+        /// registrations, including `register_preimage`, must go through `register_content_with_pop`.
+        /// Emits `PopRequirementChanged`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        #[ink(message)]
+        pub fn require_pop(&mut self, required: bool) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            self.pop_required = required;
+            let seq = self.next_event_seq();
+            self.env().emit_event(PopRequirementChanged { by: caller, required, seq });
+            Ok(())
+        }
+
+        /// Returns whether registrations must carry a proof of possession.
+        #[ink(message)]
+        pub fn pop_required(&self) -> bool {
+            self.pop_required
+        }
+
+        /// Sets the native-token fee every registration must carry, to deter spam. Collected fees stay
+        /// in the contract balance; admin imports are free. Only an admin can call this function; when
+        /// the approval threshold is above one, it must go through `AdminAction::SetRegistrationFee`.
+        /// Emits `RegistrationFeeChanged`.
+        ///
+        /// # Arguments
+        /// - `fee`: The fee per registration; zero makes registration free.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        #[ink(message)]
+        pub fn set_registration_fee(&mut self, fee: Balance) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            self.apply_registration_fee(caller, fee);
+            Ok(())
+        }
+
+        /// Stores the registration fee and emits `RegistrationFeeChanged`. Authorization is the caller's responsibility.
+        fn apply_registration_fee(&mut self, by: AccountId, fee: Balance) {
+            self.registration_fee = fee;
+            let seq = self.next_event_seq();
+            self.env().emit_event(RegistrationFeeChanged { by, fee, seq });
+        }
+
+        /// Returns the native-token fee charged per registration.
+        #[ink(message)]
+        pub fn registration_fee(&self) -> Balance {
+            self.registration_fee
+        }
+
+        /// Charges the registration fee from the value attached to the current call, crediting any
+        /// overpayment to the caller. Call it after every other check, right before storing the record.
+        ///
+        /// # Errors
+        /// - Returns `Error::InsufficientFee` if the attached value is below the registration fee.
+        /// - Returns `Error::BalanceOverflow` if the collected fees or the caller's credit would overflow.
+        fn charge_registration_fee(&mut self) -> Result<()> {
+            let paid = self.env().transferred_value();
+            let excess = paid.checked_sub(self.registration_fee).ok_or(Error::InsufficientFee)?;
+            let collected_fees = self.collected_fees
+                .checked_add(self.registration_fee)
+                .ok_or(Error::BalanceOverflow)?;
+            if excess > 0 {
+                let caller = self.env().caller();
+                let credit = self.credited(caller, excess)?;
+                self.credits.insert(caller, &credit);
+            }
+            self.collected_fees = collected_fees;
+            Ok(())
+        }
+
+        /// Sets one platform fee for all sales and payments made from now on; see `set_fee_schedule`.
+        /// When the approval threshold is above one, it must go through `AdminAction::SetFeeSchedule`.
+        /// Emits `PlatformFeeChanged`.
+        ///
+        /// # Arguments
+        /// - `bps`: The fee in basis points of the sale price, at most `MAX_PLATFORM_FEE_BPS`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        /// - Returns `Error::FeeTooHigh` if `bps` is above `MAX_PLATFORM_FEE_BPS`.
+        #[ink(message)]
+        pub fn set_platform_fee_bps(&mut self, bps: u16) -> Result<()> {
+            self.set_fee_schedule(bps, bps)
+        }
+
+        /// Sets the platform fees taken from the price of every sale made from now on, through `buy`,
+        /// `buy_dutch`, `buy_bundle`, `accept_offer`, or `settle_auction`, and of `purchase_access`,
+        /// `rent`, and `buy_license`. The primary fee applies to the first sale of a content item,
+        /// however it is sold, and the secondary fee to every later sale and to access purchases,
+        /// rentals, and licenses. The fee is added
+        /// to the collected fees and the seller is credited the rest; on `buy_with_token` the fee is
+        /// paid in the token to the treasury. Only an admin can call this function; when the approval
+        /// threshold is above one, it must go through `AdminAction::SetFeeSchedule`.
+        /// Emits `PlatformFeeChanged`.
+        ///
+        /// # Arguments
+        /// - `primary_bps`: The fee on first sales in basis points of the price, at most `MAX_PLATFORM_FEE_BPS`.
+        /// - `secondary_bps`: The fee on other payments in basis points of the price, at most `MAX_PLATFORM_FEE_BPS`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        /// - Returns `Error::FeeTooHigh` if either fee is above `MAX_PLATFORM_FEE_BPS`.
+        #[ink(message)]
+        pub fn set_fee_schedule(&mut self, primary_bps: u16, secondary_bps: u16) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            self.apply_fee_schedule(caller, primary_bps, secondary_bps)
+        }
+
+        /// Stores the platform fees and emits `PlatformFeeChanged`. Authorization is the caller's responsibility.
+        ///
+        /// # Errors
+        /// - Returns `Error::FeeTooHigh` if either fee is above `MAX_PLATFORM_FEE_BPS`.
+        fn apply_fee_schedule(&mut self, by: AccountId, primary_bps: u16, secondary_bps: u16) -> Result<()> {
+            if primary_bps > MAX_PLATFORM_FEE_BPS || secondary_bps > MAX_PLATFORM_FEE_BPS {
+                return Err(Error::FeeTooHigh);
+            }
+            self.primary_fee_bps = primary_bps;
+            self.secondary_fee_bps = secondary_bps;
+            let seq = self.next_event_seq();
+            self.env().emit_event(PlatformFeeChanged {
+                by,
+                primary_bps,
+                secondary_bps,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Returns the platform fee, in basis points, taken from the first sale of a content item.
+        #[ink(message)]
+        pub fn primary_fee_bps(&self) -> u16 {
+            self.primary_fee_bps
+        }
+
+        /// Returns the platform fee, in basis points, taken from later sales, access purchases, rentals,
+        /// and licenses.
+        #[ink(message)]
+        pub fn secondary_fee_bps(&self) -> u16 {
+            self.secondary_fee_bps
+        }
+
+        /// Checks whether a content item has completed a sale through any mechanism, so its next sale
+        /// is charged the secondary fee. Access purchases and rentals are not sales.
+        #[ink(message)]
+        pub fn is_primary_sold(&self, content_id: u64) -> bool {
+            // Every completed sale goes through `record_sale`, and its count never decreases.
+            self.sale_count(content_id) > 0
+        }
+
+        /// Returns the platform fee on a payment of `price` for a content item from `source`, rounded
+        /// down so any fraction stays with the seller.
+        fn platform_fee(&self, content_id: u64, price: Balance, source: RevenueSource) -> Balance {
+            let bps = match source {
+                RevenueSource::Sale if !self.is_primary_sold(content_id) => self.primary_fee_bps,
+                _ => self.secondary_fee_bps,
+            };
+            Self::bps_of(price, bps)
+        }
+
+        /// Returns `bps` basis points of an amount, rounded down.
+        fn bps_of(amount: Balance, bps: u16) -> Balance {
+            let bps = Balance::from(bps);
+            // Split the amount so `amount * bps` cannot overflow.
+            amount / BPS_DENOMINATOR * bps + amount % BPS_DENOMINATOR * bps / BPS_DENOMINATOR
+        }
+
+        /// Sets the cap on the royalties creators can set from now on; royalties already set are kept.
+        /// Only an admin can call this function; when the approval threshold is above one, it must go
+        /// through `AdminAction::SetMaxRoyalty`. Emits `MaxRoyaltyChanged`.
+        ///
+        /// # Arguments
+        /// - `bps`: The cap in basis points of the sale price, at most `MAX_ROYALTY_CAP_BPS`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        /// - Returns `Error::RoyaltyTooHigh` if `bps` is above `MAX_ROYALTY_CAP_BPS`.
+        #[ink(message)]
+        pub fn set_max_royalty_bps(&mut self, bps: u16) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            self.apply_max_royalty(caller, bps)
+        }
+
+        /// Stores the royalty cap and emits `MaxRoyaltyChanged`. Authorization is the caller's responsibility.
+        ///
+        /// # Errors
+        /// - Returns `Error::RoyaltyTooHigh` if `bps` is above `MAX_ROYALTY_CAP_BPS`.
+        fn apply_max_royalty(&mut self, by: AccountId, bps: u16) -> Result<()> {
+            if bps > MAX_ROYALTY_CAP_BPS {
+                return Err(Error::RoyaltyTooHigh);
+            }
+            self.max_royalty_bps = bps;
+            let seq = self.next_event_seq();
+            self.env().emit_event(MaxRoyaltyChanged { by, bps, seq });
+            Ok(())
+        }
+
+        /// Returns the cap, in basis points, on the royalties creators can set.
+        #[ink(message)]
+        pub fn max_royalty_bps(&self) -> u16 {
+            self.max_royalty_bps
+        }
+
+        /// Changes the account that receives withdrawn fees. Only an admin can call this function;
+        /// when the approval threshold is above one, it must go through `AdminAction::SetTreasury`.
+        /// Emits `TreasuryChanged`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        #[ink(message)]
+        pub fn set_treasury(&mut self, treasury: AccountId) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            self.apply_treasury(caller, treasury);
+            Ok(())
+        }
+
+        /// Stores the treasury account and emits `TreasuryChanged`. Authorization is the caller's responsibility.
+        fn apply_treasury(&mut self, by: AccountId, treasury: AccountId) {
+            self.treasury = treasury;
+            let seq = self.next_event_seq();
+            self.env().emit_event(TreasuryChanged { by, treasury, seq });
+        }
+
+        /// Returns the account that receives withdrawn fees.
+        #[ink(message)]
+        pub fn treasury(&self) -> AccountId {
+            self.treasury
+        }
+
+        /// Returns the fees collected and not yet withdrawn.
+        #[ink(message)]
+        pub fn collected_fees(&self) -> Balance {
+            self.collected_fees
+        }
+
+        /// Sends collected fees to the treasury. Funds held for users, such as escrowed offers, are
+        /// not fees and can never be withdrawn this way. Only an admin can call this function; when
+        /// the approval threshold is above one, it must go through `AdminAction::WithdrawFees`.
+        /// Emits `FeesWithdrawn`.
+        ///
+        /// # Arguments
+        /// - `amount`: The amount to send, at most `collected_fees`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        /// - Returns `Error::NothingToWithdraw` if `amount` is zero or no fees are collected.
+        /// - Returns `Error::InsufficientContractBalance` if `amount` exceeds the collected fees or the contract balance.
+        /// - Returns `Error::TransferFailed` if the transfer to the treasury fails.
+        #[ink(message)]
+        pub fn withdraw_fees(&mut self, amount: Balance) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            self.send_fees(caller, amount)
+        }
+
+        /// Transfers collected fees to the treasury and emits `FeesWithdrawn`. Authorization is the
+        /// caller's responsibility. Nothing is written if a check or the transfer fails.
+        fn send_fees(&mut self, by: AccountId, amount: Balance) -> Result<()> {
+            if amount == 0 || self.collected_fees == 0 {
+                return Err(Error::NothingToWithdraw);
+            }
+            if amount > self.collected_fees || amount > self.env().balance() {
+                return Err(Error::InsufficientContractBalance);
+            }
+            let treasury = self.treasury;
+            self.env()
+                .transfer(treasury, amount)
+                .map_err(|_| Error::TransferFailed)?;
+            self.collected_fees -= amount;
+            let seq = self.next_event_seq();
+            self.env().emit_event(FeesWithdrawn { by, treasury, amount, seq });
+            Ok(())
+        }
+
+        /// Switches how hashes declared as `HashScheme::Other` are validated at registration.
+        /// Already registered content is unaffected. Only an admin can call this function; when the
+        /// approval threshold is above one, it must go through `AdminAction::SetValidationStrategy`,
+        /// and while the oracle delay is not zero, through `propose_oracle_change`.
+        /// Emits `ValidationStrategyChanged`.
+        ///
+        /// # Arguments
+        /// - `strategy`: The new validation strategy.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        /// - Returns `Error::OracleDelayActive` if the oracle delay is not zero.
+        #[ink(message)]
+        pub fn set_validation_strategy(&mut self, strategy: ValidationStrategy) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            if self.oracle_delay() > 0 {
+                return Err(Error::OracleDelayActive);
+            }
+            self.apply_validation_strategy(caller, strategy);
+            Ok(())
+        }
+
+        /// Stores the validation strategy and emits `ValidationStrategyChanged`. Authorization is the
+        /// caller's responsibility.
+        fn apply_validation_strategy(&mut self, by: AccountId, strategy: ValidationStrategy) {
+            self.validation_strategy = strategy;
+            let seq = self.next_event_seq();
+            self.env().emit_event(ValidationStrategyChanged { by, strategy, seq });
+        }
+
+        /// Returns the current validation strategy, so frontends can pre-validate hashes.
+        #[ink(message)]
+        pub fn get_validation_strategy(&self) -> ValidationStrategy {
+            self.validation_strategy
+        }
+
+        /// Turns re-validation at transfer time on or off. While it is on, every transfer path re-checks
+        /// the stored hash like registration does, so content that no longer passes the current rules
+        /// cannot change hands. Emits `RevalidateOnTransferChanged`.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        #[ink(message)]
+        pub fn set_revalidate_on_transfer(&mut self, enabled: bool) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            self.revalidate_on_transfer = enabled;
+            let seq = self.next_event_seq();
+            self.env().emit_event(RevalidateOnTransferChanged { by: caller, enabled, seq });
+            Ok(())
+        }
+
+        /// Returns whether transfers re-validate the content hash.
+        #[ink(message)]
+        pub fn revalidate_on_transfer(&self) -> bool {
+            self.revalidate_on_transfer
+        }
+
+        /// Returns whether a content item's hash passes the current validation rules, whether or not
+        /// transfers enforce them. Returns `false` if the content ID is not found.
+        #[ink(message)]
+        pub fn revalidate(&self, content_id: u64) -> bool {
+            self.contents
+                .get(content_id)
+                .is_some_and(|record| self.check_hash_format(record.content_hash, record.scheme).is_ok())
+        }
+
+        /// Checks that a content item may change hands under the current validation rules.
+        ///
+        /// # Errors
+        /// - Returns `Error::InvalidContent` if re-validation on transfer is on and the hash no longer passes.
+        fn ensure_still_valid(&self, record: &Content) -> Result<()> {
+            if self.revalidate_on_transfer && self.check_hash_format(record.content_hash.clone(), record.scheme).is_err() {
+                return Err(Error::InvalidContent);
+            }
+            Ok(())
+        }
+
+        /// Sets or clears an external `ContentValidator` contract. While set, hashes declared as
+        /// `HashScheme::Other` are validated by calling its `is_valid` instead of the internal
+        /// `ValidationStrategy`; a failing call rejects the hash. Only an admin can call this function;
+        /// when the approval threshold is above one, it must go through `AdminAction::SetValidator`,
+        /// and while the oracle delay is not zero, through `propose_oracle_change`. Emits `ValidatorChanged`.
+        ///
+        /// # Arguments
+        /// - `validator`: The validator contract, or `None` to fall back to the internal check.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        /// - Returns `Error::OracleDelayActive` if the oracle delay is not zero.
+        #[ink(message)]
+        pub fn set_validator(&mut self, validator: Option<AccountId>) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            if self.oracle_delay() > 0 {
+                return Err(Error::OracleDelayActive);
+            }
+            self.apply_validator(caller, validator);
+            Ok(())
+        }
+
+        /// Stores the validator contract and emits `ValidatorChanged`. Authorization is the caller's responsibility.
+        fn apply_validator(&mut self, by: AccountId, validator: Option<AccountId>) {
+            self.validator = validator;
+            let seq = self.next_event_seq();
+            self.env().emit_event(ValidatorChanged { by, validator, seq });
+        }
+
+        /// Returns the external validator contract, if one is set.
+        #[ink(message)]
+        pub fn validator(&self) -> Option<AccountId> {
+            self.validator
+        }
+
+        /// Sets or clears the public key of the off-chain oracle that signs attestations for
+        /// `register_with_attestation`. Only an admin can call this function; when the approval
+        /// threshold is above one, it must go through `AdminAction::SetAttestor`. Emits `AttestorChanged`.
+        ///
+        /// # Arguments
+        /// - `attestor`: The compressed ECDSA public key, or `None` to stop accepting attestations.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        #[ink(message)]
+        pub fn set_attestor(&mut self, attestor: Option<[u8; 33]>) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            self.apply_attestor(caller, attestor);
+            Ok(())
+        }
+
+        /// Stores the attestor public key and emits `AttestorChanged`. Authorization is the caller's responsibility.
+        fn apply_attestor(&mut self, by: AccountId, attestor: Option<[u8; 33]>) {
+            self.attestor = attestor;
+            let seq = self.next_event_seq();
+            self.env().emit_event(AttestorChanged { by, attestor, seq });
+        }
+
+        /// Returns the attestor public key, if one is set.
+        #[ink(message)]
+        pub fn attestor(&self) -> Option<[u8; 33]> {
+            self.attestor
+        }
+
+        /// Replaces the Merkle root of pre-approved content hashes. Content registered under an
+        /// earlier root stays registered. Only an admin can call this function; when the approval
+        /// threshold is above one, it must go through `AdminAction::SetAllowlistRoot`.
+        /// Emits `AllowlistRootUpdated`.
+        ///
+        /// # Arguments
+        /// - `root`: The root of a tree whose leaves are `blake2_256(content_hash)`; see the `merkle` module.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        #[ink(message)]
+        pub fn set_allowlist_root(&mut self, root: Hash) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            self.apply_allowlist_root(caller, root);
+            Ok(())
+        }
+
+        /// Stores the allowlist root and emits `AllowlistRootUpdated`. Authorization is the caller's responsibility.
+        fn apply_allowlist_root(&mut self, by: AccountId, root: Hash) {
+            self.allowlist_root = Some(root);
+            let seq = self.next_event_seq();
+            self.env().emit_event(AllowlistRootUpdated { by, root, seq });
+        }
+
+        /// Returns the allowlist Merkle root, if one is set.
+        #[ink(message)]
+        pub fn allowlist_root(&self) -> Option<Hash> {
+            self.allowlist_root
+        }
+
+        /// Adds an account to the registrar whitelist used in `WhitelistOnly` mode.
+        ///
+        /// # Arguments
+        /// - `account`: The AccountId to whitelist.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        #[ink(message)]
+        pub fn add_registrar(&mut self, account: AccountId) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            self.whitelist.insert(account, &());
+            let seq = self.next_event_seq();
+            self.env().emit_event(RegistrarAdded { account, by: caller, seq });
+            Ok(())
+        }
+
+        /// Removes an account from the registrar whitelist.
+        ///
+        /// # Arguments
+        /// - `account`: The AccountId to remove.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        #[ink(message)]
+        pub fn remove_registrar(&mut self, account: AccountId) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            self.whitelist.remove(account);
+            let seq = self.next_event_seq();
+            self.env().emit_event(RegistrarRemoved { account, by: caller, seq });
+            Ok(())
+        }
+
+        /// Checks whether an account is on the registrar whitelist.
+        #[ink(message)]
+        pub fn is_registrar(&self, account: AccountId) -> bool {
+            self.whitelist.contains(account)
+        }
+
+        /// Proposes a successor for the caller's admin seat. The change only takes effect once the
+        /// successor calls `accept_admin`, so a mistyped AccountId cannot lock the admin out.
+        /// Only one handover can be pending; a new proposal replaces it.
+        ///
+        /// # Arguments
+        /// - `new_admin`: The AccountId of the proposed admin.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        #[ink(message)]
+        pub fn propose_admin(&mut self, new_admin: AccountId) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            self.pending_admin = Some((caller, new_admin));
+            let seq = self.next_event_seq();
+            self.env().emit_event(AdminProposed {
+                admin: caller,
+                proposed: Some(new_admin),
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Cancels the pending admin handover, if any. Any admin may cancel.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        #[ink(message)]
+        pub fn cancel_admin_proposal(&mut self) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            self.pending_admin = None;
+            let seq = self.next_event_seq();
+            self.env().emit_event(AdminProposed {
+                admin: caller,
+                proposed: None,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Accepts the admin role, replacing the proposer in the admin set.
+        /// Only the pending admin can call this function.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotPendingAdmin` if the caller is not the pending admin.
+        #[ink(message)]
+        pub fn accept_admin(&mut self) -> Result<()> {
+            let caller = self.env().caller();
+            let old_admin = match self.pending_admin {
+                Some((proposer, successor)) if successor == caller => proposer,
+                _ => return Err(Error::NotPendingAdmin),
+            };
+            self.pending_admin = None;
+            if old_admin != caller {
+                self.add_to_admins(caller);
+                self.remove_from_admins(old_admin);
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(AdminChanged {
+                old_admin,
+                new_admin: caller,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Gives up the caller's admin seat. Unlike `remove_admin`, this also works for the last admin:
+        /// once no admins remain the contract is immutable, all admin-only messages return
+        /// `Error::NotAdmin` forever, and the role can never be reclaimed.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        #[ink(message)]
+        pub fn renounce_admin(&mut self) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            self.remove_from_admins(caller);
+            let seq = self.next_event_seq();
+            self.env().emit_event(AdminRenounced {
+                admin: caller,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Adds an account to the admin set. Adding an existing admin is a no-op apart from the event.
+        /// Only an admin can call this function; when the approval threshold is above one, it must go
+        /// through `AdminAction::AddAdmin`.
+        ///
+        /// # Arguments
+        /// - `account`: The AccountId to add.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        #[ink(message)]
+        pub fn add_admin(&mut self, account: AccountId) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            self.insert_admin(caller, account);
+            Ok(())
+        }
+
+        /// Removes an account from the admin set. The removal takes effect immediately.
+        /// The last remaining admin cannot be removed; use `renounce_admin` to give up control for good.
+        /// Only an admin can call this function; when the approval threshold is above one, it must go
+        /// through `AdminAction::RemoveAdmin`.
+        ///
+        /// # Arguments
+        /// - `account`: The AccountId to remove.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin, or `account` is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        /// - Returns `Error::LastAdmin` if `account` is the last remaining admin.
+        #[ink(message)]
+        pub fn remove_admin(&mut self, account: AccountId) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            self.delete_admin(caller, account)
+        }
+
+        /// Adds an account to the admin set and emits `AdminAdded`. Authorization is the caller's responsibility.
+        fn insert_admin(&mut self, by: AccountId, account: AccountId) {
+            self.add_to_admins(account);
+            let seq = self.next_event_seq();
+            self.env().emit_event(AdminAdded {
+                admin: account,
+                added_by: by,
+                seq,
+            });
+        }
+
+        /// Removes an account from the admin set and emits `AdminRemoved`. Authorization is the caller's
+        /// responsibility.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if `account` is not an admin.
+        /// - Returns `Error::LastAdmin` if `account` is the last remaining admin.
+        /// - Returns `Error::InvalidThreshold` if fewer admins than the approval threshold would remain.
+        fn delete_admin(&mut self, by: AccountId, account: AccountId) -> Result<()> {
+            if !self.admins.contains(account) {
+                return Err(Error::NotAdmin);
+            }
+            if self.admin_count <= 1 {
+                return Err(Error::LastAdmin);
+            }
+            if self.admin_count - 1 < self.approval_threshold {
+                return Err(Error::InvalidThreshold);
+            }
+            self.remove_from_admins(account);
+            let seq = self.next_event_seq();
+            self.env().emit_event(AdminRemoved {
+                admin: account,
+                removed_by: by,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Checks whether an account is an admin.
+        ///
+        /// # Arguments
+        /// - `account`: The AccountId to check.
+        ///
+        /// # Returns
+        /// - `true` if `account` is in the admin set, `false` otherwise.
+        #[ink(message)]
+        pub fn is_admin(&self, account: AccountId) -> bool {
+            self.admins.contains(account)
+        }
+
+        /// Returns the number of admins.
+        ///
+        /// # Returns
+        /// - The size of the admin set; `0` once the last admin has renounced.
+        #[ink(message)]
+        pub fn admin_count(&self) -> u32 {
+            self.admin_count
+        }
+
+        /// Returns the first admin in the admin index, e.g. the deployer while it is the only admin.
+        ///
+        /// # Returns
+        /// - `None` once the last admin has renounced.
+        #[ink(message)]
+        pub fn get_admin(&self) -> Option<AccountId> {
+            self.admin_list.get(0)
+        }
+
+        /// Returns a page of the admins. The order is not stable: when an admin is removed, the last
+        /// entry takes its position (swap-remove).
+        ///
+        /// # Arguments
+        /// - `start`: The position in the admin index to start from.
+        /// - `limit`: The maximum number of accounts to return, capped at `MAX_PAGE_SIZE`.
+        ///
+        /// # Returns
+        /// - A `Vec` of admins in index order, empty if `start` is past the end.
+        #[ink(message)]
+        pub fn admins(&self, start: u32, limit: u32) -> Vec<AccountId> {
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.admin_count);
+            (start..end)
+                .filter_map(|position| self.admin_list.get(position))
+                .collect()
+        }
+
+        /// Adds an account to the admin set and index without any checks, if not already an admin.
+        fn add_to_admins(&mut self, account: AccountId) {
+            if self.admins.contains(account) {
+                return;
+            }
+            let position = self.admin_count;
+            self.admin_list.insert(position, &account);
+            self.admins.insert(account, &position);
+            self.admin_count = position.saturating_add(1);
+        }
+
+        /// Removes an account from the admin set without any checks, discarding a handover it proposed.
+        /// The last admin in the index moves into the freed position (swap-remove).
+        fn remove_from_admins(&mut self, account: AccountId) {
+            if let Some(position) = self.admins.take(account) {
+                let last = self.admin_count.saturating_sub(1);
+                if position != last {
+                    if let Some(last_admin) = self.admin_list.get(last) {
+                        self.admin_list.insert(position, &last_admin);
+                        self.admins.insert(last_admin, &position);
+                    }
+                }
+                self.admin_list.remove(last);
+                self.admin_count = last;
+            }
+            if matches!(self.pending_admin, Some((proposer, _)) if proposer == account) {
+                self.pending_admin = None;
+            }
+        }
+
+        /// Grants a role to an account. Granting a role the account already holds is a no-op
+        /// apart from the event. Only an admin can call this function; when the approval threshold is
+        /// above one, it must go through `AdminAction::GrantRole`.
+        ///
+        /// # Arguments
+        /// - `role`: The role to grant.
+        /// - `account`: The AccountId receiving the role.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        #[ink(message)]
+        pub fn grant_role(&mut self, role: Role, account: AccountId) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            self.insert_role(caller, role, account);
+            Ok(())
+        }
+
+        /// Revokes a role from an account. The revocation takes effect immediately. Only an admin can
+        /// call this function; when the approval threshold is above one, it must go through
+        /// `AdminAction::RevokeRole`.
+        ///
+        /// # Arguments
+        /// - `role`: The role to revoke.
+        /// - `account`: The AccountId losing the role.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        #[ink(message)]
+        pub fn revoke_role(&mut self, role: Role, account: AccountId) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            self.delete_role(caller, role, account);
+            Ok(())
+        }
+
+        /// Grants a role and emits `RoleGranted`. Authorization is the caller's responsibility.
+        fn insert_role(&mut self, by: AccountId, role: Role, account: AccountId) {
+            self.roles.insert((role, account), &());
+            let seq = self.next_event_seq();
+            self.env().emit_event(RoleGranted {
+                role,
+                account,
+                granted_by: by,
+                seq,
+            });
+        }
+
+        /// Revokes a role and emits `RoleRevoked`. Authorization is the caller's responsibility.
+        fn delete_role(&mut self, by: AccountId, role: Role, account: AccountId) {
+            self.roles.remove((role, account));
+            let seq = self.next_event_seq();
+            self.env().emit_event(RoleRevoked {
+                role,
+                account,
+                revoked_by: by,
+                seq,
+            });
+        }
+
+        /// Checks whether an account holds a role.
+        /// Roles are void once the last admin has renounced, since they could no longer be revoked.
+        ///
+        /// # Arguments
+        /// - `role`: The role to check.
+        /// - `account`: The AccountId to check.
+        ///
+        /// # Returns
+        /// - `true` if `account` holds `role`, `false` otherwise.
+        #[ink(message)]
+        pub fn has_role(&self, role: Role, account: AccountId) -> bool {
+            self.admin_count > 0 && self.roles.contains((role, account))
+        }
+
+        /// Returns the admin successor awaiting acceptance, if any.
+        ///
+        /// # Returns
+        /// - An `Option` containing the pending admin's AccountId.
+        #[ink(message)]
+        pub fn get_pending_admin(&self) -> Option<AccountId> {
+            self.pending_admin.map(|(_, successor)| successor)
+        }
+
+        /// Checks that the caller is in the admin set. Every admin-only message must go through this.
+        ///
+        /// # Returns
+        /// - The caller's AccountId.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        fn ensure_admin(&self) -> Result<AccountId> {
+            let caller = self.env().caller();
+            if !self.is_admin(caller) {
+                return Err(Error::NotAdmin);
+            }
+            Ok(caller)
+        }
+
+        /// Checks that the caller is an admin or holds `role`.
+        ///
+        /// # Returns
+        /// - The caller's AccountId.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither an admin nor a holder of `role`.
+        fn ensure_admin_or_role(&self, role: Role) -> Result<AccountId> {
+            let caller = self.env().caller();
+            if !self.is_admin(caller) && !self.has_role(role, caller) {
+                return Err(Error::NotAdmin);
+            }
+            Ok(caller)
+        }
+
+        /// Registers new digital content on-chain.
+        /// The caller provides a content hash, which is validated by the current `ValidationStrategy`.
+        /// If valid, the content is stored with the caller as the owner. A hash that is already
+        /// registered is rejected, even for its own owner; `get_content_id_by_hash` returns its ID.
+        /// Commitments are hidden, so this cannot check them: a registration made after another
+        /// account's `commit_registration` of the same hash is reclaimed when that commitment is revealed.
+        /// The call must carry at least `registration_fee`; any excess is credited to the caller,
+        /// claimable through `withdraw`. The other payable `register_*` messages charge the fee the same way.
+        ///
+        /// # Arguments
+        /// - `content_hash`: The unique hash representing the content (e.g., an IPFS hash).
+        ///
+        /// # Returns
+        /// - A unique content ID for the registered content.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if registrations are paused.
+        /// - Returns `Error::AccountBlacklisted` if the caller is blacklisted.
+        /// - Returns `Error::NotWhitelisted` if the mode is `WhitelistOnly` and the caller is not a registrar.
+        /// - Returns `Error::ProofRequired` if a proof of possession is required; see `require_pop`.
+        /// - Returns `Error::ContentHashTooLong` if the hash exceeds `MAX_CONTENT_HASH_LENGTH` bytes.
+        /// - Returns `Error::InvalidContent` if the content hash is invalid, including whitespace or non-ASCII characters.
+        /// - Returns `Error::AlreadyRegistered` if the content hash is already registered.
+        /// - Returns `Error::InsufficientFee` if the attached value is below `registration_fee`.
+        /// - Returns `Error::BalanceOverflow` if the collected fees or the caller's credit would overflow.
+        /// - Returns `Error::CounterOverflow` if the content ID counter overflows.
+        /// - Returns `Error::OwnedCountOverflow` if the caller's owned-content counter overflows.
+        #[ink(message, payable)]
+        pub fn register_content(&mut self, content_hash: String) -> Result<u64> {
+            let caller = self.ensure_can_register()?;
+            self.register(caller, Content::new(content_hash, caller))
+        }
+
+        /// Registers new digital content like `register_content`, with a signature binding the hash to the
+        /// caller's own ECDSA key. This is the registration path that stays open while `require_pop` is on.
+        ///
+        /// # Arguments
+        /// - `content_hash`: The unique hash representing the content.
+        /// - `signature`: The caller's 65-byte recoverable ECDSA signature over `pop_hash(content_hash, caller)`.
+        ///
+        /// # Returns
+        /// - A unique content ID for the registered content.
+        ///
+        /// # Errors
+        /// - Returns `Error::InvalidSignature` if the signature does not recover to the caller.
+        /// - Returns the same errors as `register_content` otherwise, except `ProofRequired`.
+        #[ink(message, payable)]
+        pub fn register_content_with_pop(&mut self, content_hash: String, signature: [u8; 65]) -> Result<u64> {
+            let caller = self.ensure_registrant()?;
+            let message_hash = self.pop_hash(content_hash.clone(), caller);
+            if self.recover_signer(&signature, &message_hash)? != caller {
+                return Err(Error::InvalidSignature);
+            }
+            self.register(caller, Content::new(content_hash, caller))
+        }
+
+        /// Returns the message hash a registrant signs for `register_content_with_pop`: the BLAKE2-256
+        /// hash of the SCALE-encoded `(POP_DOMAIN, contract address, content_hash, account)`.
+        #[ink(message)]
+        pub fn pop_hash(&self, content_hash: String, account: AccountId) -> [u8; 32] {
+            let payload = (POP_DOMAIN, self.env().account_id(), content_hash, account);
+            let mut output = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&payload, &mut output);
+            output
+        }
+
+        /// Registers new digital content that is permanently bound to the caller, e.g. a certificate
+        /// of authorship. Soulbound content can never be transferred, offered, or approved for transfer;
+        /// the flag cannot be changed after registration.
+        ///
+        /// # Arguments
+        /// - `content_hash`: The unique hash representing the content (e.g., an IPFS hash).
+        ///
+        /// # Returns
+        /// - A unique content ID for the registered content.
+        ///
+        /// # Errors
+        /// - Returns the same errors as `register_content`.
+        #[ink(message, payable)]
+        pub fn register_content_soulbound(&mut self, content_hash: String) -> Result<u64> {
+            let caller = self.ensure_can_register()?;
+            self.register(caller, Content { soulbound: true, ..Content::new(content_hash, caller) })
+        }
+
+        /// Registers new digital content like `register_content`, together with descriptive metadata.
+        /// Emits `ContentRegistered` followed by `MetadataUpdated`.
+        ///
+        /// # Arguments
+        /// - `content_hash`: The unique hash representing the content (e.g., an IPFS hash).
+        /// - `metadata`: The title, description, and URI of the content.
+        ///
+        /// # Returns
+        /// - A unique content ID for the registered content.
+        ///
+        /// # Errors
+        /// - Returns `Error::MetadataTooLarge` if a metadata field exceeds its byte limit.
+        /// - Returns the same errors as `register_content` otherwise.
+        #[ink(message, payable)]
+        pub fn register_content_with_metadata(&mut self, content_hash: String, metadata: ContentMetadata) -> Result<u64> {
+            if !metadata.is_within_limits() {
+                return Err(Error::MetadataTooLarge);
+            }
+            let caller = self.ensure_can_register()?;
+            let content_id = self.register(caller, Content::new(content_hash, caller))?;
+            self.store_metadata(caller, content_id, metadata);
+            Ok(content_id)
+        }
+
+        /// Replaces the metadata of a content item. Only the current owner can call this function.
+        /// Emits `MetadataUpdated`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `metadata`: The new title, description, and URI.
+        ///
+        /// # Errors
+        /// - Returns `Error::MetadataTooLarge` if a metadata field exceeds its byte limit.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner, including for renounced content.
+        /// - Returns `Error::MetadataLocked` if the metadata was locked.
+        #[ink(message)]
+        pub fn set_metadata(&mut self, content_id: u64, metadata: ContentMetadata) -> Result<()> {
+            if !metadata.is_within_limits() {
+                return Err(Error::MetadataTooLarge);
+            }
+            self.ensure_caller_owns(content_id)?;
+            if self.is_metadata_locked(content_id) {
+                return Err(Error::MetadataLocked);
+            }
+            let caller = self.env().caller();
+            self.store_metadata(caller, content_id, metadata);
+            Ok(())
+        }
+
+        /// Permanently locks the metadata of a content item, so buyers can rely on it not changing.
+        /// Only the current owner can call this function. The lock cannot be lifted and survives
+        /// transfers, so later owners cannot change the metadata either. Emits `MetadataLocked`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner, including for renounced content.
+        /// - Returns `Error::MetadataLocked` if the metadata is already locked.
+        #[ink(message)]
+        pub fn lock_metadata(&mut self, content_id: u64) -> Result<()> {
+            self.ensure_caller_owns(content_id)?;
+            if self.is_metadata_locked(content_id) {
+                return Err(Error::MetadataLocked);
+            }
+            self.metadata_locks.insert(content_id, &());
+            let seq = self.next_event_seq();
+            self.env().emit_event(MetadataLocked {
+                content_id,
+                locked_by: self.env().caller(),
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Checks whether the metadata of a content item is permanently locked.
+        #[ink(message)]
+        pub fn is_metadata_locked(&self, content_id: u64) -> bool {
+            self.metadata_locks.contains(content_id)
+        }
+
+        /// Sets the digest of a content item's licensing terms, e.g. the hash of a license document.
+        /// Only the current owner can call this function. The terms survive transfers.
+        /// Emits `LicenseTermsUpdated`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `terms_hash`: The digest, at most `MAX_LICENSE_TERMS_LENGTH` bytes; empty to remove it.
+        ///
+        /// # Errors
+        /// - Returns `Error::LicenseTermsTooLong` if the digest exceeds `MAX_LICENSE_TERMS_LENGTH` bytes.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner, including for renounced content.
+        /// - Returns `Error::LicenseTermsLocked` if the terms were locked.
+        #[ink(message)]
+        pub fn set_license_terms(&mut self, content_id: u64, terms_hash: String) -> Result<()> {
+            if terms_hash.len() > MAX_LICENSE_TERMS_LENGTH {
+                return Err(Error::LicenseTermsTooLong);
+            }
+            self.ensure_caller_owns(content_id)?;
+            if self.is_license_terms_locked(content_id) {
+                return Err(Error::LicenseTermsLocked);
+            }
+            let terms_hash = if terms_hash.is_empty() {
+                self.license_terms.remove(content_id);
+                None
+            } else {
+                self.license_terms.insert(content_id, &terms_hash);
+                Some(terms_hash)
+            };
+            let seq = self.next_event_seq();
+            self.env().emit_event(LicenseTermsUpdated {
+                content_id,
+                terms_hash,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Returns the digest of a content item's licensing terms, if set.
+        #[ink(message)]
+        pub fn license_terms_of(&self, content_id: u64) -> Option<String> {
+            self.license_terms.get(content_id)
+        }
+
+        /// Permanently locks the licensing terms of a content item, so buyers can rely on them not
+        /// changing. Only the current owner can call this function, once terms are set. The lock
+        /// cannot be lifted and survives transfers. Emits `LicenseTermsLocked`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner, including for renounced content.
+        /// - Returns `Error::LicenseTermsLocked` if the terms are already locked.
+        /// - Returns `Error::LicenseTermsNotSet` if no terms are set.
+        #[ink(message)]
+        pub fn lock_license_terms(&mut self, content_id: u64) -> Result<()> {
+            self.ensure_caller_owns(content_id)?;
+            if self.is_license_terms_locked(content_id) {
+                return Err(Error::LicenseTermsLocked);
+            }
+            if !self.license_terms.contains(content_id) {
+                return Err(Error::LicenseTermsNotSet);
+            }
+            self.license_terms_locks.insert(content_id, &());
+            let seq = self.next_event_seq();
+            self.env().emit_event(LicenseTermsLocked {
+                content_id,
+                locked_by: self.env().caller(),
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Checks whether the licensing terms of a content item are permanently locked.
+        #[ink(message)]
+        pub fn is_license_terms_locked(&self, content_id: u64) -> bool {
+            self.license_terms_locks.contains(content_id)
+        }
+
+        /// Grants `licensee` a license on a content item, or renews theirs with new terms and expiry.
+        /// Only the current owner can call this function. Licenses survive transfers until they expire
+        /// or the owner at the time revokes them. While an exclusive license is active, no other
+        /// account can be licensed; it lapses on expiry. Emits `LicenseGranted`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `licensee`: The account to license the content to.
+        /// - `terms_hash`: The digest of the license terms, at most `MAX_LICENSE_TERMS_LENGTH` bytes.
+        /// - `expires_at`: The block timestamp from which the license is no longer active, or `None` for good.
+        /// - `exclusive`: Whether no other account may be licensed while this license is active.
+        /// - `transferable`: Whether the licensee may move the license to another account.
+        ///
+        /// # Errors
+        /// - Returns `Error::LicenseTermsTooLong` if the digest exceeds `MAX_LICENSE_TERMS_LENGTH` bytes.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner, including for renounced content.
+        /// - Returns `Error::ContentNotActive` if the content's status is not `Active`.
+        /// - Returns `Error::OwnerHasAccess` if `licensee` is the owner.
+        /// - Returns `Error::LicenseExpired` if `expires_at` is not in the future.
+        /// - Returns `Error::ExclusiveLicenseActive` if another account holds an active exclusive license,
+        ///   or, for an exclusive license, if another account holds any active license.
+        /// - Returns `Error::TooManyLicensees` if `MAX_LICENSEES_PER_CONTENT` other accounts hold a license.
+        #[ink(message)]
+        pub fn grant_license(
+            &mut self,
+            content_id: u64,
+            licensee: AccountId,
+            terms_hash: String,
+            expires_at: Option<Timestamp>,
+            exclusive: bool,
+            transferable: bool,
+        ) -> Result<()> {
+            if terms_hash.len() > MAX_LICENSE_TERMS_LENGTH {
+                return Err(Error::LicenseTermsTooLong);
+            }
+            self.ensure_caller_owns(content_id)?;
+            let owner = self.env().caller();
+            if self.status_of(content_id) != Some(ContentStatus::Active) {
+                return Err(Error::ContentNotActive);
+            }
+            if licensee == owner {
+                return Err(Error::OwnerHasAccess);
+            }
+            let now = self.env().block_timestamp();
+            if expires_at.is_some_and(|expires_at| expires_at <= now) {
+                return Err(Error::LicenseExpired);
+            }
+            self.ensure_can_license(content_id, licensee, exclusive)?;
+            self.store_license(content_id, licensee, License {
+                terms_hash: terms_hash.clone(),
+                granted_by: owner,
+                granted_at: now,
+                expires_at,
+                exclusive,
+                transferable,
+            });
+            let seq = self.next_event_seq();
+            self.env().emit_event(LicenseGranted {
+                content_id,
+                licensee,
+                terms_hash,
+                expires_at,
+                exclusive,
+                transferable,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Revokes the license of `licensee` on a content item, whether it is still active or has
+        /// expired. Only the current owner can call this function. The licensee can be granted a new
+        /// license later. Emits `LicenseRevoked`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `licensee`: The account whose license to revoke.
+        /// - `reason`: The reason for the revocation, recorded in the event only.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner, including for renounced content.
+        /// - Returns `Error::NoLicense` if `licensee` holds no license on the content.
+        #[ink(message)]
+        pub fn revoke_license(&mut self, content_id: u64, licensee: AccountId, reason: String) -> Result<()> {
+            self.ensure_caller_owns(content_id)?;
+            if !self.licenses.contains((content_id, licensee)) {
+                return Err(Error::NoLicense);
+            }
+            self.remove_license(content_id, licensee);
+            let seq = self.next_event_seq();
+            self.env().emit_event(LicenseRevoked {
+                content_id,
+                licensee,
+                revoked_by: self.env().caller(),
+                reason,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Moves the caller's license on a content item, with its terms, expiry and exclusivity, to
+        /// `new_licensee`. The license must have been granted as transferable and still be active.
+        /// The owner can veto a transfer by revoking the license, before or after it moves. Emits
+        /// `LicenseTransferred`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `new_licensee`: The account to move the license to.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if transfers are paused.
+        /// - Returns `Error::SelfTransfer` or `Error::ZeroAddressRecipient` for an invalid recipient.
+        /// - Returns `Error::NoLicense` if the caller holds no license on the content, e.g. it was revoked.
+        /// - Returns `Error::LicenseNotTransferable` if the license was not granted as transferable.
+        /// - Returns `Error::LicenseExpired` if the license has expired.
+        /// - Returns `Error::OwnerHasAccess` if `new_licensee` owns the content.
+        /// - Returns `Error::AlreadyLicensed` if `new_licensee` already holds a license on the content.
+        #[ink(message)]
+        pub fn transfer_license(&mut self, content_id: u64, new_licensee: AccountId) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Transfers)?;
+            let caller = self.env().caller();
+            Self::ensure_valid_recipient(caller, new_licensee)?;
+            let license = self.licenses.get((content_id, caller)).ok_or(Error::NoLicense)?;
+            if !license.transferable {
+                return Err(Error::LicenseNotTransferable);
+            }
+            if !self.license_is_active(&license) {
+                return Err(Error::LicenseExpired);
+            }
+            if self.owner_of(content_id) == Some(new_licensee) {
+                return Err(Error::OwnerHasAccess);
+            }
+            if self.licenses.contains((content_id, new_licensee)) {
+                return Err(Error::AlreadyLicensed);
+            }
+            self.remove_license(content_id, caller);
+            self.store_license(content_id, new_licensee, license);
+            let seq = self.next_event_seq();
+            self.env().emit_event(LicenseTransferred { content_id, from: caller, to: new_licensee, seq });
+            Ok(())
+        }
+
+        /// Sets the offer on which any account can buy a standard license on a content item through
+        /// `buy_license`, or stops selling licenses. A new offer only applies to later purchases, and
+        /// licenses already sold are kept. Only the current owner can call this function, and any
+        /// ownership change clears the offer. Emits `LicenseOfferChanged`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `price`: The price of one license; zero stops selling licenses.
+        /// - `duration`: How long, in milliseconds, a bought license is active for.
+        /// - `terms_hash`: The digest of the license terms, at most `MAX_LICENSE_TERMS_LENGTH` bytes.
+        ///
+        /// # Errors
+        /// - Returns `Error::LicenseTermsTooLong` if the digest exceeds `MAX_LICENSE_TERMS_LENGTH` bytes.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner, including for renounced content.
+        /// - Returns `Error::InvalidDuration` if the duration is zero while a price is set.
+        #[ink(message)]
+        pub fn set_license_offer(&mut self, content_id: u64, price: Balance, duration: u64, terms_hash: String) -> Result<()> {
+            if terms_hash.len() > MAX_LICENSE_TERMS_LENGTH {
+                return Err(Error::LicenseTermsTooLong);
+            }
+            self.ensure_caller_owns(content_id)?;
+            if price > 0 && duration == 0 {
+                return Err(Error::InvalidDuration);
+            }
+            let offer = (price > 0).then_some(LicenseOffer { price, duration, terms_hash });
+            if let Some(offer) = &offer {
+                self.license_offers.insert(content_id, offer);
+            } else {
+                self.license_offers.remove(content_id);
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(LicenseOfferChanged { content_id, offer, seq });
+            Ok(())
+        }
+
+        /// Returns the offer on which licenses on a content item are sold, if they are.
+        #[ink(message)]
+        pub fn license_offer_of(&self, content_id: u64) -> Option<LicenseOffer> {
+            self.license_offers.get(content_id)
+        }
+
+        /// Buys a license on a content item on its current offer. The call must carry at least the
+        /// price; the owner is credited with the price minus the platform fee and any excess is
+        /// credited back to the caller. The license runs for the offer's duration from now, and buying
+        /// again replaces the caller's license with a new one, unless it is an active exclusive license.
+        /// Emits `LicensePurchased`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        ///
+        /// # Returns
+        /// - The block timestamp from which the license is no longer active.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if sales are paused.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::ContentNotActive` if the content's status is not `Active`, e.g. it was revoked.
+        /// - Returns `Error::LicenseNotOffered` if the owner does not sell licenses.
+        /// - Returns `Error::OwnerHasAccess` if the caller owns the content.
+        /// - Returns `Error::AlreadyLicensed` if the caller holds an active exclusive license, which a
+        ///   bought license would replace.
+        /// - Returns `Error::ExclusiveLicenseActive` if another account holds an active exclusive license.
+        /// - Returns `Error::TooManyLicensees` if `MAX_LICENSEES_PER_CONTENT` other accounts hold a license.
+        /// - Returns `Error::InsufficientPayment` if the attached value is below the price.
+        /// - Returns `Error::BalanceOverflow` if a credit would overflow. Nothing is written on any error.
+        #[ink(message, payable)]
+        pub fn buy_license(&mut self, content_id: u64) -> Result<Timestamp> {
+            self.ensure_not_paused(PausableOp::Sales)?;
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            if record.status != ContentStatus::Active {
+                return Err(Error::ContentNotActive);
+            }
+            let offer = self.license_offers.get(content_id).ok_or(Error::LicenseNotOffered)?;
+            let owner = record.owner;
+            let licensee = self.env().caller();
+            if licensee == owner {
+                return Err(Error::OwnerHasAccess);
+            }
+            if self.license_of(content_id, licensee)
+                .is_some_and(|license| license.exclusive && self.license_is_active(&license))
+            {
+                return Err(Error::AlreadyLicensed);
+            }
+            self.ensure_can_license(content_id, licensee, false)?;
+            let excess = self.env()
+                .transferred_value()
+                .checked_sub(offer.price)
+                .ok_or(Error::InsufficientPayment)?;
+            let payout = self.sale_payout(content_id, owner, offer.price, RevenueSource::License, None)?;
+            let licensee_credit = self.credited(licensee, excess)?;
+            let now = self.env().block_timestamp();
+            let expires_at = now.saturating_add(offer.duration);
+            self.store_license(content_id, licensee, License {
+                terms_hash: offer.terms_hash,
+                granted_by: owner,
+                granted_at: now,
+                expires_at: Some(expires_at),
+                exclusive: false,
+                transferable: false,
+            });
+            self.pay_out(&payout);
+            if excess > 0 {
+                self.credits.insert(licensee, &licensee_credit);
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(LicensePurchased {
+                content_id,
+                licensee,
+                owner,
+                price: offer.price,
+                fee: payout.fee,
+                expires_at,
+                seq,
+            });
+            Ok(expires_at)
+        }
+
+        /// Returns the license `licensee` holds on a content item, if any, including an expired one;
+        /// see `is_license_active`.
+        #[ink(message)]
+        pub fn license_of(&self, content_id: u64, licensee: AccountId) -> Option<License> {
+            self.licenses.get((content_id, licensee))
+        }
+
+        /// Checks whether `licensee` holds a license on a content item that has not expired. Expired
+        /// licenses read as inactive without being removed.
+        #[ink(message)]
+        pub fn is_license_active(&self, content_id: u64, licensee: AccountId) -> bool {
+            self.license_of(content_id, licensee)
+                .is_some_and(|license| self.license_is_active(&license))
+        }
+
+        /// Returns a page of the accounts holding a license on a content item, active or expired.
+        /// The order is not stable: when a license is revoked, the last entry takes its position
+        /// (swap-remove).
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `start`: The position in the content's licensee index to start from.
+        /// - `limit`: The maximum number of accounts to return, capped at `MAX_PAGE_SIZE`.
+        ///
+        /// # Returns
+        /// - A `Vec` of licensees in index order, empty if `start` is past the end.
+        #[ink(message)]
+        pub fn licensees_of(&self, content_id: u64, start: u32, limit: u32) -> Vec<AccountId> {
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.licensee_counts.get(content_id).unwrap_or(0));
+            (start..end)
+                .filter_map(|position| self.licensees.get((content_id, position)))
+                .collect()
+        }
+
+        /// Checks whether an account holds an active exclusive license on a content item. Exclusivity
+        /// lapses when the license expires or is revoked.
+        #[ink(message)]
+        pub fn has_exclusive_license(&self, content_id: u64) -> bool {
+            self.exclusive_licensees
+                .get(content_id)
+                .and_then(|holder| self.license_of(content_id, holder))
+                .is_some_and(|license| license.exclusive && self.license_is_active(&license))
+        }
+
+        /// Checks that `licensee` can be granted or sold a license on a content item.
+        ///
+        /// # Errors
+        /// - Returns `Error::ExclusiveLicenseActive` if another account holds an active exclusive
+        ///   license, or, if `exclusive`, another account holds any active license.
+        /// - Returns `Error::TooManyLicensees` if `licensee` would exceed `MAX_LICENSEES_PER_CONTENT`.
+        fn ensure_can_license(&self, content_id: u64, licensee: AccountId, exclusive: bool) -> Result<()> {
+            if self.has_exclusive_license(content_id) && self.exclusive_licensees.get(content_id) != Some(licensee) {
+                return Err(Error::ExclusiveLicenseActive);
+            }
+            let count = self.licensee_counts.get(content_id).unwrap_or(0);
+            if exclusive {
+                let other_active = (0..count)
+                    .filter_map(|position| self.licensees.get((content_id, position)))
+                    .any(|other| other != licensee && self.is_license_active(content_id, other));
+                if other_active {
+                    return Err(Error::ExclusiveLicenseActive);
+                }
+            }
+            if count >= MAX_LICENSEES_PER_CONTENT && !self.licenses.contains((content_id, licensee)) {
+                return Err(Error::TooManyLicensees);
+            }
+            Ok(())
+        }
+
+        /// Checks whether a license has not expired by the current block timestamp.
+        fn license_is_active(&self, license: &License) -> bool {
+            license.expires_at.is_none_or(|expires_at| self.env().block_timestamp() < expires_at)
+        }
+
+        /// Writes the license of `licensee` on a content item, adding them to the licensee index
+        /// unless they already hold one.
+        fn store_license(&mut self, content_id: u64, licensee: AccountId, license: License) {
+            if !self.licenses.contains((content_id, licensee)) {
+                let position = self.licensee_counts.get(content_id).unwrap_or(0);
+                self.licensees.insert((content_id, position), &licensee);
+                self.licensee_positions.insert((content_id, licensee), &position);
+                // Cannot overflow: every licensee is a distinct account.
+                self.licensee_counts.insert(content_id, &(position + 1));
+            }
+            if license.exclusive {
+                self.exclusive_licensees.insert(content_id, &licensee);
+            } else if self.exclusive_licensees.get(content_id) == Some(licensee) {
+                self.exclusive_licensees.remove(content_id);
+            }
+            self.licenses.insert((content_id, licensee), &license);
+        }
+
+        /// Removes the license of `licensee` on a content item, moving the last entry of the
+        /// licensee index into the freed position (swap-remove).
+        fn remove_license(&mut self, content_id: u64, licensee: AccountId) {
+            self.licenses.remove((content_id, licensee));
+            if self.exclusive_licensees.get(content_id) == Some(licensee) {
+                self.exclusive_licensees.remove(content_id);
+            }
+            let Some(position) = self.licensee_positions.take((content_id, licensee)) else {
+                return;
+            };
+            let last = self.licensee_counts.get(content_id).unwrap_or(1).saturating_sub(1);
+            if position != last {
+                if let Some(last_licensee) = self.licensees.get((content_id, last)) {
+                    self.licensees.insert((content_id, position), &last_licensee);
+                    self.licensee_positions.insert((content_id, last_licensee), &position);
+                }
+            }
+            self.licensees.remove((content_id, last));
+            self.licensee_counts.insert(content_id, &last);
+        }
+
+        /// Removes every license on a content item together with the licensee index.
+        fn clear_licenses(&mut self, content_id: u64) {
+            let count = self.licensee_counts.take(content_id).unwrap_or(0);
+            for position in 0..count {
+                if let Some(licensee) = self.licensees.take((content_id, position)) {
+                    self.licenses.remove((content_id, licensee));
+                    self.licensee_positions.remove((content_id, licensee));
+                }
+            }
+            self.exclusive_licensees.remove(content_id);
+        }
+
+        /// Sets the base URI that `content_uri` prepends to content hashes, e.g. a preview gateway.
+        /// Only an admin can call this function. The change applies to existing content immediately.
+        /// Emits `BaseUriUpdated`.
+        ///
+        /// # Arguments
+        /// - `uri`: The new base URI, at most `MAX_URI_LENGTH` bytes; empty to resolve to the bare hash.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::UriTooLong` if the URI exceeds `MAX_URI_LENGTH` bytes.
+        #[ink(message)]
+        pub fn set_base_uri(&mut self, uri: String) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if uri.len() > MAX_URI_LENGTH {
+                return Err(Error::UriTooLong);
+            }
+            let old_uri = core::mem::replace(&mut self.base_uri, uri.clone());
+            let seq = self.next_event_seq();
+            self.env().emit_event(BaseUriUpdated {
+                updated_by: caller,
+                old_uri,
+                new_uri: uri,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Returns the base URI that `content_uri` prepends to content hashes.
+        #[ink(message)]
+        pub fn base_uri(&self) -> String {
+            self.base_uri.clone()
+        }
+
+        /// Sets a URI that `content_uri` returns for a content item instead of the base URI and hash.
+        /// Only the current owner can call this function. Emits `ContentUriOverrideSet`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `uri`: The override, at most `MAX_URI_LENGTH` bytes; empty to remove it.
+        ///
+        /// # Errors
+        /// - Returns `Error::UriTooLong` if the URI exceeds `MAX_URI_LENGTH` bytes.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner, including for renounced content.
+        #[ink(message)]
+        pub fn set_content_uri_override(&mut self, content_id: u64, uri: String) -> Result<()> {
+            if uri.len() > MAX_URI_LENGTH {
+                return Err(Error::UriTooLong);
+            }
+            self.ensure_caller_owns(content_id)?;
+            let uri = if uri.is_empty() {
+                self.uri_overrides.remove(content_id);
+                None
+            } else {
+                self.uri_overrides.insert(content_id, &uri);
+                Some(uri)
+            };
+            let seq = self.next_event_seq();
+            self.env().emit_event(ContentUriOverrideSet {
+                content_id,
+                uri,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Returns the URI of a content item: its owner's override if set, otherwise the base URI
+        /// followed by the content hash.
+        ///
+        /// # Returns
+        /// - `None` if the content ID is not found.
+        #[ink(message)]
+        pub fn content_uri(&self, content_id: u64) -> Option<String> {
+            let record = self.contents.get(content_id)?;
+            Some(self.uri_overrides.get(content_id).unwrap_or_else(|| {
+                let mut uri = self.base_uri.clone();
+                uri.push_str(&record.content_hash);
+                uri
+            }))
+        }
+
+        /// Replaces the tags of a content item, e.g. "music" or "dataset". Only the current owner can
+        /// call this function. Tags are trimmed and lowercased before storage, so "Music " and "music"
+        /// are the same tag; duplicates after normalization are dropped. An empty list removes all tags.
+        /// Emits `TagsUpdated`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `tags`: The new tags, at most `MAX_TAGS_PER_CONTENT` distinct ones.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner, including for renounced content.
+        /// - Returns `Error::InvalidTag` if a tag is empty or longer than `MAX_TAG_LENGTH` bytes once normalized.
+        /// - Returns `Error::TooManyTags` if more than `MAX_TAGS_PER_CONTENT` distinct tags are passed.
+        #[ink(message)]
+        pub fn set_tags(&mut self, content_id: u64, tags: Vec<String>) -> Result<()> {
+            self.ensure_caller_owns(content_id)?;
+            let mut normalized: Vec<String> = Vec::new();
+            for tag in tags {
+                let tag = tag.trim().to_lowercase();
+                if tag.is_empty() || tag.len() > MAX_TAG_LENGTH {
+                    return Err(Error::InvalidTag);
+                }
+                if !normalized.contains(&tag) {
+                    normalized.push(tag);
+                }
+            }
+            if normalized.len() > MAX_TAGS_PER_CONTENT {
+                return Err(Error::TooManyTags);
+            }
+            self.clear_tags(content_id);
+            for tag in &normalized {
+                self.add_to_tag_index(tag, content_id);
+            }
+            if !normalized.is_empty() {
+                self.content_tags.insert(content_id, &normalized);
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(TagsUpdated {
+                content_id,
+                tags: normalized,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Returns the normalized tags of a content item, empty if it has none.
+        #[ink(message)]
+        pub fn tags_of(&self, content_id: u64) -> Vec<String> {
+            self.content_tags.get(content_id).unwrap_or_default()
+        }
+
+        /// Returns a page of the content IDs carrying a tag. The tag is normalized like in `set_tags`.
+        /// The order is not stable: when a tag is removed, the tag's last entry takes its position.
+        ///
+        /// # Arguments
+        /// - `tag`: The tag to look up.
+        /// - `start`: The position in the tag's index to start from.
+        /// - `limit`: The maximum number of IDs to return, capped at `MAX_PAGE_SIZE`.
+        ///
+        /// # Returns
+        /// - A `Vec` of content IDs, empty if `start` is past the end or the tag is unused.
+        #[ink(message)]
+        pub fn content_with_tag(&self, tag: String, start: u32, limit: u32) -> Vec<u64> {
+            let tag = tag.trim().to_lowercase();
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.tag_count.get(&tag).unwrap_or(0));
+            (start..end)
+                .filter_map(|position| self.tagged_contents.get((tag.clone(), position)))
+                .collect()
+        }
+
+        /// Removes all tags of a content item, along with their index entries.
+        fn clear_tags(&mut self, content_id: u64) {
+            for tag in self.content_tags.take(content_id).unwrap_or_default() {
+                self.remove_from_tag_index(&tag, content_id);
+            }
+        }
+
+        /// Appends a content ID to a tag's index.
+        /// The count cannot overflow, as it never exceeds the number of content records.
+        fn add_to_tag_index(&mut self, tag: &String, content_id: u64) {
+            let position = self.tag_count.get(tag).unwrap_or(0);
+            self.tagged_contents.insert((tag.clone(), position), &content_id);
+            self.tagged_content_position.insert((tag.clone(), content_id), &position);
+            self.tag_count.insert(tag, &position.saturating_add(1));
+        }
+
+        /// Removes a content ID from a tag's index, moving the tag's last entry into the freed
+        /// position (swap-remove).
+        fn remove_from_tag_index(&mut self, tag: &String, content_id: u64) {
+            let Some(last) = self.tag_count.get(tag).unwrap_or(0).checked_sub(1) else {
+                return;
+            };
+            let position = self.tagged_content_position.get((tag.clone(), content_id)).unwrap_or(last);
+            if position != last {
+                if let Some(last_id) = self.tagged_contents.get((tag.clone(), last)) {
+                    self.tagged_contents.insert((tag.clone(), position), &last_id);
+                    self.tagged_content_position.insert((tag.clone(), last_id), &position);
+                }
+            }
+            self.tagged_contents.remove((tag.clone(), last));
+            self.tagged_content_position.remove((tag.clone(), content_id));
+            if last == 0 {
+                self.tag_count.remove(tag);
+            } else {
+                self.tag_count.insert(tag, &last);
+            }
+        }
+
+        /// Returns the metadata of a content item, or `None` if it has none.
+        #[ink(message)]
+        pub fn get_metadata(&self, content_id: u64) -> Option<ContentMetadata> {
+            self.metadata.get(content_id)
+        }
+
+        /// Stores the metadata of a content item and emits `MetadataUpdated`.
+        fn store_metadata(&mut self, updated_by: AccountId, content_id: u64, metadata: ContentMetadata) {
+            self.metadata.insert(content_id, &metadata);
+            let seq = self.next_event_seq();
+            self.env().emit_event(MetadataUpdated {
+                content_id,
+                metadata,
+                updated_by,
+                seq,
+            });
+        }
+
+        /// Registers a derivative work, e.g. a remix or translation, as a new content item owned by the
+        /// caller that points at its parent. If the parent's owner requires consent, the caller needs
+        /// a prior `approve_derivative` from them, which this call consumes.
+        /// Emits `ContentRegistered` followed by `DerivativeRegistered`.
+        ///
+        /// # Arguments
+        /// - `parent_content_id`: The content the new work derives from.
+        /// - `content_hash`: The content hash of the derivative.
+        ///
+        /// # Returns
+        /// - The content ID of the derivative.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the parent is not found.
+        /// - Returns `Error::ContentNotActive` if the parent's status is not `Active`, e.g. it was revoked.
+        /// - Returns `Error::DerivativeNotApproved` if the parent requires consent and the caller has none.
+        /// - Returns the same errors as `register_content` otherwise.
+        #[ink(message, payable)]
+        pub fn register_derivative(&mut self, parent_content_id: u64, content_hash: String) -> Result<u64> {
+            let caller = self.ensure_can_register()?;
+            let parent = self.contents.get(parent_content_id).ok_or(Error::ContentNotFound)?;
+            if parent.status != ContentStatus::Active {
+                return Err(Error::ContentNotActive);
+            }
+            if self.requires_derivative_consent(parent_content_id) {
+                if self.derivative_approvals.get((parent_content_id, caller)) != Some(parent.owner) || parent.renounced {
+                    return Err(Error::DerivativeNotApproved);
+                }
+                self.derivative_approvals.remove((parent_content_id, caller));
+            }
+            let record = Content { parent: Some(parent_content_id), ..Content::new(content_hash, caller) };
+            let content_id = self.register(caller, record)?;
+            let position = self.derivative_count.get(parent_content_id).unwrap_or(0);
+            self.derivatives.insert((parent_content_id, position), &content_id);
+            self.derivative_position.insert(content_id, &position);
+            self.derivative_count.insert(parent_content_id, &position.saturating_add(1));
+            let seq = self.next_event_seq();
+            self.env().emit_event(DerivativeRegistered {
+                content_id,
+                parent_id: parent_content_id,
+                seq,
+            });
+            Ok(content_id)
+        }
+
+        /// Requires or stops requiring consent for derivatives of a content item. Only the current owner
+        /// can call this function; the setting survives transfers. Emits `DerivativeConsentChanged`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `required`: Whether derivatives require `approve_derivative`.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner, including for renounced content.
+        #[ink(message)]
+        pub fn set_derivative_consent_required(&mut self, content_id: u64, required: bool) -> Result<()> {
+            self.ensure_caller_owns(content_id)?;
+            if required {
+                self.derivative_consent_required.insert(content_id, &());
+            } else {
+                self.derivative_consent_required.remove(content_id);
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(DerivativeConsentChanged {
+                content_id,
+                required,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Checks whether derivatives of a content item require the owner's consent.
+        #[ink(message)]
+        pub fn requires_derivative_consent(&self, content_id: u64) -> bool {
+            self.derivative_consent_required.contains(content_id)
+        }
+
+        /// Allows an account to register one derivative of a content item. Only the current owner can
+        /// call this function, and the approval lapses if the content changes hands before it is used.
+        /// Emits `DerivativeApproved`.
+        ///
+        /// # Arguments
+        /// - `parent_id`: The unique ID of the parent content.
+        /// - `account`: The account allowed to register a derivative.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner, including for renounced content.
+        #[ink(message)]
+        pub fn approve_derivative(&mut self, parent_id: u64, account: AccountId) -> Result<()> {
+            self.ensure_caller_owns(parent_id)?;
+            self.derivative_approvals.insert((parent_id, account), &self.env().caller());
+            let seq = self.next_event_seq();
+            self.env().emit_event(DerivativeApproved {
+                parent_id,
+                account,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Returns the content a content item derives from, if any.
+        #[ink(message)]
+        pub fn parent_of(&self, content_id: u64) -> Option<u64> {
+            self.contents.get(content_id).and_then(|record| record.parent)
+        }
+
+        /// Returns a page of the existing derivatives of a content item.
+        /// The order is not stable: when a derivative is burned, the last entry takes its position.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the parent content.
+        /// - `start`: The position in the parent's derivative index to start from.
+        /// - `limit`: The maximum number of IDs to return, capped at `MAX_PAGE_SIZE`.
+        ///
+        /// # Returns
+        /// - A `Vec` of content IDs, empty if `start` is past the end.
+        #[ink(message)]
+        pub fn derivatives_of(&self, content_id: u64, start: u32, limit: u32) -> Vec<u64> {
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.derivative_count.get(content_id).unwrap_or(0));
+            (start..end)
+                .filter_map(|position| self.derivatives.get((content_id, position)))
+                .collect()
+        }
+
+        /// Removes a derivative from its parent's index, moving the parent's last entry into the
+        /// freed position (swap-remove).
+        fn remove_from_derivative_index(&mut self, parent_id: u64, content_id: u64) {
+            let Some(last) = self.derivative_count.get(parent_id).unwrap_or(0).checked_sub(1) else {
+                return;
+            };
+            let position = self.derivative_position.get(content_id).unwrap_or(last);
+            if position != last {
+                if let Some(last_id) = self.derivatives.get((parent_id, last)) {
+                    self.derivatives.insert((parent_id, position), &last_id);
+                    self.derivative_position.insert(last_id, &position);
+                }
+            }
+            self.derivatives.remove((parent_id, last));
+            self.derivative_position.remove(content_id);
+            self.derivative_count.insert(parent_id, &last);
+        }
+
+        /// Registers a revision of a content item as a new content item owned by the caller, and links
+        /// the two: the previous version is `superseded_by` the new one, which `supersedes` it. Each
+        /// version can be superseded only once, so versions form a single chain. Versions remain
+        /// independent content items: an old version can still be transferred, and transferring it
+        /// does not move the other versions. Emits `ContentRegistered` followed by `NewVersionRegistered`.
+        ///
+        /// # Arguments
+        /// - `previous_content_id`: The version the new one supersedes.
+        /// - `new_hash`: The content hash of the new version.
+        ///
+        /// # Returns
+        /// - The content ID of the new version.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the previous version is not found.
+        /// - Returns `Error::NotOwner` if the caller does not own the previous version.
+        /// - Returns `Error::AlreadySuperseded` if the previous version already has a newer version.
+        /// - Returns the same errors as `register_content` otherwise.
+        #[ink(message, payable)]
+        pub fn register_new_version(&mut self, previous_content_id: u64, new_hash: String) -> Result<u64> {
+            self.ensure_caller_owns(previous_content_id)?;
+            if self.superseded_by.contains(previous_content_id) {
+                return Err(Error::AlreadySuperseded);
+            }
+            let caller = self.ensure_can_register()?;
+            let content_id = self.register(caller, Content::new(new_hash, caller))?;
+            let root = self.version_roots.get(previous_content_id).unwrap_or(previous_content_id);
+            self.superseded_by.insert(previous_content_id, &content_id);
+            self.supersedes.insert(content_id, &previous_content_id);
+            self.version_roots.insert(content_id, &root);
+            self.latest_versions.insert(root, &content_id);
+            let seq = self.next_event_seq();
+            self.env().emit_event(NewVersionRegistered {
+                content_id,
+                supersedes: previous_content_id,
+                seq,
+            });
+            Ok(content_id)
+        }
+
+        /// Unlinks the latest version of a version chain, making the version it superseded the latest
+        /// again. Does nothing for content outside a chain.
+        fn remove_from_version_chain(&mut self, content_id: u64) {
+            let Some(previous) = self.supersedes.take(content_id) else {
+                return;
+            };
+            self.superseded_by.remove(previous);
+            let root = self.version_roots.take(content_id).unwrap_or(previous);
+            if previous == root {
+                self.latest_versions.remove(root);
+            } else {
+                self.latest_versions.insert(root, &previous);
+            }
+        }
+
+        /// Returns the latest version in the chain of a content item, which is the item itself
+        /// if it was never superseded.
+        ///
+        /// # Returns
+        /// - `None` if the content ID is not found.
+        #[ink(message)]
+        pub fn latest_version(&self, content_id: u64) -> Option<u64> {
+            if !self.content_exists(content_id) {
+                return None;
+            }
+            let root = self.version_roots.get(content_id).unwrap_or(content_id);
+            Some(self.latest_versions.get(root).unwrap_or(content_id))
+        }
+
+        /// Returns the versions of a content item from `content_id` onwards, following `superseded_by` links.
+        ///
+        /// # Arguments
+        /// - `content_id`: The first version to return.
+        /// - `limit`: The maximum number of versions to return, capped at `MAX_PAGE_SIZE`.
+        ///
+        /// # Returns
+        /// - A `Vec` of content IDs, oldest first, empty if the content ID is not found.
+        #[ink(message)]
+        pub fn version_chain(&self, content_id: u64, limit: u32) -> Vec<u64> {
+            if !self.content_exists(content_id) {
+                return Vec::new();
+            }
+            core::iter::successors(Some(content_id), |&version| self.superseded_by.get(version))
+                .take(limit.min(MAX_PAGE_SIZE) as usize)
+                .collect()
+        }
+
+        /// Returns the version that superseded a content item, if any.
+        #[ink(message)]
+        pub fn superseded_by(&self, content_id: u64) -> Option<u64> {
+            self.superseded_by.get(content_id)
+        }
+
+        /// Returns the version a content item supersedes, if any.
+        #[ink(message)]
+        pub fn supersedes(&self, content_id: u64) -> Option<u64> {
+            self.supersedes.get(content_id)
+        }
+
+        /// Checks that the caller may register content for themselves.
+        ///
+        /// # Returns
+        /// - The caller's AccountId.
+        ///
+        /// # Errors
+        /// - Returns `Error::AccountBlacklisted` if the caller is blacklisted.
+        /// - Returns `Error::NotWhitelisted` if the mode is `WhitelistOnly` and the caller is not a registrar.
+        /// - Returns `Error::ProofRequired` if a proof of possession is required.
+        fn ensure_can_register(&self) -> Result<AccountId> {
+            let caller = self.ensure_registrant()?;
+            if self.pop_required {
+                return Err(Error::ProofRequired);
+            }
+            Ok(caller)
+        }
+
+        /// Checks the caller against the blacklist and registration mode, like `ensure_can_register`
+        /// but without the proof-of-possession requirement.
+        fn ensure_registrant(&self) -> Result<AccountId> {
+            let caller = self.env().caller();
+            if self.is_blacklisted(caller) {
+                return Err(Error::AccountBlacklisted);
+            }
+            if self.registration_mode == RegistrationMode::WhitelistOnly && !self.is_registrar(caller) {
+                return Err(Error::NotWhitelisted);
+            }
+            Ok(caller)
+        }
+
+        /// Registers new digital content on behalf of another account, e.g. by a relayer paying gas.
+        /// Only accounts holding `Role::Registrar` can call this function. The content is recorded
+        /// with `owner` rather than the caller as its owner; duplicate hashes behave exactly as in
+        /// `register_content`. The caller's role stands in for the registration whitelist.
+        ///
+        /// # Arguments
+        /// - `content_hash`: The unique hash representing the content (e.g., an IPFS hash).
+        /// - `owner`: The AccountId that will own the content.
+        ///
+        /// # Returns
+        /// - A unique content ID for the registered content.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither an admin nor a holder of `Role::Registrar`.
+        /// - Returns `Error::AccountBlacklisted` if `owner` is blacklisted.
+        /// - Returns the same errors as `register_content` otherwise.
+        #[ink(message, payable)]
+        pub fn register_content_for(&mut self, content_hash: String, owner: AccountId) -> Result<u64> {
+            let caller = self.ensure_admin_or_role(Role::Registrar)?;
+            if self.is_blacklisted(owner) {
+                return Err(Error::AccountBlacklisted);
+            }
+            self.register(caller, Content::new(content_hash, owner))
+        }
+
+        /// Commits to registering a content hash without revealing it, so it cannot be copied from the
+        /// transaction pool. Reveal it with `reveal_registration` after `MIN_REVEAL_DELAY_BLOCKS` and
+        /// within `COMMITMENT_EXPIRY_BLOCKS`. Emits `RegistrationCommitted`.
+        ///
+        /// # Arguments
+        /// - `commitment`: `registration_commitment(content_hash, caller, salt)`, computed off-chain.
+        ///
+        /// # Errors
+        /// - Returns `Error::AccountBlacklisted` if the caller is blacklisted.
+        /// - Returns `Error::NotWhitelisted` if registration is whitelist-only and the caller is not a registrar.
+        /// - Returns `Error::CommitmentExists` if the caller has an unexpired commitment with the same value.
+        #[ink(message)]
+        pub fn commit_registration(&mut self, commitment: Hash) -> Result<()> {
+            let caller = self.ensure_can_register()?;
+            let now = self.env().block_number();
+            if let Some(committed_at) = self.commitments.get((caller, commitment)) {
+                if now <= committed_at.saturating_add(COMMITMENT_EXPIRY_BLOCKS) {
+                    return Err(Error::CommitmentExists);
+                }
+            }
+            self.commitments.insert((caller, commitment), &now);
+            let seq = self.next_event_seq();
+            self.env().emit_event(RegistrationCommitted {
+                committer: caller,
+                commitment,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Registers a content hash the caller committed to with `commit_registration`.
+        /// If another account registered the same hash after the commitment was made, the older
+        /// commitment wins: that record is reassigned to the caller, who also becomes its creator.
+        /// Emits `ContentRegistered` (or `OwnershipTransferred` on reclaim), then `RegistrationRevealed`.
+        ///
+        /// # Arguments
+        /// - `content_hash`: The committed content hash.
+        /// - `salt`: The salt used to compute the commitment.
+        ///
+        /// # Returns
+        /// - The content ID now owned by the caller.
+        ///
+        /// # Errors
+        /// - Returns `Error::CommitmentNotFound` if the caller has no matching commitment, e.g. the salt is wrong.
+        /// - Returns `Error::CommitmentTooRecent` if the commitment is younger than `MIN_REVEAL_DELAY_BLOCKS`.
+        /// - Returns `Error::CommitmentExpired` if the commitment is older than `COMMITMENT_EXPIRY_BLOCKS`.
+        /// - Returns `Error::AlreadyRegistered` if the hash was registered, or committed to, before the commitment.
+        /// - Returns `Error::LoanActive` if the record to reclaim secures a funded loan.
+        /// - Returns `Error::RentalActive` if the record to reclaim is rented out.
+        /// - Returns the same errors as `register_content` otherwise.
+        #[ink(message, payable)]
+        pub fn reveal_registration(&mut self, content_hash: String, salt: [u8; 32]) -> Result<u64> {
+            let caller = self.ensure_can_register()?;
+            self.ensure_not_paused(PausableOp::Registrations)?;
+            let commitment = Hash::from(self.registration_commitment(content_hash.clone(), caller, salt));
+            let committed_at = self.commitments.get((caller, commitment)).ok_or(Error::CommitmentNotFound)?;
+            let now = self.env().block_number();
+            if now < committed_at.saturating_add(MIN_REVEAL_DELAY_BLOCKS) {
+                return Err(Error::CommitmentTooRecent);
+            }
+            if now > committed_at.saturating_add(COMMITMENT_EXPIRY_BLOCKS) {
+                return Err(Error::CommitmentExpired);
+            }
+            let (content_id, reclaimed_from) = match self.content_hash_to_id.get(&content_hash).copied() {
+                Some(content_id) => {
+                    let registered_at = self.commitment_blocks
+                        .get(content_id)
+                        .or_else(|| self.registrations.get(content_id).map(|info| info.registered_at_block))
+                        .unwrap_or(0);
+                    if registered_at <= committed_at {
+                        return Err(Error::AlreadyRegistered);
+                    }
+                    self.charge_registration_fee()?;
+                    let from = self.reclaim_front_run(content_id, caller)?;
+                    (content_id, Some(from))
+                }
+                None => (self.register(caller, Content::new(content_hash, caller))?, None),
+            };
+            self.commitments.remove((caller, commitment));
+            self.commitment_blocks.insert(content_id, &committed_at);
+            let seq = self.next_event_seq();
+            self.env().emit_event(RegistrationRevealed {
+                content_id,
+                owner: caller,
+                reclaimed_from,
+                seq,
+            });
+            Ok(content_id)
+        }
+
+        /// Returns the commitment to pass to `commit_registration`:
+        /// `blake2_256(content_hash ++ account ++ salt)` over the raw bytes.
+        #[ink(message)]
+        pub fn registration_commitment(&self, content_hash: String, account: AccountId, salt: [u8; 32]) -> [u8; 32] {
+            let mut payload = Vec::from(content_hash.as_bytes());
+            payload.extend_from_slice(account.as_ref());
+            payload.extend_from_slice(&salt);
+            let mut output = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&payload, &mut output);
+            output
+        }
+
+        /// Returns the block in which an account committed to a registration, if the commitment is pending.
+        #[ink(message)]
+        pub fn commitment_block(&self, account: AccountId, commitment: Hash) -> Option<BlockNumber> {
+            self.commitments.get((account, commitment))
+        }
+
+        /// Reassigns a content record registered by a front-runner to the account whose earlier
+        /// commitment it copied, making that account both owner and creator. Owner-level state such
+        /// as offers, listings, auctions, approvals, locks, and collection membership is cleared, and
+        /// the highest bid of a cleared auction is credited back to its bidder; an unfunded loan offer is
+        /// cancelled, while a funded loan or a running rental blocks the reclaim. Everything the front-runner set up as
+        /// creator is cleared too: the royalty, its split and lock, marketplace-only mode and its
+        /// exemptions, the metadata and license-terms locks, the derivative consent requirement, and
+        /// all licenses granted. Admin-level state such as status and freezes is kept.
+        /// Emits `OwnershipTransferred`.
+        ///
+        /// # Returns
+        /// - The account that owned the record before.
+        ///
+        /// # Errors
+        /// - Returns `Error::LoanActive` if the content secures a funded loan.
+        /// - Returns `Error::RentalActive` if the content is rented out.
+        /// - Returns `Error::OwnedCountOverflow` or `Error::CounterOverflow` if the new owner's counters
+        ///   would overflow.
+        /// - Returns `Error::BalanceOverflow` if the highest bidder's credit would overflow.
+        ///   Nothing is written on any error.
+        fn reclaim_front_run(&mut self, content_id: u64, to: AccountId) -> Result<AccountId> {
+            let mut record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            let from = record.owner;
+            if self.loans.get(content_id).is_some_and(|loan| loan.lender.is_some()) {
+                return Err(Error::LoanActive);
+            }
+            if self.renter_of(content_id).is_some() {
+                return Err(Error::RentalActive);
+            }
+            if self.balance_of(to) == u32::MAX {
+                return Err(Error::OwnedCountOverflow);
+            }
+            let created_position = self.created_count.get(to).unwrap_or(0);
+            let created_count = created_position.checked_add(1).ok_or(Error::CounterOverflow)?;
+            let highest_bid = self.auctions
+                .get(content_id)
+                .and_then(|auction| auction.highest_bidder.map(|bidder| (bidder, auction.highest_bid)));
+            let bid_refund = match highest_bid {
+                Some((bidder, amount)) => Some((bidder, self.credited(bidder, amount)?)),
+                None => None,
+            };
+            if !record.renounced {
+                self.remove_from_owner_index(from, content_id)?;
+            }
+            self.add_to_owner_index(to, content_id)?;
+            self.remove_from_creator_index(record.creator, content_id);
+            self.created_contents.insert((to, created_position), &content_id);
+            self.created_content_position.insert(content_id, &created_position);
+            self.created_count.insert(to, &created_count);
+            record.owner = to;
+            record.creator = to;
+            record.renounced = false;
+            self.contents.insert(content_id, &record);
+            self.owners.insert(content_id, &to);
+            self.transfer_offers.remove(content_id);
+            self.listings.remove(content_id);
+            self.dutch_auctions.remove(content_id);
+            self.access_terms.remove(content_id);
+            self.rental_terms.remove(content_id);
+            self.license_offers.remove(content_id);
+            self.auctions.remove(content_id);
+            self.loans.remove(content_id);
+            self.rentals.remove(content_id);
+            if let Some((bidder, credit)) = bid_refund {
+                self.credits.insert(bidder, &credit);
+            }
+            self.transfer_memos.remove(content_id);
+            self.locks.remove(content_id);
+            self.royalty_bps.remove(content_id);
+            self.royalty_splits.remove(content_id);
+            self.royalty_locks.remove(content_id);
+            self.marketplace_only.remove(content_id);
+            self.metadata_locks.remove(content_id);
+            self.license_terms_locks.remove(content_id);
+            self.derivative_consent_required.remove(content_id);
+            self.clear_licenses(content_id);
+            self.clear_approval(from, content_id);
+            self.remove_from_collection_index(content_id);
+            self.record_provenance(content_id, from, to);
+            let seq = self.next_event_seq();
+            self.env().emit_event(OwnershipTransferred {
+                content_id,
+                from,
+                to,
+                memo: None,
+                relayed_by: None,
+                seq,
+            });
+            Ok(from)
+        }
+
+        /// Registers new digital content with an attestation signed off-chain by the attestor set with
+        /// `set_attestor`, instead of validating the hash on-chain. The attestation must be an ECDSA
+        /// signature over `attestation_hash(content_hash, caller, expires_at)` and can be used once.
+        ///
+        /// # Arguments
+        /// - `content_hash`: The attested content hash.
+        /// - `expires_at`: The last block timestamp at which the attestation can be used.
+        /// - `signature`: The 65-byte recoverable ECDSA signature of the attestor.
+        ///
+        /// # Returns
+        /// - A unique content ID for the registered content.
+        ///
+        /// # Errors
+        /// - Returns `Error::AttestationExpired` if the current block timestamp is past `expires_at`.
+        /// - Returns `Error::InvalidSignature` if no attestor is set or the signature is not the attestor's.
+        /// - Returns `Error::AttestationUsed` if the attestation already registered content.
+        /// - Returns the same errors as `register_content` otherwise, except `InvalidContent`.
+        #[ink(message, payable)]
+        pub fn register_with_attestation(&mut self, content_hash: String, expires_at: Timestamp, signature: [u8; 65]) -> Result<u64> {
+            let caller = self.ensure_can_register()?;
+            self.ensure_not_paused(PausableOp::Registrations)?;
+            Self::ensure_hash_within_bounds(&content_hash)?;
+            if self.env().block_timestamp() > expires_at {
+                return Err(Error::AttestationExpired);
+            }
+            let message_hash = self.attestation_hash(content_hash.clone(), caller, expires_at);
+            let signer = self.env()
+                .ecdsa_recover(&signature, &message_hash)
+                .map_err(|_| Error::InvalidSignature)?;
+            if self.attestor != Some(signer) {
+                return Err(Error::InvalidSignature);
+            }
+            if self.used_attestations.contains(message_hash) {
+                return Err(Error::AttestationUsed);
+            }
+            if self.content_hash_to_id.contains_key(&content_hash) {
+                return Err(Error::AlreadyRegistered);
+            }
+            self.charge_registration_fee()?;
+            let content_id = self.store_content(caller, Content::new(content_hash, caller))?;
+            self.used_attestations.insert(message_hash, &());
+            Ok(content_id)
+        }
+
+        /// Registers a content hash pre-approved through the allowlist, instead of validating it on-chain.
+        /// The leaf is `blake2_256(content_hash)`, and `proof` must link it to `allowlist_root`.
+        ///
+        /// # Arguments
+        /// - `content_hash`: The pre-approved content hash.
+        /// - `proof`: The siblings from the leaf up to the root, at most `MAX_PROOF_LENGTH`.
+        ///
+        /// # Returns
+        /// - A unique content ID for the registered content.
+        ///
+        /// # Errors
+        /// - Returns `Error::AllowlistNotSet` if no allowlist root is set.
+        /// - Returns `Error::InvalidProof` if the proof is too long or does not match the root.
+        /// - Returns the same errors as `register_content` otherwise, except `InvalidContent`.
+        #[ink(message, payable)]
+        pub fn register_with_proof(&mut self, content_hash: String, proof: Vec<Hash>) -> Result<u64> {
+            let caller = self.ensure_can_register()?;
+            self.ensure_not_paused(PausableOp::Registrations)?;
+            Self::ensure_hash_within_bounds(&content_hash)?;
+            let root = self.allowlist_root.ok_or(Error::AllowlistNotSet)?;
+            if proof.len() > MAX_PROOF_LENGTH {
+                return Err(Error::InvalidProof);
+            }
+            let proof: Vec<[u8; 32]> = proof.into_iter().map(<[u8; 32]>::from).collect();
+            let leaf = crate::merkle::leaf(content_hash.as_bytes());
+            if !crate::merkle::verify(&root.into(), leaf, &proof) {
+                return Err(Error::InvalidProof);
+            }
+            if self.content_hash_to_id.contains_key(&content_hash) {
+                return Err(Error::AlreadyRegistered);
+            }
+            self.charge_registration_fee()?;
+            self.store_content(caller, Content::new(content_hash, caller))
+        }
+
+        /// Returns the message hash an attestor signs for `register_with_attestation`: the BLAKE2-256 hash
+        /// of the SCALE-encoded `(ATTESTATION_DOMAIN, contract address, content_hash, account, expires_at)`.
+        #[ink(message)]
+        pub fn attestation_hash(&self, content_hash: String, account: AccountId, expires_at: Timestamp) -> [u8; 32] {
+            let payload = (ATTESTATION_DOMAIN, self.env().account_id(), content_hash, account, expires_at);
+            let mut output = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&payload, &mut output);
+            output
+        }
+
+        /// Validates, deduplicates, and stores a new content record, then emits `ContentRegistered`.
+        /// All registration paths must call this. Caller-specific access checks are the
+        /// responsibility of the public message.
+        ///
+        /// # Arguments
+        /// - `registrar`: The account submitting the registration.
+        /// - `record`: The new content record, holding the hash and the account that will own it.
+        ///
+        /// # Returns
+        /// - The new content ID.
+        ///
+        /// # Errors
+        /// - Returns `Error::AlreadyRegistered` if the hash is already registered, whoever owns it.
+        fn register(&mut self, registrar: AccountId, record: Content) -> Result<u64> {
+            self.ensure_not_paused(PausableOp::Registrations)?;
+            Self::ensure_hash_within_bounds(&record.content_hash)?;
+            if self.check_hash_format(record.content_hash.clone(), record.scheme).is_err() {
+                return Err(Error::InvalidContent);
+            }
+
+            if self.content_hash_to_id.contains_key(&record.content_hash) {
+                return Err(Error::AlreadyRegistered);
+            }
+            self.charge_registration_fee()?;
+            self.store_content(registrar, record)
+        }
+
+        /// Checks that a content hash is short enough to store and validate cheaply, and made of
+        /// printable ASCII only. Runs before any other check on the hash.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentHashTooLong` if the hash exceeds `MAX_CONTENT_HASH_LENGTH` bytes.
+        /// - Returns `Error::InvalidContent` if the hash contains whitespace, control, or non-ASCII characters.
+        fn ensure_hash_within_bounds(content_hash: &str) -> Result<()> {
+            if content_hash.len() > MAX_CONTENT_HASH_LENGTH {
+                return Err(Error::ContentHashTooLong);
+            }
+            if !content_hash.bytes().all(|byte| byte.is_ascii_graphic()) {
+                return Err(Error::InvalidContent);
+            }
+            Ok(())
+        }
+
+        /// Assigns the next content ID to a new record, indexes it, and emits `ContentRegistered`.
+        /// The caller must have checked that the hash is not registered yet.
+        ///
+        /// # Errors
+        /// - Returns `Error::CounterOverflow` if the content ID counter overflows.
+        /// - Returns `Error::OwnedCountOverflow` if the owner's owned-content counter overflows.
+        ///   Nothing is written in either case.
+        fn store_content(&mut self, registrar: AccountId, record: Content) -> Result<u64> {
+            let owner = record.owner;
+            let content_hash = record.content_hash.clone();
+            let content_id = self.next_content_id;
+            let next_content_id = self.next_content_id
+                .checked_add(1)
+                .ok_or(Error::CounterOverflow)?;
+            let creator = record.creator;
+            let created_position = self.created_count.get(creator).unwrap_or(0);
+            let created_count = created_position.checked_add(1).ok_or(Error::CounterOverflow)?;
+            self.add_to_owner_index(owner, content_id)?;
+            self.created_contents.insert((creator, created_position), &content_id);
+            self.created_content_position.insert(content_id, &created_position);
+            self.created_count.insert(creator, &created_count);
+            let position = self.content_count;
+            self.all_contents.insert(position, &content_id);
+            self.all_content_position.insert(content_id, &position);
+            self.content_count = position.saturating_add(1);
+            self.next_content_id = next_content_id;
+            self.contents.insert(content_id, &record);
+            self.owners.insert(content_id, &owner);
+            self.content_hash_to_id.insert(content_hash.clone(), content_id);
+            let registered = RegistrationInfo {
+                registered_at_block: self.env().block_number(),
+                registered_at_time: self.env().block_timestamp(),
+                oracle_version: self.oracle_version,
+            };
+            self.registrations.insert(content_id, &registered);
+            let seq = self.next_event_seq();
+            self.env().emit_event(ContentRegistered {
+                content_id,
+                content_hash,
+                owner,
+                registrar,
+                registered_at_block: registered.registered_at_block,
+                registered_at_time: registered.registered_at_time,
+                seq,
+            });
+            Ok(content_id)
+        }
+
+        /// Imports legacy registrations from an off-chain registry.
+        /// Only an admin can call this function. Entries skip oracle validation and are flagged
+        /// as imported (see `Content::is_imported`); each emits `ContentRegistered` with the admin
+        /// as registrar. A failing entry does not abort the batch. Imports are not affected by
+        /// pausing registrations, so they can run while user registrations are suspended.
+        ///
+        /// # Arguments
+        /// - `entries`: Up to `MAX_IMPORT_BATCH_SIZE` `(content_hash, owner)` pairs.
+        ///
+        /// # Returns
+        /// - One result per entry, in order: the new content ID, or
+        ///   `Error::ContentHashTooLong` or `Error::InvalidContent` if the hash is out of bounds,
+        ///   `Error::AlreadyRegistered` if the hash is already registered (including earlier in the batch),
+        ///   `Error::CounterOverflow` or `Error::OwnedCountOverflow` if a counter overflows.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::BatchTooLarge` if more than `MAX_IMPORT_BATCH_SIZE` entries are passed.
+        #[ink(message)]
+        pub fn bulk_import(&mut self, entries: Vec<(String, AccountId)>) -> Result<Vec<Result<u64>>> {
+            let caller = self.ensure_admin()?;
+            if entries.len() > MAX_IMPORT_BATCH_SIZE as usize {
+                return Err(Error::BatchTooLarge);
+            }
+            let results = entries
+                .into_iter()
+                .map(|(content_hash, owner)| {
+                    Self::ensure_hash_within_bounds(&content_hash)?;
+                    if self.content_hash_to_id.contains_key(&content_hash) {
+                        return Err(Error::AlreadyRegistered);
+                    }
+                    let record = Content { imported: true, ..Content::new(content_hash, owner) };
+                    self.store_content(caller, record)
+                })
+                .collect();
+            Ok(results)
+        }
+
+        /// Replaces the content hash of a content item, e.g. after re-pinning it under a new IPFS CID,
+        /// keeping its ID, owner, and transfer history. Only the current owner can call this function.
+        /// The new hash is validated like a fresh registration, and the old hash is appended to the
+        /// item's `hash_history` and released for registration. Emits `ContentHashUpdated`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `new_hash`: The replacement content hash.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if registrations are paused.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner, including for renounced content.
+        /// - Returns `Error::ContentNotActive` if the content's status is not `Active`.
+        /// - Returns `Error::ContentFrozen` if an admin or moderator froze the content.
+        /// - Returns `Error::InvalidContent` if the new hash is invalid for the content's `HashScheme`.
+        /// - Returns `Error::AlreadyRegistered` if the new hash is already registered, including to this item.
+        /// - Returns `Error::CounterOverflow` if the hash history is full.
+        #[ink(message)]
+        pub fn update_content_hash(&mut self, content_id: u64, new_hash: String) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Registrations)?;
+            self.ensure_caller_owns(content_id)?;
+            let mut record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            if record.status != ContentStatus::Active {
+                return Err(Error::ContentNotActive);
+            }
+            self.ensure_not_frozen(content_id)?;
+            Self::ensure_hash_within_bounds(&new_hash)?;
+            if self.check_hash_format(new_hash.clone(), record.scheme).is_err() {
+                return Err(Error::InvalidContent);
+            }
+            if self.content_hash_to_id.contains_key(&new_hash) {
+                return Err(Error::AlreadyRegistered);
+            }
+            let index = self.hash_history_length(content_id);
+            let length = index.checked_add(1).ok_or(Error::CounterOverflow)?;
+            let old_hash = core::mem::replace(&mut record.content_hash, new_hash.clone());
+            self.hash_history.insert((content_id, index), &old_hash);
+            self.hash_history_lengths.insert(content_id, &length);
+            self.content_hash_to_id.remove(&old_hash);
+            self.content_hash_to_id.insert(new_hash.clone(), content_id);
+            self.contents.insert(content_id, &record);
+            let seq = self.next_event_seq();
+            self.env().emit_event(ContentHashUpdated {
+                content_id,
+                old_hash,
+                new_hash,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Returns a page of the hashes a content item had before its current one, oldest first.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `start`: The index of the first entry to return.
+        /// - `limit`: The maximum number of entries to return, capped at `MAX_PAGE_SIZE`.
+        ///
+        /// # Returns
+        /// - A `Vec` of previous hashes, empty if `start` is past the end or the hash was never updated.
+        #[ink(message)]
+        pub fn hash_history(&self, content_id: u64, start: u32, limit: u32) -> Vec<String> {
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.hash_history_length(content_id));
+            (start..end)
+                .filter_map(|index| self.hash_history.get((content_id, index)))
+                .collect()
+        }
+
+        /// Returns the number of times the hash of a content item was updated (0 for unknown IDs).
+        #[ink(message)]
+        pub fn hash_history_length(&self, content_id: u64) -> u32 {
+            self.hash_history_lengths.get(content_id).unwrap_or(0)
+        }
+
+        /// Registers new digital content like `register_content`, declaring the format of its hash.
+        /// Hashes of a structured scheme are validated against that structure instead of the oracle
+        /// data; `HashScheme::Other` is validated by the current `ValidationStrategy`.
+        ///
+        /// # Arguments
+        /// - `content_hash`: The unique hash representing the content.
+        /// - `scheme`: The format of `content_hash`.
+        ///
+        /// # Returns
+        /// - A unique content ID for the registered content.
+        ///
+        /// # Errors
+        /// - Returns `Error::InvalidContent` if the hash does not match `scheme`; `check_hash_format` tells why.
+        /// - Returns the same errors as `register_content` otherwise.
+        #[ink(message, payable)]
+        pub fn register_content_with_scheme(&mut self, content_hash: String, scheme: HashScheme) -> Result<u64> {
+            let caller = self.ensure_can_register()?;
+            self.register(caller, Content { scheme, ..Content::new(content_hash, caller) })
+        }
+
+        /// Registers small content by its bytes, hashing them on-chain so the stored hash is known to
+        /// match the content. The hash is the lowercase hex BLAKE2-256 digest of `preimage`, stored as
+        /// `HashScheme::Blake2x256Hex` and emitted in `ContentRegistered`.
+        ///
+        /// # Arguments
+        /// - `preimage`: The content itself, at most `MAX_PREIMAGE_SIZE` bytes.
+        ///
+        /// # Returns
+        /// - A unique content ID for the registered content.
+        ///
+        /// # Errors
+        /// - Returns `Error::PreimageTooLarge` if the preimage exceeds `MAX_PREIMAGE_SIZE`.
+        /// - Returns `Error::AlreadyRegistered` if the computed hash is registered, whichever way it was.
+        /// - Returns the same errors as `register_content` otherwise.
+        #[ink(message, payable)]
+        pub fn register_preimage(&mut self, preimage: Vec<u8>) -> Result<u64> {
+            let caller = self.ensure_can_register()?;
+            if preimage.len() > MAX_PREIMAGE_SIZE {
+                return Err(Error::PreimageTooLarge);
+            }
+            let mut digest = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&preimage, &mut digest);
+            const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+            let content_hash: String = digest
+                .iter()
+                .flat_map(|byte| [HEX_DIGITS[usize::from(byte >> 4)], HEX_DIGITS[usize::from(byte & 0x0f)]])
+                .map(char::from)
+                .collect();
+            self.register(caller, Content { scheme: HashScheme::Blake2x256Hex, ..Content::new(content_hash, caller) })
+        }
+
+        /// Checks a content hash against a `HashScheme` the way registration does, reporting why it is rejected.
+        #[ink(message)]
+        pub fn check_hash_format(&self, content_hash: String, scheme: HashScheme) -> core::result::Result<(), HashFormatError> {
+            match Self::ensure_hash_within_bounds(&content_hash) {
+                Err(Error::ContentHashTooLong) => return Err(HashFormatError::WrongLength),
+                Err(_) => return Err(HashFormatError::InvalidCharacter),
+                Ok(()) => {}
+            }
+            scheme.check_structure(&content_hash)?;
+            if scheme == HashScheme::Other {
+                self.validate_content(&content_hash)?;
+            }
+            Ok(())
+        }
+
+        /// Returns the declared format of a content item's hash, or `None` if the content ID is not found.
+        #[ink(message)]
+        pub fn hash_scheme_of(&self, content_id: u64) -> Option<HashScheme> {
+            self.contents.get(content_id).map(|record| record.scheme)
+        }
+
+        /// Validates a content hash with the external validator contract if one is set, or else
+        /// against the current `ValidationStrategy` and oracle data.
+        ///
+        /// # Arguments
+        /// - `content_hash`: The hash to validate.
+        ///
+        /// # Errors
+        /// - Returns `HashFormatError::ValidatorRejected` if the validator returns `false` or the call fails.
+        /// - Returns the `HashFormatError` of the first failing check of the strategy otherwise.
+        fn validate_content(&self, content_hash: &str) -> core::result::Result<(), HashFormatError> {
+            let Some(validator) = self.validator else {
+                return self.validation_strategy.check(content_hash, &self.oracle_prefixes);
+            };
+            use ink::codegen::TraitCallBuilder;
+            let validator: ink::contract_ref!(ContentValidator) = validator.into();
+            let valid = validator
+                .call()
+                .is_valid(String::from(content_hash))
+                .try_invoke();
+            // A reverted or trapped validator, or a missing contract, is a rejection rather than a trap.
+            if !matches!(valid, Ok(Ok(true))) {
+                return Err(HashFormatError::ValidatorRejected);
+            }
+            Ok(())
+        }
+
+        /// Transfers ownership of a registered content item to a new owner.
+        /// Only the current owner can authorize the transfer.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to transfer.
+        /// - `new_owner`: The AccountId of the new owner.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if transfers are paused.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns `Error::OwnershipRenounced` if the content's ownership was renounced.
+        /// - Returns `Error::ContentSoulbound` if the content is soulbound.
+        /// - Returns `Error::ContentNotActive` if the content's status is not `Active`.
+        /// - Returns `Error::ContentLocked` if the owner locked the content.
+        /// - Returns `Error::ContentFrozen` if an admin or moderator froze the content.
+        /// - Returns `Error::MarketplaceOnly` if the content can only change hands through a sale and
+        ///   `new_owner` is not exempt.
+        /// - Returns `Error::SelfTransfer` if `new_owner` is the current owner.
+        /// - Returns `Error::ZeroAddressRecipient` if `new_owner` is the all-zero account.
+        /// - Returns `Error::OwnedCountOverflow` if the new owner's owned-content counter overflows.
+        #[ink(message)]
+        pub fn transfer_ownership(&mut self, content_id: u64, new_owner: AccountId) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Transfers)?;
+            let caller = self.env().caller();
+            self.transfer_owned(caller, content_id, new_owner, None)
+        }
+
+        /// Transfers ownership like `transfer_ownership`, then, if `new_owner` is a contract, calls its
+        /// `ContentReceiver::on_content_received` hook. The transfer is reverted unless the hook
+        /// returns `true`, so content cannot end up in a contract that does not know how to manage it.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to transfer.
+        /// - `new_owner`: The AccountId of the new owner.
+        /// - `data`: Arbitrary data forwarded to the receiver hook.
+        ///
+        /// # Errors
+        /// - Returns `Error::ReceiverRejected` if the receiving contract fails or returns `false`.
+        /// - Returns the same errors as `transfer_ownership` otherwise.
+        #[ink(message)]
+        pub fn safe_transfer_ownership(&mut self, content_id: u64, new_owner: AccountId, data: Vec<u8>) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Transfers)?;
+            let caller = self.env().caller();
+            self.transfer_owned(caller, content_id, new_owner, None)?;
+            if self.env().is_contract(&new_owner) {
+                use ink::codegen::TraitCallBuilder;
+                let mut receiver: ink::contract_ref!(ContentReceiver) = new_owner.into();
+                let accepted = receiver
+                    .call_mut()
+                    .on_content_received(content_id, caller, data)
+                    .try_invoke();
+                // Returning an error reverts the transfer written above.
+                if !matches!(accepted, Ok(Ok(true))) {
+                    return Err(Error::ReceiverRejected);
+                }
+            }
+            Ok(())
+        }
+
+        /// Transfers several content items owned by the caller to a new owner, e.g. a treasury wallet.
+        /// Each item is attempted independently: a missing or not-owned item fails on its own
+        /// without reverting the others. Every successful item emits `OwnershipTransferred`.
+        ///
+        /// # Arguments
+        /// - `content_ids`: Up to `MAX_BATCH_SIZE` content IDs to transfer.
+        /// - `new_owner`: The AccountId of the new owner.
+        ///
+        /// # Returns
+        /// - One result per content ID, in order, with the same errors as `transfer_ownership`.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if transfers are paused.
+        /// - Returns `Error::BatchTooLarge` if more than `MAX_BATCH_SIZE` IDs are passed.
+        #[ink(message)]
+        pub fn transfer_batch(&mut self, content_ids: Vec<u64>, new_owner: AccountId) -> Result<Vec<Result<()>>> {
+            self.ensure_not_paused(PausableOp::Transfers)?;
+            Self::ensure_batch_size(content_ids.len())?;
+            let caller = self.env().caller();
+            Ok(content_ids
+                .into_iter()
+                .map(|content_id| self.transfer_owned(caller, content_id, new_owner, None))
+                .collect())
+        }
+
+        /// Transfers up to `limit` content items owned by the caller to a new owner, e.g. after a key rotation.
+        /// Call it repeatedly until it returns `0` to move a large holding. Items that cannot be
+        /// transferred are skipped rather than failing the call. Every moved item emits `OwnershipTransferred`.
+        ///
+        /// # Arguments
+        /// - `new_owner`: The AccountId of the new owner.
+        /// - `limit`: The maximum number of items to move, capped at `MAX_BATCH_SIZE`.
+        ///
+        /// # Returns
+        /// - The number of items moved.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if transfers are paused.
+        /// - Returns `Error::SelfTransfer` or `Error::ZeroAddressRecipient` for an invalid recipient.
+        #[ink(message)]
+        pub fn transfer_all(&mut self, new_owner: AccountId, limit: u32) -> Result<u32> {
+            self.ensure_not_paused(PausableOp::Transfers)?;
+            let caller = self.env().caller();
+            Self::ensure_valid_recipient(caller, new_owner)?;
+            let limit = limit.min(MAX_BATCH_SIZE);
+            let mut moved = 0;
+            // Walk the owner index from the end: a removal only swaps in entries from higher
+            // positions, which have already been visited and skipped.
+            let mut position = self.balance_of(caller);
+            while moved < limit && position > 0 {
+                position -= 1;
+                let Some(content_id) = self.owned_contents.get((caller, position)) else {
+                    continue;
+                };
+                if self.transfer_owned(caller, content_id, new_owner, None).is_ok() {
+                    moved += 1;
+                }
+            }
+            Ok(moved)
+        }
+
+        /// Transfers a content item on behalf of `owner`, who must be its current owner.
+        /// Shared by the owner-initiated transfer messages; pause checks are the caller's responsibility.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if `owner` is not the current owner.
+        /// - Returns `Error::MarketplaceOnly` if the content can only change hands through a sale.
+        /// - Returns the errors of `apply_transfer` otherwise.
+        fn transfer_owned(&mut self, owner: AccountId, content_id: u64, new_owner: AccountId, memo: Option<String>) -> Result<()> {
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            if owner != record.owner {
+                return Err(Error::NotOwner);
+            }
+            self.ensure_plain_transfer_allowed(content_id, new_owner)?;
+            self.apply_transfer(content_id, record, new_owner, memo, None)
+        }
+
+        /// Creates an empty collection owned by the caller. Emits `CollectionCreated`.
+        ///
+        /// # Arguments
+        /// - `name`: Up to `MAX_COLLECTION_NAME_LENGTH` bytes, e.g. an album title.
+        ///
+        /// # Returns
+        /// - The unique ID of the collection.
+        ///
+        /// # Errors
+        /// - Returns `Error::CollectionNameTooLong` if the name exceeds `MAX_COLLECTION_NAME_LENGTH` bytes.
+        /// - Returns `Error::CounterOverflow` if the collection ID counter overflows.
+        #[ink(message)]
+        pub fn create_collection(&mut self, name: String) -> Result<u64> {
+            if name.len() > MAX_COLLECTION_NAME_LENGTH {
+                return Err(Error::CollectionNameTooLong);
+            }
+            let owner = self.env().caller();
+            let collection_id = self.next_collection_id;
+            self.next_collection_id = collection_id.checked_add(1).ok_or(Error::CounterOverflow)?;
+            self.collections.insert(collection_id, &Collection { owner, name: name.clone() });
+            let seq = self.next_event_seq();
+            self.env().emit_event(CollectionCreated {
+                collection_id,
+                owner,
+                name,
+                seq,
+            });
+            Ok(collection_id)
+        }
+
+        /// Adds a content item to a collection. The caller must own both, and the item must not
+        /// belong to another collection. Emits `CollectionItemAdded`.
+        ///
+        /// # Arguments
+        /// - `collection_id`: The unique ID of the collection.
+        /// - `content_id`: The unique ID of the content.
+        ///
+        /// # Errors
+        /// - Returns `Error::CollectionNotFound` if the collection ID is not found.
+        /// - Returns `Error::NotOwner` if the caller owns neither the collection nor the content.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::AlreadyInCollection` if the content already belongs to a collection.
+        /// - Returns `Error::CollectionFull` if the collection holds `MAX_COLLECTION_SIZE` items.
+        #[ink(message)]
+        pub fn add_to_collection(&mut self, collection_id: u64, content_id: u64) -> Result<()> {
+            self.ensure_caller_owns_collection(collection_id)?;
+            self.ensure_caller_owns(content_id)?;
+            if self.item_collection.contains(content_id) {
+                return Err(Error::AlreadyInCollection);
+            }
+            let position = self.collection_size.get(collection_id).unwrap_or(0);
+            if position >= MAX_COLLECTION_SIZE {
+                return Err(Error::CollectionFull);
+            }
+            self.collection_items.insert((collection_id, position), &content_id);
+            self.item_collection.insert(content_id, &(collection_id, position));
+            self.collection_size.insert(collection_id, &position.saturating_add(1));
+            let seq = self.next_event_seq();
+            self.env().emit_event(CollectionItemAdded {
+                collection_id,
+                content_id,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Removes a content item from a collection. The caller must own both.
+        /// Emits `CollectionItemRemoved`.
+        ///
+        /// # Arguments
+        /// - `collection_id`: The unique ID of the collection.
+        /// - `content_id`: The unique ID of the content.
+        ///
+        /// # Errors
+        /// - Returns `Error::CollectionNotFound` if the collection ID is not found.
+        /// - Returns `Error::NotOwner` if the caller owns neither the collection nor the content.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotInCollection` if the content does not belong to the collection.
+        #[ink(message)]
+        pub fn remove_from_collection(&mut self, collection_id: u64, content_id: u64) -> Result<()> {
+            self.ensure_caller_owns_collection(collection_id)?;
+            self.ensure_caller_owns(content_id)?;
+            if self.collection_of(content_id) != Some(collection_id) {
+                return Err(Error::NotInCollection);
+            }
+            self.remove_from_collection_index(content_id);
+            Ok(())
+        }
+
+        /// Transfers a collection and every item in it to a new owner. All items must be
+        /// transferable: if one is locked, frozen, auctioned, soulbound, or not `Active`,
+        /// nothing moves. Items already sold off on their own are no longer part of the collection.
+        /// Emits `OwnershipTransferred` per item, then `CollectionTransferred`.
+        ///
+        /// # Arguments
+        /// - `collection_id`: The unique ID of the collection.
+        /// - `new_owner`: The AccountId of the new owner.
+        ///
+        /// # Returns
+        /// - The number of items moved.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if transfers are paused.
+        /// - Returns `Error::CollectionNotFound` if the collection ID is not found.
+        /// - Returns `Error::NotOwner` if the caller does not own the collection.
+        /// - Returns `Error::SelfTransfer` or `Error::ZeroAddressRecipient` for an invalid recipient.
+        /// - Returns the errors of `transfer_ownership` for the first item that cannot be transferred.
+        #[ink(message)]
+        pub fn transfer_collection(&mut self, collection_id: u64, new_owner: AccountId) -> Result<u32> {
+            self.ensure_not_paused(PausableOp::Transfers)?;
+            let mut collection = self.ensure_caller_owns_collection(collection_id)?;
+            let from = collection.owner;
+            Self::ensure_valid_recipient(from, new_owner)?;
+            let items = self.collection_size(collection_id);
+            let content_ids = self.collection_items(collection_id, 0, items);
+            // Check every item up front so a failure leaves the collection and all items untouched.
+            for &content_id in &content_ids {
+                let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+                Self::ensure_transferable(&record)?;
+                self.ensure_unlocked(content_id)?;
+                self.ensure_not_frozen(content_id)?;
+                self.ensure_not_auctioned(content_id)?;
+                self.ensure_not_pledged(content_id)?;
+                self.ensure_still_valid(&record)?;
+            }
+            collection.owner = new_owner;
+            self.collections.insert(collection_id, &collection);
+            for content_id in content_ids {
+                self.transfer_owned(from, content_id, new_owner, None)?;
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(CollectionTransferred {
+                collection_id,
+                from,
+                to: new_owner,
+                items,
+                seq,
+            });
+            Ok(items)
+        }
+
+        /// Returns a collection record, if it exists.
+        #[ink(message)]
+        pub fn get_collection(&self, collection_id: u64) -> Option<Collection> {
+            self.collections.get(collection_id)
+        }
+
+        /// Returns the number of items in a collection.
+        #[ink(message)]
+        pub fn collection_size(&self, collection_id: u64) -> u32 {
+            self.collection_size.get(collection_id).unwrap_or(0)
+        }
+
+        /// Returns the collection a content item belongs to, if any.
+        #[ink(message)]
+        pub fn collection_of(&self, content_id: u64) -> Option<u64> {
+            self.item_collection.get(content_id).map(|(collection_id, _)| collection_id)
+        }
+
+        /// Returns a page of the items in a collection.
+        /// The order is not stable: when an item leaves, the last entry takes its position.
+        ///
+        /// # Arguments
+        /// - `collection_id`: The unique ID of the collection.
+        /// - `start`: The position in the collection to start from.
+        /// - `limit`: The maximum number of IDs to return, capped at `MAX_PAGE_SIZE`.
+        ///
+        /// # Returns
+        /// - A `Vec` of content IDs, empty if `start` is past the end.
+        #[ink(message)]
+        pub fn collection_items(&self, collection_id: u64, start: u32, limit: u32) -> Vec<u64> {
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.collection_size(collection_id));
+            (start..end)
+                .filter_map(|position| self.collection_items.get((collection_id, position)))
+                .collect()
+        }
+
+        /// Checks that the caller owns a collection and returns its record.
+        ///
+        /// # Errors
+        /// - Returns `Error::CollectionNotFound` if the collection ID is not found.
+        /// - Returns `Error::NotOwner` if the caller does not own the collection.
+        fn ensure_caller_owns_collection(&self, collection_id: u64) -> Result<Collection> {
+            let collection = self.collections.get(collection_id).ok_or(Error::CollectionNotFound)?;
+            if collection.owner != self.env().caller() {
+                return Err(Error::NotOwner);
+            }
+            Ok(collection)
+        }
+
+        /// Removes a content item from its collection, if any, moving the collection's last entry
+        /// into the freed position (swap-remove). Emits `CollectionItemRemoved`.
+        fn remove_from_collection_index(&mut self, content_id: u64) {
+            let Some((collection_id, position)) = self.item_collection.get(content_id) else {
+                return;
+            };
+            let last = self.collection_size(collection_id).saturating_sub(1);
+            if position != last {
+                if let Some(last_id) = self.collection_items.get((collection_id, last)) {
+                    self.collection_items.insert((collection_id, position), &last_id);
+                    self.item_collection.insert(last_id, &(collection_id, position));
+                }
+            }
+            self.collection_items.remove((collection_id, last));
+            self.item_collection.remove(content_id);
+            self.collection_size.insert(collection_id, &last);
+            let seq = self.next_event_seq();
+            self.env().emit_event(CollectionItemRemoved {
+                collection_id,
+                content_id,
+                seq,
+            });
+        }
+
+        /// Transfers ownership like `transfer_ownership`, attaching a memo that references an
+        /// off-chain agreement. The memo is included in `OwnershipTransferred` and returned by
+        /// `last_transfer_memo` until the next transfer.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to transfer.
+        /// - `new_owner`: The AccountId of the new owner.
+        /// - `memo`: Up to `MAX_MEMO_LENGTH` bytes, e.g. a hash or URI of the agreement.
+        ///
+        /// # Errors
+        /// - Returns `Error::MemoTooLong` if the memo exceeds `MAX_MEMO_LENGTH` bytes.
+        /// - Returns the same errors as `transfer_ownership` otherwise.
+        #[ink(message)]
+        pub fn transfer_ownership_with_memo(&mut self, content_id: u64, new_owner: AccountId, memo: String) -> Result<()> {
+            if memo.len() > MAX_MEMO_LENGTH {
+                return Err(Error::MemoTooLong);
+            }
+            self.ensure_not_paused(PausableOp::Transfers)?;
+            let caller = self.env().caller();
+            self.transfer_owned(caller, content_id, new_owner, Some(memo))
+        }
+
+        /// Returns the memo of the latest transfer of a content item.
+        ///
+        /// # Returns
+        /// - `None` if the content was never transferred or its latest transfer carried no memo.
+        #[ink(message)]
+        pub fn last_transfer_memo(&self, content_id: u64) -> Option<String> {
+            self.transfer_memos.get(content_id)
+        }
+
+        /// Transfers ownership like `transfer_ownership`, but only if the content is still owned by
+        /// `expected_current_owner`. This compare-and-swap guard lets integrations fail cleanly when
+        /// the owner changed between reading the state and the transfer executing.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to transfer.
+        /// - `new_owner`: The AccountId of the new owner.
+        /// - `expected_current_owner`: The owner the caller observed.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if transfers are paused.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::OwnerMismatch` if the stored owner is not `expected_current_owner`.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns `Error::MarketplaceOnly` if the content can only change hands through a sale.
+        /// - Returns `Error::OwnedCountOverflow` if the new owner's owned-content counter overflows.
+        #[ink(message)]
+        pub fn transfer_ownership_checked(&mut self, content_id: u64, new_owner: AccountId, expected_current_owner: AccountId) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Transfers)?;
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            if record.owner != expected_current_owner {
+                return Err(Error::OwnerMismatch);
+            }
+            if self.env().caller() != record.owner {
+                return Err(Error::NotOwner);
+            }
+            self.ensure_plain_transfer_allowed(content_id, new_owner)?;
+            self.apply_transfer(content_id, record, new_owner, None, None)
+        }
+
+        /// Approves an operator, e.g. a marketplace contract, to transfer a content item through
+        /// `transfer_from`. Only the current owner can approve; a new approval replaces the previous one.
+        /// The approval is cleared whenever the content changes hands. Emits `Approval`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `operator`: The AccountId allowed to transfer the content.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns `Error::ContentSoulbound` if the content is soulbound.
+        /// - Returns `Error::ContentNotActive` if the content's status is not `Active`.
+        /// - Returns `Error::ContentLocked` if the owner locked the content.
+        /// - Returns `Error::ContentFrozen` if an admin or moderator froze the content.
+        #[ink(message)]
+        pub fn approve(&mut self, content_id: u64, operator: AccountId) -> Result<()> {
+            let caller = self.env().caller();
+            self.set_approval(caller, content_id, operator, None)
+        }
+
+        /// Approves an operator like `approve`, but only until the given block timestamp.
+        /// From `expires_at` on, `get_approved` returns `None` and `transfer_from` fails with `ApprovalExpired`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `operator`: The AccountId allowed to transfer the content.
+        /// - `expires_at`: The block timestamp, in milliseconds, from which the approval is no longer valid.
+        ///
+        /// # Errors
+        /// - Returns the same errors as `approve`.
+        #[ink(message)]
+        pub fn approve_until(&mut self, content_id: u64, operator: AccountId, expires_at: Timestamp) -> Result<()> {
+            let caller = self.env().caller();
+            self.set_approval(caller, content_id, operator, Some(expires_at))
+        }
+
+        /// Approves an operator on behalf of the owner, who signed the approval off-chain,
+        /// so a relayer can pay the fees. The signature must be an ECDSA signature by the owner's key
+        /// over `permit_hash(content_id, operator, nonce_of(owner), deadline)`; the owner's AccountId
+        /// is the BLAKE2-256 hash of the compressed public key. Using a permit advances the owner's
+        /// nonce, so each signature can be used only once. Emits `Approval`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `operator`: The AccountId allowed to transfer the content.
+        /// - `deadline`: The last block timestamp at which the permit can be used.
+        /// - `signature`: The 65-byte recoverable ECDSA signature.
+        ///
+        /// # Errors
+        /// - Returns `Error::PermitExpired` if the deadline has passed.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::InvalidSignature` if the signature was not made by the current owner over this permit.
+        /// - Returns `Error::NonceOverflow` if the owner's nonce would overflow.
+        /// - Returns the errors of `approve` otherwise.
+        #[ink(message)]
+        pub fn permit(&mut self, content_id: u64, operator: AccountId, deadline: Timestamp, signature: [u8; 65]) -> Result<()> {
+            if self.env().block_timestamp() > deadline {
+                return Err(Error::PermitExpired);
+            }
+            let owner = self.contents.get(content_id).ok_or(Error::ContentNotFound)?.owner;
+            let nonce = self.nonce_of(owner);
+            let message_hash = self.permit_hash(content_id, operator, nonce, deadline);
+            if self.recover_signer(&signature, &message_hash)? != owner {
+                return Err(Error::InvalidSignature);
+            }
+            self.use_nonce(owner, nonce)?;
+            self.set_approval(owner, content_id, operator, None)
+        }
+
+        /// Returns the message hash an owner signs to create a `permit`: the BLAKE2-256 hash of the
+        /// SCALE-encoded `(PERMIT_DOMAIN, contract address, content_id, operator, nonce, deadline)`.
+        #[ink(message)]
+        pub fn permit_hash(&self, content_id: u64, operator: AccountId, nonce: u64, deadline: Timestamp) -> [u8; 32] {
+            let payload = (PERMIT_DOMAIN, self.env().account_id(), content_id, operator, nonce, deadline);
+            let mut output = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&payload, &mut output);
+            output
+        }
+
+        /// Returns the nonce the next signed message of an account must use.
+        #[ink(message)]
+        pub fn nonce_of(&self, account: AccountId) -> u64 {
+            self.nonces.get(account).unwrap_or(0)
+        }
+
+        /// Transfers a content item on behalf of its owner, who signed the transfer intent off-chain,
+        /// so a relayer can pay the fees. The signature must be an ECDSA signature by the owner's key
+        /// over `transfer_hash(content_id, new_owner, nonce, deadline)`, and `nonce` must be the owner's
+        /// current nonce, which the transfer advances. Emits `OwnershipTransferred` with `relayed_by`
+        /// set to the caller.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to transfer.
+        /// - `new_owner`: The AccountId of the new owner.
+        /// - `deadline`: The last block timestamp at which the intent can be executed.
+        /// - `nonce`: The owner's nonce the intent was signed with.
+        /// - `signature`: The 65-byte recoverable ECDSA signature.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if transfers are paused.
+        /// - Returns `Error::PermitExpired` if the deadline has passed.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::InvalidNonce` if `nonce` is not the owner's current nonce.
+        /// - Returns `Error::InvalidSignature` if the signature was not made by the current owner over this intent.
+        /// - Returns `Error::NonceOverflow` if the owner's nonce would overflow.
+        /// - Returns the errors of `transfer_ownership` otherwise.
+        #[ink(message)]
+        pub fn execute_signed_transfer(
+            &mut self,
+            content_id: u64,
+            new_owner: AccountId,
+            deadline: Timestamp,
+            nonce: u64,
+            signature: [u8; 65],
+        ) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Transfers)?;
+            if self.env().block_timestamp() > deadline {
+                return Err(Error::PermitExpired);
+            }
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            let owner = record.owner;
+            if nonce != self.nonce_of(owner) {
+                return Err(Error::InvalidNonce);
+            }
+            let message_hash = self.transfer_hash(content_id, new_owner, nonce, deadline);
+            if self.recover_signer(&signature, &message_hash)? != owner {
+                return Err(Error::InvalidSignature);
+            }
+            self.ensure_plain_transfer_allowed(content_id, new_owner)?;
+            self.use_nonce(owner, nonce)?;
+            let relayer = self.env().caller();
+            self.apply_transfer(content_id, record, new_owner, None, Some(relayer))
+        }
+
+        /// Returns the message hash an owner signs for `execute_signed_transfer`: the BLAKE2-256 hash of the
+        /// SCALE-encoded `(TRANSFER_DOMAIN, contract address, content_id, new_owner, nonce, deadline)`.
+        #[ink(message)]
+        pub fn transfer_hash(&self, content_id: u64, new_owner: AccountId, nonce: u64, deadline: Timestamp) -> [u8; 32] {
+            let payload = (TRANSFER_DOMAIN, self.env().account_id(), content_id, new_owner, nonce, deadline);
+            let mut output = [0u8; 32];
+            ink::env::hash_encoded::<ink::env::hash::Blake2x256, _>(&payload, &mut output);
+            output
+        }
+
+        /// Recovers the AccountId that made an ECDSA signature over a message hash.
+        /// The AccountId is the BLAKE2-256 hash of the compressed public key, as for Substrate ECDSA accounts.
+        ///
+        /// # Errors
+        /// - Returns `Error::InvalidSignature` if no public key can be recovered.
+        fn recover_signer(&self, signature: &[u8; 65], message_hash: &[u8; 32]) -> Result<AccountId> {
+            let public_key = self.env()
+                .ecdsa_recover(signature, message_hash)
+                .map_err(|_| Error::InvalidSignature)?;
+            let mut account = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&public_key, &mut account);
+            Ok(AccountId::from(account))
+        }
+
+        /// Consumes `nonce`, the current nonce of `account`, so signatures over it cannot be replayed.
+        ///
+        /// # Errors
+        /// - Returns `Error::NonceOverflow` if the nonce would overflow.
+        fn use_nonce(&mut self, account: AccountId, nonce: u64) -> Result<()> {
+            let next = nonce.checked_add(1).ok_or(Error::NonceOverflow)?;
+            self.nonces.insert(account, &next);
+            Ok(())
+        }
+
+        /// Records a per-content approval granted by `owner` and emits `Approval`.
+        ///
+        /// # Errors
+        /// - Returns the errors of `ensure_can_approve`.
+        fn set_approval(&mut self, owner: AccountId, content_id: u64, operator: AccountId, expires_at: Option<Timestamp>) -> Result<()> {
+            self.ensure_can_approve(owner, content_id)?;
+            if !self.approvals.contains(content_id) {
+                self.add_to_approval_index(owner, content_id);
+            }
+            self.approvals.insert(content_id, &ContentApproval { operator, expires_at });
+            let seq = self.next_event_seq();
+            self.env().emit_event(Approval {
+                content_id,
+                owner,
+                operator: Some(operator),
+                expires_at,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Revokes the approval of a content item. Only the current owner can revoke.
+        /// Emits `Approval` with no operator, even if no approval was set.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        #[ink(message)]
+        pub fn revoke_approval(&mut self, content_id: u64) -> Result<()> {
+            self.ensure_caller_owns(content_id)?;
+            let owner = self.env().caller();
+            self.clear_approval(owner, content_id);
+            let seq = self.next_event_seq();
+            self.env().emit_event(Approval {
+                content_id,
+                owner,
+                operator: None,
+                expires_at: None,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Approves an operator for several content items owned by the caller, e.g. when listing a
+        /// catalog on a new marketplace. Each item is attempted independently: a missing or not-owned
+        /// item fails on its own without reverting the others. Every approved item emits `Approval`.
+        ///
+        /// # Arguments
+        /// - `content_ids`: Up to `MAX_BATCH_SIZE` content IDs to approve.
+        /// - `operator`: The AccountId allowed to transfer the content.
+        ///
+        /// # Returns
+        /// - One result per content ID, in order, with the same errors as `approve`.
+        ///
+        /// # Errors
+        /// - Returns `Error::BatchTooLarge` if more than `MAX_BATCH_SIZE` IDs are passed.
+        #[ink(message)]
+        pub fn approve_batch(&mut self, content_ids: Vec<u64>, operator: AccountId) -> Result<Vec<Result<()>>> {
+            Self::ensure_batch_size(content_ids.len())?;
+            let caller = self.env().caller();
+            Ok(content_ids
+                .into_iter()
+                .map(|content_id| self.set_approval(caller, content_id, operator, None))
+                .collect())
+        }
+
+        /// Revokes the approvals of several content items owned by the caller. Each item is
+        /// attempted independently, and every revoked item emits `Approval` with no operator.
+        ///
+        /// # Arguments
+        /// - `content_ids`: Up to `MAX_BATCH_SIZE` content IDs to revoke.
+        ///
+        /// # Returns
+        /// - One result per content ID, in order, with the same errors as `revoke_approval`.
+        ///
+        /// # Errors
+        /// - Returns `Error::BatchTooLarge` if more than `MAX_BATCH_SIZE` IDs are passed.
+        #[ink(message)]
+        pub fn revoke_batch(&mut self, content_ids: Vec<u64>) -> Result<Vec<Result<()>>> {
+            Self::ensure_batch_size(content_ids.len())?;
+            Ok(content_ids
+                .into_iter()
+                .map(|content_id| self.revoke_approval(content_id))
+                .collect())
+        }
+
+        /// Returns the operator approved to transfer a content item, if any.
+        ///
+        /// # Returns
+        /// - `None` if no approval is set or the approval has expired.
+        #[ink(message)]
+        pub fn get_approved(&self, content_id: u64) -> Option<AccountId> {
+            self.approvals
+                .get(content_id)
+                .filter(|approval| !self.is_expired(approval.expires_at))
+                .map(|approval| approval.operator)
+        }
+
+        /// Returns whether an optional expiry timestamp has been reached.
+        fn is_expired(&self, expires_at: Option<Timestamp>) -> bool {
+            expires_at.is_some_and(|expires_at| self.env().block_timestamp() >= expires_at)
+        }
+
+        /// Approves or revokes an operator for all of the caller's content, present and future.
+        /// Unlike per-content approvals, the flag survives transfers of individual items; revocation
+        /// takes effect immediately. Emits `ApprovalForAll`.
+        ///
+        /// # Arguments
+        /// - `operator`: The AccountId to approve or revoke.
+        /// - `approved`: Whether the operator may transfer the caller's content.
+        ///
+        /// # Errors
+        /// - Returns `Error::SelfApproval` if `operator` is the caller.
+        #[ink(message)]
+        pub fn set_approval_for_all(&mut self, operator: AccountId, approved: bool) -> Result<()> {
+            let owner = self.env().caller();
+            if operator == owner {
+                return Err(Error::SelfApproval);
+            }
+            if approved {
+                self.add_operator(owner, operator);
+            } else {
+                self.remove_operator(owner, operator);
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(ApprovalForAll {
+                owner,
+                operator,
+                approved,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Checks whether `operator` may transfer all of `owner`'s content.
+        #[ink(message)]
+        pub fn is_approved_for_all(&self, owner: AccountId, operator: AccountId) -> bool {
+            self.operator_approvals.contains((owner, operator))
+        }
+
+        /// Returns a page of the per-content approvals granted by an owner, so wallets can show
+        /// which items an operator may still move. Approvals that have expired but were not
+        /// revoked are included with their `expires_at`. The order is not stable: when an approval
+        /// is revoked or cleared, the owner's last entry takes its position (swap-remove).
+        ///
+        /// # Arguments
+        /// - `owner`: The AccountId whose approvals to list.
+        /// - `start`: The position in the owner's approval index to start from.
+        /// - `limit`: The maximum number of approvals to return, capped at `MAX_PAGE_SIZE`.
+        ///
+        /// # Returns
+        /// - A `Vec` of `(content_id, operator, expires_at)`, empty if `start` is past the end.
+        #[ink(message)]
+        pub fn approvals_of(&self, owner: AccountId, start: u32, limit: u32) -> Vec<(u64, AccountId, Option<Timestamp>)> {
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.approved_count.get(owner).unwrap_or(0));
+            (start..end)
+                .filter_map(|position| self.approved_contents.get((owner, position)))
+                .filter_map(|content_id| {
+                    self.approvals
+                        .get(content_id)
+                        .map(|approval| (content_id, approval.operator, approval.expires_at))
+                })
+                .collect()
+        }
+
+        /// Returns the operators approved for all of an owner's content, in no stable order.
+        #[ink(message)]
+        pub fn operators_of(&self, owner: AccountId) -> Vec<AccountId> {
+            (0..self.operator_count.get(owner).unwrap_or(0))
+                .filter_map(|position| self.operators.get((owner, position)))
+                .collect()
+        }
+
+        /// Removes the approval of a content item owned by `owner`, if any, along with its index entry.
+        fn clear_approval(&mut self, owner: AccountId, content_id: u64) {
+            if self.approvals.contains(content_id) {
+                self.approvals.remove(content_id);
+                self.remove_from_approval_index(owner, content_id);
+            }
+        }
+
+        /// Appends a content ID to an owner's approval index.
+        /// The count cannot overflow, as it never exceeds the owner's owned-content counter.
+        fn add_to_approval_index(&mut self, owner: AccountId, content_id: u64) {
+            let position = self.approved_count.get(owner).unwrap_or(0);
+            self.approved_contents.insert((owner, position), &content_id);
+            self.approved_content_position.insert(content_id, &position);
+            self.approved_count.insert(owner, &position.saturating_add(1));
+        }
+
+        /// Removes a content ID from an owner's approval index, moving the owner's last entry
+        /// into the freed position (swap-remove).
+        fn remove_from_approval_index(&mut self, owner: AccountId, content_id: u64) {
+            let Some(last) = self.approved_count.get(owner).unwrap_or(0).checked_sub(1) else {
+                return;
+            };
+            let position = self.approved_content_position.get(content_id).unwrap_or(last);
+            if position != last {
+                if let Some(last_id) = self.approved_contents.get((owner, last)) {
+                    self.approved_contents.insert((owner, position), &last_id);
+                    self.approved_content_position.insert(last_id, &position);
+                }
+            }
+            self.approved_contents.remove((owner, last));
+            self.approved_content_position.remove(content_id);
+            self.approved_count.insert(owner, &last);
+        }
+
+        /// Adds an operator to an owner's operator set and index, if not already approved.
+        fn add_operator(&mut self, owner: AccountId, operator: AccountId) {
+            if self.operator_approvals.contains((owner, operator)) {
+                return;
+            }
+            let position = self.operator_count.get(owner).unwrap_or(0);
+            self.operators.insert((owner, position), &operator);
+            self.operator_approvals.insert((owner, operator), &position);
+            self.operator_count.insert(owner, &position.saturating_add(1));
+        }
+
+        /// Removes an operator from an owner's operator set and index, if approved, moving the
+        /// owner's last operator into the freed position (swap-remove).
+        fn remove_operator(&mut self, owner: AccountId, operator: AccountId) {
+            let Some(position) = self.operator_approvals.get((owner, operator)) else {
+                return;
+            };
+            let last = self.operator_count.get(owner).unwrap_or(1).saturating_sub(1);
+            if position != last {
+                if let Some(last_operator) = self.operators.get((owner, last)) {
+                    self.operators.insert((owner, position), &last_operator);
+                    self.operator_approvals.insert((owner, last_operator), &position);
+                }
+            }
+            self.operators.remove((owner, last));
+            self.operator_approvals.remove((owner, operator));
+            self.operator_count.insert(owner, &last);
+        }
+
+        /// Transfers a content item on behalf of its owner. Callable by the owner, the item's approved
+        /// operator, or an operator approved for all of the owner's content.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to transfer.
+        /// - `to`: The AccountId of the new owner.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if transfers are paused.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotApproved` if the caller is neither the owner nor approved.
+        /// - Returns `Error::ApprovalExpired` if the caller's approval has expired.
+        /// - Returns `Error::MarketplaceOnly` if the content can only change hands through a sale and
+        ///   `to` is not exempt.
+        /// - Returns the errors of `apply_transfer` otherwise.
+        #[ink(message)]
+        pub fn transfer_from(&mut self, content_id: u64, to: AccountId) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Transfers)?;
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            self.ensure_approved(content_id, record.owner, self.env().caller())?;
+            self.ensure_plain_transfer_allowed(content_id, to)?;
+            self.apply_transfer(content_id, record, to, None, None)
+        }
+
+        /// Checks that `owner` owns a content item that may be approved for transfer.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if `owner` is not the current owner.
+        /// - Returns `Error::ContentSoulbound` if the content is soulbound.
+        /// - Returns `Error::ContentNotActive` if the content's status is not `Active`.
+        /// - Returns `Error::ContentLocked` if the owner locked the content.
+        /// - Returns `Error::ContentFrozen` if an admin or moderator froze the content.
+        fn ensure_can_approve(&self, owner: AccountId, content_id: u64) -> Result<()> {
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            if record.renounced || owner != record.owner {
+                return Err(Error::NotOwner);
+            }
+            Self::ensure_transferable(&record)?;
+            self.ensure_unlocked(content_id)?;
+            self.ensure_not_frozen(content_id)
+        }
+
+        /// Checks that `operator` may transfer a content item owned by `owner`.
+        ///
+        /// # Errors
+        /// - Returns `Error::ApprovalExpired` if `operator` holds a per-content approval that has expired.
+        /// - Returns `Error::NotApproved` if `operator` is neither the owner nor approved for the content.
+        fn ensure_approved(&self, content_id: u64, owner: AccountId, operator: AccountId) -> Result<()> {
+            if operator == owner || self.is_approved_for_all(owner, operator) {
+                return Ok(());
+            }
+            match self.approvals.get(content_id) {
+                Some(approval) if approval.operator == operator => {
+                    if self.is_expired(approval.expires_at) {
+                        return Err(Error::ApprovalExpired);
+                    }
+                    Ok(())
+                }
+                _ => Err(Error::NotApproved),
+            }
+        }
+
+        /// Offers a content item to a recipient, who must call `accept_transfer` to receive it.
+        /// Only the current owner can make an offer. A new offer replaces any pending one, and
+        /// any ownership change, including a direct `transfer_ownership`, withdraws it.
+        /// Emits `TransferOffered`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to offer.
+        /// - `to`: The AccountId that may accept the offer.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::OwnershipRenounced` if the content's ownership was renounced.
+        /// - Returns `Error::ContentSoulbound` if the content is soulbound.
+        /// - Returns `Error::ContentNotActive` if the content's status is not `Active`.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns `Error::ContentLocked` if the owner locked the content.
+        /// - Returns `Error::ContentFrozen` if an admin or moderator froze the content.
+        /// - Returns `Error::SelfTransfer` or `Error::ZeroAddressRecipient` for an invalid recipient.
+        #[ink(message)]
+        pub fn offer_transfer(&mut self, content_id: u64, to: AccountId) -> Result<()> {
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            Self::ensure_transferable(&record)?;
+            let from = record.owner;
+            if self.env().caller() != from {
+                return Err(Error::NotOwner);
+            }
+            self.ensure_unlocked(content_id)?;
+            self.ensure_not_frozen(content_id)?;
+            Self::ensure_valid_recipient(from, to)?;
+            self.transfer_offers.insert(content_id, &to);
+            let seq = self.next_event_seq();
+            self.env().emit_event(TransferOffered {
+                content_id,
+                from,
+                to,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Withdraws the pending transfer offer of a content item.
+        /// Only the current owner can cancel. Emits `TransferOfferCancelled`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns `Error::NoPendingTransfer` if the content has no pending offer.
+        #[ink(message)]
+        pub fn cancel_transfer_offer(&mut self, content_id: u64) -> Result<()> {
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            if self.env().caller() != record.owner {
+                return Err(Error::NotOwner);
+            }
+            let to = self.transfer_offers.take(content_id).ok_or(Error::NoPendingTransfer)?;
+            let seq = self.next_event_seq();
+            self.env().emit_event(TransferOfferCancelled {
+                content_id,
+                to,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Accepts a pending transfer offer, moving the content to the caller.
+        /// Emits `OwnershipTransferred` followed by `TransferOfferAccepted`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if transfers are paused.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NoPendingTransfer` if the content has no pending offer.
+        /// - Returns `Error::NotTransferRecipient` if the caller is not the offered recipient.
+        /// - Returns `Error::MarketplaceOnly` if the content can only change hands through a sale and
+        ///   the caller is not exempt.
+        /// - Returns `Error::OwnedCountOverflow` if the caller's owned-content counter overflows.
+        #[ink(message)]
+        pub fn accept_transfer(&mut self, content_id: u64) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Transfers)?;
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            let to = self.transfer_offers.get(content_id).ok_or(Error::NoPendingTransfer)?;
+            if self.env().caller() != to {
+                return Err(Error::NotTransferRecipient);
+            }
+            self.ensure_plain_transfer_allowed(content_id, to)?;
+            self.apply_transfer(content_id, record, to, None, None)?;
+            let seq = self.next_event_seq();
+            self.env().emit_event(TransferOfferAccepted {
+                content_id,
+                to,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Returns the recipient of the pending transfer offer of a content item, if any.
+        #[ink(message)]
+        pub fn get_transfer_offer(&self, content_id: u64) -> Option<AccountId> {
+            self.transfer_offers.get(content_id)
+        }
+
+        /// Lists a content item for sale at a fixed price in native tokens, which anyone can pay
+        /// through `buy`. Only the current owner can list. Listing again changes the price, and any
+        /// ownership change, including a direct `transfer_ownership`, withdraws the listing.
+        /// Emits `ContentListed`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to sell.
+        /// - `price`: The asking price, above zero.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if sales are paused.
+        /// - Returns `Error::InvalidPrice` if the price is zero.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns the errors of `ensure_transferable`, `Error::ContentLocked`, `Error::ContentFrozen`,
+        ///   `Error::AuctionActive`, or `Error::ContentPledged` if the content cannot change hands.
+        /// - Returns `Error::ForcedTransferPending` if an admin scheduled a forced transfer of the content.
+        #[ink(message)]
+        pub fn list_for_sale(&mut self, content_id: u64, price: Balance) -> Result<()> {
+            self.list(content_id, Listing { price, currency: None })
+        }
+
+        /// Lists a content item for sale at a fixed price in a PSP22 token, which anyone who approved
+        /// this contract to spend the price can pay through `buy_with_token`. Otherwise works like
+        /// `list_for_sale`, whose listing it replaces. Emits `ContentListed`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to sell.
+        /// - `token`: The PSP22 token contract the price is paid in.
+        /// - `price`: The asking price in units of `token`, above zero.
+        ///
+        /// # Errors
+        /// - Returns the same errors as `list_for_sale`.
+        #[ink(message)]
+        pub fn list_for_sale_in_token(&mut self, content_id: u64, token: AccountId, price: Balance) -> Result<()> {
+            self.list(content_id, Listing { price, currency: Some(token) })
+        }
+
+        /// Stores a listing for the caller's content and emits `ContentListed`.
+        ///
+        /// # Errors
+        /// - Returns the errors of `list_for_sale`.
+        fn list(&mut self, content_id: u64, listing: Listing) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Sales)?;
+            if listing.price == 0 {
+                return Err(Error::InvalidPrice);
+            }
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            let seller = record.owner;
+            if self.env().caller() != seller {
+                return Err(Error::NotOwner);
+            }
+            Self::ensure_transferable(&record)?;
+            self.ensure_unlocked(content_id)?;
+            self.ensure_not_frozen(content_id)?;
+            self.ensure_not_auctioned(content_id)?;
+            self.ensure_not_pledged(content_id)?;
+            self.ensure_no_forced_transfer(content_id)?;
+            self.listings.insert(content_id, &listing);
+            let seq = self.next_event_seq();
+            self.env().emit_event(ContentListed {
+                content_id,
+                seller,
+                price: listing.price,
+                currency: listing.currency,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Withdraws a content item from sale. Only the current owner can delist. Emits `ContentDelisted`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the listed content.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns `Error::NotListed` if the content is not listed.
+        #[ink(message)]
+        pub fn delist(&mut self, content_id: u64) -> Result<()> {
+            self.ensure_caller_owns(content_id)?;
+            self.listings.take(content_id).ok_or(Error::NotListed)?;
+            let seq = self.next_event_seq();
+            self.env().emit_event(ContentDelisted { content_id, seq });
+            Ok(())
+        }
+
+        /// Returns the listing of a content item, with its price and currency, if it is listed for sale.
+        #[ink(message)]
+        pub fn get_listing(&self, content_id: u64) -> Option<Listing> {
+            self.listings.get(content_id)
+        }
+
+        /// Buys a content item listed in native tokens at its asking price. The call must carry at least the price;
+        /// the seller is credited with the price minus the platform fee and any royalty, the creator
+        /// with the royalty, and any excess is credited back to the buyer, all
+        /// paid out through `withdraw`. Clears the listing and any approval.
+        /// Emits `OwnershipTransferred`, `RoyaltyPaid` if a royalty is owed, and `ContentSold`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the listed content.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if sales or transfers are paused.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotListed` if the content is not listed.
+        /// - Returns `Error::WrongCurrency` if the listing is priced in a PSP22 token.
+        /// - Returns `Error::SelfTransfer` if the caller is the seller.
+        /// - Returns `Error::InsufficientPayment` if the attached value is below the price.
+        /// - Returns `Error::BalanceOverflow` if a credit would overflow.
+        /// - Returns the errors of `apply_transfer` otherwise. Nothing is written on any error.
+        #[ink(message, payable)]
+        pub fn buy(&mut self, content_id: u64) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Sales)?;
+            self.ensure_not_paused(PausableOp::Transfers)?;
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            let listing = self.listings.get(content_id).ok_or(Error::NotListed)?;
+            if listing.currency.is_some() {
+                return Err(Error::WrongCurrency);
+            }
+            let price = listing.price;
+            let seller = record.owner;
+            let buyer = self.env().caller();
+            if buyer == seller {
+                return Err(Error::SelfTransfer);
+            }
+            let excess = self.env()
+                .transferred_value()
+                .checked_sub(price)
+                .ok_or(Error::InsufficientPayment)?;
+            let mut payout = self.sale_payout(content_id, seller, price, RevenueSource::Sale, None)?;
+            self.add_refund(&mut payout, buyer, excess)?;
+            self.apply_transfer(content_id, record, buyer, None, None)?;
+            self.pay_out(&payout);
+            self.record_sale(content_id, seller, buyer, price, None);
+            let seq = self.next_event_seq();
+            self.env().emit_event(ContentSold {
+                content_id,
+                seller,
+                buyer,
+                price,
+                currency: None,
+                fee: payout.fee,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Buys a content item listed in a PSP22 token at its asking price. The contract pulls the
+        /// price from the caller with `transfer_from`, so the caller must first approve this contract
+        /// to spend it: the seller receives the price minus the platform fee and any royalty, the fee
+        /// goes straight to the treasury, and the royalty to the creator. Clears the listing and any
+        /// approval; a failed token transfer reverts the whole call. Emits `OwnershipTransferred`,
+        /// `RoyaltyPaid` if a royalty is owed, and `ContentSold`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the listed content.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if sales or transfers are paused.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotListed` if the content is not listed.
+        /// - Returns `Error::WrongCurrency` if the listing is priced in native tokens.
+        /// - Returns `Error::SelfTransfer` if the caller is the seller.
+        /// - Returns the errors of `apply_transfer` if the content cannot change hands.
+        /// - Returns `Error::TokenTransferFailed` if a token transfer fails or the token contract traps.
+        #[ink(message)]
+        pub fn buy_with_token(&mut self, content_id: u64) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Sales)?;
+            self.ensure_not_paused(PausableOp::Transfers)?;
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            let listing = self.listings.get(content_id).ok_or(Error::NotListed)?;
+            let Some(token) = listing.currency else {
+                return Err(Error::WrongCurrency);
+            };
+            let seller = record.owner;
+            let buyer = self.env().caller();
+            if buyer == seller {
+                return Err(Error::SelfTransfer);
+            }
+            let price = listing.price;
+            let payout = self.sale_payout(content_id, seller, price, RevenueSource::Sale, Some(token))?;
+            self.apply_transfer(content_id, record, buyer, None, None)?;
+            // Returning an error reverts the transfer written above.
+            for &(payee, amount) in &payout.payees {
+                self.pull_tokens(token, buyer, payee, amount)?;
+            }
+            self.pull_tokens(token, buyer, self.treasury, payout.fee)?;
+            self.pay_out(&payout);
+            self.record_sale(content_id, seller, buyer, price, Some(token));
+            let seq = self.next_event_seq();
+            self.env().emit_event(ContentSold {
+                content_id,
+                seller,
+                buyer,
+                price,
+                currency: Some(token),
+                fee: payout.fee,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Moves `value` PSP22 tokens from `from` to `to` out of this contract's allowance. Moving
+        /// nothing makes no call.
+        ///
+        /// # Errors
+        /// - Returns `Error::TokenTransferFailed` if the transfer fails or the token contract traps.
+        fn pull_tokens(&self, token: AccountId, from: AccountId, to: AccountId, value: Balance) -> Result<()> {
+            if value == 0 {
+                return Ok(());
+            }
+            use ink::codegen::TraitCallBuilder;
+            let mut token: ink::contract_ref!(PSP22) = token.into();
+            let transferred = token
+                .call_mut()
+                .transfer_from(from, to, value, Vec::new())
+                .try_invoke();
+            if !matches!(transferred, Ok(Ok(Ok(())))) {
+                return Err(Error::TokenTransferFailed);
+            }
+            Ok(())
+        }
+
+        /// Escrows the attached value as an offer to buy a content item, which its owner can accept
+        /// whether or not it is listed. Offers are kept per buyer; a second offer from the same buyer
+        /// tops up the first and replaces its expiry. Emits `OfferMade` with the buyer's total.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to buy.
+        /// - `expires_at`: The block timestamp, in milliseconds, from which the offer can no longer be
+        ///   accepted and anyone may return its escrow to the buyer through `reclaim_expired_offer`.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if sales are paused.
+        /// - Returns `Error::InvalidPrice` if no value is attached.
+        /// - Returns `Error::OfferExpired` if `expires_at` is not in the future.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::SelfTransfer` if the caller owns the content.
+        /// - Returns the errors of `ensure_transferable` if the content cannot change hands.
+        /// - Returns `Error::BalanceOverflow` if the offer would overflow.
+        #[ink(message, payable)]
+        pub fn make_offer(&mut self, content_id: u64, expires_at: Timestamp) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Sales)?;
+            let value = self.env().transferred_value();
+            if value == 0 {
+                return Err(Error::InvalidPrice);
+            }
+            if self.env().block_timestamp() >= expires_at {
+                return Err(Error::OfferExpired);
+            }
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            let buyer = self.env().caller();
+            if buyer == record.owner {
+                return Err(Error::SelfTransfer);
+            }
+            Self::ensure_transferable(&record)?;
+            let amount = match self.purchase_offers.get((content_id, buyer)) {
+                Some(escrowed) => escrowed.amount.checked_add(value).ok_or(Error::BalanceOverflow)?,
+                None => {
+                    let position = self.offer_count.get(content_id).unwrap_or(0);
+                    self.offer_buyers.insert((content_id, position), &buyer);
+                    self.offer_position.insert((content_id, buyer), &position);
+                    self.offer_count.insert(content_id, &position.saturating_add(1));
+                    value
+                }
+            };
+            self.purchase_offers.insert((content_id, buyer), &PurchaseOffer { amount, expires_at });
+            let seq = self.next_event_seq();
+            self.env().emit_event(OfferMade {
+                content_id,
+                buyer,
+                amount,
+                expires_at,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Withdraws the caller's offer on a content item, crediting its escrow back to them.
+        /// Works even if the content was burned since. Emits `OfferWithdrawn`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content the offer is for.
+        ///
+        /// # Errors
+        /// - Returns `Error::OfferNotFound` if the caller has no offer on the content.
+        /// - Returns `Error::BalanceOverflow` if the caller's credit would overflow.
+        #[ink(message)]
+        pub fn withdraw_offer(&mut self, content_id: u64) -> Result<()> {
+            let buyer = self.env().caller();
+            let amount = self.refund_offer(content_id, buyer)?;
+            let seq = self.next_event_seq();
+            self.env().emit_event(OfferWithdrawn {
+                content_id,
+                buyer,
+                amount,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Returns an expired offer's escrow to the buyer's credit and closes the offer. Anyone can
+        /// call this function, but the escrow only ever goes back to the buyer that made the offer.
+        /// Works even if the content was burned since. Emits `OfferReclaimed`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content the offer is for.
+        /// - `buyer`: The buyer whose expired offer to sweep.
+        ///
+        /// # Errors
+        /// - Returns `Error::OfferNotFound` if the buyer has no offer on the content.
+        /// - Returns `Error::OfferNotExpired` if the offer has not expired yet.
+        /// - Returns `Error::BalanceOverflow` if the buyer's credit would overflow.
+        #[ink(message)]
+        pub fn reclaim_expired_offer(&mut self, content_id: u64, buyer: AccountId) -> Result<()> {
+            let offer = self.purchase_offers.get((content_id, buyer)).ok_or(Error::OfferNotFound)?;
+            if self.env().block_timestamp() < offer.expires_at {
+                return Err(Error::OfferNotExpired);
+            }
+            let amount = self.refund_offer(content_id, buyer)?;
+            let seq = self.next_event_seq();
+            self.env().emit_event(OfferReclaimed {
+                content_id,
+                buyer,
+                amount,
+                reclaimed_by: self.env().caller(),
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Rejects a buyer's offer on a content item, crediting its escrow back to the buyer.
+        /// Only the current owner can call this function. Emits `OfferRejected`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `buyer`: The buyer whose offer to reject.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns `Error::OfferNotFound` if the buyer has no offer on the content.
+        /// - Returns `Error::BalanceOverflow` if the buyer's credit would overflow.
+        #[ink(message)]
+        pub fn reject_offer(&mut self, content_id: u64, buyer: AccountId) -> Result<()> {
+            self.ensure_caller_owns(content_id)?;
+            let amount = self.refund_offer(content_id, buyer)?;
+            let seq = self.next_event_seq();
+            self.env().emit_event(OfferRejected {
+                content_id,
+                buyer,
+                amount,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Accepts a buyer's offer, moving the content to the buyer and crediting the escrow minus the
+        /// platform fee to the caller. Only the current owner can call this function; other buyers' offers stay open.
+        /// Emits `OwnershipTransferred` followed by `OfferAccepted`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `buyer`: The buyer whose offer to accept.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if sales or transfers are paused.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns `Error::OfferNotFound` if the buyer has no offer on the content.
+        /// - Returns `Error::OfferExpired` if the offer has expired.
+        /// - Returns `Error::BalanceOverflow` if the seller's credit or the collected fees would overflow.
+        /// - Returns the errors of `apply_transfer` otherwise. Nothing is written on any error.
+        #[ink(message)]
+        pub fn accept_offer(&mut self, content_id: u64, buyer: AccountId) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Sales)?;
+            self.ensure_not_paused(PausableOp::Transfers)?;
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            let seller = record.owner;
+            if self.env().caller() != seller {
+                return Err(Error::NotOwner);
+            }
+            let offer = self.purchase_offers.get((content_id, buyer)).ok_or(Error::OfferNotFound)?;
+            if self.env().block_timestamp() >= offer.expires_at {
+                return Err(Error::OfferExpired);
+            }
+            let amount = offer.amount;
+            let payout = self.sale_payout(content_id, seller, amount, RevenueSource::Sale, None)?;
+            self.apply_transfer(content_id, record, buyer, None, None)?;
+            self.remove_purchase_offer(content_id, buyer);
+            self.pay_out(&payout);
+            self.record_sale(content_id, seller, buyer, amount, None);
+            let seq = self.next_event_seq();
+            self.env().emit_event(OfferAccepted {
+                content_id,
+                seller,
+                buyer,
+                amount,
+                fee: payout.fee,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Returns a buyer's open offer on a content item, if any, whether or not it has expired.
+        #[ink(message)]
+        pub fn offer_of(&self, content_id: u64, buyer: AccountId) -> Option<PurchaseOffer> {
+            self.purchase_offers.get((content_id, buyer))
+        }
+
+        /// Returns the unexpired offers on a content item, in the order of the offer index.
+        /// Removing an offer moves the last one into its position, so the order is not stable.
+        ///
+        /// # Arguments
+        /// - `start`: The first position in the offer index to scan.
+        /// - `limit`: The number of positions to scan, capped at `MAX_PAGE_SIZE`. Expired offers count
+        ///   towards the limit, so the result may hold fewer than `limit` entries.
+        ///
+        /// # Returns
+        /// - A `Vec` of `(buyer, offer)` pairs.
+        #[ink(message)]
+        pub fn offers_for(&self, content_id: u64, start: u32, limit: u32) -> Vec<(AccountId, PurchaseOffer)> {
+            let now = self.env().block_timestamp();
+            let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(self.offer_count(content_id));
+            (start..end)
+                .filter_map(|position| self.offer_buyers.get((content_id, position)))
+                .filter_map(|buyer| self.purchase_offers.get((content_id, buyer)).map(|offer| (buyer, offer)))
+                .filter(|(_, offer)| now < offer.expires_at)
+                .collect()
+        }
+
+        /// Returns the number of open offers on a content item.
+        #[ink(message)]
+        pub fn offer_count(&self, content_id: u64) -> u32 {
+            self.offer_count.get(content_id).unwrap_or(0)
+        }
+
+        /// Closes a buyer's offer and credits its escrow back to the buyer.
+        ///
+        /// # Errors
+        /// - Returns `Error::OfferNotFound` if the buyer has no offer on the content.
+        /// - Returns `Error::BalanceOverflow` if the buyer's credit would overflow. Nothing is written.
+        fn refund_offer(&mut self, content_id: u64, buyer: AccountId) -> Result<Balance> {
+            let amount = self.purchase_offers.get((content_id, buyer)).ok_or(Error::OfferNotFound)?.amount;
+            let credit = self.credited(buyer, amount)?;
+            self.remove_purchase_offer(content_id, buyer);
+            self.credits.insert(buyer, &credit);
+            Ok(amount)
+        }
+
+        /// Removes a buyer's offer from the offer storage and index, without moving its escrow.
+        fn remove_purchase_offer(&mut self, content_id: u64, buyer: AccountId) {
+            self.purchase_offers.remove((content_id, buyer));
+            let Some(last) = self.offer_count.get(content_id).unwrap_or(0).checked_sub(1) else {
+                return;
+            };
+            let position = self.offer_position.get((content_id, buyer)).unwrap_or(last);
+            if position != last {
+                if let Some(last_buyer) = self.offer_buyers.get((content_id, last)) {
+                    self.offer_buyers.insert((content_id, position), &last_buyer);
+                    self.offer_position.insert((content_id, last_buyer), &position);
+                }
+            }
+            self.offer_buyers.remove((content_id, last));
+            self.offer_position.remove((content_id, buyer));
+            self.offer_count.insert(content_id, &last);
+        }
+
+        /// Splits a sale price into the platform fee, the creator's royalty on resales, and the seller's
+        /// proceeds, and returns the credits to write once the sale goes through, counting the proceeds
+        /// as income from `source`. Only `RevenueSource::Sale` pays a royalty. Every sale path, including
+        /// access purchases and `buy_with_token`, pays out through this function; a token sale credits
+        /// nothing, as its caller pays `payees` and the fee in the token before writing the payout.
+        ///
+        /// # Errors
+        /// - Returns `Error::BalanceOverflow` if a credit, the collected fees, or an income counter
+        ///   would overflow.
+        fn sale_payout(
+            &self,
+            content_id: u64,
+            seller: AccountId,
+            price: Balance,
+            source: RevenueSource,
+            currency: Option<AccountId>,
+        ) -> Result<SalePayout> {
+            let fee = self.platform_fee(content_id, price, source);
+            let royalties = match source {
+                RevenueSource::Sale => self.royalty_on(content_id, seller, price),
+                _ => Vec::new(),
+            };
+            let royalty: Balance = royalties.iter().map(|(_, amount)| amount).sum();
+            // Cannot underflow: the royalty cap and the platform fee together stay within the price.
+            let proceeds = price - fee - royalty;
+            let revenue = self.revenue_of(content_id)
+                .add(source, proceeds)
+                .and_then(|revenue| revenue.add(RevenueSource::Royalty, royalty))
+                .ok_or(Error::BalanceOverflow)?;
+            let mut payees = ink::prelude::vec![(seller, proceeds)];
+            for &(recipient, amount) in &royalties {
+                Self::add_payee(&mut payees, recipient, amount)?;
+            }
+            let mut credits = Vec::with_capacity(payees.len());
+            let mut earnings = Vec::with_capacity(payees.len());
+            for &(account, amount) in &payees {
+                if currency.is_none() {
+                    credits.push((account, self.credited(account, amount)?));
+                }
+                earnings.push((account, self.earnings_of(account).checked_add(amount).ok_or(Error::BalanceOverflow)?));
+            }
+            let royalties_earned = royalties.iter()
+                .map(|&(recipient, amount)| {
+                    let total = self.royalties_earned(recipient).checked_add(amount).ok_or(Error::BalanceOverflow)?;
+                    Ok((recipient, total))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let collected_fees = match currency {
+                None => self.collected_fees.checked_add(fee).ok_or(Error::BalanceOverflow)?,
+                Some(_) => self.collected_fees,
+            };
+            Ok(SalePayout {
+                content_id,
+                currency,
+                payees,
+                credits,
+                earnings,
+                revenue,
+                royalties,
+                royalties_earned,
+                fee,
+                collected_fees,
+            })
+        }
+
+        /// Adds `amount` to what `account` is paid, merging it with an earlier entry for the account.
+        ///
+        /// # Errors
+        /// - Returns `Error::BalanceOverflow` if the merged amount would overflow.
+        fn add_payee(payees: &mut Vec<(AccountId, Balance)>, account: AccountId, amount: Balance) -> Result<()> {
+            match payees.iter_mut().find(|(payee, _)| *payee == account) {
+                Some((_, total)) => *total = total.checked_add(amount).ok_or(Error::BalanceOverflow)?,
+                None => payees.push((account, amount)),
+            }
+            Ok(())
+        }
+
+        /// Adds a refund of `amount` to what a sale credits `account`, on top of any proceeds or royalty
+        /// the sale pays the account, so writing the payout cannot overwrite either.
+        ///
+        /// # Errors
+        /// - Returns `Error::BalanceOverflow` if the account's credit would overflow.
+        fn add_refund(&self, payout: &mut SalePayout, account: AccountId, amount: Balance) -> Result<()> {
+            if amount == 0 {
+                return Ok(());
+            }
+            match payout.credits.iter_mut().find(|(payee, _)| *payee == account) {
+                Some((_, credit)) => *credit = credit.checked_add(amount).ok_or(Error::BalanceOverflow)?,
+                None => payout.credits.push((account, self.credited(account, amount)?)),
+            }
+            Ok(())
+        }
+
+        /// Writes the credits and income counters of a sale computed by `sale_payout`, and emits
+        /// `RoyaltyPaid` for each royalty recipient.
+        fn pay_out(&mut self, payout: &SalePayout) {
+            for (account, credit) in &payout.credits {
+                self.credits.insert(account, credit);
+            }
+            for (account, earnings) in &payout.earnings {
+                self.earnings.insert(account, earnings);
+            }
+            self.revenue.insert(payout.content_id, &payout.revenue);
+            for (recipient, total) in &payout.royalties_earned {
+                self.royalty_earnings.insert(recipient, total);
+            }
+            self.collected_fees = payout.collected_fees;
+            for &(recipient, amount) in &payout.royalties {
+                let seq = self.next_event_seq();
+                self.env().emit_event(RoyaltyPaid {
+                    content_id: payout.content_id,
+                    recipient,
+                    amount,
+                    currency: payout.currency,
+                    seq,
+                });
+            }
+        }
+
+        /// Sets the royalty paid to the creator of a content item out of the price of every later sale
+        /// by anyone else. Only the original creator can call this function, whoever owns the content.
+        /// Clears any royalty split, so the whole new royalty goes to the creator. Emits `RoyaltySet`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `bps`: The royalty in basis points of the sale price, at most `max_royalty_bps()`; zero for none.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotCreator` if the caller is not the creator.
+        /// - Returns `Error::RoyaltyLocked` if the royalty was locked.
+        /// - Returns `Error::RoyaltyTooHigh` if `bps` is above the royalty cap.
+        #[ink(message)]
+        pub fn set_royalty(&mut self, content_id: u64, bps: u16) -> Result<()> {
+            let creator = self.ensure_royalty_editable(content_id)?;
+            if bps > self.max_royalty_bps {
+                return Err(Error::RoyaltyTooHigh);
+            }
+            if bps == 0 {
+                self.royalty_bps.remove(content_id);
+            } else {
+                self.royalty_bps.insert(content_id, &bps);
+            }
+            self.royalty_splits.remove(content_id);
+            let seq = self.next_event_seq();
+            self.env().emit_event(RoyaltySet { content_id, creator, bps, seq });
+            Ok(())
+        }
+
+        /// Splits the royalty of a content item among several recipients, for sales from now on. Each
+        /// recipient is credited their share of the price through `withdraw`, rounded down, and any
+        /// rounding dust goes to the first recipient. Only the original creator can call this function;
+        /// `set_royalty` clears the split. Emits `RoyaltySplitSet`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `recipients`: The recipients and their shares in basis points of the sale price, which must
+        ///   sum to `royalty_bps_of(content_id)`.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotCreator` if the caller is not the creator.
+        /// - Returns `Error::RoyaltyLocked` if the royalty was locked.
+        /// - Returns `Error::InvalidRoyaltySplit` if `recipients` is empty, has more than
+        ///   `MAX_ROYALTY_RECIPIENTS` entries, repeats a recipient, has a zero share, or its shares do
+        ///   not sum to the royalty.
+        #[ink(message)]
+        pub fn set_royalty_split(&mut self, content_id: u64, recipients: RoyaltySplit) -> Result<()> {
+            let creator = self.ensure_royalty_editable(content_id)?;
+            if recipients.is_empty() || recipients.len() > MAX_ROYALTY_RECIPIENTS {
+                return Err(Error::InvalidRoyaltySplit);
+            }
+            let mut total: u32 = 0;
+            for (i, (recipient, share)) in recipients.iter().enumerate() {
+                if *share == 0 || recipients[..i].iter().any(|(other, _)| other == recipient) {
+                    return Err(Error::InvalidRoyaltySplit);
+                }
+                total += u32::from(*share);
+            }
+            if total != u32::from(self.royalty_bps_of(content_id)) {
+                return Err(Error::InvalidRoyaltySplit);
+            }
+            self.royalty_splits.insert(content_id, &recipients);
+            let seq = self.next_event_seq();
+            self.env().emit_event(RoyaltySplitSet { content_id, creator, recipients, seq });
+            Ok(())
+        }
+
+        /// Returns the total royalties an account has been paid across all content, as the creator
+        /// or a split recipient. Counts every sale, which all settle through one internal payout path;
+        /// royalties paid in a PSP22 token through `buy_with_token` are added in the token's units.
+        #[ink(message)]
+        pub fn royalties_earned(&self, creator: AccountId) -> Balance {
+            self.royalty_earnings.get(creator).unwrap_or(0)
+        }
+
+        /// Returns the total royalties sales of a content item have paid, across all recipients. Kept across ownership changes and burns, like the rest of `revenue_of`.
+        #[ink(message)]
+        pub fn royalties_paid_for(&self, content_id: u64) -> Balance {
+            self.revenue_of(content_id).royalties
+        }
+
+        /// Permanently locks the royalty of a content item and its split, so buyers can rely on them
+        /// not being raised. Only the original creator can call this function. The lock cannot be
+        /// lifted and survives transfers; sales keep paying the locked royalty. Emits `RoyaltyLocked`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotCreator` if the caller is not the creator.
+        /// - Returns `Error::RoyaltyLocked` if the royalty is already locked.
+        #[ink(message)]
+        pub fn lock_royalty(&mut self, content_id: u64) -> Result<()> {
+            let creator = self.ensure_royalty_editable(content_id)?;
+            self.royalty_locks.insert(content_id, &());
+            let seq = self.next_event_seq();
+            self.env().emit_event(RoyaltyLocked {
+                content_id,
+                locked_by: creator,
+                bps: self.royalty_bps_of(content_id),
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Checks whether the royalty of a content item is permanently locked.
+        #[ink(message)]
+        pub fn is_royalty_locked(&self, content_id: u64) -> bool {
+            self.royalty_locks.contains(content_id)
+        }
+
+        /// Checks that the caller is the creator of a content item whose royalty is not locked, and
+        /// returns the caller.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotCreator` if the caller is not the creator.
+        /// - Returns `Error::RoyaltyLocked` if the royalty was locked.
+        fn ensure_royalty_editable(&self, content_id: u64) -> Result<AccountId> {
+            let caller = self.ensure_caller_created(content_id)?;
+            if self.is_royalty_locked(content_id) {
+                return Err(Error::RoyaltyLocked);
+            }
+            Ok(caller)
+        }
+
+        /// Makes a content item marketplace-only, so its ownership can only change through a sale
+        /// (`buy`, `buy_with_token`, `buy_dutch`, `buy_bundle`, `accept_offer`, or `settle_auction`)
+        /// and resales cannot skip the royalty; plain and approved transfers fail unless the recipient
+        /// is exempt. Only the original creator can call this function, and only switch it on before
+        /// the content first changes hands and while it secures no loan; switching it off is always
+        /// possible. Loans can then only be funded by exempt lenders, so collateral claims cannot skip
+        /// the royalty either. The setting does not apply to forced transfers scheduled by an admin,
+        /// which otherwise follow the rules of `admin_execute_transfer`: they cancel an auction or an
+        /// unfunded loan offer, but still fail while the content secures a funded loan or is frozen.
+        /// Emits `MarketplaceOnlyChanged`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `enforce`: Whether ownership can only change through a sale.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotCreator` if the caller is not the creator.
+        /// - Returns `Error::AlreadyTransferred` if switching it on for content that changed hands.
+        /// - Returns `Error::ContentPledged` if switching it on for content that secures a loan or loan offer.
+        #[ink(message)]
+        pub fn set_marketplace_only(&mut self, content_id: u64, enforce: bool) -> Result<()> {
+            self.ensure_caller_created(content_id)?;
+            if enforce {
+                if self.transfer_count(content_id) > 0 {
+                    return Err(Error::AlreadyTransferred);
+                }
+                self.ensure_not_pledged(content_id)?;
+                self.marketplace_only.insert(content_id, &());
+            } else {
+                self.marketplace_only.remove(content_id);
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(MarketplaceOnlyChanged { content_id, enforced: enforce, seq });
+            Ok(())
+        }
+
+        /// Checks whether a content item can only change hands through a sale.
+        #[ink(message)]
+        pub fn is_marketplace_only(&self, content_id: u64) -> bool {
+            self.marketplace_only.contains(content_id)
+        }
+
+        /// Allows plain transfers of a marketplace-only content item to `account`, e.g. an owner's
+        /// own cold wallet. Only the original creator can call this function. Emits
+        /// `TransferExemptionChanged`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `account`: The exempted recipient.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotCreator` if the caller is not the creator.
+        #[ink(message)]
+        pub fn add_transfer_exemption(&mut self, content_id: u64, account: AccountId) -> Result<()> {
+            let caller = self.ensure_caller_created(content_id)?;
+            self.transfer_exemptions.insert((content_id, account), &caller);
+            let seq = self.next_event_seq();
+            self.env().emit_event(TransferExemptionChanged { content_id, account, exempt: true, seq });
+            Ok(())
+        }
+
+        /// Lifts an exemption granted through `add_transfer_exemption`. Only the original creator can
+        /// call this function. Emits `TransferExemptionChanged`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `account`: The recipient that is no longer exempt.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotCreator` if the caller is not the creator.
+        #[ink(message)]
+        pub fn remove_transfer_exemption(&mut self, content_id: u64, account: AccountId) -> Result<()> {
+            self.ensure_caller_created(content_id)?;
+            self.transfer_exemptions.remove((content_id, account));
+            let seq = self.next_event_seq();
+            self.env().emit_event(TransferExemptionChanged { content_id, account, exempt: false, seq });
+            Ok(())
+        }
+
+        /// Checks whether plain transfers of a content item to `account` are exempt from marketplace-only mode.
+        #[ink(message)]
+        pub fn is_transfer_exempt(&self, content_id: u64, account: AccountId) -> bool {
+            match (self.transfer_exemptions.get((content_id, account)), self.contents.get(content_id)) {
+                (Some(granted_by), Some(record)) => granted_by == record.creator,
+                _ => false,
+            }
+        }
+
+        /// Checks that a content item may be transferred to `new_owner` other than through a sale.
+        ///
+        /// # Errors
+        /// - Returns `Error::MarketplaceOnly` if the content is marketplace-only and `new_owner` is not exempt.
+        fn ensure_plain_transfer_allowed(&self, content_id: u64, new_owner: AccountId) -> Result<()> {
+            if self.is_marketplace_only(content_id) && !self.is_transfer_exempt(content_id, new_owner) {
+                return Err(Error::MarketplaceOnly);
+            }
+            Ok(())
+        }
+
+        /// Checks that the caller is the original creator of a content item and returns the caller.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotCreator` if the caller is not the creator.
+        fn ensure_caller_created(&self, content_id: u64) -> Result<AccountId> {
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            let caller = self.env().caller();
+            if caller != record.creator {
+                return Err(Error::NotCreator);
+            }
+            Ok(caller)
+        }
+
+        /// Returns the recipients of the royalty of a content item and their shares in basis points:
+        /// the split if one is set, otherwise the creator with the whole royalty. Empty if there is no
+        /// royalty or the content ID is not found.
+        #[ink(message)]
+        pub fn royalty_split_of(&self, content_id: u64) -> RoyaltySplit {
+            if let Some(recipients) = self.royalty_splits.get(content_id) {
+                return recipients;
+            }
+            match (self.contents.get(content_id), self.royalty_bps_of(content_id)) {
+                (Some(record), bps) if bps > 0 => ink::prelude::vec![(record.creator, bps)],
+                _ => Vec::new(),
+            }
+        }
+
+        /// Returns the royalty, in basis points, set on a content item (0 for none or unknown IDs).
+        #[ink(message)]
+        pub fn royalty_bps_of(&self, content_id: u64) -> u16 {
+            self.royalty_bps.get(content_id).unwrap_or(0)
+        }
+
+        /// Returns who receives the royalty on a sale of a content item at `sale_price`, and how much,
+        /// in the spirit of ERC-2981. Sales by the creator pay no royalty. For a split royalty, this is
+        /// the first recipient with the whole royalty; see `royalty_split_of` for the shares. Returns
+        /// the creator and no royalty if there is none, or the all-zero account if the content ID is
+        /// not found.
+        #[ink(message)]
+        pub fn royalty_info(&self, content_id: u64, sale_price: Balance) -> (AccountId, Balance) {
+            let Some(record) = self.contents.get(content_id) else {
+                return (AccountId::from([0u8; 32]), 0);
+            };
+            let recipient = self.royalty_split_of(content_id).first().map_or(record.creator, |(account, _)| *account);
+            (recipient, Self::bps_of(sale_price, self.royalty_bps_of(content_id)))
+        }
+
+        /// Returns the royalty owed on a sale of a content item by `seller` at `price`, by recipient,
+        /// or nothing if none is owed, e.g. because the creator is selling. Each share is rounded down
+        /// and the dust left from the whole royalty goes to the first recipient.
+        fn royalty_on(&self, content_id: u64, seller: AccountId, price: Balance) -> Vec<(AccountId, Balance)> {
+            let Some(record) = self.contents.get(content_id) else {
+                return Vec::new();
+            };
+            let royalty = Self::bps_of(price, self.royalty_bps_of(content_id));
+            if record.creator == seller || royalty == 0 {
+                return Vec::new();
+            }
+            let mut royalties: Vec<(AccountId, Balance)> = self.royalty_split_of(content_id)
+                .into_iter()
+                .map(|(recipient, share)| (recipient, Self::bps_of(price, share)))
+                .collect();
+            // The shares sum to the whole royalty, so rounding each down leaves at most some dust.
+            let paid: Balance = royalties.iter().map(|(_, amount)| amount).sum();
+            royalties[0].1 += royalty - paid;
+            royalties.retain(|(_, amount)| *amount > 0);
+            royalties
+        }
+
+        /// Tips the current owner of a content item with the attached value, credited to them through
+        /// the pull-payment ledger. Emits `Tipped`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to tip.
+        /// - `message`: An optional message of up to `MAX_TIP_MESSAGE_LENGTH` bytes.
+        ///
+        /// # Errors
+        /// - Returns `Error::InvalidPrice` if no value is attached.
+        /// - Returns `Error::TipMessageTooLong` if the message exceeds `MAX_TIP_MESSAGE_LENGTH` bytes.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found, e.g. it was burned.
+        /// - Returns `Error::ContentNotActive` if the content's status is not `Active`, e.g. it was revoked.
+        /// - Returns `Error::OwnershipRenounced` if the content has no owner.
+        /// - Returns `Error::BalanceOverflow` if the owner's credit or income counters would overflow.
+        #[ink(message, payable)]
+        pub fn tip(&mut self, content_id: u64, message: Option<String>) -> Result<()> {
+            let amount = self.env().transferred_value();
+            if amount == 0 {
+                return Err(Error::InvalidPrice);
+            }
+            if message.as_ref().is_some_and(|message| message.len() > MAX_TIP_MESSAGE_LENGTH) {
+                return Err(Error::TipMessageTooLong);
+            }
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            if record.status != ContentStatus::Active {
+                return Err(Error::ContentNotActive);
+            }
+            if record.renounced {
+                return Err(Error::OwnershipRenounced);
+            }
+            let owner = record.owner;
+            let credit = self.credited(owner, amount)?;
+            let (revenue, earnings) = self.earned(content_id, owner, RevenueSource::Tip, amount)?;
+            self.credits.insert(owner, &credit);
+            self.record_earnings(content_id, owner, revenue, earnings);
+            let seq = self.next_event_seq();
+            self.env().emit_event(Tipped {
+                content_id,
+                from: self.env().caller(),
+                to: owner,
+                amount,
+                message,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Returns the total tipped to the owners of a content item over its lifetime.
+        #[ink(message)]
+        pub fn total_tips(&self, content_id: u64) -> Balance {
+            self.revenue_of(content_id).tips
+        }
+
+        /// Returns the income a content item has earned its owners, by source. The history stays with
+        /// the content when it changes hands, while later income goes to the new owner.
+        #[ink(message)]
+        pub fn revenue_of(&self, content_id: u64) -> RevenueStats {
+            self.revenue.get(content_id).unwrap_or_default()
+        }
+
+        /// Returns the total income an account has been credited as an owner or royalty recipient: sale
+        /// and access proceeds after the platform fee, royalties, and tips. Refunds and overpayments
+        /// are not income.
+        #[ink(message)]
+        pub fn earnings_of(&self, account: AccountId) -> Balance {
+            self.earnings.get(account).unwrap_or(0)
+        }
+
+        /// Returns the revenue of a content item and the earnings of an account after crediting the
+        /// account `amount` of income from `source` on the content, without writing them.
+        ///
+        /// # Errors
+        /// - Returns `Error::BalanceOverflow` if either counter would overflow.
+        fn earned(&self, content_id: u64, account: AccountId, source: RevenueSource, amount: Balance) -> Result<(RevenueStats, Balance)> {
+            let revenue = self.revenue_of(content_id).add(source, amount).ok_or(Error::BalanceOverflow)?;
+            let earnings = self.earnings_of(account).checked_add(amount).ok_or(Error::BalanceOverflow)?;
+            Ok((revenue, earnings))
+        }
+
+        /// Writes the counters computed by `earned`.
+        fn record_earnings(&mut self, content_id: u64, account: AccountId, revenue: RevenueStats, earnings: Balance) {
+            self.revenue.insert(content_id, &revenue);
+            self.earnings.insert(account, &earnings);
+        }
+
+        /// Sets the terms on which access to a content item is sold through `purchase_access`, or stops
+        /// selling it. New terms only apply to later purchases. Only the current owner can call this
+        /// function, and any ownership change clears the terms. Emits `AccessPriceChanged`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `price`: The price of one purchase; zero stops selling access.
+        /// - `duration`: How long, in milliseconds, one purchase grants access for, or `None` for good.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns `Error::InvalidDuration` if the duration is zero.
+        #[ink(message)]
+        pub fn set_access_price(&mut self, content_id: u64, price: Balance, duration: Option<u64>) -> Result<()> {
+            self.ensure_caller_owns(content_id)?;
+            if duration == Some(0) {
+                return Err(Error::InvalidDuration);
+            }
+            let terms = (price > 0).then_some(AccessTerms { price, duration });
+            if let Some(terms) = &terms {
+                self.access_terms.insert(content_id, terms);
+            } else {
+                self.access_terms.remove(content_id);
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(AccessPriceChanged { content_id, terms, seq });
+            Ok(())
+        }
+
+        /// Returns the terms on which access to a content item is sold, if it is.
+        #[ink(message)]
+        pub fn access_terms_of(&self, content_id: u64) -> Option<AccessTerms> {
+            self.access_terms.get(content_id)
+        }
+
+        /// Purchases access to a content item on its current terms. The call must carry at least the
+        /// price; the owner is credited with the price minus the platform fee and any excess is
+        /// credited back to the caller. Purchasing while access is still running extends it from its
+        /// current end. Emits `AccessPurchased`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        ///
+        /// # Returns
+        /// - The block timestamp from which the caller's access ends, `Timestamp::MAX` for good.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if sales are paused.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::ContentNotActive` if the content's status is not `Active`.
+        /// - Returns `Error::AccessNotForSale` if the owner does not sell access.
+        /// - Returns `Error::OwnerHasAccess` if the caller owns the content.
+        /// - Returns `Error::InsufficientPayment` if the attached value is below the price.
+        /// - Returns `Error::BalanceOverflow` if a credit would overflow. Nothing is written on any error.
+        #[ink(message, payable)]
+        pub fn purchase_access(&mut self, content_id: u64) -> Result<Timestamp> {
+            self.ensure_not_paused(PausableOp::Sales)?;
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            if record.status != ContentStatus::Active {
+                return Err(Error::ContentNotActive);
+            }
+            let terms = self.access_terms.get(content_id).ok_or(Error::AccessNotForSale)?;
+            let owner = record.owner;
+            let buyer = self.env().caller();
+            if buyer == owner {
+                return Err(Error::OwnerHasAccess);
+            }
+            let excess = self.env()
+                .transferred_value()
+                .checked_sub(terms.price)
+                .ok_or(Error::InsufficientPayment)?;
+            let payout = self.sale_payout(content_id, owner, terms.price, RevenueSource::Access, None)?;
+            let buyer_credit = self.credited(buyer, excess)?;
+            let now = self.env().block_timestamp();
+            let expires_at = match terms.duration {
+                Some(duration) => self.access_grants
+                    .get((content_id, buyer))
+                    .unwrap_or(now)
+                    .max(now)
+                    .saturating_add(duration),
+                None => Timestamp::MAX,
+            };
+            self.access_grants.insert((content_id, buyer), &expires_at);
+            self.pay_out(&payout);
+            if excess > 0 {
+                self.credits.insert(buyer, &buyer_credit);
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(AccessPurchased {
+                content_id,
+                buyer,
+                owner,
+                price: terms.price,
+                fee: payout.fee,
+                expires_at,
+                seq,
+            });
+            Ok(expires_at)
+        }
+
+        /// Returns whether an account may access a content item: its owner always may, other accounts
+        /// while their purchased access runs. Always `false` for burned content.
+        #[ink(message)]
+        pub fn has_access(&self, content_id: u64, account: AccountId) -> bool {
+            let Some(record) = self.contents.get(content_id) else {
+                return false;
+            };
+            if !record.renounced && record.owner == account {
+                return true;
+            }
+            self.access_grants
+                .get((content_id, account))
+                .is_some_and(|expires_at| self.env().block_timestamp() < expires_at)
+        }
+
+        /// Returns the block timestamp from which an account's purchased access to a content item
+        /// ends, `Timestamp::MAX` for good, or `None` if it never purchased access or the content was
+        /// burned. The timestamp may be in the past.
+        #[ink(message)]
+        pub fn access_expires_at(&self, content_id: u64, account: AccountId) -> Option<Timestamp> {
+            if !self.contents.contains(content_id) {
+                return None;
+            }
+            self.access_grants.get((content_id, account))
+        }
+
+        /// Sets the terms on which the usage rights of a content item are rented out through `rent`, or
+        /// stops renting it out. New terms only apply to later rentals, and a running rental is not
+        /// affected. Only the current owner can call this function, and any ownership change clears
+        /// the terms. Emits `RentalTermsChanged`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `price_per_day`: The price of one `RENTAL_DAY`; zero stops renting it out.
+        /// - `max_days`: The longest rental that can be taken out at once, in days.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns `Error::InvalidDuration` if `max_days` is zero while a price is set.
+        #[ink(message)]
+        pub fn set_rental_terms(&mut self, content_id: u64, price_per_day: Balance, max_days: u32) -> Result<()> {
+            self.ensure_caller_owns(content_id)?;
+            if price_per_day > 0 && max_days == 0 {
+                return Err(Error::InvalidDuration);
+            }
+            let terms = (price_per_day > 0).then_some(RentalTerms { price_per_day, max_days });
+            if let Some(terms) = &terms {
+                self.rental_terms.insert(content_id, terms);
+            } else {
+                self.rental_terms.remove(content_id);
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(RentalTermsChanged { content_id, terms, seq });
+            Ok(())
+        }
+
+        /// Returns the terms on which a content item is rented out, if it is.
+        #[ink(message)]
+        pub fn rental_terms_of(&self, content_id: u64) -> Option<RentalTerms> {
+            self.rental_terms.get(content_id)
+        }
+
+        /// Rents the usage rights of a content item for a number of days on its current terms. Each
+        /// item has one rental slot: it must be free or expired, and a rental keeps it until expiry
+        /// even if the content changes hands. The call must carry at least the price of all days; the
+        /// owner is credited with the price minus the platform fee and any excess is credited back to
+        /// the caller. Emits `ContentRented`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `days`: The number of `RENTAL_DAY`s to rent for, from 1 to the terms' `max_days`.
+        ///
+        /// # Returns
+        /// - The block timestamp from which the rental is over.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if sales are paused.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::ContentNotActive` if the content's status is not `Active`.
+        /// - Returns `Error::RentalNotAvailable` if the owner does not rent it out.
+        /// - Returns `Error::InvalidDuration` if `days` is zero or above `max_days`.
+        /// - Returns `Error::OwnerHasAccess` if the caller owns the content.
+        /// - Returns `Error::RentalActive` if another rental has not expired yet.
+        /// - Returns `Error::InsufficientPayment` if the attached value is below the price.
+        /// - Returns `Error::BalanceOverflow` if the price or a credit would overflow.
+        ///   Nothing is written on any error.
+        #[ink(message, payable)]
+        pub fn rent(&mut self, content_id: u64, days: u32) -> Result<Timestamp> {
+            self.ensure_not_paused(PausableOp::Sales)?;
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            if record.status != ContentStatus::Active {
+                return Err(Error::ContentNotActive);
+            }
+            let terms = self.rental_terms.get(content_id).ok_or(Error::RentalNotAvailable)?;
+            if days == 0 || days > terms.max_days {
+                return Err(Error::InvalidDuration);
+            }
+            let owner = record.owner;
+            let renter = self.env().caller();
+            if renter == owner {
+                return Err(Error::OwnerHasAccess);
+            }
+            if self.renter_of(content_id).is_some() {
+                return Err(Error::RentalActive);
+            }
+            let price = terms.price_per_day
+                .checked_mul(Balance::from(days))
+                .ok_or(Error::BalanceOverflow)?;
+            let excess = self.env()
+                .transferred_value()
+                .checked_sub(price)
+                .ok_or(Error::InsufficientPayment)?;
+            let payout = self.sale_payout(content_id, owner, price, RevenueSource::Rental, None)?;
+            let renter_credit = self.credited(renter, excess)?;
+            let expires_at = self.env()
+                .block_timestamp()
+                .saturating_add(RENTAL_DAY.saturating_mul(Timestamp::from(days)));
+            self.rentals.insert(content_id, &Rental { renter, expires_at });
+            self.pay_out(&payout);
+            if excess > 0 {
+                self.credits.insert(renter, &renter_credit);
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(ContentRented {
+                content_id,
+                renter,
+                owner,
+                price,
+                fee: payout.fee,
+                expires_at,
+                seq,
+            });
+            Ok(expires_at)
+        }
+
+        /// Clears the expired rental slot of a content item. Anyone can call this function; renting
+        /// again also replaces an expired rental, so this only tidies up. Emits `RentalReclaimed`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        ///
+        /// # Errors
+        /// - Returns `Error::NoRental` if the content has no rental.
+        /// - Returns `Error::RentalActive` if the rental has not expired yet.
+        #[ink(message)]
+        pub fn reclaim_rental(&mut self, content_id: u64) -> Result<()> {
+            let rental = self.rentals.get(content_id).ok_or(Error::NoRental)?;
+            if self.env().block_timestamp() < rental.expires_at {
+                return Err(Error::RentalActive);
+            }
+            self.rentals.remove(content_id);
+            let seq = self.next_event_seq();
+            self.env().emit_event(RentalReclaimed {
+                content_id,
+                renter: rental.renter,
+                reclaimed_by: self.env().caller(),
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Returns whether a content item is currently rented out.
+        #[ink(message)]
+        pub fn is_rented(&self, content_id: u64) -> bool {
+            self.renter_of(content_id).is_some()
+        }
+
+        /// Returns the account currently renting a content item, or `None` if it is not rented or the
+        /// rental has expired.
+        #[ink(message)]
+        pub fn renter_of(&self, content_id: u64) -> Option<AccountId> {
+            self.rentals
+                .get(content_id)
+                .filter(|rental| self.env().block_timestamp() < rental.expires_at)
+                .map(|rental| rental.renter)
+        }
+
+        /// Returns the rental of a content item, running or expired, if its slot was not cleared.
+        #[ink(message)]
+        pub fn get_rental(&self, content_id: u64) -> Option<Rental> {
+            self.rentals.get(content_id)
+        }
+
+        /// Offers to borrow against a content item owned by the caller. Nothing is escrowed, but from now
+        /// on the content is pledged: it cannot change hands, be locked, or be put up for sale until the
+        /// loan is repaid, claimed, or the offer cancelled. Emits `LoanOffered`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to pledge.
+        /// - `principal`: The amount to borrow, above zero.
+        /// - `interest`: The interest owed on top of the principal.
+        /// - `duration`: How long, in milliseconds, until the loan is due; the offer can only be funded
+        ///   before then.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if sales are paused.
+        /// - Returns `Error::InvalidPrice` if the principal is zero.
+        /// - Returns `Error::InvalidDuration` if the duration is zero.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns the errors of `ensure_sellable` if the content cannot change hands, for example
+        ///   `Error::ContentPledged` if it already secures a loan.
+        /// - Returns `Error::ForcedTransferPending` if an admin scheduled a forced transfer of the content.
+        /// - Returns `Error::BalanceOverflow` if the principal plus interest would overflow.
+        #[ink(message)]
+        pub fn create_loan_offer(&mut self, content_id: u64, principal: Balance, interest: Balance, duration: u64) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Sales)?;
+            if principal == 0 {
+                return Err(Error::InvalidPrice);
+            }
+            if duration == 0 {
+                return Err(Error::InvalidDuration);
+            }
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            let borrower = record.owner;
+            if self.env().caller() != borrower {
+                return Err(Error::NotOwner);
+            }
+            self.ensure_sellable(content_id, &record)?;
+            self.ensure_no_forced_transfer(content_id)?;
+            principal.checked_add(interest).ok_or(Error::BalanceOverflow)?;
+            let due_at = self.env().block_timestamp().saturating_add(duration);
+            self.loans.insert(content_id, &Loan {
+                borrower,
+                principal,
+                interest,
+                due_at,
+                lender: None,
+            });
+            let seq = self.next_event_seq();
+            self.env().emit_event(LoanOffered {
+                content_id,
+                borrower,
+                principal,
+                interest,
+                due_at,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Funds a loan offer. The call must carry at least the principal, which is credited to the
+        /// borrower; any excess is credited back to the caller. Marketplace-only content can only be
+        /// pledged to a lender the creator exempted, since claiming it skips the royalty. Emits `LoanFunded`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the pledged content.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if sales are paused.
+        /// - Returns `Error::NoLoan` if the content has no loan offer.
+        /// - Returns `Error::LoanAlreadyFunded` if another lender funded it.
+        /// - Returns `Error::LoanOfferExpired` if the loan is already due.
+        /// - Returns `Error::SelfTransfer` if the caller is the borrower.
+        /// - Returns `Error::MarketplaceOnly` if the content is marketplace-only and the caller is not exempt.
+        /// - Returns `Error::InsufficientPayment` if the attached value is below the principal.
+        /// - Returns `Error::BalanceOverflow` if a credit would overflow. Nothing is written on any error.
+        #[ink(message, payable)]
+        pub fn fund_loan(&mut self, content_id: u64) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Sales)?;
+            let mut loan = self.loans.get(content_id).ok_or(Error::NoLoan)?;
+            if loan.lender.is_some() {
+                return Err(Error::LoanAlreadyFunded);
+            }
+            if self.env().block_timestamp() >= loan.due_at {
+                return Err(Error::LoanOfferExpired);
+            }
+            let lender = self.env().caller();
+            if lender == loan.borrower {
+                return Err(Error::SelfTransfer);
+            }
+            self.ensure_plain_transfer_allowed(content_id, lender)?;
+            let excess = self.env()
+                .transferred_value()
+                .checked_sub(loan.principal)
+                .ok_or(Error::InsufficientPayment)?;
+            let borrower_credit = self.credited(loan.borrower, loan.principal)?;
+            let lender_credit = self.credited(lender, excess)?;
+            loan.lender = Some(lender);
+            self.loans.insert(content_id, &loan);
+            self.credits.insert(loan.borrower, &borrower_credit);
+            if excess > 0 {
+                self.credits.insert(lender, &lender_credit);
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(LoanFunded {
+                content_id,
+                lender,
+                borrower: loan.borrower,
+                principal: loan.principal,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Repays a funded loan before it is due, early or not, releasing the content. Only the borrower
+        /// can repay. The call must carry at least the principal plus interest, which is credited to the
+        /// lender; any excess is credited back to the caller. Emits `LoanRepaid`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the pledged content.
+        ///
+        /// # Errors
+        /// - Returns `Error::NoLoan` if the content has no loan.
+        /// - Returns `Error::NotOwner` if the caller is not the borrower.
+        /// - Returns `Error::LoanNotFunded` if the loan was never funded; use `cancel_loan_offer` instead.
+        /// - Returns `Error::LoanOverdue` if the loan is already due.
+        /// - Returns `Error::InsufficientPayment` if the attached value is below the amount owed.
+        /// - Returns `Error::BalanceOverflow` if a credit would overflow. Nothing is written on any error.
+        #[ink(message, payable)]
+        pub fn repay_loan(&mut self, content_id: u64) -> Result<()> {
+            let loan = self.loans.get(content_id).ok_or(Error::NoLoan)?;
+            let borrower = self.env().caller();
+            if borrower != loan.borrower {
+                return Err(Error::NotOwner);
+            }
+            let lender = loan.lender.ok_or(Error::LoanNotFunded)?;
+            if self.env().block_timestamp() >= loan.due_at {
+                return Err(Error::LoanOverdue);
+            }
+            // Cannot overflow: checked when the offer was created.
+            let amount = loan.principal + loan.interest;
+            let excess = self.env()
+                .transferred_value()
+                .checked_sub(amount)
+                .ok_or(Error::InsufficientPayment)?;
+            let lender_credit = self.credited(lender, amount)?;
+            let borrower_credit = self.credited(borrower, excess)?;
+            self.loans.remove(content_id);
+            self.credits.insert(lender, &lender_credit);
+            if excess > 0 {
+                self.credits.insert(borrower, &borrower_credit);
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(LoanRepaid {
+                content_id,
+                borrower,
+                lender,
+                amount,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Transfers the content of a defaulted loan to its lender. Only the lender can claim, from the
+        /// loan's due time on. Emits `OwnershipTransferred` followed by `CollateralClaimed`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the pledged content.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if transfers are paused.
+        /// - Returns `Error::NoLoan` if the content has no loan.
+        /// - Returns `Error::LoanNotFunded` if the loan was never funded.
+        /// - Returns `Error::NotOwner` if the caller is not the lender.
+        /// - Returns `Error::LoanNotDue` if the loan is not due yet.
+        /// - Returns the errors of `apply_transfer` otherwise, e.g. if an admin froze the content.
+        #[ink(message)]
+        pub fn claim_collateral(&mut self, content_id: u64) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Transfers)?;
+            let loan = self.loans.get(content_id).ok_or(Error::NoLoan)?;
+            let lender = loan.lender.ok_or(Error::LoanNotFunded)?;
+            if self.env().caller() != lender {
+                return Err(Error::NotOwner);
+            }
+            if self.env().block_timestamp() < loan.due_at {
+                return Err(Error::LoanNotDue);
+            }
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            self.loans.remove(content_id);
+            // Returning an error restores the loan removed above.
+            self.apply_transfer(content_id, record, lender, None, None)?;
+            let seq = self.next_event_seq();
+            self.env().emit_event(CollateralClaimed {
+                content_id,
+                lender,
+                borrower: loan.borrower,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Withdraws an unfunded loan offer, releasing the content. Only the borrower can cancel, whether
+        /// or not the offer expired. Emits `LoanOfferCancelled`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the pledged content.
+        ///
+        /// # Errors
+        /// - Returns `Error::NoLoan` if the content has no loan offer.
+        /// - Returns `Error::NotOwner` if the caller is not the borrower.
+        /// - Returns `Error::LoanAlreadyFunded` if the offer was funded; the loan must be repaid instead.
+        #[ink(message)]
+        pub fn cancel_loan_offer(&mut self, content_id: u64) -> Result<()> {
+            let loan = self.loans.get(content_id).ok_or(Error::NoLoan)?;
+            if self.env().caller() != loan.borrower {
+                return Err(Error::NotOwner);
+            }
+            if loan.lender.is_some() {
+                return Err(Error::LoanAlreadyFunded);
+            }
+            self.loans.remove(content_id);
+            let seq = self.next_event_seq();
+            self.env().emit_event(LoanOfferCancelled { content_id, seq });
+            Ok(())
+        }
+
+        /// Returns the loan or loan offer a content item secures, if any.
+        #[ink(message)]
+        pub fn get_loan(&self, content_id: u64) -> Option<Loan> {
+            self.loans.get(content_id)
+        }
+
+        /// Returns what an account's credit would become after adding `amount`, without writing it.
+        ///
+        /// # Errors
+        /// - Returns `Error::BalanceOverflow` if the credit would overflow.
+        fn credited(&self, account: AccountId, amount: Balance) -> Result<Balance> {
+            self.credit_of(account).checked_add(amount).ok_or(Error::BalanceOverflow)
+        }
+
+        /// Returns the native tokens the contract owes an account, claimable through `withdraw`.
+        #[ink(message)]
+        pub fn credit_of(&self, account: AccountId) -> Balance {
+            self.credits.get(account).unwrap_or(0)
+        }
+
+        /// Transfers the caller's full credit to them and zeroes it. Sale proceeds, refunds, and
+        /// overpayments are only ever credited, never pushed, so a failing or reentrant recipient cannot
+        /// block or interfere with the flow that owes it. Emits `CreditWithdrawn`.
+        ///
+        /// # Returns
+        /// - The amount transferred.
+        ///
+        /// # Errors
+        /// - Returns `Error::NothingToWithdraw` if the caller has no credit.
+        /// - Returns `Error::TransferFailed` if the transfer fails; the credit is kept.
+        #[ink(message)]
+        pub fn withdraw(&mut self) -> Result<Balance> {
+            let account = self.env().caller();
+            let amount = self.credit_of(account);
+            if amount == 0 {
+                return Err(Error::NothingToWithdraw);
+            }
+            self.credits.remove(account);
+            if self.env().transfer(account, amount).is_err() {
+                self.credits.insert(account, &amount);
+                return Err(Error::TransferFailed);
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(CreditWithdrawn { account, amount, seq });
+            Ok(amount)
+        }
+
+        /// Starts an English auction of a content item. Bids are escrowed through `bid`, and once the
+        /// deadline passes anyone can `settle_auction`. Until then the content cannot change hands,
+        /// be locked, or be removed, and any listing or Dutch auction is withdrawn. Only the current owner can call
+        /// this function. Emits `AuctionStarted`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to auction.
+        /// - `reserve_price`: The lowest winning bid; lower bids are accepted but the item is not sold.
+        /// - `duration`: How long, in milliseconds, bids are accepted, above zero.
+        /// - `min_increment`: How much each bid must exceed the previous highest bid by.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if sales are paused.
+        /// - Returns `Error::InvalidDuration` if the duration is zero.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns `Error::AuctionActive` if the content is already being auctioned.
+        /// - Returns `Error::ContentPledged` if the content secures a loan or loan offer.
+        /// - Returns the errors of `ensure_transferable`, `Error::ContentLocked`, or `Error::ContentFrozen`
+        ///   if the content cannot change hands.
+        /// - Returns `Error::ForcedTransferPending` if an admin scheduled a forced transfer of the content.
+        #[ink(message)]
+        pub fn start_auction(&mut self, content_id: u64, reserve_price: Balance, duration: u64, min_increment: Balance) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Sales)?;
+            if duration == 0 {
+                return Err(Error::InvalidDuration);
+            }
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            let seller = record.owner;
+            if self.env().caller() != seller {
+                return Err(Error::NotOwner);
+            }
+            self.ensure_not_auctioned(content_id)?;
+            self.ensure_not_pledged(content_id)?;
+            Self::ensure_transferable(&record)?;
+            self.ensure_unlocked(content_id)?;
+            self.ensure_not_frozen(content_id)?;
+            self.ensure_no_forced_transfer(content_id)?;
+            let ends_at = self.env().block_timestamp().saturating_add(duration);
+            self.listings.remove(content_id);
+            self.dutch_auctions.remove(content_id);
+            self.auctions.insert(content_id, &Auction {
+                seller,
+                reserve_price,
+                min_increment,
+                ends_at,
+                highest_bidder: None,
+                highest_bid: 0,
+            });
+            let seq = self.next_event_seq();
+            self.env().emit_event(AuctionStarted {
+                content_id,
+                seller,
+                reserve_price,
+                min_increment,
+                ends_at,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Bids the attached value on an auction. The bid must be above zero and, after the first bid,
+        /// at least the highest bid plus the minimum increment. The bid is escrowed and the previous
+        /// highest bid is credited back to its bidder. A bid within `auction_extension` of the deadline
+        /// moves the deadline to that long after the bid. Emits `BidPlaced`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the auctioned content.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if sales are paused.
+        /// - Returns `Error::NoAuction` if the content is not being auctioned.
+        /// - Returns `Error::AuctionEnded` if the deadline has passed.
+        /// - Returns `Error::SelfTransfer` if the caller is the seller.
+        /// - Returns `Error::BidTooLow` if the bid is too low.
+        /// - Returns `Error::BalanceOverflow` if the previous bidder's credit would overflow.
+        #[ink(message, payable)]
+        pub fn bid(&mut self, content_id: u64) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Sales)?;
+            let mut auction = self.auctions.get(content_id).ok_or(Error::NoAuction)?;
+            let now = self.env().block_timestamp();
+            if now >= auction.ends_at {
+                return Err(Error::AuctionEnded);
+            }
+            let bidder = self.env().caller();
+            if bidder == auction.seller {
+                return Err(Error::SelfTransfer);
+            }
+            let amount = self.env().transferred_value();
+            let minimum = match auction.highest_bidder {
+                Some(_) => auction.highest_bid.saturating_add(auction.min_increment.max(1)),
+                None => 1,
+            };
+            if amount < minimum {
+                return Err(Error::BidTooLow);
+            }
+            if let Some(previous) = auction.highest_bidder {
+                let credit = self.credited(previous, auction.highest_bid)?;
+                self.credits.insert(previous, &credit);
+            }
+            let extended = now.saturating_add(self.auction_extension);
+            if extended > auction.ends_at {
+                auction.ends_at = extended;
+            }
+            auction.highest_bidder = Some(bidder);
+            auction.highest_bid = amount;
+            self.auctions.insert(content_id, &auction);
+            let seq = self.next_event_seq();
+            self.env().emit_event(BidPlaced {
+                content_id,
+                bidder,
+                amount,
+                ends_at: auction.ends_at,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Settles an auction once its deadline has passed. Anyone can call this function.
+        /// If the highest bid meets the reserve, the content moves to the highest bidder and the
+        /// bid minus the platform fee is credited to the seller. Otherwise, or if the content can no longer change hands
+        /// (e.g. it was frozen or revoked meanwhile), the content stays with the seller and the
+        /// highest bid, if any, is credited back to its bidder. Emits `AuctionSettled`, preceded by
+        /// `OwnershipTransferred` if the content was sold.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the auctioned content.
+        ///
+        /// # Returns
+        /// - The new owner, or `None` if the content was not sold.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if sales or transfers are paused.
+        /// - Returns `Error::NoAuction` if the content is not being auctioned, e.g. it was already settled.
+        /// - Returns `Error::AuctionNotEnded` if the deadline has not passed.
+        /// - Returns `Error::BalanceOverflow` if a credit would overflow. Nothing is written on any error.
+        #[ink(message)]
+        pub fn settle_auction(&mut self, content_id: u64) -> Result<Option<AccountId>> {
+            self.ensure_not_paused(PausableOp::Sales)?;
+            self.ensure_not_paused(PausableOp::Transfers)?;
+            let auction = self.auctions.get(content_id).ok_or(Error::NoAuction)?;
+            if self.env().block_timestamp() < auction.ends_at {
+                return Err(Error::AuctionNotEnded);
+            }
+            let amount = auction.highest_bid;
+            let payout = self.sale_payout(content_id, auction.seller, amount, RevenueSource::Sale, None)?;
+            let mut winner = None;
+            let mut fee = 0;
+            if let Some(bidder) = auction.highest_bidder {
+                let bidder_credit = self.credited(bidder, amount)?;
+                self.auctions.remove(content_id);
+                let sold = amount >= auction.reserve_price
+                    && self.contents
+                        .get(content_id)
+                        .is_some_and(|record| self.apply_transfer(content_id, record, bidder, None, None).is_ok());
+                if sold {
+                    self.pay_out(&payout);
+                    self.record_sale(content_id, auction.seller, bidder, amount, None);
+                    winner = Some(bidder);
+                    fee = payout.fee;
+                } else {
+                    self.credits.insert(bidder, &bidder_credit);
+                }
+            } else {
+                self.auctions.remove(content_id);
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(AuctionSettled {
+                content_id,
+                seller: auction.seller,
+                winner,
+                amount,
+                fee,
+                seq,
+            });
+            Ok(winner)
+        }
+
+        /// Cancels an auction that has not received any bid. Only the seller can call this function.
+        /// Emits `AuctionCancelled`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the auctioned content.
+        ///
+        /// # Errors
+        /// - Returns `Error::NoAuction` if the content is not being auctioned.
+        /// - Returns `Error::NotOwner` if the caller is not the seller.
+        /// - Returns `Error::AuctionHasBids` if a bid was placed.
+        #[ink(message)]
+        pub fn cancel_auction(&mut self, content_id: u64) -> Result<()> {
+            let auction = self.auctions.get(content_id).ok_or(Error::NoAuction)?;
+            if self.env().caller() != auction.seller {
+                return Err(Error::NotOwner);
+            }
+            if auction.highest_bidder.is_some() {
+                return Err(Error::AuctionHasBids);
+            }
+            self.auctions.remove(content_id);
+            let seq = self.next_event_seq();
+            self.env().emit_event(AuctionCancelled { content_id, seq });
+            Ok(())
+        }
+
+        /// Returns the running auction of a content item, if any.
+        #[ink(message)]
+        pub fn get_auction(&self, content_id: u64) -> Option<Auction> {
+            self.auctions.get(content_id)
+        }
+
+        /// Starts a declining-price sale of a content item: the price falls linearly from `start_price`
+        /// to `end_price` over `duration`, and the first `buy_dutch` at or above the current price
+        /// buys the item. The sale ends on purchase, expiry, cancellation, or any ownership change.
+        /// Starting again replaces a running sale. Only the current owner can call this function.
+        /// Emits `DutchAuctionStarted`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to sell.
+        /// - `start_price`: The price at the start.
+        /// - `end_price`: The price at the end, above zero and at most `start_price`.
+        /// - `duration`: How long, in milliseconds, the sale runs, above zero.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if sales are paused.
+        /// - Returns `Error::InvalidPrice` if `end_price` is zero or above `start_price`.
+        /// - Returns `Error::InvalidDuration` if the duration is zero.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns the errors of `ensure_transferable`, `Error::ContentLocked`, `Error::ContentFrozen`,
+        ///   `Error::AuctionActive`, or `Error::ContentPledged` if the content cannot change hands.
+        /// - Returns `Error::ForcedTransferPending` if an admin scheduled a forced transfer of the content.
+        #[ink(message)]
+        pub fn start_dutch_auction(&mut self, content_id: u64, start_price: Balance, end_price: Balance, duration: u64) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Sales)?;
+            if end_price == 0 || end_price > start_price {
+                return Err(Error::InvalidPrice);
+            }
+            if duration == 0 {
+                return Err(Error::InvalidDuration);
+            }
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            let seller = record.owner;
+            if self.env().caller() != seller {
+                return Err(Error::NotOwner);
+            }
+            Self::ensure_transferable(&record)?;
+            self.ensure_unlocked(content_id)?;
+            self.ensure_not_frozen(content_id)?;
+            self.ensure_not_auctioned(content_id)?;
+            self.ensure_not_pledged(content_id)?;
+            self.ensure_no_forced_transfer(content_id)?;
+            let started_at = self.env().block_timestamp();
+            let ends_at = started_at.saturating_add(duration);
+            self.dutch_auctions.insert(content_id, &DutchAuction {
+                seller,
+                start_price,
+                end_price,
+                started_at,
+                ends_at,
+            });
+            let seq = self.next_event_seq();
+            self.env().emit_event(DutchAuctionStarted {
+                content_id,
+                seller,
+                start_price,
+                end_price,
+                ends_at,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Buys a content item on a declining-price sale at its current price. The call must carry at
+        /// least `current_dutch_price`; the seller is credited with the price minus the platform fee and
+        /// any royalty, and any excess is credited back to the buyer. Emits `OwnershipTransferred`,
+        /// `RoyaltyPaid` if a royalty is owed, and `ContentSold`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content for sale.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if sales or transfers are paused.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NoAuction` if the content is not on a declining-price sale.
+        /// - Returns `Error::AuctionEnded` if the sale has expired.
+        /// - Returns `Error::SelfTransfer` if the caller is the seller.
+        /// - Returns `Error::InsufficientPayment` if the attached value is below the current price.
+        /// - Returns `Error::BalanceOverflow` if a credit would overflow.
+        /// - Returns the errors of `apply_transfer` otherwise. Nothing is written on any error.
+        #[ink(message, payable)]
+        pub fn buy_dutch(&mut self, content_id: u64) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Sales)?;
+            self.ensure_not_paused(PausableOp::Transfers)?;
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            let auction = self.dutch_auctions.get(content_id).ok_or(Error::NoAuction)?;
+            let price = auction.price_at(self.env().block_timestamp()).ok_or(Error::AuctionEnded)?;
+            let seller = record.owner;
+            let buyer = self.env().caller();
+            if buyer == seller {
+                return Err(Error::SelfTransfer);
+            }
+            let excess = self.env()
+                .transferred_value()
+                .checked_sub(price)
+                .ok_or(Error::InsufficientPayment)?;
+            let mut payout = self.sale_payout(content_id, seller, price, RevenueSource::Sale, None)?;
+            self.add_refund(&mut payout, buyer, excess)?;
+            self.apply_transfer(content_id, record, buyer, None, None)?;
+            self.pay_out(&payout);
+            self.record_sale(content_id, seller, buyer, price, None);
+            let seq = self.next_event_seq();
+            self.env().emit_event(ContentSold {
+                content_id,
+                seller,
+                buyer,
+                price,
+                currency: None,
+                fee: payout.fee,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Cancels a declining-price sale, running or expired. Only the current owner can call this
+        /// function. Emits `DutchAuctionCancelled`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content for sale.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns `Error::NoAuction` if the content is not on a declining-price sale.
+        #[ink(message)]
+        pub fn cancel_dutch_auction(&mut self, content_id: u64) -> Result<()> {
+            self.ensure_caller_owns(content_id)?;
+            self.dutch_auctions.take(content_id).ok_or(Error::NoAuction)?;
+            let seq = self.next_event_seq();
+            self.env().emit_event(DutchAuctionCancelled { content_id, seq });
+            Ok(())
+        }
+
+        /// Returns the declining-price sale of a content item, if any, whether or not it has expired.
+        #[ink(message)]
+        pub fn get_dutch_auction(&self, content_id: u64) -> Option<DutchAuction> {
+            self.dutch_auctions.get(content_id)
+        }
+
+        /// Returns the price `buy_dutch` would charge for a content item now, or `None` if it is not
+        /// on a declining-price sale or the sale has expired.
+        #[ink(message)]
+        pub fn current_dutch_price(&self, content_id: u64) -> Option<Balance> {
+            self.dutch_auctions
+                .get(content_id)
+                .and_then(|auction| auction.price_at(self.env().block_timestamp()))
+        }
+
+        /// Lists several content items owned by the caller, e.g. the items of a collection, for sale
+        /// together at one price in native tokens, paid through `buy_bundle`. The items stay
+        /// individually transferable: if one changes hands, is locked, or otherwise cannot be sold
+        /// before the bundle is bought, buying fails with `Error::BundleInvalidated`. Emits `BundleListed`.
+        ///
+        /// # Arguments
+        /// - `content_ids`: Up to `MAX_BATCH_SIZE` distinct content IDs to sell together.
+        /// - `price`: The price of the whole bundle, above zero.
+        ///
+        /// # Returns
+        /// - The unique ID of the bundle.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if sales are paused.
+        /// - Returns `Error::InvalidPrice` if the price is zero.
+        /// - Returns `Error::BatchTooLarge` if more than `MAX_BATCH_SIZE` IDs are passed.
+        /// - Returns `Error::InvalidBundle` if no ID is passed, or the same ID twice.
+        /// - Returns `Error::ContentNotFound` or `Error::NotOwner` for the first item the caller does not own.
+        /// - Returns the errors of `ensure_sellable` for the first item that cannot change hands.
+        /// - Returns `Error::CounterOverflow` if the bundle ID counter overflows.
+        #[ink(message)]
+        pub fn list_bundle(&mut self, content_ids: Vec<u64>, price: Balance) -> Result<u64> {
+            self.ensure_not_paused(PausableOp::Sales)?;
+            if price == 0 {
+                return Err(Error::InvalidPrice);
+            }
+            Self::ensure_batch_size(content_ids.len())?;
+            if content_ids.is_empty() {
+                return Err(Error::InvalidBundle);
+            }
+            let seller = self.env().caller();
+            for (position, &content_id) in content_ids.iter().enumerate() {
+                if content_ids[..position].contains(&content_id) {
+                    return Err(Error::InvalidBundle);
+                }
+                let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+                if record.owner != seller {
+                    return Err(Error::NotOwner);
+                }
+                self.ensure_sellable(content_id, &record)?;
+            }
+            let bundle_id = self.next_bundle_id;
+            self.next_bundle_id = bundle_id.checked_add(1).ok_or(Error::CounterOverflow)?;
+            self.bundles.insert(bundle_id, &Bundle { seller, content_ids: content_ids.clone(), price });
+            let seq = self.next_event_seq();
+            self.env().emit_event(BundleListed {
+                bundle_id,
+                seller,
+                content_ids,
+                price,
+                seq,
+            });
+            Ok(bundle_id)
+        }
+
+        /// Buys a bundle: every item moves to the caller, or none does. The call must carry at least
+        /// the price. The price is split evenly across the items, the first item taking any remainder,
+        /// and each item is paid out and recorded in its sale history like a separate sale, so the
+        /// seller is credited the price minus the summed platform fees. Any excess is credited back to
+        /// the buyer. Emits `OwnershipTransferred` per item, then `BundleSold`.
+        ///
+        /// # Arguments
+        /// - `bundle_id`: The unique ID of the bundle.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContractPaused` if sales or transfers are paused.
+        /// - Returns `Error::BundleNotFound` if the bundle ID is not found.
+        /// - Returns `Error::SelfTransfer` if the caller is the seller.
+        /// - Returns `Error::InsufficientPayment` if the attached value is below the price.
+        /// - Returns `Error::BundleInvalidated` if the seller no longer owns every item, or an item can no
+        ///   longer change hands.
+        /// - Returns `Error::BalanceOverflow` if a credit would overflow.
+        /// - Returns the errors of `apply_transfer` otherwise. Nothing is written on any of these errors.
+        #[ink(message, payable)]
+        pub fn buy_bundle(&mut self, bundle_id: u64) -> Result<()> {
+            self.ensure_not_paused(PausableOp::Sales)?;
+            self.ensure_not_paused(PausableOp::Transfers)?;
+            let bundle = self.bundles.get(bundle_id).ok_or(Error::BundleNotFound)?;
+            let seller = bundle.seller;
+            let buyer = self.env().caller();
+            if buyer == seller {
+                return Err(Error::SelfTransfer);
+            }
+            let excess = self.env()
+                .transferred_value()
+                .checked_sub(bundle.price)
+                .ok_or(Error::InsufficientPayment)?;
+            // Check every item up front so an invalidated bundle leaves all items untouched.
+            for &content_id in &bundle.content_ids {
+                let still_sellable = self
+                    .contents
+                    .get(content_id)
+                    .is_some_and(|record| record.owner == seller && self.ensure_sellable(content_id, &record).is_ok());
+                if !still_sellable {
+                    return Err(Error::BundleInvalidated);
+                }
+            }
+            // Fail on overflow before anything is written, as if the whole price went to the first item.
+            self.sale_payout(bundle.content_ids[0], seller, bundle.price, RevenueSource::Sale, None)?;
+            self.credited(buyer, excess)?;
+            let items = bundle.content_ids.len() as Balance;
+            let share = bundle.price / items;
+            let mut fee: Balance = 0;
+            for (position, &content_id) in bundle.content_ids.iter().enumerate() {
+                let price = if position == 0 { bundle.price - share * (items - 1) } else { share };
+                let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+                let payout = self.sale_payout(content_id, seller, price, RevenueSource::Sale, None)?;
+                self.apply_transfer(content_id, record, buyer, None, None)?;
+                self.pay_out(&payout);
+                self.record_sale(content_id, seller, buyer, price, None);
+                fee += payout.fee;
+            }
+            self.bundles.remove(bundle_id);
+            if excess > 0 {
+                // Read the credit afresh: the buyer may have been paid a royalty on one of the items.
+                let buyer_credit = self.credited(buyer, excess)?;
+                self.credits.insert(buyer, &buyer_credit);
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(BundleSold {
+                bundle_id,
+                seller,
+                buyer,
+                price: bundle.price,
+                fee,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Withdraws a bundle from sale, whether or not it is still buyable. Only the seller can cancel.
+        /// Emits `BundleCancelled`.
+        ///
+        /// # Arguments
+        /// - `bundle_id`: The unique ID of the bundle.
+        ///
+        /// # Errors
+        /// - Returns `Error::BundleNotFound` if the bundle ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the seller.
+        #[ink(message)]
+        pub fn cancel_bundle(&mut self, bundle_id: u64) -> Result<()> {
+            let bundle = self.bundles.get(bundle_id).ok_or(Error::BundleNotFound)?;
+            if self.env().caller() != bundle.seller {
+                return Err(Error::NotOwner);
+            }
+            self.bundles.remove(bundle_id);
+            let seq = self.next_event_seq();
+            self.env().emit_event(BundleCancelled { bundle_id, seq });
+            Ok(())
+        }
+
+        /// Returns a listed bundle, if it exists. A returned bundle may no longer be buyable.
+        #[ink(message)]
+        pub fn get_bundle(&self, bundle_id: u64) -> Option<Bundle> {
+            self.bundles.get(bundle_id)
+        }
+
+        /// Checks that a content record can currently be sold.
+        ///
+        /// # Errors
+        /// - Returns the errors of `ensure_transferable`, `Error::ContentLocked`, `Error::ContentFrozen`,
+        ///   `Error::AuctionActive`, `Error::ContentPledged`, or those of `ensure_still_valid`.
+        fn ensure_sellable(&self, content_id: u64, record: &Content) -> Result<()> {
+            Self::ensure_transferable(record)?;
+            self.ensure_unlocked(content_id)?;
+            self.ensure_not_frozen(content_id)?;
+            self.ensure_not_auctioned(content_id)?;
+            self.ensure_not_pledged(content_id)?;
+            self.ensure_still_valid(record)
+        }
+
+        /// Sets the anti-sniping window of auctions, including running ones: a bid placed less than
+        /// `window` milliseconds before an auction ends moves its end to `window` after the bid.
+        /// Zero turns extensions off. Only an admin can call this function; when the approval threshold
+        /// is above one, it must go through `AdminAction::SetAuctionExtension`. Emits `AuctionExtensionChanged`.
+        ///
+        /// # Arguments
+        /// - `window`: The window in milliseconds.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        #[ink(message)]
+        pub fn set_auction_extension(&mut self, window: Timestamp) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            self.apply_auction_extension(caller, window);
+            Ok(())
+        }
+
+        /// Stores the anti-sniping window and emits `AuctionExtensionChanged`. Authorization is the caller's responsibility.
+        fn apply_auction_extension(&mut self, by: AccountId, window: Timestamp) {
+            self.auction_extension = window;
+            let seq = self.next_event_seq();
+            self.env().emit_event(AuctionExtensionChanged { by, window, seq });
+        }
+
+        /// Returns the anti-sniping window, in milliseconds, of auctions.
+        #[ink(message)]
+        pub fn auction_extension(&self) -> Timestamp {
+            self.auction_extension
+        }
+
+        /// Schedules a forced transfer of a content item, e.g. to comply with a legal takedown order.
+        /// Only an admin can call this function. The transfer can be executed with
+        /// `admin_execute_transfer` once the forced-transfer delay has elapsed, and cancelled
+        /// with `admin_cancel_transfer` until then. Scheduling again replaces the pending
+        /// transfer and restarts the delay. Emits `TransferScheduled`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to transfer.
+        /// - `new_owner`: The AccountId of the new owner.
+        /// - `reason_hash`: A hash of the off-chain document justifying the transfer.
+        ///
+        /// # Returns
+        /// - The block timestamp from which the transfer may be executed.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::OwnershipRenounced` if the content's ownership was renounced.
+        /// - Returns `Error::ContentSoulbound` if the content is soulbound.
+        /// - Returns `Error::ContentNotActive` if the content's status is not `Active`.
+        /// - Returns `Error::SelfTransfer` or `Error::ZeroAddressRecipient` for an invalid recipient.
+        #[ink(message)]
+        pub fn admin_schedule_transfer(&mut self, content_id: u64, new_owner: AccountId, reason_hash: String) -> Result<Timestamp> {
+            let caller = self.ensure_admin()?;
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            Self::ensure_transferable(&record)?;
+            let owner = record.owner;
+            Self::ensure_valid_recipient(owner, new_owner)?;
+            let executable_at = self.env().block_timestamp().saturating_add(self.forced_transfer_delay);
+            self.scheduled_transfers.insert(content_id, &ScheduledTransfer {
+                new_owner,
+                reason_hash: reason_hash.clone(),
+                scheduled_by: caller,
+                executable_at,
+            });
+            let seq = self.next_event_seq();
+            self.env().emit_event(TransferScheduled {
+                content_id,
+                owner,
+                new_owner,
+                reason_hash,
+                scheduled_by: caller,
+                executable_at,
+                seq,
+            });
+            Ok(executable_at)
+        }
+
+        /// Cancels a scheduled forced transfer.
+        /// Only an admin can call this function. Emits `ScheduledTransferCancelled`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::TransferNotScheduled` if no forced transfer is scheduled for the content.
+        #[ink(message)]
+        pub fn admin_cancel_transfer(&mut self, content_id: u64) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if !self.content_exists(content_id) {
+                return Err(Error::ContentNotFound);
+            }
+            if self.scheduled_transfers.take(content_id).is_none() {
+                return Err(Error::TransferNotScheduled);
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(ScheduledTransferCancelled {
+                content_id,
+                cancelled_by: caller,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Executes a scheduled forced transfer once its delay has elapsed.
+        /// Only an admin can call this function. The content moves to the scheduled recipient
+        /// regardless of who owns it now; an owner lock, an unfunded loan offer, or an auction does not
+        /// block it. The offer is cancelled with `LoanOfferCancelled`, and the auction with
+        /// `AuctionCancelled` after its highest bid is credited back to the bidder.
+        /// Emits `OwnershipTransferred` followed by `ScheduledTransferExecuted`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ContractPaused` if transfers are paused.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::TransferNotScheduled` if no forced transfer is scheduled for the content.
+        /// - Returns `Error::TransferNotReady` if the delay has not elapsed yet.
+        /// - Returns `Error::ContentPledged` if a lender funded a loan against the content.
+        /// - Returns `Error::BalanceOverflow` if crediting back the highest bid would overflow.
+        /// - Returns `Error::OwnedCountOverflow` if the new owner's owned-content counter overflows.
+        #[ink(message)]
+        pub fn admin_execute_transfer(&mut self, content_id: u64) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            self.ensure_not_paused(PausableOp::Transfers)?;
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            let scheduled = self.scheduled_transfers.get(content_id).ok_or(Error::TransferNotScheduled)?;
+            if self.env().block_timestamp() < scheduled.executable_at {
+                return Err(Error::TransferNotReady);
+            }
+            let auction = self.auctions.get(content_id);
+            let highest_bid = auction
+                .as_ref()
+                .and_then(|auction| auction.highest_bidder.map(|bidder| (bidder, auction.highest_bid)));
+            let bid_refund = match highest_bid {
+                Some((bidder, amount)) => Some((bidder, self.credited(bidder, amount)?)),
+                None => None,
+            };
+            self.locks.remove(content_id);
+            if auction.is_some() {
+                self.auctions.remove(content_id);
+                if let Some((bidder, credit)) = bid_refund {
+                    self.credits.insert(bidder, &credit);
+                }
+                let seq = self.next_event_seq();
+                self.env().emit_event(AuctionCancelled { content_id, seq });
+            }
+            if self.loans.get(content_id).is_some_and(|loan| loan.lender.is_none()) {
+                self.loans.remove(content_id);
+                let seq = self.next_event_seq();
+                self.env().emit_event(LoanOfferCancelled { content_id, seq });
+            }
+            self.apply_transfer(content_id, record, scheduled.new_owner, None, None)?;
+            self.scheduled_transfers.remove(content_id);
+            let seq = self.next_event_seq();
+            self.env().emit_event(ScheduledTransferExecuted {
+                content_id,
+                executed_by: caller,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Returns the forced transfer scheduled for a content item, if any.
+        #[ink(message)]
+        pub fn get_scheduled_transfer(&self, content_id: u64) -> Option<ScheduledTransfer> {
+            self.scheduled_transfers.get(content_id)
+        }
+
+        /// Sets the delay applied to forced transfers scheduled from now on.
+        /// Already scheduled transfers keep their execution time.
+        /// Only an admin can call this function; when the approval threshold is above one, it must go
+        /// through `AdminAction::SetForcedTransferDelay`. Emits `ForcedTransferDelayChanged`.
+        ///
+        /// # Arguments
+        /// - `delay`: The delay in milliseconds.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ApprovalRequired` if the approval threshold is above one.
+        #[ink(message)]
+        pub fn set_forced_transfer_delay(&mut self, delay: Timestamp) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            if self.approval_threshold > 1 {
+                return Err(Error::ApprovalRequired);
+            }
+            self.apply_forced_transfer_delay(caller, delay);
+            Ok(())
+        }
+
+        /// Stores the forced-transfer delay and emits `ForcedTransferDelayChanged`. Authorization is the caller's responsibility.
+        fn apply_forced_transfer_delay(&mut self, by: AccountId, delay: Timestamp) {
+            self.forced_transfer_delay = delay;
+            let seq = self.next_event_seq();
+            self.env().emit_event(ForcedTransferDelayChanged { by, delay, seq });
+        }
+
+        /// Returns the delay, in milliseconds, applied to newly scheduled forced transfers.
+        #[ink(message)]
+        pub fn forced_transfer_delay(&self) -> Timestamp {
+            self.forced_transfer_delay
+        }
+
+        /// Locks a content item so it cannot be transferred, offered, or approved until unlocked,
+        /// e.g. to protect it against a compromised session key.
+        /// Only the current owner can call this function. Emits `ContentLockChanged`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to lock.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        #[ink(message)]
+        pub fn lock_content(&mut self, content_id: u64) -> Result<()> {
+            self.lock_content_until(content_id, Timestamp::MAX)
+        }
+
+        /// Locks a content item like `lock_content`, until the given block timestamp.
+        /// The lock expires on its own once the timestamp is reached; a timestamp in the past
+        /// leaves the content unlocked. Replaces any existing lock. Emits `ContentLockChanged`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to lock.
+        /// - `until`: The block timestamp, in milliseconds, from which the content is unlocked again.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns `Error::AuctionActive` if the content is being auctioned.
+        /// - Returns `Error::ContentPledged` if the content secures a loan or loan offer.
+        #[ink(message)]
+        pub fn lock_content_until(&mut self, content_id: u64, until: Timestamp) -> Result<()> {
+            self.ensure_caller_owns(content_id)?;
+            self.ensure_not_auctioned(content_id)?;
+            self.ensure_not_pledged(content_id)?;
+            self.locks.insert(content_id, &until);
+            let seq = self.next_event_seq();
+            self.env().emit_event(ContentLockChanged {
+                content_id,
+                locked_until: Some(until),
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Removes the lock of a content item. Only the current owner can call this function.
+        /// Emits `ContentLockChanged`, even if the content was not locked.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to unlock.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        #[ink(message)]
+        pub fn unlock_content(&mut self, content_id: u64) -> Result<()> {
+            self.ensure_caller_owns(content_id)?;
+            self.locks.remove(content_id);
+            let seq = self.next_event_seq();
+            self.env().emit_event(ContentLockChanged {
+                content_id,
+                locked_until: None,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Checks whether a content item is currently locked by its owner.
+        ///
+        /// # Returns
+        /// - `true` if the content is locked and the lock has not expired, `false` otherwise.
+        #[ink(message)]
+        pub fn is_locked(&self, content_id: u64) -> bool {
+            self.locks
+                .get(content_id)
+                .is_some_and(|until| self.env().block_timestamp() < until)
+        }
+
+        /// Places a compliance hold on a content item, e.g. while an investigation runs. The content
+        /// stays valid, but it cannot be transferred, approved, sold, or burned until it is unfrozen;
+        /// this includes forced transfers. Only an admin or an account holding `Role::Moderator` can
+        /// call this function. Freezing frozen content is a no-op. Emits `ContentFreezeChanged`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither an admin nor a holder of `Role::Moderator`.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        #[ink(message)]
+        pub fn freeze_content(&mut self, content_id: u64) -> Result<()> {
+            self.set_frozen(content_id, true)
+        }
+
+        /// Lifts the compliance hold of a content item, returning full control to its owner.
+        /// Unfreezing content that is not frozen is a no-op. Emits `ContentFreezeChanged`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        ///
+        /// # Errors
+        /// - Returns the errors of `freeze_content`.
+        #[ink(message)]
+        pub fn unfreeze_content(&mut self, content_id: u64) -> Result<()> {
+            self.set_frozen(content_id, false)
+        }
+
+        /// Returns the block timestamp at which a content item was frozen, or `None` if it is not frozen.
+        #[ink(message)]
+        pub fn frozen_since(&self, content_id: u64) -> Option<Timestamp> {
+            self.frozen.get(content_id)
+        }
+
+        /// Freezes or unfreezes a content item, emitting `ContentFreezeChanged` only if the freeze changed.
+        ///
+        /// # Errors
+        /// - Returns the errors of `freeze_content`.
+        fn set_frozen(&mut self, content_id: u64, frozen: bool) -> Result<()> {
+            let caller = self.ensure_admin_or_role(Role::Moderator)?;
+            if !self.content_exists(content_id) {
+                return Err(Error::ContentNotFound);
+            }
+            if self.frozen.contains(content_id) == frozen {
+                return Ok(());
+            }
+            if frozen {
+                self.frozen.insert(content_id, &self.env().block_timestamp());
+            } else {
+                self.frozen.remove(content_id);
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(ContentFreezeChanged {
+                content_id,
+                frozen,
+                changed_by: caller,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Checks that a content item is not under a compliance hold.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentFrozen` if the content is frozen.
+        fn ensure_not_frozen(&self, content_id: u64) -> Result<()> {
+            if self.frozen.contains(content_id) {
+                return Err(Error::ContentFrozen);
+            }
+            Ok(())
+        }
+
+        /// Checks that the caller is the current owner of a content item.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner, including for renounced content.
+        fn ensure_caller_owns(&self, content_id: u64) -> Result<()> {
+            if !self.content_exists(content_id) {
+                return Err(Error::ContentNotFound);
+            }
+            if !self.is_owner(content_id, self.env().caller()) {
+                return Err(Error::NotOwner);
+            }
+            Ok(())
+        }
+
+        /// Checks that the owner has not locked a content item.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentLocked` if the content is locked.
+        fn ensure_unlocked(&self, content_id: u64) -> Result<()> {
+            if self.is_locked(content_id) {
+                return Err(Error::ContentLocked);
+            }
+            Ok(())
+        }
+
+        /// Checks that a content item does not secure a loan or loan offer.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentPledged` if the content secures a loan or loan offer.
+        fn ensure_not_pledged(&self, content_id: u64) -> Result<()> {
+            if self.loans.contains(content_id) {
+                return Err(Error::ContentPledged);
+            }
+            Ok(())
+        }
+
+        /// Checks that a content item is not being auctioned.
+        ///
+        /// # Errors
+        /// - Returns `Error::AuctionActive` if an auction of the content is running or awaits settlement.
+        fn ensure_not_auctioned(&self, content_id: u64) -> Result<()> {
+            if self.auctions.contains(content_id) {
+                return Err(Error::AuctionActive);
+            }
+            Ok(())
+        }
+
+        /// Checks that no forced transfer of a content item is scheduled.
+        ///
+        /// # Errors
+        /// - Returns `Error::ForcedTransferPending` if an admin scheduled a forced transfer of the content.
+        fn ensure_no_forced_transfer(&self, content_id: u64) -> Result<()> {
+            if self.scheduled_transfers.contains(content_id) {
+                return Err(Error::ForcedTransferPending);
+            }
+            Ok(())
+        }
+
+        /// Renounces ownership of a content item, e.g. to release it into the public domain.
+        /// Only the current owner can call this function, and only while the content could change
+        /// hands. Afterwards the record can still be read, but it has no owner (`owner_of` returns
+        /// `None`) and can never be transferred again. Any pending transfer offer is withdrawn and
+        /// every license granted on the content ends. Emits `ContentRenounced`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to renounce.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::OwnershipRenounced` if the content's ownership was already renounced.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner.
+        /// - Returns `Error::ContentNotActive` if the content's status is not `Active`.
+        /// - Returns `Error::ContentLocked` if the owner locked the content.
+        /// - Returns `Error::ContentFrozen` if an admin or moderator froze the content.
+        /// - Returns `Error::AuctionActive` if the content is being auctioned.
+        /// - Returns `Error::ContentPledged` if the content secures a loan or loan offer.
+        /// - Returns `Error::RentalActive` if the content is rented out.
+        /// - Returns `Error::ForcedTransferPending` if an admin scheduled a forced transfer of the content.
+        #[ink(message)]
+        pub fn renounce_ownership(&mut self, content_id: u64) -> Result<()> {
+            let mut record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            if record.renounced {
+                return Err(Error::OwnershipRenounced);
+            }
+            let former_owner = record.owner;
+            if self.env().caller() != former_owner {
+                return Err(Error::NotOwner);
+            }
+            if record.status != ContentStatus::Active {
+                return Err(Error::ContentNotActive);
+            }
+            self.ensure_unlocked(content_id)?;
+            self.ensure_not_frozen(content_id)?;
+            self.ensure_not_auctioned(content_id)?;
+            self.ensure_not_pledged(content_id)?;
+            if self.renter_of(content_id).is_some() {
+                return Err(Error::RentalActive);
+            }
+            if self.scheduled_transfers.contains(content_id) {
+                return Err(Error::ForcedTransferPending);
+            }
+            self.remove_from_owner_index(former_owner, content_id)?;
+            record.renounced = true;
+            self.contents.insert(content_id, &record);
+            self.owners.remove(content_id);
+            self.transfer_offers.remove(content_id);
+            self.listings.remove(content_id);
+            self.dutch_auctions.remove(content_id);
+            self.access_terms.remove(content_id);
+            self.rental_terms.remove(content_id);
+            self.license_offers.remove(content_id);
+            self.rentals.remove(content_id);
+            self.clear_licenses(content_id);
+            self.clear_approval(former_owner, content_id);
+            self.remove_from_collection_index(content_id);
+            let seq = self.next_event_seq();
+            self.env().emit_event(ContentRenounced {
+                content_id,
+                former_owner,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Permanently removes a content item, e.g. after a takedown or a mistaken registration.
+        /// Only the current owner can burn it. The content ID is never reused, while the content
+        /// hash can be registered again by anyone. Licenses, purchased access, and transfer
+        /// exemptions end with it. Only the latest version of a version chain can be burned, which
+        /// makes the version it superseded the latest again. The transfer history stays queryable.
+        /// Emits `ContentBurned`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to burn.
+        ///
+        /// # Errors
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::NotOwner` if the caller is not the current owner, including for renounced content.
+        /// - Returns `Error::ContentNotActive` if the content's status is not `Active`.
+        /// - Returns `Error::ContentLocked` if the owner locked the content.
+        /// - Returns `Error::ContentFrozen` if an admin or moderator froze the content.
+        /// - Returns `Error::AuctionActive` if the content is being auctioned.
+        /// - Returns `Error::ContentPledged` if the content secures a loan or loan offer.
+        /// - Returns `Error::RentalActive` if the content is rented out.
+        /// - Returns `Error::AlreadySuperseded` if a newer version supersedes the content.
+        /// - Returns `Error::ForcedTransferPending` if an admin scheduled a forced transfer of the content.
+        #[ink(message)]
+        pub fn burn_content(&mut self, content_id: u64) -> Result<()> {
+            self.ensure_caller_owns(content_id)?;
+            let record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            if record.status != ContentStatus::Active {
+                return Err(Error::ContentNotActive);
+            }
+            self.ensure_unlocked(content_id)?;
+            self.ensure_not_frozen(content_id)?;
+            self.ensure_not_auctioned(content_id)?;
+            self.ensure_not_pledged(content_id)?;
+            if self.renter_of(content_id).is_some() {
+                return Err(Error::RentalActive);
+            }
+            if self.superseded_by.contains(content_id) {
+                return Err(Error::AlreadySuperseded);
+            }
+            if self.scheduled_transfers.contains(content_id) {
+                return Err(Error::ForcedTransferPending);
+            }
+            let owner = record.owner;
+            self.remove_from_owner_index(owner, content_id)?;
+            self.remove_from_creator_index(record.creator, content_id);
+            if let Some(parent_id) = record.parent {
+                self.remove_from_derivative_index(parent_id, content_id);
+            }
+            self.remove_from_collection_index(content_id);
+            self.remove_from_global_index(content_id);
+            self.remove_from_version_chain(content_id);
+            self.clear_approval(owner, content_id);
+            self.contents.remove(content_id);
+            self.owners.remove(content_id);
+            self.content_hash_to_id.remove(&record.content_hash);
+            self.transfer_offers.remove(content_id);
+            self.listings.remove(content_id);
+            self.dutch_auctions.remove(content_id);
+            self.access_terms.remove(content_id);
+            self.rental_terms.remove(content_id);
+            self.license_offers.remove(content_id);
+            self.rentals.remove(content_id);
+            self.transfer_memos.remove(content_id);
+            self.metadata.remove(content_id);
+            self.metadata_locks.remove(content_id);
+            self.royalty_bps.remove(content_id);
+            self.royalty_splits.remove(content_id);
+            self.royalty_locks.remove(content_id);
+            self.marketplace_only.remove(content_id);
+            self.license_terms.remove(content_id);
+            self.license_terms_locks.remove(content_id);
+            self.clear_licenses(content_id);
+            self.derivative_consent_required.remove(content_id);
+            self.registrations.remove(content_id);
+            self.commitment_blocks.remove(content_id);
+            self.clear_tags(content_id);
+            self.uri_overrides.remove(content_id);
+            let seq = self.next_event_seq();
+            self.env().emit_event(ContentBurned {
+                content_id,
+                owner,
+                content_hash: record.content_hash,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Moves a content item to a new lifecycle status, e.g. to flag it as disputed or revoke it.
+        /// Only an admin can call this function, and only the transitions allowed by
+        /// `ContentStatus::can_transition_to` are accepted. Emits `ContentStatusChanged`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `status`: The new status.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is not an admin.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::InvalidStatusTransition` if the content cannot move to `status`.
+        #[ink(message)]
+        pub fn set_content_status(&mut self, content_id: u64, status: ContentStatus) -> Result<()> {
+            let caller = self.ensure_admin()?;
+            let mut record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            let old_status = record.status;
+            if !old_status.can_transition_to(status) {
+                return Err(Error::InvalidStatusTransition);
+            }
+            record.status = status;
+            self.contents.insert(content_id, &record);
+            let seq = self.next_event_seq();
+            self.env().emit_event(ContentStatusChanged {
+                content_id,
+                old_status,
+                new_status: status,
+                changed_by: caller,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Revokes a content item, e.g. when the oracle provider flags its hash as infringing.
+        /// Only an admin or an account holding `Role::Moderator` can call this function. Revoked content
+        /// cannot be transferred, approved, or sold, and its hash stays registered so nobody else can
+        /// claim it. Emits `ContentRevoked`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `reason_hash`: The hash of the off-chain reason for the revocation.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither an admin nor a holder of `Role::Moderator`.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::InvalidStatusTransition` if the content is already revoked.
+        #[ink(message)]
+        pub fn revoke_content(&mut self, content_id: u64, reason_hash: String) -> Result<()> {
+            let caller = self.ensure_admin_or_role(Role::Moderator)?;
+            let mut record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            if !record.status.can_transition_to(ContentStatus::Revoked) {
+                return Err(Error::InvalidStatusTransition);
+            }
+            record.status = ContentStatus::Revoked;
+            self.contents.insert(content_id, &record);
+            let seq = self.next_event_seq();
+            self.env().emit_event(ContentRevoked {
+                content_id,
+                reason_hash,
+                revoked_by: caller,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Lifts the revocation of a content item after a successful appeal, returning it to `Active`.
+        /// Only an admin or an account holding `Role::Moderator` can call this function.
+        /// Emits `ContentUnrevoked`.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        ///
+        /// # Errors
+        /// - Returns `Error::NotAdmin` if the caller is neither an admin nor a holder of `Role::Moderator`.
+        /// - Returns `Error::ContentNotFound` if the content ID is not found.
+        /// - Returns `Error::InvalidStatusTransition` if the content is not revoked.
+        #[ink(message)]
+        pub fn unrevoke_content(&mut self, content_id: u64) -> Result<()> {
+            let caller = self.ensure_admin_or_role(Role::Moderator)?;
+            let mut record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
+            if record.status != ContentStatus::Revoked {
+                return Err(Error::InvalidStatusTransition);
+            }
+            record.status = ContentStatus::Active;
+            self.contents.insert(content_id, &record);
+            let seq = self.next_event_seq();
+            self.env().emit_event(ContentUnrevoked {
+                content_id,
+                unrevoked_by: caller,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Returns the lifecycle status of a content item.
+        ///
+        /// # Returns
+        /// - `ContentStatus::Burned` for an issued ID whose content was burned.
+        /// - `None` if the ID was never issued.
+        #[ink(message)]
+        pub fn status_of(&self, content_id: u64) -> Option<ContentStatus> {
+            match self.contents.get(content_id) {
+                Some(record) => Some(record.status),
+                None if content_id != 0 && content_id < self.next_content_id => Some(ContentStatus::Burned),
+                None => None,
+            }
+        }
+
+        /// Returns the content IDs owned by an account that have a given status.
+        ///
+        /// # Arguments
+        /// - `owner`: The AccountId whose content to list.
+        /// - `status`: The status to filter by.
+        /// - `start`: The position in the owner's index to start scanning from.
+        /// - `limit`: The number of positions to scan, capped at `MAX_PAGE_SIZE`. Skipped items count
+        ///   towards the limit, so the result may hold fewer than `limit` entries.
+        ///
+        /// # Returns
+        /// - A `Vec` of content IDs in index order, empty if `start` is past the end.
+        #[ink(message)]
+        pub fn contents_of_with_status(&self, owner: AccountId, status: ContentStatus, start: u32, limit: u32) -> Vec<u64> {
+            self.contents_of(owner, start, limit)
+                .into_iter()
+                .filter(|&content_id| self.status_of(content_id) == Some(status))
+                .collect()
+        }
+
+        /// Removes a content ID from its creator's index, moving the creator's last entry into the
+        /// freed position (swap-remove).
+        fn remove_from_creator_index(&mut self, creator: AccountId, content_id: u64) {
+            let Some(last) = self.created_count.get(creator).unwrap_or(0).checked_sub(1) else {
+                return;
+            };
+            let position = self.created_content_position.get(content_id).unwrap_or(last);
+            if position != last {
+                if let Some(last_id) = self.created_contents.get((creator, last)) {
+                    self.created_contents.insert((creator, position), &last_id);
+                    self.created_content_position.insert(last_id, &position);
+                }
+            }
+            self.created_contents.remove((creator, last));
+            self.created_content_position.remove(content_id);
+            self.created_count.insert(creator, &last);
+        }
+
+        /// Removes a content ID from the global index, moving the last entry into the freed
+        /// position (swap-remove), and decrements the supply.
+        fn remove_from_global_index(&mut self, content_id: u64) {
+            let Some(last) = self.content_count.checked_sub(1) else {
+                return;
+            };
+            let position = self.all_content_position.get(content_id).unwrap_or(last);
+            if position != last {
+                if let Some(last_id) = self.all_contents.get(last) {
+                    self.all_contents.insert(position, &last_id);
+                    self.all_content_position.insert(last_id, &position);
+                }
+            }
+            self.all_contents.remove(last);
+            self.all_content_position.remove(content_id);
+            self.content_count = last;
+        }
+
+        /// Moves a content record to a new owner and emits `OwnershipTransferred`.
+        /// All transfer paths must call this so storage updates and events stay consistent.
+        /// Any pending transfer offer, sale listing, approval, and owner lock are withdrawn, and the transfer is
+        /// appended to the provenance history.
+        /// Authorization checks are the responsibility of the caller.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to transfer.
+        /// - `record`: The current content record, as loaded from storage.
+        /// - `new_owner`: The AccountId of the new owner.
+        /// - `memo`: The memo to record as the latest transfer memo; `None` clears the previous one.
+        ///
+        /// # Errors
+        /// - Returns the errors of `ensure_transferable` if the content cannot change hands.
+        /// - Returns `Error::ContentLocked` if the owner locked the content.
+        /// - Returns `Error::ContentFrozen` if an admin or moderator froze the content.
+        /// - Returns `Error::AuctionActive` or `Error::ContentPledged` if the content is auctioned or pledged.
+        /// - Returns `Error::InvalidContent` if re-validation on transfer is on and the hash no longer passes.
+        /// - Returns `Error::SelfTransfer` or `Error::ZeroAddressRecipient` for an invalid recipient.
+        /// - Returns `Error::OwnedCountOverflow` if either owner's counter would overflow or underflow.
+        ///   Nothing is written in any of these cases.
+        fn apply_transfer(
+            &mut self,
+            content_id: u64,
+            mut record: Content,
+            new_owner: AccountId,
+            memo: Option<String>,
+            relayed_by: Option<AccountId>,
+        ) -> Result<()> {
+            Self::ensure_transferable(&record)?;
+            self.ensure_unlocked(content_id)?;
+            self.ensure_not_frozen(content_id)?;
+            self.ensure_not_auctioned(content_id)?;
+            self.ensure_not_pledged(content_id)?;
+            self.ensure_still_valid(&record)?;
+            let from = record.owner;
+            Self::ensure_valid_recipient(from, new_owner)?;
+            // Check the recipient first so a failure leaves both owners' indexes untouched.
+            if self.balance_of(new_owner) == u32::MAX {
+                return Err(Error::OwnedCountOverflow);
+            }
+            self.remove_from_owner_index(from, content_id)?;
+            self.add_to_owner_index(new_owner, content_id)?;
+            record.owner = new_owner;
+            self.contents.insert(content_id, &record);
+            self.owners.insert(content_id, &new_owner);
+            self.transfer_offers.remove(content_id);
+            self.listings.remove(content_id);
+            self.dutch_auctions.remove(content_id);
+            self.access_terms.remove(content_id);
+            self.rental_terms.remove(content_id);
+            self.license_offers.remove(content_id);
+            self.locks.remove(content_id);
+            self.clear_approval(from, content_id);
+            // Items only stay in a collection while they share its owner; `transfer_collection`
+            // moves the collection record first, so its own items are kept.
+            let collection_owner = self.item_collection
+                .get(content_id)
+                .and_then(|(collection_id, _)| self.collections.get(collection_id))
+                .map(|collection| collection.owner);
+            if collection_owner.is_some_and(|owner| owner != new_owner) {
+                self.remove_from_collection_index(content_id);
+            }
+            self.record_provenance(content_id, from, new_owner);
+            if let Some(memo) = &memo {
+                self.transfer_memos.insert(content_id, memo);
+            } else {
+                self.transfer_memos.remove(content_id);
+            }
+            let seq = self.next_event_seq();
+            self.env().emit_event(OwnershipTransferred {
+                content_id,
+                from,
+                to: new_owner,
+                memo,
+                relayed_by,
+                seq,
+            });
+            Ok(())
+        }
+
+        /// Appends a transfer to the provenance history of a content item.
+        /// The history is unbounded: each entry occupies its own storage cell, so a long history
+        /// does not make transfers more expensive, and it is read page by page. It only stops
+        /// growing at `u32::MAX` entries, which is unreachable in practice.
+        fn record_provenance(&mut self, content_id: u64, from: AccountId, to: AccountId) {
+            let index = self.transfer_count(content_id);
+            if let Some(count) = index.checked_add(1) {
+                self.provenance.insert((content_id, index), &ProvenanceEntry {
+                    from,
+                    to,
+                    block: self.env().block_number(),
+                    timestamp: self.env().block_timestamp(),
+                });
+                self.transfer_counts.insert(content_id, &count);
+            }
+        }
+
+        /// Checks that a content record may change hands at all, whoever initiates the transfer.
+        ///
+        /// # Errors
+        /// - Returns `Error::OwnershipRenounced` if the content's ownership was renounced.
+        /// - Returns `Error::ContentSoulbound` if the content is soulbound.
+        /// - Returns `Error::ContentNotActive` if the content's status is not `Active`.
+        fn ensure_transferable(record: &Content) -> Result<()> {
+            if record.renounced {
+                return Err(Error::OwnershipRenounced);
+            }
+            if record.soulbound {
+                return Err(Error::ContentSoulbound);
+            }
+            if record.status != ContentStatus::Active {
+                return Err(Error::ContentNotActive);
+            }
+            Ok(())
+        }
+
+        /// Checks that `to` is a meaningful recipient for content currently owned by `from`.
+        ///
+        /// # Errors
+        /// - Returns `Error::SelfTransfer` if `to` is `from`.
+        /// - Returns `Error::ZeroAddressRecipient` if `to` is the all-zero account.
+        fn ensure_valid_recipient(from: AccountId, to: AccountId) -> Result<()> {
+            if to == from {
+                return Err(Error::SelfTransfer);
+            }
+            if to == AccountId::from([0u8; 32]) {
+                return Err(Error::ZeroAddressRecipient);
+            }
+            Ok(())
+        }
+
+        /// Appends a content ID to an owner's index and increments their owned-content counter.
+        ///
+        /// # Errors
+        /// - Returns `Error::OwnedCountOverflow` if the owner's counter would overflow. Nothing is written in that case.
+        fn add_to_owner_index(&mut self, owner: AccountId, content_id: u64) -> Result<()> {
+            let position = self.balance_of(owner);
+            let count = position.checked_add(1).ok_or(Error::OwnedCountOverflow)?;
+            self.owned_contents.insert((owner, position), &content_id);
+            self.owned_content_position.insert(content_id, &position);
+            self.owned_count.insert(owner, &count);
+            Ok(())
+        }
+
+        /// Removes a content ID from an owner's index and decrements their owned-content counter.
+        /// The owner's last entry is moved into the freed position (swap-remove), so the order
+        /// reported by `contents_of` is not stable across transfers.
+        ///
+        /// # Errors
+        /// - Returns `Error::OwnedCountOverflow` if the owner's counter would underflow.
+        fn remove_from_owner_index(&mut self, owner: AccountId, content_id: u64) -> Result<()> {
+            let last = self.balance_of(owner)
+                .checked_sub(1)
+                .ok_or(Error::OwnedCountOverflow)?;
+            let position = self.owned_content_position.get(content_id).unwrap_or(last);
+            if position != last {
+                if let Some(last_id) = self.owned_contents.get((owner, last)) {
+                    self.owned_contents.insert((owner, position), &last_id);
+                    self.owned_content_position.insert(last_id, &position);
+                }
+            }
+            self.owned_contents.remove((owner, last));
+            self.owned_content_position.remove(content_id);
+            self.owned_count.insert(owner, &last);
+            Ok(())
+        }
+
+        /// Advances the event sequence counter and returns the number for the next event.
+        /// Saturates rather than wrapping, which is unreachable in practice for a `u64`.
+        fn next_event_seq(&mut self) -> u64 {
+            self.last_event_seq = self.last_event_seq.saturating_add(1);
+            self.last_event_seq
+        }
+
+        /// Retrieves a content record by its unique identifier.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content to retrieve.
+        ///
+        /// # Returns
+        /// - An `Option` containing the content record if found, or `None` if not found.
+        #[ink(message)]
+        pub fn get_content(&self, content_id: u64) -> Option<Content> {
+            self.contents.get(content_id)
+        }
+
+        /// Returns when a content item was registered, as a proof of existence, and the oracle data
+        /// version it was validated against. Imported content reports the time and oracle version of
+        /// its import, not of its original registration.
+        ///
+        /// # Returns
+        /// - `None` if the content ID is not found or the content predates registration tracking.
+        #[ink(message)]
+        pub fn registration_info(&self, content_id: u64) -> Option<RegistrationInfo> {
+            self.registrations.get(content_id)
+        }
+
+        /// Returns the number of content records currently in existence.
+        ///
+        /// # Returns
+        /// - The number of registered content records.
+        #[ink(message)]
+        pub fn total_supply(&self) -> u64 {
+            self.content_count
+        }
+
+        /// Returns the number of content records owned by an account.
+        ///
+        /// # Arguments
+        /// - `owner`: The AccountId to query.
+        ///
+        /// # Returns
+        /// - The number of content records owned by `owner`.
+        #[ink(message)]
+        pub fn balance_of(&self, owner: AccountId) -> u32 {
+            self.owned_count.get(owner).unwrap_or(0)
+        }
+
+        /// Returns a page of the content IDs owned by an account.
+        /// The order is not stable: when content is transferred away, the owner's last entry
+        /// takes its position (swap-remove).
+        ///
+        /// # Arguments
+        /// - `owner`: The AccountId whose content to list.
+        /// - `start`: The position in the owner's index to start from.
+        /// - `limit`: The maximum number of IDs to return, capped at `MAX_PAGE_SIZE`.
+        ///
+        /// # Returns
+        /// - A `Vec` of content IDs, empty if `start` is past the end.
+        #[ink(message)]
+        pub fn contents_of(&self, owner: AccountId, start: u32, limit: u32) -> Vec<u64> {
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.balance_of(owner));
+            (start..end)
+                .filter_map(|position| self.owned_contents.get((owner, position)))
+                .collect()
+        }
+
+        /// Returns the original creator of a content item, or `None` if the content ID is not found.
+        #[ink(message)]
+        pub fn creator_of(&self, content_id: u64) -> Option<AccountId> {
+            self.contents.get(content_id).map(|record| record.creator)
+        }
+
+        /// Returns a page of the existing content IDs created by an account, whoever owns them now.
+        /// The order is not stable: when content is burned, the creator's last entry takes its position.
+        ///
+        /// # Arguments
+        /// - `creator`: The AccountId whose works to list.
+        /// - `start`: The position in the creator's index to start from.
+        /// - `limit`: The maximum number of IDs to return, capped at `MAX_PAGE_SIZE`.
+        ///
+        /// # Returns
+        /// - A `Vec` of content IDs, empty if `start` is past the end.
+        #[ink(message)]
+        pub fn created_by(&self, creator: AccountId, start: u32, limit: u32) -> Vec<u64> {
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.created_count.get(creator).unwrap_or(0));
+            (start..end)
+                .filter_map(|position| self.created_contents.get((creator, position)))
+                .collect()
+        }
+
+        /// Returns a page of the transfer history of a content item, oldest first.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `start`: The index of the first entry to return.
+        /// - `limit`: The maximum number of entries to return, capped at `MAX_PAGE_SIZE`.
+        ///
+        /// # Returns
+        /// - A `Vec` of provenance entries, empty if `start` is past the end or the content is unknown.
+        #[ink(message)]
+        pub fn transfer_history(&self, content_id: u64, start: u32, limit: u32) -> Vec<ProvenanceEntry> {
+            let end = start
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(self.transfer_count(content_id));
+            (start..end)
+                .filter_map(|index| self.provenance.get((content_id, index)))
+                .collect()
+        }
+
+        /// Returns the number of times a content item changed hands (0 for unknown IDs).
+        #[ink(message)]
+        pub fn transfer_count(&self, content_id: u64) -> u32 {
+            self.transfer_counts.get(content_id).unwrap_or(0)
+        }
+
+        /// Appends a completed sale to the sale history of a content item. Every sale path must call
+        /// this once the sale went through. Only the latest `MAX_SALE_HISTORY` sales are kept; like the
+        /// transfer history, the count stops growing at `u32::MAX`.
+        fn record_sale(&mut self, content_id: u64, seller: AccountId, buyer: AccountId, price: Balance, currency: Option<AccountId>) {
+            let index = self.sale_count(content_id);
+            if let Some(count) = index.checked_add(1) {
+                self.sale_records.insert((content_id, index % MAX_SALE_HISTORY), &SaleRecord {
+                    buyer,
+                    seller,
+                    price,
+                    currency,
+                    timestamp: self.env().block_timestamp(),
+                });
+                self.sale_counts.insert(content_id, &count);
+            }
+        }
+
+        /// Returns a page of the kept sale history of a content item, oldest first: fixed-price,
+        /// Dutch-auction, and token purchases, accepted offers, and settled auctions.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `start`: The position of the first entry to return among the kept ones, at most the latest
+        ///   `MAX_SALE_HISTORY` sales.
+        /// - `limit`: The maximum number of entries to return, capped at `MAX_PAGE_SIZE`.
+        ///
+        /// # Returns
+        /// - A `Vec` of sale records, empty if `start` is past the end or the content is unknown.
+        #[ink(message)]
+        pub fn sale_history(&self, content_id: u64, start: u32, limit: u32) -> Vec<SaleRecord> {
+            let count = self.sale_count(content_id);
+            let first = count.saturating_sub(MAX_SALE_HISTORY);
+            let end = first
+                .saturating_add(start)
+                .saturating_add(limit.min(MAX_PAGE_SIZE))
+                .min(count);
+            (first.saturating_add(start)..end)
+                .filter_map(|index| self.sale_records.get((content_id, index % MAX_SALE_HISTORY)))
+                .collect()
+        }
+
+        /// Returns the most recent sale of a content item, if it was ever sold.
+        #[ink(message)]
+        pub fn last_sale(&self, content_id: u64) -> Option<SaleRecord> {
+            let index = self.sale_count(content_id).checked_sub(1)?;
+            self.sale_records.get((content_id, index % MAX_SALE_HISTORY))
+        }
+
+        /// Returns the number of sales ever recorded for a content item, including those no longer kept.
+        #[ink(message)]
+        pub fn sale_count(&self, content_id: u64) -> u32 {
+            self.sale_counts.get(content_id).unwrap_or(0)
+        }
+
+        /// Retrieves several content records in one call.
+        ///
+        /// # Arguments
+        /// - `ids`: The content IDs to retrieve, at most `MAX_BATCH_SIZE`.
+        ///
+        /// # Returns
+        /// - A `Vec` in the same order as `ids`, holding `None` for IDs that are not found.
+        ///
+        /// # Errors
+        /// - Returns `Error::BatchTooLarge` if more than `MAX_BATCH_SIZE` IDs are requested.
+        #[ink(message)]
+        pub fn get_content_batch(&self, ids: Vec<u64>) -> Result<Vec<Option<Content>>> {
+            Self::ensure_batch_size(ids.len())?;
+            Ok(ids.into_iter().map(|content_id| self.contents.get(content_id)).collect())
+        }
+
+        /// Checks a batch length against `MAX_BATCH_SIZE`.
+        ///
+        /// # Errors
+        /// - Returns `Error::BatchTooLarge` if `len` exceeds `MAX_BATCH_SIZE`.
+        fn ensure_batch_size(len: usize) -> Result<()> {
+            if len > MAX_BATCH_SIZE as usize {
+                return Err(Error::BatchTooLarge);
+            }
+            Ok(())
+        }
+
+        /// Returns the current owner of a content record.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        ///
+        /// # Returns
+        /// - An `Option` containing the owner's AccountId, or `None` if the content is not found
+        ///   or its ownership was renounced.
+        #[ink(message)]
+        pub fn owner_of(&self, content_id: u64) -> Option<AccountId> {
+            self.owners.get(content_id)
+        }
+
+        /// Checks whether a content record exists.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        ///
+        /// # Returns
+        /// - `true` if the content exists, `false` otherwise.
+        #[ink(message)]
+        pub fn content_exists(&self, content_id: u64) -> bool {
+            self.contents.contains(content_id)
+        }
+
+        /// Checks whether an account is the current owner of a content record.
+        ///
+        /// # Arguments
+        /// - `content_id`: The unique ID of the content.
+        /// - `account`: The AccountId to check.
+        ///
+        /// # Returns
+        /// - `true` if `account` owns the content, `false` otherwise (including for unknown IDs).
+        #[ink(message)]
+        pub fn is_owner(&self, content_id: u64, account: AccountId) -> bool {
+            self.owner_of(content_id) == Some(account)
+        }
+
+        /// Checks whether a content record is soulbound, i.e. can never be transferred.
+        ///
+        /// # Returns
+        /// - `true` if the content is soulbound, `false` otherwise (including for unknown IDs).
+        #[ink(message)]
+        pub fn is_soulbound(&self, content_id: u64) -> bool {
+            self.contents.get(content_id).is_some_and(|record| record.soulbound)
+        }
+
+        /// Returns the existing content records within a range of content IDs.
+        /// IDs without a record (e.g. removed content) are skipped.
+        ///
+        /// # Arguments
+        /// - `start_id`: The first content ID to scan.
+        /// - `limit`: The number of IDs to scan, capped at `MAX_PAGE_SIZE`. Skipped IDs count towards
+        ///   the limit, so the result may hold fewer than `limit` entries.
+        ///
+        /// # Returns
+        /// - A `Vec` of `(content_id, content)` pairs in ascending ID order.
+        #[ink(message)]
+        pub fn get_contents(&self, start_id: u64, limit: u32) -> Vec<(u64, Content)> {
+            let end = start_id.saturating_add(u64::from(limit.min(MAX_PAGE_SIZE)));
+            (start_id..end)
+                .filter_map(|content_id| self.contents.get(content_id).map(|content| (content_id, content)))
+                .collect()
+        }
+
+        /// Returns the content ID at a position in the global index of existing content.
+        /// Positions are dense in `0..total_supply()`, unlike content IDs. Burning content moves the
+        /// last entry into the freed position, so the order is not stable.
+        ///
+        /// # Arguments
+        /// - `index`: The position in the global index.
+        ///
+        /// # Returns
+        /// - An `Option` containing the content ID, or `None` if `index` is out of range.
+        #[ink(message)]
+        pub fn content_by_index(&self, index: u64) -> Option<u64> {
+            self.all_contents.get(index)
+        }
+
+        /// Exports the content records within an inclusive range of content IDs.
+        /// This is a pure query intended for rebuilding off-chain indexes; missing IDs are skipped.
+        ///
+        /// # Arguments
+        /// - `from_id`: The first content ID of the range.
+        /// - `to_id`: The last content ID of the range (inclusive).
+        ///
+        /// # Returns
+        /// - A `Vec` of `ContentExport` in ascending ID order, empty if `from_id > to_id`.
+        ///
+        /// # Errors
+        /// - Returns `Error::RangeTooLarge` if the range covers more than `MAX_EXPORT_RANGE` IDs.
+        #[ink(message)]
+        pub fn export_range(&self, from_id: u64, to_id: u64) -> Result<Vec<ContentExport>> {
+            if from_id > to_id {
+                return Ok(Vec::new());
+            }
+            if to_id - from_id >= MAX_EXPORT_RANGE {
+                return Err(Error::RangeTooLarge);
+            }
+            Ok((from_id..=to_id)
+                .filter_map(|content_id| {
+                    self.contents.get(content_id).map(|content| {
+                        let registration = self.registrations.get(content_id);
+                        ContentExport {
+                            content_id,
+                            content_hash: content.content_hash,
+                            owner: content.owner,
+                            renounced: content.renounced,
+                            status: content.status,
+                            registered_at_block: registration.map(|info| info.registered_at_block),
+                            registered_at_time: registration.map(|info| info.registered_at_time),
+                        }
+                    })
+                })
+                .collect())
+        }
+
+        /// Looks up the content ID registered for a content hash.
+        /// Matching is exact and case-sensitive, the same as the duplicate check in `register_content`.
+        ///
+        /// # Arguments
+        /// - `content_hash`: The content hash to look up.
+        ///
+        /// # Returns
+        /// - An `Option` containing the content ID if the hash is registered, or `None` otherwise.
+        #[ink(message)]
+        pub fn get_content_id_by_hash(&self, content_hash: String) -> Option<u64> {
+            self.content_hash_to_id.get(&content_hash).copied()
+        }
+
+        /// Checks whether a content hash is already registered.
+        ///
+        /// # Arguments
+        /// - `content_hash`: The content hash to check.
+        ///
+        /// # Returns
+        /// - `true` if the hash is registered, `false` otherwise.
+        #[ink(message)]
+        pub fn is_registered(&self, content_hash: String) -> bool {
+            self.content_hash_to_id.contains_key(&content_hash)
+        }
+
+        /// Returns the current oracle data stored in the contract, i.e. the first accepted prefix.
+        /// Use `get_oracle_prefixes` to list all of them.
+        ///
+        /// # Returns
+        /// - A `String` containing the oracle data, empty if no prefix is accepted.
+        #[ink(message)]
+        pub fn get_oracle_data(&self) -> String {
+            self.oracle_prefixes.first().cloned().unwrap_or_default()
+        }
+
+        /// Returns the version of the deployed contract logic.
+        ///
+        /// # Returns
+        /// - `CONTRACT_VERSION`.
+        #[ink(message)]
+        pub fn contract_version(&self) -> u32 {
+            CONTRACT_VERSION
+        }
+
+        /// Returns a summary of the contract's configuration and state.
+        ///
+        /// # Returns
+        /// - A `ContractInfo` bundling the admin count, version, total supply, and oracle data.
+        #[ink(message)]
+        pub fn contract_info(&self) -> ContractInfo {
+            ContractInfo {
+                admin_count: self.admin_count,
+                version: CONTRACT_VERSION,
+                total_supply: self.total_supply(),
+                oracle_data: self.get_oracle_data(),
+            }
+        }
+
+        /// Returns the sequence number of the most recently emitted event.
+        /// Sequence numbers start at 1 and increase by one per event, so a gap indicates a missed event.
+        ///
+        /// # Returns
+        /// - The last emitted sequence number, or `0` if no event has been emitted yet.
+        #[ink(message)]
+        pub fn get_last_event_seq(&self) -> u64 {
+            self.last_event_seq
+        }
+    }
+
+    //----------------------------------
+    // Unit Tests
+    //----------------------------------
+
+    /// # ContentOwnership Contract Test Suite
+    ///
+    /// This module contains comprehensive tests for the `ContentOwnership` contract,
+    /// covering core functionality, edge cases, and security requirements.
+    ///
+    /// ## Test Accounts Convention:
+    /// - **Alice**: Default caller/initiator (ink! default account)
+    /// - **Bob**: Counterparty account
+    /// - **Charlie**: Unauthorized third party
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use ink::env::{test, DefaultEnvironment};
+        use secp256k1::{Message, PublicKey, SecretKey, SECP256K1};
+
+        /// A far-off expiry for purchase offers in tests that do not exercise expiry.
+        const OFFER_EXPIRY: Timestamp = Timestamp::MAX;
+
+        /// Encodes a value the way the off-chain environment stores an event topic.
+        /// Only valid for values whose SCALE encoding fits in 32 bytes.
+        fn topic_of<T: scale::Encode>(value: &T) -> Vec<u8> {
+            let mut topic = scale::Encode::encode(value);
+            topic.resize(32, 0);
+            topic
+        }
+
+        /// Derives an ECDSA key pair from `seed` and returns it with the matching AccountId.
+        fn ecdsa_account(seed: u8) -> (SecretKey, AccountId) {
+            let secret_key = SecretKey::from_slice(&[seed; 32]).unwrap();
+            let public_key = PublicKey::from_secret_key(SECP256K1, &secret_key).serialize();
+            let mut account = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(&public_key, &mut account);
+            (secret_key, AccountId::from(account))
+        }
+
+        /// Signs a message hash, returning the 65-byte recoverable signature expected by the contract.
+        fn ecdsa_sign(secret_key: &SecretKey, message_hash: [u8; 32]) -> [u8; 65] {
+            let message = Message::from_digest_slice(&message_hash).unwrap();
+            let (recovery_id, compact) = SECP256K1
+                .sign_ecdsa_recoverable(&message, secret_key)
+                .serialize_compact();
+            let mut signature = [0u8; 65];
+            signature[..64].copy_from_slice(&compact);
+            signature[64] = recovery_id.to_i32() as u8;
+            signature
+        }
+
+        /// Attaches `amount` to the next call, crediting it to the contract like the chain would.
+        fn send_value(amount: Balance) {
+            let contract = test::callee::<DefaultEnvironment>();
+            let balance = test::get_account_balance::<DefaultEnvironment>(contract).unwrap_or(0);
+            test::set_account_balance::<DefaultEnvironment>(contract, balance + amount);
+            test::set_value_transferred::<DefaultEnvironment>(amount);
+        }
+
+        /// Tests the `new` constructor to ensure the contract initializes correctly.
+        /// - Verifies that the admin is set to the caller.
+        /// - Verifies that the default oracle data is initialized.
+        #[ink::test]
+        fn test_new() {
+            let contract = ContentOwnership::new();
+            let caller = test::default_accounts::<DefaultEnvironment>().alice;
+            assert!(contract.is_admin(caller));
+            assert_eq!(contract.admin_count(), 1);
+            assert_eq!(contract.get_oracle_data(), "default_oracle");
+        }
+
+        /// Tests the `update_oracle_data` function for successful updates by the admin.
+        /// - Verifies that the oracle data is updated when called by the admin.
+        #[ink::test]
+        fn test_update_oracle_data() {
+            let mut contract = ContentOwnership::new();
+            let caller = test::default_accounts::<DefaultEnvironment>().alice;
+            test::set_caller::<DefaultEnvironment>(caller);
+
+            assert!(contract.update_oracle_data(String::from("new_oracle")).is_ok());
+            assert_eq!(contract.get_oracle_data(), "new_oracle");
+        }
+
+        /// Tests the `update_oracle_data` function for unauthorized access.
+        /// - Verifies that a non-admin user cannot update the oracle data.
+        #[ink::test]
+        fn test_update_oracle_data_not_admin() {
+            let mut contract = ContentOwnership::new();
+            let caller = test::default_accounts::<DefaultEnvironment>().bob;
+            test::set_caller::<DefaultEnvironment>(caller);
+
+            assert_eq!(
+                contract.update_oracle_data(String::from("new_oracle")),
+                Err(Error::NotAdmin)
+            );
+        }
+
+        /// Tests the `register_content` function for successful content registration.
+        /// - Verifies that valid content is registered with a unique ID.
+        /// - Verifies that the caller becomes the owner of the content.
+        #[ink::test]
+        fn test_register_content() {
+            let mut contract = ContentOwnership::new();
+            let caller = test::default_accounts::<DefaultEnvironment>().alice;
+            test::set_caller::<DefaultEnvironment>(caller);
+
+            let content_hash = String::from("default_oracle_content");
+            let content_id = contract.register_content(content_hash.clone()).unwrap();
+            assert_eq!(content_id, 1);
+
+            let content = contract.get_content(content_id).unwrap();
+            assert_eq!(content.content_hash, content_hash);
+            assert_eq!(content.owner, caller);
+        }
+
+        /// Tests the `register_content` function for invalid content.
+        /// - Verifies that content with an invalid hash is rejected.
+        #[ink::test]
+        fn test_register_content_invalid() {
+            let mut contract = ContentOwnership::new();
+            let caller = test::default_accounts::<DefaultEnvironment>().alice;
+            test::set_caller::<DefaultEnvironment>(caller);
+
+            let content_hash = String::from("invalid_content");
+            assert_eq!(contract.register_content(content_hash), Err(Error::InvalidContent));
+        }
+
+        /// Tests the `transfer_ownership` function for successful ownership transfer.
+        /// - Verifies that the current owner can transfer ownership to another account.
+        #[ink::test]
+        fn test_transfer_ownership() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+            test::set_caller::<DefaultEnvironment>(alice);
+
+            let content_hash = String::from("default_oracle_content");
+            let content_id = contract.register_content(content_hash.clone()).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(alice);
+            assert!(contract.transfer_ownership(content_id, bob).is_ok());
+
+            let content = contract.get_content(content_id).unwrap();
+            assert_eq!(content.owner, bob);
+        }
+
+        /// Tests the `transfer_ownership` function for unauthorized access.
+        /// - Verifies that a non-owner cannot transfer ownership.
+        #[ink::test]
+        fn test_transfer_ownership_not_owner() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+            test::set_caller::<DefaultEnvironment>(alice);
+
+            let content_hash = String::from("default_oracle_content");
+            let content_id = contract.register_content(content_hash.clone()).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(bob);
+            assert_eq!(contract.transfer_ownership(content_id, alice), Err(Error::NotOwner));
+        }
+
+        /// Tests the `transfer_ownership` function for unauthorized access by Charlie.
+        /// - Verifies that a third party (Charlie) cannot transfer ownership of content they do not own.
+        #[ink::test]
+        fn test_transfer_ownership_unauthorized_charlie() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let charlie = test::default_accounts::<DefaultEnvironment>().charlie;
+            test::set_caller::<DefaultEnvironment>(alice);
+
+            let content_hash = String::from("default_oracle_content");
+            let content_id = contract.register_content(content_hash.clone()).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(charlie);
+            assert_eq!(
+                contract.transfer_ownership(content_id, alice),
+                Err(Error::NotOwner)
+            );
+        }
+
+        /// Tests the `get_content` function for non-existent content.
+        /// - Verifies that querying a non-existent content ID returns `None`.
+        #[ink::test]
+        fn test_get_content_not_found() {
+            let contract = ContentOwnership::new();
+            assert!(contract.get_content(999).is_none());
+        }
+
+        /// Tests the `register_content` function for duplicate content registration.
+        /// - Verifies that registering a registered hash fails with `AlreadyRegistered`, for the owner and for anyone else.
+        /// - Verifies the existing ID remains queryable and the duplicate leaves ownership unchanged.
+        #[ink::test]
+        fn test_register_content_duplicate() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+
+            let content_hash = String::from("default_oracle_content");
+            let content_id = contract.register_content(content_hash.clone()).unwrap();
+            assert_eq!(contract.register_content(content_hash.clone()), Err(Error::AlreadyRegistered));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.register_content(content_hash.clone()), Err(Error::AlreadyRegistered));
+            assert_eq!(contract.register_content_soulbound(content_hash.clone()), Err(Error::AlreadyRegistered));
+            assert_eq!(contract.get_content_id_by_hash(content_hash), Some(content_id));
+            assert_eq!(contract.owner_of(content_id), Some(accounts.alice));
+            assert_eq!(contract.balance_of(accounts.bob), 0);
+            assert_eq!(contract.total_supply(), 1);
+        }
+
+        /// Tests the `register_content` function for counter overflow.
+        /// - Verifies that attempting to register content when the counter is at its maximum value results in an error.
+        #[ink::test]
+        fn test_counter_overflow() {
+            let mut contract = ContentOwnership::new();
+            contract.next_content_id = u64::MAX;
+
+            let caller = test::default_accounts::<DefaultEnvironment>().alice;
+            test::set_caller::<DefaultEnvironment>(caller);
+
+            let content_hash = String::from("default_oracle_content");
+            assert_eq!(contract.register_content(content_hash), Err(Error::CounterOverflow));
+        }
+
+        /// Tests that `register_content` emits a `ContentRegistered` event.
+        /// - Verifies the event payload carries the content ID, hash, and owner.
+        /// - Verifies that registering a duplicate hash does not emit a second event.
+        #[ink::test]
+        fn test_register_content_emits_event() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            test::set_caller::<DefaultEnvironment>(alice);
+
+            let content_hash = String::from("default_oracle_content");
+            let content_id = contract.register_content(content_hash.clone()).unwrap();
+            assert_eq!(contract.register_content(content_hash.clone()), Err(Error::AlreadyRegistered));
+
+            let events: Vec<_> = test::recorded_events().collect();
+            assert_eq!(events.len(), 1);
+            let event = <ContentRegistered as scale::Decode>::decode(&mut &events[0].data[..]).unwrap();
+            assert_eq!(event.content_id, content_id);
+            assert_eq!(event.content_hash, content_hash);
+            assert_eq!(event.owner, alice);
+        }
+
+        /// Tests that `transfer_ownership` emits an `OwnershipTransferred` event.
+        /// - Verifies the event payload carries the content ID, previous owner, and new owner.
+        /// - Verifies that a rejected transfer does not emit an event.
+        #[ink::test]
+        fn test_transfer_ownership_emits_event() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+            test::set_caller::<DefaultEnvironment>(alice);
+
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            assert!(contract.transfer_ownership(content_id, bob).is_ok());
+
+            // Alice no longer owns the content, so this transfer fails and emits nothing.
+            assert_eq!(contract.transfer_ownership(content_id, alice), Err(Error::NotOwner));
+
+            let events: Vec<_> = test::recorded_events().collect();
+            assert_eq!(events.len(), 2);
+            let event = <OwnershipTransferred as scale::Decode>::decode(&mut &events[1].data[..]).unwrap();
+            assert_eq!(event.content_id, content_id);
+            assert_eq!(event.from, alice);
+            assert_eq!(event.to, bob);
+        }
+
+        /// Tests that `update_oracle_data` emits an `OracleDataUpdated` event in order with registrations.
+        /// - Verifies the event carries the updating account and the old and new values.
+        /// - Verifies the event is ordered between the registrations made before and after the update.
+        #[ink::test]
+        fn test_update_oracle_data_emits_event_in_order() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            test::set_caller::<DefaultEnvironment>(alice);
+
+            let first_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            contract.update_oracle_data(String::from("new_oracle")).unwrap();
+            let second_id = contract.register_content(String::from("new_oracle_content")).unwrap();
+
+            let events: Vec<_> = test::recorded_events().collect();
+            assert_eq!(events.len(), 3);
+            let first = <ContentRegistered as scale::Decode>::decode(&mut &events[0].data[..]).unwrap();
+            assert_eq!(first.content_id, first_id);
+            let update = <OracleDataUpdated as scale::Decode>::decode(&mut &events[1].data[..]).unwrap();
+            assert_eq!(update.updated_by, alice);
+            assert_eq!(update.old_data, "default_oracle");
+            assert_eq!(update.new_data, "new_oracle");
+            let second = <ContentRegistered as scale::Decode>::decode(&mut &events[2].data[..]).unwrap();
+            assert_eq!(second.content_id, second_id);
+        }
+
+        /// Tests that `update_oracle_data` emits an event when the value does not change.
+        /// - Verifies that setting the same oracle data succeeds and records identical old and new values.
+        /// - Verifies that a rejected non-admin update emits nothing.
+        #[ink::test]
+        fn test_update_oracle_data_same_value_emits_event() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+            test::set_caller::<DefaultEnvironment>(alice);
+
+            assert!(contract.update_oracle_data(String::from("default_oracle")).is_ok());
+            test::set_caller::<DefaultEnvironment>(bob);
+            assert_eq!(contract.update_oracle_data(String::from("other")), Err(Error::NotAdmin));
+
+            let events: Vec<_> = test::recorded_events().collect();
+            assert_eq!(events.len(), 1);
+            let update = <OracleDataUpdated as scale::Decode>::decode(&mut &events[0].data[..]).unwrap();
+            assert_eq!(update.old_data, update.new_data);
+        }
+
+        /// Tests the topics attached to registration and transfer events.
+        /// - Verifies `content_id` and `owner` are topics of `ContentRegistered`.
+        /// - Verifies `content_id`, `from`, and `to` are topics of `OwnershipTransferred`.
+        #[ink::test]
+        fn test_event_topics() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+            test::set_caller::<DefaultEnvironment>(alice);
+
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            contract.transfer_ownership(content_id, bob).unwrap();
+
+            let events: Vec<_> = test::recorded_events().collect();
+            // The first topic is the event signature; field topics follow in declaration order.
+            assert_eq!(events[0].topics.len(), 3);
+            assert_eq!(events[0].topics[1], topic_of(&content_id));
+            assert_eq!(events[0].topics[2], topic_of(&alice));
+            assert_eq!(events[1].topics.len(), 4);
+            assert_eq!(events[1].topics[1], topic_of(&content_id));
+            assert_eq!(events[1].topics[2], topic_of(&alice));
+            assert_eq!(events[1].topics[3], topic_of(&bob));
+        }
+
+        /// Tests the event sequence counter.
+        /// - Verifies `get_last_event_seq` starts at 0 and advances by one per emitted event.
+        /// - Verifies each event carries its own sequence number.
+        #[ink::test]
+        fn test_event_sequence() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+            test::set_caller::<DefaultEnvironment>(alice);
+            assert_eq!(contract.get_last_event_seq(), 0);
+
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            contract.update_oracle_data(String::from("new_oracle")).unwrap();
+            contract.transfer_ownership(content_id, bob).unwrap();
+            assert_eq!(contract.get_last_event_seq(), 3);
+
+            let events: Vec<_> = test::recorded_events().collect();
+            let registered = <ContentRegistered as scale::Decode>::decode(&mut &events[0].data[..]).unwrap();
+            let updated = <OracleDataUpdated as scale::Decode>::decode(&mut &events[1].data[..]).unwrap();
+            let transferred = <OwnershipTransferred as scale::Decode>::decode(&mut &events[2].data[..]).unwrap();
+            assert_eq!(registered.seq, 1);
+            assert_eq!(updated.seq, 2);
+            assert_eq!(transferred.seq, 3);
+        }
+
+        /// Tests the `get_content_id_by_hash` and `is_registered` queries.
+        /// - Verifies a registered hash resolves to the ID returned by `register_content`.
+        /// - Verifies an unregistered hash is reported as missing.
+        /// - Verifies matching is case-sensitive, consistent with the duplicate check.
+        #[ink::test]
+        fn test_get_content_id_by_hash() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            test::set_caller::<DefaultEnvironment>(alice);
+
+            let content_hash = String::from("default_oracle_content");
+            let content_id = contract.register_content(content_hash.clone()).unwrap();
+
+            assert_eq!(contract.get_content_id_by_hash(content_hash.clone()), Some(content_id));
+            assert!(contract.is_registered(content_hash));
+
+            let unregistered = String::from("default_oracle_other");
+            assert_eq!(contract.get_content_id_by_hash(unregistered.clone()), None);
+            assert!(!contract.is_registered(unregistered));
+
+            let different_case = String::from("default_oracle_CONTENT");
+            assert_eq!(contract.get_content_id_by_hash(different_case.clone()), None);
+            assert!(!contract.is_registered(different_case.clone()));
+            assert_ne!(contract.register_content(different_case).unwrap(), content_id);
+        }
+
+        /// Tests the `owner_of`, `content_exists`, and `is_owner` queries.
+        /// - Verifies that IDs registered by different callers report their respective owners.
+        /// - Verifies that missing IDs report no owner and do not exist.
+        /// - Verifies that ownership queries follow a transfer.
+        #[ink::test]
+        fn test_owner_queries() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+            let charlie = test::default_accounts::<DefaultEnvironment>().charlie;
+
+            test::set_caller::<DefaultEnvironment>(alice);
+            let alice_id = contract.register_content(String::from("default_oracle_alice")).unwrap();
+            test::set_caller::<DefaultEnvironment>(bob);
+            let bob_id = contract.register_content(String::from("default_oracle_bob")).unwrap();
+
+            assert_eq!(contract.owner_of(alice_id), Some(alice));
+            assert_eq!(contract.owner_of(bob_id), Some(bob));
+            assert!(contract.content_exists(alice_id));
+            assert!(contract.is_owner(alice_id, alice));
+            assert!(!contract.is_owner(alice_id, bob));
+
+            assert_eq!(contract.owner_of(999), None);
+            assert!(!contract.content_exists(999));
+            assert!(!contract.is_owner(999, alice));
+
+            contract.transfer_ownership(bob_id, charlie).unwrap();
+            assert_eq!(contract.owner_of(bob_id), Some(charlie));
+            assert!(!contract.is_owner(bob_id, bob));
+        }
+
+        /// Tests the public `Content` accessors as seen by a linking contract.
+        /// - Verifies that a record returned by `get_content` survives a SCALE round trip,
+        ///   as it would when received through a cross-contract call.
+        /// - Verifies that `content_hash` and `owner` expose the decoded fields and that `Content::new` builds an equal fixture.
+        #[ink::test]
+        fn test_content_accessors() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            test::set_caller::<DefaultEnvironment>(alice);
+
+            let content_hash = String::from("default_oracle_content");
+            let content_id = contract.register_content(content_hash.clone()).unwrap();
+
+            let encoded = scale::Encode::encode(&contract.get_content(content_id));
+            let decoded = <Option<Content> as scale::Decode>::decode(&mut &encoded[..]).unwrap().unwrap();
+            assert_eq!(decoded.content_hash(), content_hash);
+            assert_eq!(decoded.owner(), alice);
+            assert_eq!(decoded, Content::new(content_hash, alice));
+        }
+
+        /// Tests the `total_supply` and `balance_of` queries.
+        /// - Verifies counts across multiple registrations by different callers.
+        /// - Verifies a transfer moves one unit from the old owner to the new owner.
+        /// - Verifies the admin, having registered nothing, owns nothing.
+        #[ink::test]
+        fn test_supply_and_balances() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            assert_eq!(contract.total_supply(), 0);
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let bob_id = contract.register_content(String::from("default_oracle_bob_1")).unwrap();
+            contract.register_content(String::from("default_oracle_bob_2")).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            contract.register_content(String::from("default_oracle_charlie")).unwrap();
+
+            assert_eq!(contract.total_supply(), 3);
+            assert_eq!(contract.balance_of(accounts.bob), 2);
+            assert_eq!(contract.balance_of(accounts.charlie), 1);
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.transfer_ownership(bob_id, accounts.charlie).unwrap();
+            assert_eq!(contract.total_supply(), 3);
+            assert_eq!(contract.balance_of(accounts.bob), 1);
+            assert_eq!(contract.balance_of(accounts.charlie), 2);
+
+            assert_eq!(contract.balance_of(accounts.alice), 0);
+        }
+
+        /// Tests that owned-content counters use checked arithmetic.
+        /// - Verifies a transfer to an account whose counter is at its maximum fails without changing ownership.
+        #[ink::test]
+        fn test_owned_count_overflow() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+            test::set_caller::<DefaultEnvironment>(alice);
+
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            contract.owned_count.insert(bob, &u32::MAX);
+
+            assert_eq!(contract.transfer_ownership(content_id, bob), Err(Error::OwnedCountOverflow));
+            assert_eq!(contract.owner_of(content_id), Some(alice));
+            assert_eq!(contract.balance_of(alice), 1);
+        }
+
+        /// Tests the `contents_of` owner enumeration.
+        /// - Verifies five registrations across two accounts are listed per owner.
+        /// - Verifies a transfer removes the item from the sender (swap-remove) and appends it for the recipient.
+        /// - Verifies pagination and the empty page past the end.
+        #[ink::test]
+        fn test_contents_of() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+
+            test::set_caller::<DefaultEnvironment>(alice);
+            let a1 = contract.register_content(String::from("default_oracle_a1")).unwrap();
+            let a2 = contract.register_content(String::from("default_oracle_a2")).unwrap();
+            let a3 = contract.register_content(String::from("default_oracle_a3")).unwrap();
+            test::set_caller::<DefaultEnvironment>(bob);
+            let b1 = contract.register_content(String::from("default_oracle_b1")).unwrap();
+            let b2 = contract.register_content(String::from("default_oracle_b2")).unwrap();
+
+            assert_eq!(contract.contents_of(alice, 0, 10), vec![a1, a2, a3]);
+            assert_eq!(contract.contents_of(bob, 0, 10), vec![b1, b2]);
+
+            test::set_caller::<DefaultEnvironment>(alice);
+            contract.transfer_ownership(a1, bob).unwrap();
+
+            // `a3` was the last entry, so it takes the position freed by `a1`.
+            assert_eq!(contract.contents_of(alice, 0, 10), vec![a3, a2]);
+            assert_eq!(contract.contents_of(bob, 0, 10), vec![b1, b2, a1]);
+            assert_eq!(contract.contents_of(bob, 1, 1), vec![b2]);
+            assert!(contract.contents_of(bob, 3, 10).is_empty());
+        }
+
+        /// Tests the `get_contents` range listing.
+        /// - Verifies records are returned in ascending ID order.
+        /// - Verifies that a gap in IDs is skipped and still counts towards the limit.
+        /// - Verifies the limit is capped at `MAX_PAGE_SIZE`.
+        #[ink::test]
+        fn test_get_contents_skips_gaps() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            test::set_caller::<DefaultEnvironment>(alice);
+
+            for i in 1..=4 {
+                contract.register_content(format!("default_oracle_{}", i)).unwrap();
+            }
+            // Simulate a hole in the ID space.
+            contract.contents.remove(2);
+
+            let page = contract.get_contents(1, 10);
+            let ids: Vec<u64> = page.iter().map(|(id, _)| *id).collect();
+            assert_eq!(ids, vec![1, 3, 4]);
+            assert_eq!(page[1].1.content_hash(), "default_oracle_3");
+
+            let ids: Vec<u64> = contract.get_contents(1, 2).iter().map(|(id, _)| *id).collect();
+            assert_eq!(ids, vec![1]);
+
+            assert_eq!(contract.get_contents(1, u32::MAX).len(), 3);
+            assert!(contract.get_contents(5, 10).is_empty());
+        }
+
+        /// Tests the `content_by_index` enumeration.
+        /// - Verifies dense positions map to registered IDs in registration order.
+        /// - Verifies out-of-range positions return `None`.
+        #[ink::test]
+        fn test_content_by_index() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            test::set_caller::<DefaultEnvironment>(alice);
+
+            let first = contract.register_content(String::from("default_oracle_1")).unwrap();
+            let second = contract.register_content(String::from("default_oracle_2")).unwrap();
+
+            assert_eq!(contract.content_by_index(0), Some(first));
+            assert_eq!(contract.content_by_index(1), Some(second));
+            assert_eq!(contract.content_by_index(contract.total_supply()), None);
+        }
+
+        /// Tests the `get_content_batch` query.
+        /// - Verifies results preserve input order and hold `None` for missing IDs.
+        /// - Verifies batches larger than `MAX_BATCH_SIZE` are rejected while the maximum is accepted.
+        #[ink::test]
+        fn test_get_content_batch() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            test::set_caller::<DefaultEnvironment>(alice);
+
+            let first = contract.register_content(String::from("default_oracle_1")).unwrap();
+            let second = contract.register_content(String::from("default_oracle_2")).unwrap();
+
+            let batch = contract.get_content_batch(vec![second, 999, first]).unwrap();
+            assert_eq!(batch.len(), 3);
+            assert_eq!(batch[0].as_ref().unwrap().content_hash(), "default_oracle_2");
+            assert!(batch[1].is_none());
+            assert_eq!(batch[2].as_ref().unwrap().content_hash(), "default_oracle_1");
+
+            let max: Vec<u64> = (0..u64::from(MAX_BATCH_SIZE)).collect();
+            assert!(contract.get_content_batch(max).is_ok());
+            let oversized: Vec<u64> = (0..=u64::from(MAX_BATCH_SIZE)).collect();
+            assert_eq!(contract.get_content_batch(oversized), Err(Error::BatchTooLarge));
+        }
+
+        /// Tests the `is_admin`, `get_admin`, `contract_version`, and `contract_info` queries.
+        /// - Verifies the constructor caller is reported as admin.
+        /// - Verifies `contract_info` bundles the current state.
+        #[ink::test]
+        fn test_contract_info() {
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+            test::set_caller::<DefaultEnvironment>(bob);
+            let mut contract = ContentOwnership::new();
+            assert!(contract.is_admin(bob));
+            assert_eq!(contract.get_admin(), Some(bob));
+            assert_eq!(contract.contract_version(), CONTRACT_VERSION);
+
+            contract.register_content(String::from("default_oracle_content")).unwrap();
+            assert_eq!(
+                contract.contract_info(),
+                ContractInfo {
+                    admin_count: 1,
+                    version: CONTRACT_VERSION,
+                    total_supply: 1,
+                    oracle_data: String::from("default_oracle"),
+                }
+            );
+        }
+
+        /// Tests the `export_range` query.
+        /// - Verifies exported records are in ascending ID order and carry the current owner, status, and registration time.
+        /// - Verifies a range of exactly `MAX_EXPORT_RANGE` IDs is accepted and one more is rejected.
+        #[ink::test]
+        fn test_export_range() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+            test::set_caller::<DefaultEnvironment>(alice);
+
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            for i in 1..=3 {
+                contract.register_content(format!("default_oracle_{}", i)).unwrap();
+            }
+            contract.transfer_ownership(2, bob).unwrap();
+            contract.set_content_status(3, ContentStatus::Disputed).unwrap();
+
+            let export = contract.export_range(1, 3).unwrap();
+            let ids: Vec<u64> = export.iter().map(|entry| entry.content_id).collect();
+            assert_eq!(ids, vec![1, 2, 3]);
+            assert_eq!(export[1].content_hash, "default_oracle_2");
+            assert_eq!(export[1].owner, bob);
+            assert_eq!((export[1].status, export[2].status), (ContentStatus::Active, ContentStatus::Disputed));
+            let registration = contract.registration_info(2).unwrap();
+            assert_eq!(export[1].registered_at_block, Some(registration.registered_at_block));
+            assert_eq!(export[1].registered_at_time, Some(1_000));
+
+            assert_eq!(contract.export_range(1, MAX_EXPORT_RANGE).unwrap().len(), 3);
+            assert_eq!(contract.export_range(1, MAX_EXPORT_RANGE + 1), Err(Error::RangeTooLarge));
+            assert!(contract.export_range(3, 1).unwrap().is_empty());
+        }
+
+        /// Tests the two-step admin handover happy path.
+        /// - Verifies the admin does not change until the proposed account accepts.
+        /// - Verifies the new admin gains admin rights and the old admin loses them.
+        /// - Verifies `AdminProposed` and `AdminChanged` are emitted.
+        #[ink::test]
+        fn test_admin_handover() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+            test::set_caller::<DefaultEnvironment>(alice);
+
+            contract.propose_admin(bob).unwrap();
+            assert_eq!(contract.get_pending_admin(), Some(bob));
+            assert!(contract.is_admin(alice));
+            assert!(!contract.is_admin(bob));
+
+            test::set_caller::<DefaultEnvironment>(bob);
+            contract.accept_admin().unwrap();
+            assert!(contract.is_admin(bob));
+            assert!(!contract.is_admin(alice));
+            assert_eq!(contract.admin_count(), 1);
+            assert_eq!(contract.get_pending_admin(), None);
+            assert!(contract.update_oracle_data(String::from("bob_oracle")).is_ok());
+
+            test::set_caller::<DefaultEnvironment>(alice);
+            assert_eq!(contract.update_oracle_data(String::from("alice_oracle")), Err(Error::NotAdmin));
+
+            let events: Vec<_> = test::recorded_events().collect();
+            let proposed = <AdminProposed as scale::Decode>::decode(&mut &events[0].data[..]).unwrap();
+            assert_eq!(proposed.proposed, Some(bob));
+            let changed = <AdminChanged as scale::Decode>::decode(&mut &events[1].data[..]).unwrap();
+            assert_eq!(changed.old_admin, alice);
+            assert_eq!(changed.new_admin, bob);
+        }
+
+        /// Tests the guards on the admin handover.
+        /// - Verifies only the admin can propose.
+        /// - Verifies an account other than the pending admin cannot accept.
+        /// - Verifies a cancelled proposal can no longer be accepted.
+        #[ink::test]
+        fn test_admin_handover_guards() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+            let charlie = test::default_accounts::<DefaultEnvironment>().charlie;
+
+            test::set_caller::<DefaultEnvironment>(charlie);
+            assert_eq!(contract.propose_admin(charlie), Err(Error::NotAdmin));
+
+            test::set_caller::<DefaultEnvironment>(alice);
+            contract.propose_admin(bob).unwrap();
+            test::set_caller::<DefaultEnvironment>(charlie);
+            assert_eq!(contract.accept_admin(), Err(Error::NotPendingAdmin));
+
+            test::set_caller::<DefaultEnvironment>(bob);
+            assert_eq!(contract.cancel_admin_proposal(), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(alice);
+            contract.cancel_admin_proposal().unwrap();
+            assert_eq!(contract.get_pending_admin(), None);
+
+            test::set_caller::<DefaultEnvironment>(bob);
+            assert_eq!(contract.accept_admin(), Err(Error::NotPendingAdmin));
+            assert!(contract.is_admin(alice));
+            assert!(!contract.is_admin(bob));
+        }
+
+        /// Tests the `renounce_admin` function.
+        /// - Verifies oracle updates fail for the former admin after renunciation.
+        /// - Verifies the change is irreversible: a proposal made beforehand can no longer be accepted
+        ///   and no admin-only message succeeds afterwards.
+        /// - Verifies an `AdminRenounced` event is emitted.
+        #[ink::test]
+        fn test_renounce_admin() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+            test::set_caller::<DefaultEnvironment>(alice);
+
+            test::set_caller::<DefaultEnvironment>(bob);
+            assert_eq!(contract.renounce_admin(), Err(Error::NotAdmin));
+
+            test::set_caller::<DefaultEnvironment>(alice);
+            contract.propose_admin(bob).unwrap();
+            contract.renounce_admin().unwrap();
+            assert!(!contract.is_admin(alice));
+            assert_eq!(contract.admin_count(), 0);
+            assert_eq!(contract.get_pending_admin(), None);
+
+            assert_eq!(contract.update_oracle_data(String::from("new_oracle")), Err(Error::NotAdmin));
+            assert_eq!(contract.propose_admin(alice), Err(Error::NotAdmin));
+            assert_eq!(contract.renounce_admin(), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(bob);
+            assert_eq!(contract.accept_admin(), Err(Error::NotPendingAdmin));
+            assert_eq!(contract.get_oracle_data(), "default_oracle");
+
+            let events: Vec<_> = test::recorded_events().collect();
+            let renounced = <AdminRenounced as scale::Decode>::decode(&mut &events[1].data[..]).unwrap();
+            assert_eq!(renounced.admin, alice);
+        }
+
+        /// Tests the `OracleUpdater` role.
+        /// - Verifies only the admin can grant and revoke roles.
+        /// - Verifies a granted updater can push oracle data and a revoked one no longer can.
+        /// - Verifies `RoleGranted` and `RoleRevoked` events are emitted.
+        #[ink::test]
+        fn test_oracle_updater_role() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+
+            test::set_caller::<DefaultEnvironment>(bob);
+            assert_eq!(contract.grant_role(Role::OracleUpdater, bob), Err(Error::NotAdmin));
+
+            test::set_caller::<DefaultEnvironment>(alice);
+            contract.grant_role(Role::OracleUpdater, bob).unwrap();
+            assert!(contract.has_role(Role::OracleUpdater, bob));
+
+            test::set_caller::<DefaultEnvironment>(bob);
+            assert!(contract.update_oracle_data(String::from("ops_oracle")).is_ok());
+            assert_eq!(contract.get_oracle_data(), "ops_oracle");
+            // The role does not confer admin rights.
+            assert_eq!(contract.propose_admin(bob), Err(Error::NotAdmin));
+            assert_eq!(contract.revoke_role(Role::OracleUpdater, bob), Err(Error::NotAdmin));
+
+            test::set_caller::<DefaultEnvironment>(alice);
+            contract.revoke_role(Role::OracleUpdater, bob).unwrap();
+            assert!(!contract.has_role(Role::OracleUpdater, bob));
+
+            test::set_caller::<DefaultEnvironment>(bob);
+            assert_eq!(contract.update_oracle_data(String::from("stale")), Err(Error::NotAdmin));
+
+            let events: Vec<_> = test::recorded_events().collect();
+            let granted = <RoleGranted as scale::Decode>::decode(&mut &events[0].data[..]).unwrap();
+            assert_eq!((granted.role, granted.account, granted.granted_by), (Role::OracleUpdater, bob, alice));
+            let revoked = <RoleRevoked as scale::Decode>::decode(&mut &events[2].data[..]).unwrap();
+            assert_eq!((revoked.role, revoked.account, revoked.revoked_by), (Role::OracleUpdater, bob, alice));
+        }
+
+        /// Tests that roles are void after the admin role is renounced.
+        /// - Verifies an oracle updater cannot update once the admin has renounced.
+        #[ink::test]
+        fn test_roles_void_after_renounce() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+            test::set_caller::<DefaultEnvironment>(alice);
+
+            contract.grant_role(Role::OracleUpdater, bob).unwrap();
+            contract.renounce_admin().unwrap();
+            assert!(!contract.has_role(Role::OracleUpdater, bob));
+
+            test::set_caller::<DefaultEnvironment>(bob);
+            assert_eq!(contract.update_oracle_data(String::from("new_oracle")), Err(Error::NotAdmin));
+        }
+
+        /// Tests the `add_admin` and `remove_admin` functions.
+        /// - Verifies an added co-admin can perform admin-only actions.
+        /// - Verifies a removed admin immediately loses access.
+        /// - Verifies `AdminAdded` and `AdminRemoved` events are emitted.
+        #[ink::test]
+        fn test_co_admins() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+            let charlie = test::default_accounts::<DefaultEnvironment>().charlie;
+
+            test::set_caller::<DefaultEnvironment>(charlie);
+            assert_eq!(contract.add_admin(charlie), Err(Error::NotAdmin));
+
+            test::set_caller::<DefaultEnvironment>(alice);
+            contract.add_admin(bob).unwrap();
+            assert!(contract.is_admin(bob));
+            assert_eq!(contract.admin_count(), 2);
+
+            test::set_caller::<DefaultEnvironment>(bob);
+            assert!(contract.update_oracle_data(String::from("bob_oracle")).is_ok());
+            contract.remove_admin(alice).unwrap();
+            assert_eq!(contract.admin_count(), 1);
+
+            test::set_caller::<DefaultEnvironment>(alice);
+            assert_eq!(contract.update_oracle_data(String::from("alice_oracle")), Err(Error::NotAdmin));
+            assert_eq!(contract.add_admin(alice), Err(Error::NotAdmin));
+
+            let events: Vec<_> = test::recorded_events().collect();
+            let added = <AdminAdded as scale::Decode>::decode(&mut &events[0].data[..]).unwrap();
+            assert_eq!((added.admin, added.added_by), (bob, alice));
+            let removed = <AdminRemoved as scale::Decode>::decode(&mut &events[2].data[..]).unwrap();
+            assert_eq!((removed.admin, removed.removed_by), (alice, bob));
+        }
+
+        /// Tests that the last admin cannot be removed.
+        /// - Verifies `remove_admin` on the only admin fails with `LastAdmin`, including self-removal.
+        /// - Verifies removing a non-admin fails with `NotAdmin`.
+        #[ink::test]
+        fn test_remove_last_admin() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+            test::set_caller::<DefaultEnvironment>(alice);
+
+            assert_eq!(contract.remove_admin(alice), Err(Error::LastAdmin));
+            assert_eq!(contract.remove_admin(bob), Err(Error::NotAdmin));
+
+            contract.add_admin(bob).unwrap();
+            contract.remove_admin(bob).unwrap();
+            assert_eq!(contract.remove_admin(alice), Err(Error::LastAdmin));
+            assert!(contract.is_admin(alice));
+        }
+
+        /// Tests the admin handover and renunciation with several admins.
+        /// - Verifies a handover replaces only the proposer's seat.
+        /// - Verifies removing the proposer discards their pending handover.
+        /// - Verifies roles stay valid while at least one admin remains.
+        /// - Verifies `admins` and `get_admin` follow the admin set.
+        #[ink::test]
+        fn test_co_admin_handover_and_renounce() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.add_admin(accounts.bob).unwrap();
+
+            contract.propose_admin(accounts.charlie).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            contract.accept_admin().unwrap();
+            assert_eq!(contract.admins(0, 10), vec![accounts.charlie, accounts.bob]);
+            assert!(!contract.is_admin(accounts.alice));
+            assert!(contract.is_admin(accounts.bob));
+            assert!(contract.is_admin(accounts.charlie));
+
+            contract.propose_admin(accounts.django).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.remove_admin(accounts.charlie).unwrap();
+            assert_eq!(contract.get_pending_admin(), None);
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(contract.accept_admin(), Err(Error::NotPendingAdmin));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.grant_role(Role::OracleUpdater, accounts.eve).unwrap();
+            contract.add_admin(accounts.frank).unwrap();
+            assert_eq!(contract.admins(0, 10), vec![accounts.bob, accounts.frank]);
+            contract.renounce_admin().unwrap();
+            assert!(contract.has_role(Role::OracleUpdater, accounts.eve));
+            assert_eq!(contract.admin_count(), 1);
+            assert_eq!(contract.admins(0, 10), vec![accounts.frank]);
+            assert_eq!(contract.get_admin(), Some(accounts.frank));
+        }
+
+        /// Sets up a contract with Alice, Bob, and Charlie as admins and a 2-of-3 approval threshold.
+        fn two_of_three_contract() -> ContentOwnership {
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let mut contract = ContentOwnership::new();
+            contract.add_admin(accounts.bob).unwrap();
+            contract.add_admin(accounts.charlie).unwrap();
+            contract.set_approval_threshold(2).unwrap();
+            contract
+        }
+
+        /// Tests M-of-N approval of an oracle update.
+        /// - Verifies direct admin updates are rejected once the threshold is above one.
+        /// - Verifies the action executes only when the second admin approves.
+        #[ink::test]
+        fn test_admin_action_threshold_reached() {
+            let mut contract = two_of_three_contract();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            assert_eq!(contract.update_oracle_data(String::from("direct")), Err(Error::ApprovalRequired));
+
+            let action_id = contract
+                .propose_admin_action(AdminAction::UpdateOracle(String::from("approved_oracle")))
+                .unwrap();
+            assert_eq!(contract.get_oracle_data(), "default_oracle");
+            assert_eq!(contract.get_pending_action(action_id).unwrap().approvers, vec![accounts.alice]);
+
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(contract.approve_action(action_id), Err(Error::NotAdmin));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.approve_action(action_id).unwrap();
+            assert_eq!(contract.get_oracle_data(), "approved_oracle");
+            assert_eq!(contract.get_pending_action(action_id), None);
+            assert_eq!(contract.approve_action(action_id), Err(Error::ActionNotFound));
+        }
+
+        /// Tests that an admin cannot approve the same action twice.
+        /// - Verifies the proposer's implicit approval cannot be repeated.
+        #[ink::test]
+        fn test_admin_action_duplicate_approval() {
+            let mut contract = two_of_three_contract();
+
+            let action_id = contract
+                .propose_admin_action(AdminAction::UpdateOracle(String::from("approved_oracle")))
+                .unwrap();
+            assert_eq!(contract.approve_action(action_id), Err(Error::AlreadyApproved));
+            assert_eq!(contract.get_oracle_data(), "default_oracle");
+        }
+
+        /// Tests the expiry of pending admin actions.
+        /// - Verifies an action can be approved at its expiry block but not after.
+        #[ink::test]
+        fn test_admin_action_expiry() {
+            let mut contract = two_of_three_contract();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            let expiring = contract
+                .propose_admin_action(AdminAction::UpdateOracle(String::from("late_oracle")))
+                .unwrap();
+            let on_time = contract
+                .propose_admin_action(AdminAction::UpdateOracle(String::from("on_time_oracle")))
+                .unwrap();
+            let expires_at = contract.get_pending_action(expiring).unwrap().expires_at;
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            test::set_block_number::<DefaultEnvironment>(expires_at);
+            contract.approve_action(on_time).unwrap();
+            assert_eq!(contract.get_oracle_data(), "on_time_oracle");
+
+            test::set_block_number::<DefaultEnvironment>(expires_at + 1);
+            assert_eq!(contract.approve_action(expiring), Err(Error::ActionExpired));
+            assert_eq!(contract.get_oracle_data(), "on_time_oracle");
+        }
+
+        /// Tests changing the approval threshold.
+        /// - Verifies invalid thresholds are rejected.
+        /// - Verifies the threshold can only be lowered through an approved action once above one.
+        #[ink::test]
+        fn test_admin_action_set_threshold() {
+            let mut contract = two_of_three_contract();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            assert_eq!(contract.set_approval_threshold(1), Err(Error::ApprovalRequired));
+
+            let invalid = contract.propose_admin_action(AdminAction::SetApprovalThreshold(4)).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.approve_action(invalid), Err(Error::InvalidThreshold));
+
+            let action_id = contract.propose_admin_action(AdminAction::SetApprovalThreshold(1)).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            contract.approve_action(action_id).unwrap();
+            assert_eq!(contract.approval_threshold(), 1);
+            assert!(contract.update_oracle_data(String::from("direct")).is_ok());
+            assert_eq!(contract.set_approval_threshold(0), Err(Error::InvalidThreshold));
+        }
+
+        /// Tests that admin and role changes need M-of-N approval.
+        /// - Verifies a single admin can neither change the admin set nor delegate a role directly.
+        /// - Verifies approved actions apply, and removals cannot leave fewer admins than the threshold.
+        #[ink::test]
+        fn test_admin_action_admins_and_roles() {
+            let mut contract = two_of_three_contract();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            assert_eq!(contract.add_admin(accounts.django), Err(Error::ApprovalRequired));
+            assert_eq!(contract.remove_admin(accounts.bob), Err(Error::ApprovalRequired));
+            assert_eq!(contract.grant_role(Role::OracleUpdater, accounts.alice), Err(Error::ApprovalRequired));
+            assert_eq!(contract.revoke_role(Role::OracleUpdater, accounts.alice), Err(Error::ApprovalRequired));
+
+            let grant = contract.propose_admin_action(AdminAction::GrantRole(Role::OracleUpdater, accounts.eve)).unwrap();
+            let remove = contract.propose_admin_action(AdminAction::RemoveAdmin(accounts.charlie)).unwrap();
+            assert!(!contract.has_role(Role::OracleUpdater, accounts.eve));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.approve_action(grant).unwrap();
+            assert!(contract.has_role(Role::OracleUpdater, accounts.eve));
+            contract.approve_action(remove).unwrap();
+            assert!(!contract.is_admin(accounts.charlie));
+            assert_eq!(contract.admin_count(), 2);
+
+            let remove = contract.propose_admin_action(AdminAction::RemoveAdmin(accounts.alice)).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.approve_action(remove), Err(Error::InvalidThreshold));
+            assert_eq!(contract.admin_count(), 2);
+
+            let add = contract.propose_admin_action(AdminAction::AddAdmin(accounts.django)).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.approve_action(add).unwrap();
+            assert!(contract.is_admin(accounts.django));
+        }
+
+        /// Tests admin actions that change contract-wide settings.
+        /// - Verifies the fee, royalty cap, auction extension, and forced-transfer delay setters require an
+        ///   `AdminAction` once the approval threshold is above one, and emit their events.
+        /// - Verifies the actions apply the settings once approved, and invalid ones fail on execution.
+        #[ink::test]
+        fn test_admin_action_settings() {
+            let mut contract = two_of_three_contract();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+
+            assert_eq!(contract.set_platform_fee_bps(100), Err(Error::ApprovalRequired));
+            assert_eq!(contract.set_fee_schedule(200, 100), Err(Error::ApprovalRequired));
+
+            let fees = contract.propose_admin_action(AdminAction::SetFeeSchedule(200, 100)).unwrap();
+            let too_high = contract.propose_admin_action(AdminAction::SetFeeSchedule(MAX_PLATFORM_FEE_BPS + 1, 0)).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.approve_action(fees).unwrap();
+            assert_eq!((contract.primary_fee_bps(), contract.secondary_fee_bps()), (200, 100));
+            assert_eq!(contract.approve_action(too_high), Err(Error::FeeTooHigh));
+            assert_eq!(contract.primary_fee_bps(), 200);
+
+            assert_eq!(contract.set_registration_fee(Balance::MAX), Err(Error::ApprovalRequired));
+            let registration = contract.propose_admin_action(AdminAction::SetRegistrationFee(50)).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            contract.approve_action(registration).unwrap();
+            assert_eq!(contract.registration_fee(), 50);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+
+            assert_eq!(contract.set_max_royalty_bps(500), Err(Error::ApprovalRequired));
+            let cap = contract.propose_admin_action(AdminAction::SetMaxRoyalty(500)).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.approve_action(cap).unwrap();
+            assert_eq!(contract.max_royalty_bps(), 500);
+
+            assert_eq!(contract.set_auction_extension(0), Err(Error::ApprovalRequired));
+            let window = contract.propose_admin_action(AdminAction::SetAuctionExtension(60_000)).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.approve_action(window).unwrap();
+            assert_eq!(contract.auction_extension(), 60_000);
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <AuctionExtensionChanged as scale::Decode>::decode(&mut &events[events.len() - 2].data[..]).unwrap();
+            assert_eq!((event.by, event.window), (accounts.bob, 60_000));
+
+            assert_eq!(contract.set_forced_transfer_delay(0), Err(Error::ApprovalRequired));
+            let delay = contract.propose_admin_action(AdminAction::SetForcedTransferDelay(1_000)).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            contract.approve_action(delay).unwrap();
+            assert_eq!(contract.forced_transfer_delay(), 1_000);
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <ForcedTransferDelayChanged as scale::Decode>::decode(&mut &events[events.len() - 2].data[..]).unwrap();
+            assert_eq!((event.by, event.delay), (accounts.charlie, 1_000));
+
+            let strategy = ValidationStrategy::Disabled;
+            assert_eq!(contract.set_validation_strategy(strategy), Err(Error::ApprovalRequired));
+            assert_eq!(contract.propose_oracle_change(OracleChange::SetValidationStrategy(strategy)), Err(Error::ApprovalRequired));
+            let switch = contract.propose_admin_action(AdminAction::SetValidationStrategy(strategy)).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.approve_action(switch).unwrap();
+            assert_eq!(contract.get_validation_strategy(), strategy);
+        }
+
+        /// Tests the `pause` and `unpause` functions.
+        /// - Verifies only admins can pause and unpause.
+        /// - Verifies registrations and transfers are blocked while paused, and queries still work.
+        /// - Verifies both operations work again after unpausing, and `Paused`/`Unpaused` are emitted.
+        #[ink::test]
+        fn test_pause_and_unpause() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+            test::set_caller::<DefaultEnvironment>(alice);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(bob);
+            assert_eq!(contract.pause(), Err(Error::NotAdmin));
+
+            test::set_caller::<DefaultEnvironment>(alice);
+            contract.pause().unwrap();
+            assert!(contract.is_paused());
+            assert_eq!(contract.register_content(String::from("default_oracle_other")), Err(Error::ContractPaused));
+            assert_eq!(contract.transfer_ownership(content_id, bob), Err(Error::ContractPaused));
+            assert_eq!(contract.owner_of(content_id), Some(alice));
+
+            test::set_caller::<DefaultEnvironment>(bob);
+            assert_eq!(contract.unpause(), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(alice);
+            contract.unpause().unwrap();
+            assert!(!contract.is_paused());
+            assert!(contract.register_content(String::from("default_oracle_other")).is_ok());
+            assert!(contract.transfer_ownership(content_id, bob).is_ok());
+
+            let events: Vec<_> = test::recorded_events().collect();
+            let paused = <Paused as scale::Decode>::decode(&mut &events[1].data[..]).unwrap();
+            assert_eq!(paused.account, alice);
+            let unpaused = <Unpaused as scale::Decode>::decode(&mut &events[2].data[..]).unwrap();
+            assert_eq!(unpaused.account, alice);
+        }
+
+        /// Tests pausing individual operations with `set_paused`.
+        /// - Verifies registrations can be blocked while transfers succeed, and vice versa.
+        /// - Verifies `pause_state` reports each flag and `pause`/`unpause` set them all.
+        #[ink::test]
+        fn test_granular_pause() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+            test::set_caller::<DefaultEnvironment>(alice);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(bob);
+            assert_eq!(contract.set_paused(PausableOp::Registrations, true), Err(Error::NotAdmin));
+
+            test::set_caller::<DefaultEnvironment>(alice);
+            contract.set_paused(PausableOp::Registrations, true).unwrap();
+            assert_eq!(
+                contract.pause_state(),
+                PauseState { registrations: true, transfers: false, sales: false }
+            );
+            assert!(contract.is_paused());
+            assert_eq!(contract.register_content(String::from("default_oracle_other")), Err(Error::ContractPaused));
+            contract.transfer_ownership(content_id, bob).unwrap();
+
+            contract.set_paused(PausableOp::Registrations, false).unwrap();
+            contract.set_paused(PausableOp::Transfers, true).unwrap();
+            let other_id = contract.register_content(String::from("default_oracle_other")).unwrap();
+            assert_eq!(contract.transfer_ownership(other_id, bob), Err(Error::ContractPaused));
+
+            contract.pause().unwrap();
+            assert_eq!(contract.pause_state(), PauseState { registrations: true, transfers: true, sales: true });
+            contract.unpause().unwrap();
+            assert_eq!(contract.pause_state(), PauseState::default());
+            assert!(!contract.is_paused());
+        }
+
+        /// Tests the registration blacklist.
+        /// - Verifies an account blacklisted between two registrations can no longer register.
+        /// - Verifies a blacklisted account can still transfer content it already owns.
+        /// - Verifies unblacklisting restores registration, and only admins manage the list.
+        #[ink::test]
+        fn test_blacklist() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+
+            test::set_caller::<DefaultEnvironment>(bob);
+            let content_id = contract.register_content(String::from("default_oracle_bob_1")).unwrap();
+            assert_eq!(contract.blacklist_account(alice), Err(Error::NotAdmin));
+
+            test::set_caller::<DefaultEnvironment>(alice);
+            contract.blacklist_account(bob).unwrap();
+            assert!(contract.is_blacklisted(bob));
+
+            test::set_caller::<DefaultEnvironment>(bob);
+            assert_eq!(
+                contract.register_content(String::from("default_oracle_bob_2")),
+                Err(Error::AccountBlacklisted)
+            );
+            assert!(contract.transfer_ownership(content_id, alice).is_ok());
+
+            test::set_caller::<DefaultEnvironment>(alice);
+            contract.unblacklist_account(bob).unwrap();
+            assert!(!contract.is_blacklisted(bob));
+            test::set_caller::<DefaultEnvironment>(bob);
+            assert!(contract.register_content(String::from("default_oracle_bob_2")).is_ok());
+
+            let events: Vec<_> = test::recorded_events().collect();
+            let blacklisted = <Blacklisted as scale::Decode>::decode(&mut &events[1].data[..]).unwrap();
+            assert_eq!((blacklisted.account, blacklisted.by), (bob, alice));
+            let unblacklisted = <Unblacklisted as scale::Decode>::decode(&mut &events[3].data[..]).unwrap();
+            assert_eq!((unblacklisted.account, unblacklisted.by), (bob, alice));
+        }
+
+        /// Tests the `WhitelistOnly` registration mode.
+        /// - Verifies any account can register in `Open` mode.
+        /// - Verifies only whitelisted registrars can register after switching mid-stream.
+        /// - Verifies existing content stays transferable and switching back reopens registration.
+        #[ink::test]
+        fn test_whitelist_registration_mode() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let bob = test::default_accounts::<DefaultEnvironment>().bob;
+            let charlie = test::default_accounts::<DefaultEnvironment>().charlie;
+            assert_eq!(contract.registration_mode(), RegistrationMode::Open);
+
+            test::set_caller::<DefaultEnvironment>(bob);
+            let content_id = contract.register_content(String::from("default_oracle_bob")).unwrap();
+            assert_eq!(contract.set_registration_mode(RegistrationMode::WhitelistOnly), Err(Error::NotAdmin));
+            assert_eq!(contract.add_registrar(bob), Err(Error::NotAdmin));
+
+            test::set_caller::<DefaultEnvironment>(alice);
+            contract.set_registration_mode(RegistrationMode::WhitelistOnly).unwrap();
+            contract.add_registrar(charlie).unwrap();
+            assert!(contract.is_registrar(charlie));
+            assert!(!contract.is_registrar(bob));
+
+            test::set_caller::<DefaultEnvironment>(bob);
+            assert_eq!(contract.register_content(String::from("default_oracle_bob_2")), Err(Error::NotWhitelisted));
+            assert!(contract.transfer_ownership(content_id, charlie).is_ok());
+            test::set_caller::<DefaultEnvironment>(charlie);
+            assert!(contract.register_content(String::from("default_oracle_charlie")).is_ok());
+
+            test::set_caller::<DefaultEnvironment>(alice);
+            contract.remove_registrar(charlie).unwrap();
+            test::set_caller::<DefaultEnvironment>(charlie);
+            assert_eq!(contract.register_content(String::from("default_oracle_charlie_2")), Err(Error::NotWhitelisted));
+
+            test::set_caller::<DefaultEnvironment>(alice);
+            contract.set_registration_mode(RegistrationMode::Open).unwrap();
+            test::set_caller::<DefaultEnvironment>(bob);
+            assert!(contract.register_content(String::from("default_oracle_bob_2")).is_ok());
+        }
+
+        /// Tests the `register_content_for` function.
+        /// - Verifies callers without `Role::Registrar` are rejected.
+        /// - Verifies the named owner, not the relayer, owns the content and the event records both.
+        /// - Verifies a duplicate hash submitted for a different owner fails with `AlreadyRegistered`.
+        #[ink::test]
+        fn test_register_content_for() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let relayer = accounts.django;
+            let content_hash = String::from("default_oracle_content");
+
+            test::set_caller::<DefaultEnvironment>(relayer);
+            assert_eq!(contract.register_content_for(content_hash.clone(), accounts.bob), Err(Error::NotAdmin));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.grant_role(Role::Registrar, relayer).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(relayer);
+            let content_id = contract.register_content_for(content_hash.clone(), accounts.bob).unwrap();
+            assert_eq!(contract.owner_of(content_id), Some(accounts.bob));
+            assert_eq!(contract.balance_of(relayer), 0);
+
+            assert_eq!(contract.register_content_for(content_hash, accounts.charlie), Err(Error::AlreadyRegistered));
+            assert_eq!(contract.owner_of(content_id), Some(accounts.bob));
+            assert_eq!(contract.balance_of(accounts.charlie), 0);
+
+            let events: Vec<_> = test::recorded_events().collect();
+            assert_eq!(events.len(), 2);
+            let registered = <ContentRegistered as scale::Decode>::decode(&mut &events[1].data[..]).unwrap();
+            assert_eq!(registered.owner, accounts.bob);
+            assert_eq!(registered.registrar, relayer);
+        }
+
+
+        /// Tests the scheduled forced transfer functions.
+        /// - Verifies scheduling requires an admin and an existing content ID.
+        /// - Verifies execution fails with `TransferNotReady` before the delay and succeeds after it.
+        /// - Verifies a cancelled transfer can no longer be executed, and each step emits an event.
+        #[ink::test]
+        fn test_admin_forced_transfer() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let delay = contract.forced_transfer_delay();
+            let reason = String::from("takedown_order_hash");
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            assert_eq!(contract.admin_schedule_transfer(content_id, accounts.charlie, reason.clone()), Err(Error::NotAdmin));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.admin_schedule_transfer(99, accounts.charlie, reason.clone()), Err(Error::ContentNotFound));
+            assert_eq!(contract.admin_execute_transfer(99), Err(Error::ContentNotFound));
+            assert_eq!(contract.admin_cancel_transfer(99), Err(Error::ContentNotFound));
+
+            // Cancel path.
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            contract.admin_schedule_transfer(content_id, accounts.charlie, reason.clone()).unwrap();
+            contract.admin_cancel_transfer(content_id).unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(1_000 + delay);
+            assert_eq!(contract.admin_execute_transfer(content_id), Err(Error::TransferNotScheduled));
+            assert_eq!(contract.owner_of(content_id), Some(accounts.bob));
+
+            // Not-ready and execute paths.
+            let executable_at = contract.admin_schedule_transfer(content_id, accounts.charlie, reason.clone()).unwrap();
+            assert_eq!(executable_at, 1_000 + 2 * delay);
+            test::set_block_timestamp::<DefaultEnvironment>(executable_at - 1);
+            assert_eq!(contract.admin_execute_transfer(content_id), Err(Error::TransferNotReady));
+            test::set_block_timestamp::<DefaultEnvironment>(executable_at);
+            contract.admin_execute_transfer(content_id).unwrap();
+            assert_eq!(contract.owner_of(content_id), Some(accounts.charlie));
+            assert_eq!(contract.get_scheduled_transfer(content_id), None);
+
+            let events: Vec<_> = test::recorded_events().collect();
+            assert_eq!(events.len(), 6);
+            let scheduled = <TransferScheduled as scale::Decode>::decode(&mut &events[1].data[..]).unwrap();
+            assert_eq!(scheduled.owner, accounts.bob);
+            assert_eq!(scheduled.reason_hash, reason);
+            let cancelled = <ScheduledTransferCancelled as scale::Decode>::decode(&mut &events[2].data[..]).unwrap();
+            assert_eq!(cancelled.cancelled_by, accounts.alice);
+            let executed = <ScheduledTransferExecuted as scale::Decode>::decode(&mut &events[5].data[..]).unwrap();
+            assert_eq!(executed.content_id, content_id);
+        }
+
+        /// Tests that a loan offer cannot hold off a scheduled forced transfer.
+        /// - Verifies no loan offer can be opened while a forced transfer is pending.
+        /// - Verifies an unfunded offer opened before scheduling is cancelled when the transfer executes.
+        #[ink::test]
+        fn test_forced_transfer_with_loan_offer() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            contract.create_loan_offer(content_id, 100, 10, 1_000_000).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let executable_at = contract.admin_schedule_transfer(content_id, accounts.charlie, String::from("takedown_order_hash")).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.cancel_loan_offer(content_id).unwrap();
+            assert_eq!(contract.create_loan_offer(content_id, 100, 10, 1_000_000), Err(Error::ForcedTransferPending));
+
+            // An offer that predates the schedule is dropped on execution.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.admin_cancel_transfer(content_id).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.create_loan_offer(content_id, 100, 10, 1_000_000).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.admin_schedule_transfer(content_id, accounts.charlie, String::from("takedown_order_hash")).unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(executable_at);
+            contract.admin_execute_transfer(content_id).unwrap();
+            assert_eq!(contract.owner_of(content_id), Some(accounts.charlie));
+            assert_eq!(contract.get_loan(content_id), None);
+        }
+
+        /// Tests that a sale cannot hold off a scheduled forced transfer.
+        /// - Verifies no listing, auction, or Dutch auction can start while a forced transfer is pending.
+        /// - Verifies an auction started before scheduling is cancelled on execution and its highest bid
+        ///   credited back.
+        #[ink::test]
+        fn test_forced_transfer_with_auction() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            contract.start_auction(content_id, 100, 1_000_000, 10).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            send_value(50);
+            contract.bid(content_id).unwrap();
+            send_value(0);
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let executable_at = contract.admin_schedule_transfer(content_id, accounts.charlie, String::from("takedown_order_hash")).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.list_for_sale(content_id, 100), Err(Error::AuctionActive));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            test::set_block_timestamp::<DefaultEnvironment>(executable_at);
+            contract.admin_execute_transfer(content_id).unwrap();
+            assert_eq!(contract.owner_of(content_id), Some(accounts.charlie));
+            assert_eq!(contract.get_auction(content_id), None);
+            assert_eq!(contract.credit_of(accounts.django), 50);
+
+            contract.admin_schedule_transfer(content_id, accounts.bob, String::from("takedown_order_hash")).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(contract.list_for_sale(content_id, 100), Err(Error::ForcedTransferPending));
+            assert_eq!(contract.start_auction(content_id, 100, 1_000, 10), Err(Error::ForcedTransferPending));
+            assert_eq!(contract.start_dutch_auction(content_id, 100, 10, 1_000), Err(Error::ForcedTransferPending));
+        }
+
+
+        /// Tests the `bulk_import` function.
+        /// - Verifies only admins can import and batches over `MAX_IMPORT_BATCH_SIZE` are rejected.
+        /// - Verifies entries skip oracle validation and are flagged as imported.
+        /// - Verifies a duplicate in the middle of the batch fails alone without aborting the rest.
+        #[ink::test]
+        fn test_bulk_import() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let entries = vec![
+                (String::from("legacy_a"), accounts.bob),
+                (String::from("legacy_a"), accounts.charlie),
+                (String::from("legacy_b"), accounts.charlie),
+            ];
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.bulk_import(entries.clone()), Err(Error::NotAdmin));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let oversized = vec![(String::from("legacy"), accounts.bob); MAX_IMPORT_BATCH_SIZE as usize + 1];
+            assert_eq!(contract.bulk_import(oversized), Err(Error::BatchTooLarge));
+
+            let results = contract.bulk_import(entries).unwrap();
+            assert_eq!(results, vec![Ok(1), Err(Error::AlreadyRegistered), Ok(2)]);
+            assert_eq!(contract.total_supply(), 2);
+            assert_eq!(contract.owner_of(1), Some(accounts.bob));
+            assert_eq!(contract.owner_of(2), Some(accounts.charlie));
+            assert!(contract.get_content(2).unwrap().is_imported());
+
+            let registered = contract.register_content(String::from("default_oracle_content")).unwrap();
+            assert!(!contract.get_content(registered).unwrap().is_imported());
+            assert_eq!(contract.bulk_import(vec![(String::from("legacy_b"), accounts.bob)]).unwrap(), vec![Err(Error::AlreadyRegistered)]);
+        }
+
+
+        /// Tests the two-step `offer_transfer` / `accept_transfer` flow.
+        /// - Verifies only the owner can offer or cancel, and only the recipient can accept.
+        /// - Verifies a replaced offer can no longer be accepted by the previous recipient.
+        /// - Verifies cancelling and a direct transfer both withdraw the offer.
+        #[ink::test]
+        fn test_transfer_offer() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            assert_eq!(contract.accept_transfer(content_id), Err(Error::NoPendingTransfer));
+            assert_eq!(contract.cancel_transfer_offer(content_id), Err(Error::NoPendingTransfer));
+            assert_eq!(contract.offer_transfer(99, accounts.bob), Err(Error::ContentNotFound));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.offer_transfer(content_id, accounts.bob), Err(Error::NotOwner));
+
+            // Cancel.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.offer_transfer(content_id, accounts.bob).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.cancel_transfer_offer(content_id), Err(Error::NotOwner));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.cancel_transfer_offer(content_id).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.accept_transfer(content_id), Err(Error::NoPendingTransfer));
+
+            // Replace, then accept.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.offer_transfer(content_id, accounts.bob).unwrap();
+            contract.offer_transfer(content_id, accounts.charlie).unwrap();
+            assert_eq!(contract.get_transfer_offer(content_id), Some(accounts.charlie));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.accept_transfer(content_id), Err(Error::NotTransferRecipient));
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            contract.accept_transfer(content_id).unwrap();
+            assert_eq!(contract.owner_of(content_id), Some(accounts.charlie));
+            assert_eq!(contract.get_transfer_offer(content_id), None);
+
+            // A direct transfer clears the offer.
+            contract.offer_transfer(content_id, accounts.bob).unwrap();
+            contract.transfer_ownership(content_id, accounts.django).unwrap();
+            assert_eq!(contract.get_transfer_offer(content_id), None);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.accept_transfer(content_id), Err(Error::NoPendingTransfer));
+
+            let events: Vec<_> = test::recorded_events().collect();
+            assert_eq!(events.len(), 9);
+            let cancelled = <TransferOfferCancelled as scale::Decode>::decode(&mut &events[2].data[..]).unwrap();
+            assert_eq!(cancelled.to, accounts.bob);
+            let accepted = <TransferOfferAccepted as scale::Decode>::decode(&mut &events[6].data[..]).unwrap();
+            assert_eq!(accepted.to, accounts.charlie);
+        }
+
+
+        /// Tests the `transfer_ownership_checked` function.
+        /// - Verifies the transfer fails with `OwnerMismatch` when the owner changed after it was read.
+        /// - Verifies a caller that is not the owner still gets `NotOwner` when the expectation matches.
+        /// - Verifies the transfer succeeds when the expectation matches the stored owner.
+        #[ink::test]
+        fn test_transfer_ownership_checked() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            assert_eq!(contract.transfer_ownership_checked(99, accounts.bob, accounts.alice), Err(Error::ContentNotFound));
+
+            // Alice reads the owner, then a concurrent transfer lands first.
+            let observed = contract.owner_of(content_id).unwrap();
+            contract.transfer_ownership(content_id, accounts.bob).unwrap();
+            assert_eq!(contract.transfer_ownership_checked(content_id, accounts.charlie, observed), Err(Error::OwnerMismatch));
+            assert_eq!(contract.owner_of(content_id), Some(accounts.bob));
+
+            assert_eq!(contract.transfer_ownership_checked(content_id, accounts.charlie, accounts.bob), Err(Error::NotOwner));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.transfer_ownership_checked(content_id, accounts.charlie, accounts.bob).unwrap();
+            assert_eq!(contract.owner_of(content_id), Some(accounts.charlie));
+        }
+
+
+        /// Tests the `transfer_ownership_with_memo` function.
+        /// - Verifies memos longer than `MAX_MEMO_LENGTH` bytes are rejected.
+        /// - Verifies the memo is stored, returned by `last_transfer_memo`, and included in the event.
+        /// - Verifies a later transfer without a memo clears it.
+        #[ink::test]
+        fn test_transfer_ownership_with_memo() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            assert_eq!(contract.last_transfer_memo(content_id), None);
+
+            let too_long = "x".repeat(MAX_MEMO_LENGTH + 1);
+            assert_eq!(contract.transfer_ownership_with_memo(content_id, accounts.bob, too_long), Err(Error::MemoTooLong));
+
+            let memo = "a".repeat(MAX_MEMO_LENGTH);
+            contract.transfer_ownership_with_memo(content_id, accounts.bob, memo.clone()).unwrap();
+            assert_eq!(contract.owner_of(content_id), Some(accounts.bob));
+            assert_eq!(contract.last_transfer_memo(content_id), Some(memo.clone()));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.transfer_ownership(content_id, accounts.charlie).unwrap();
+            assert_eq!(contract.last_transfer_memo(content_id), None);
+
+            let events: Vec<_> = test::recorded_events().collect();
+            let with_memo = <OwnershipTransferred as scale::Decode>::decode(&mut &events[1].data[..]).unwrap();
+            assert_eq!(with_memo.memo, Some(memo));
+            let without_memo = <OwnershipTransferred as scale::Decode>::decode(&mut &events[2].data[..]).unwrap();
+            assert_eq!(without_memo.memo, None);
+        }
+
+
+        /// Tests the `renounce_ownership` function.
+        /// - Verifies only the owner can renounce, and only once.
+        /// - Verifies renounced content can still be read but has no owner and leaves the owner's index.
+        /// - Verifies renounced content can never be transferred or offered again.
+        #[ink::test]
+        fn test_renounce_ownership() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            contract.offer_transfer(content_id, accounts.bob).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.renounce_ownership(content_id), Err(Error::NotOwner));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.renounce_ownership(99), Err(Error::ContentNotFound));
+            contract.renounce_ownership(content_id).unwrap();
+            assert_eq!(contract.renounce_ownership(content_id), Err(Error::OwnershipRenounced));
+
+            let content = contract.get_content(content_id).unwrap();
+            assert!(content.is_renounced());
+            assert_eq!(content.content_hash(), "default_oracle_content");
+            assert!(contract.content_exists(content_id));
+            assert_eq!(contract.owner_of(content_id), None);
+            assert!(!contract.is_owner(content_id, accounts.alice));
+            assert_eq!(contract.balance_of(accounts.alice), 0);
+            assert_eq!(contract.get_transfer_offer(content_id), None);
+
+            assert_eq!(contract.transfer_ownership(content_id, accounts.bob), Err(Error::OwnershipRenounced));
+            assert_eq!(contract.offer_transfer(content_id, accounts.bob), Err(Error::OwnershipRenounced));
+            assert_eq!(contract.admin_schedule_transfer(content_id, accounts.bob, String::from("order")), Err(Error::OwnershipRenounced));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.accept_transfer(content_id), Err(Error::NoPendingTransfer));
+
+            let events: Vec<_> = test::recorded_events().collect();
+            let renounced = <ContentRenounced as scale::Decode>::decode(&mut &events[2].data[..]).unwrap();
+            assert_eq!(renounced.former_owner, accounts.alice);
+        }
+
+
+        /// Tests that `renounce_ownership` applies the same preconditions as a transfer.
+        /// - Verifies locked, frozen, rented, and inactive content and content with a scheduled forced
+        ///   transfer cannot be renounced.
+        /// - Verifies renouncing ends the licenses granted on the content.
+        #[ink::test]
+        fn test_renounce_ownership_preconditions() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_renounced")).unwrap();
+            contract.lock_content(content_id).unwrap();
+            assert_eq!(contract.renounce_ownership(content_id), Err(Error::ContentLocked));
+            contract.unlock_content(content_id).unwrap();
+            contract.freeze_content(content_id).unwrap();
+            assert_eq!(contract.renounce_ownership(content_id), Err(Error::ContentFrozen));
+            contract.unfreeze_content(content_id).unwrap();
+            contract.admin_schedule_transfer(content_id, accounts.bob, String::from("order")).unwrap();
+            assert_eq!(contract.renounce_ownership(content_id), Err(Error::ForcedTransferPending));
+            contract.admin_cancel_transfer(content_id).unwrap();
+            contract.set_content_status(content_id, ContentStatus::Disputed).unwrap();
+            assert_eq!(contract.renounce_ownership(content_id), Err(Error::ContentNotActive));
+            contract.set_content_status(content_id, ContentStatus::Active).unwrap();
+
+            contract.set_rental_terms(content_id, 10, 1).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(10);
+            let expires_at = contract.rent(content_id, 1).unwrap();
+            send_value(0);
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.renounce_ownership(content_id), Err(Error::RentalActive));
+
+            test::set_block_timestamp::<DefaultEnvironment>(expires_at);
+            contract.grant_license(content_id, accounts.charlie, String::from("terms"), None, false, false).unwrap();
+            contract.renounce_ownership(content_id).unwrap();
+            assert_eq!(contract.license_of(content_id, accounts.charlie), None);
+            assert_eq!(contract.licensees_of(content_id, 0, 10), Vec::<AccountId>::new());
+            assert_eq!(contract.get_rental(content_id), None);
+        }
+
+
+        /// Tests the `transfer_batch` function.
+        /// - Verifies items the caller does not own, or that do not exist, fail individually.
+        /// - Verifies owned items move to the new owner, with one event each and a consistent owner index.
+        /// - Verifies batches over `MAX_BATCH_SIZE` are rejected.
+        #[ink::test]
+        fn test_transfer_batch() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let first = contract.register_content(String::from("default_oracle_a")).unwrap();
+            let second = contract.register_content(String::from("default_oracle_b")).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let bobs = contract.register_content(String::from("default_oracle_c")).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let results = contract.transfer_batch(vec![first, bobs, 99, second], accounts.charlie).unwrap();
+            assert_eq!(results, vec![Ok(()), Err(Error::NotOwner), Err(Error::ContentNotFound), Ok(())]);
+            assert_eq!(contract.owner_of(bobs), Some(accounts.bob));
+            assert_eq!(contract.balance_of(accounts.alice), 0);
+            assert_eq!(contract.balance_of(accounts.charlie), 2);
+            let mut charlies = contract.contents_of(accounts.charlie, 0, 10);
+            charlies.sort();
+            assert_eq!(charlies, vec![first, second]);
+
+            let events: Vec<_> = test::recorded_events().collect();
+            assert_eq!(events.len(), 5);
+
+            let oversized = vec![first; MAX_BATCH_SIZE as usize + 1];
+            assert_eq!(contract.transfer_batch(oversized, accounts.bob), Err(Error::BatchTooLarge));
+        }
+
+
+        /// Tests the `transfer_all` function.
+        /// - Verifies repeated calls drain a holding `limit` items at a time, then return `0`.
+        /// - Verifies moving an item withdraws its pending transfer offer.
+        #[ink::test]
+        fn test_transfer_all() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let ids: Vec<u64> = (0..5)
+                .map(|i| contract.register_content(format!("default_oracle_{i}")).unwrap())
+                .collect();
+            contract.offer_transfer(ids[0], accounts.bob).unwrap();
+
+            assert_eq!(contract.transfer_all(accounts.charlie, 2), Ok(2));
+            assert_eq!(contract.balance_of(accounts.alice), 3);
+            assert_eq!(contract.transfer_all(accounts.charlie, 2), Ok(2));
+            assert_eq!(contract.transfer_all(accounts.charlie, 2), Ok(1));
+            assert_eq!(contract.transfer_all(accounts.charlie, 2), Ok(0));
+
+            assert_eq!(contract.balance_of(accounts.alice), 0);
+            assert_eq!(contract.balance_of(accounts.charlie), 5);
+            assert!(ids.iter().all(|id| contract.owner_of(*id) == Some(accounts.charlie)));
+            assert_eq!(contract.get_transfer_offer(ids[0]), None);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.accept_transfer(ids[0]), Err(Error::NoPendingTransfer));
+        }
+
+
+        /// Tests `safe_transfer_ownership` to an account that is not a contract.
+        /// - Verifies the transfer behaves like `transfer_ownership` without calling a receiver hook.
+        /// - Receiving contracts are covered by the end-to-end tests.
+        #[ink::test]
+        fn test_safe_transfer_ownership_to_account() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.safe_transfer_ownership(content_id, accounts.bob, Vec::new()), Err(Error::NotOwner));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.safe_transfer_ownership(content_id, accounts.bob, vec![1, 2, 3]).unwrap();
+            assert_eq!(contract.owner_of(content_id), Some(accounts.bob));
+        }
+
+        /// Tests recipient validation on every transfer entry point.
+        /// - Verifies transfers to the current owner fail with `SelfTransfer` and emit nothing.
+        /// - Verifies transfers to the all-zero account fail with `ZeroAddressRecipient`.
+        #[ink::test]
+        fn test_invalid_transfer_recipients() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            let zero = AccountId::from([0u8; 32]);
+            test::set_caller::<DefaultEnvironment>(alice);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+
+            for to in [alice, zero] {
+                let error = || if to == alice { Error::SelfTransfer } else { Error::ZeroAddressRecipient };
+                assert_eq!(contract.transfer_ownership(content_id, to), Err(error()));
+                assert_eq!(contract.transfer_ownership_checked(content_id, to, alice), Err(error()));
+                assert_eq!(contract.transfer_ownership_with_memo(content_id, to, String::from("memo")), Err(error()));
+                assert_eq!(contract.safe_transfer_ownership(content_id, to, Vec::new()), Err(error()));
+                assert_eq!(contract.transfer_batch(vec![content_id], to), Ok(vec![Err(error())]));
+                assert_eq!(contract.transfer_all(to, 10), Err(error()));
+                assert_eq!(contract.offer_transfer(content_id, to), Err(error()));
+                assert_eq!(contract.admin_schedule_transfer(content_id, to, String::from("order")), Err(error()));
+            }
+            assert_eq!(contract.owner_of(content_id), Some(alice));
+            assert_eq!(test::recorded_events().count(), 1);
+        }
+
+        /// Tests the provenance history queries.
+        /// - Verifies a content item that changes hands three times records three entries in order.
+        /// - Verifies each entry holds the block number and timestamp of its transfer.
+        /// - Verifies pagination and unknown IDs.
+        #[ink::test]
+        fn test_transfer_history() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            assert_eq!(contract.transfer_count(content_id), 0);
+
+            let chain = [accounts.alice, accounts.bob, accounts.charlie, accounts.django];
+            for (i, pair) in chain.windows(2).enumerate() {
+                test::advance_block::<DefaultEnvironment>();
+                test::set_block_timestamp::<DefaultEnvironment>(1_000 * (i as u64 + 1));
+                test::set_caller::<DefaultEnvironment>(pair[0]);
+                contract.transfer_ownership(content_id, pair[1]).unwrap();
+            }
+
+            assert_eq!(contract.transfer_count(content_id), 3);
+            let history = contract.transfer_history(content_id, 0, 10);
+            assert_eq!(history.len(), 3);
+            for (i, entry) in history.iter().enumerate() {
+                assert_eq!((entry.from, entry.to), (chain[i], chain[i + 1]));
+                assert_eq!(entry.block, i as u32 + 1);
+                assert_eq!(entry.timestamp, 1_000 * (i as u64 + 1));
+            }
+            assert_eq!(contract.transfer_history(content_id, 1, 1), history[1..2].to_vec());
+            assert!(contract.transfer_history(content_id, 3, 10).is_empty());
+            assert!(contract.transfer_history(99, 0, 10).is_empty());
+        }
+
+        /// Tests the `register_content_soulbound` function.
+        /// - Verifies the soulbound flag is visible through `get_content` and `is_soulbound`.
+        /// - Verifies every transfer path, offers, and forced transfers reject soulbound content.
+        #[ink::test]
+        fn test_soulbound_content() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let bound = contract.register_content_soulbound(String::from("default_oracle_certificate")).unwrap();
+            let regular = contract.register_content(String::from("default_oracle_content")).unwrap();
+            assert!(contract.get_content(bound).unwrap().is_soulbound());
+            assert!(contract.is_soulbound(bound));
+            assert!(!contract.is_soulbound(regular));
+            assert!(!contract.is_soulbound(99));
+
+            let bob = accounts.bob;
+            assert_eq!(contract.transfer_ownership(bound, bob), Err(Error::ContentSoulbound));
+            assert_eq!(contract.transfer_ownership_checked(bound, bob, accounts.alice), Err(Error::ContentSoulbound));
+            assert_eq!(contract.transfer_ownership_with_memo(bound, bob, String::from("memo")), Err(Error::ContentSoulbound));
+            assert_eq!(contract.safe_transfer_ownership(bound, bob, Vec::new()), Err(Error::ContentSoulbound));
+            assert_eq!(contract.transfer_batch(vec![bound, regular], bob), Ok(vec![Err(Error::ContentSoulbound), Ok(())]));
+            assert_eq!(contract.offer_transfer(bound, bob), Err(Error::ContentSoulbound));
+            assert_eq!(contract.admin_schedule_transfer(bound, bob, String::from("order")), Err(Error::ContentSoulbound));
+            assert_eq!(contract.transfer_all(bob, 10), Ok(0));
+            assert_eq!(contract.owner_of(bound), Some(accounts.alice));
+        }
+
+        /// Tests the owner lock functions.
+        /// - Verifies only the owner can lock, and locked content cannot be transferred or offered.
+        /// - Verifies unlocking allows the transfer again, and the transfer clears any lock.
+        /// - Verifies `lock_content_until` expires on its own once the block timestamp is reached.
+        #[ink::test]
+        fn test_lock_content() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.lock_content(content_id), Err(Error::NotOwner));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.lock_content(99), Err(Error::ContentNotFound));
+
+            contract.lock_content(content_id).unwrap();
+            assert!(contract.is_locked(content_id));
+            assert_eq!(contract.owner_of(content_id), Some(accounts.alice));
+            assert_eq!(contract.transfer_ownership(content_id, accounts.bob), Err(Error::ContentLocked));
+            assert_eq!(contract.offer_transfer(content_id, accounts.bob), Err(Error::ContentLocked));
+            assert_eq!(contract.transfer_all(accounts.bob, 10), Ok(0));
+
+            contract.unlock_content(content_id).unwrap();
+            assert!(!contract.is_locked(content_id));
+            contract.transfer_ownership(content_id, accounts.bob).unwrap();
+
+            // Timed lock.
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            contract.lock_content_until(content_id, 2_000).unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(1_999);
+            assert!(contract.is_locked(content_id));
+            assert_eq!(contract.transfer_ownership(content_id, accounts.charlie), Err(Error::ContentLocked));
+            test::set_block_timestamp::<DefaultEnvironment>(2_000);
+            assert!(!contract.is_locked(content_id));
+            contract.transfer_ownership(content_id, accounts.charlie).unwrap();
+            assert_eq!(contract.owner_of(content_id), Some(accounts.charlie));
+        }
+
+        /// Tests the `approve` / `transfer_from` flow.
+        /// - Verifies only the owner can approve, and only the owner or the operator can call `transfer_from`.
+        /// - Verifies revocation takes effect immediately.
+        /// - Verifies the approval is cleared by `transfer_from` and by any other transfer path.
+        #[ink::test]
+        fn test_approve_and_transfer_from() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let market = accounts.eve;
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            let bound = contract.register_content_soulbound(String::from("default_oracle_certificate")).unwrap();
+            assert_eq!(contract.approve(bound, market), Err(Error::ContentSoulbound));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.approve(content_id, market), Err(Error::NotOwner));
+
+            // Revocation.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.approve(content_id, market).unwrap();
+            assert_eq!(contract.get_approved(content_id), Some(market));
+            contract.revoke_approval(content_id).unwrap();
+            assert_eq!(contract.get_approved(content_id), None);
+            test::set_caller::<DefaultEnvironment>(market);
+            assert_eq!(contract.transfer_from(content_id, accounts.bob), Err(Error::NotApproved));
+
+            // Approve, then transfer_from clears the approval.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.approve(content_id, market).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(contract.transfer_from(content_id, accounts.charlie), Err(Error::NotApproved));
+            test::set_caller::<DefaultEnvironment>(market);
+            contract.transfer_from(content_id, accounts.bob).unwrap();
+            assert_eq!(contract.owner_of(content_id), Some(accounts.bob));
+            assert_eq!(contract.get_approved(content_id), None);
+            assert_eq!(contract.transfer_from(content_id, accounts.charlie), Err(Error::NotApproved));
+
+            // A direct transfer clears the approval too.
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.approve(content_id, market).unwrap();
+            contract.transfer_ownership(content_id, accounts.charlie).unwrap();
+            assert_eq!(contract.get_approved(content_id), None);
+        }
+
+        /// Tests the `set_approval_for_all` function.
+        /// - Verifies an account cannot approve itself.
+        /// - Verifies an operator can move two different items of the owner but nothing of other accounts.
+        /// - Verifies revocation takes effect immediately.
+        #[ink::test]
+        fn test_set_approval_for_all() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let operator = accounts.eve;
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let first = contract.register_content(String::from("default_oracle_a")).unwrap();
+            let second = contract.register_content(String::from("default_oracle_b")).unwrap();
+            let third = contract.register_content(String::from("default_oracle_c")).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let bobs = contract.register_content(String::from("default_oracle_d")).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.set_approval_for_all(accounts.alice, true), Err(Error::SelfApproval));
+            contract.set_approval_for_all(operator, true).unwrap();
+            assert!(contract.is_approved_for_all(accounts.alice, operator));
+
+            test::set_caller::<DefaultEnvironment>(operator);
+            contract.transfer_from(first, accounts.charlie).unwrap();
+            contract.transfer_from(second, accounts.charlie).unwrap();
+            assert_eq!(contract.balance_of(accounts.charlie), 2);
+            assert_eq!(contract.transfer_from(bobs, accounts.charlie), Err(Error::NotApproved));
+            assert_eq!(contract.transfer_from(first, accounts.django), Err(Error::NotApproved));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_approval_for_all(operator, false).unwrap();
+            assert!(!contract.is_approved_for_all(accounts.alice, operator));
+            test::set_caller::<DefaultEnvironment>(operator);
+            assert_eq!(contract.transfer_from(third, accounts.charlie), Err(Error::NotApproved));
+
+            let events: Vec<_> = test::recorded_events().collect();
+            let revoked = <ApprovalForAll as scale::Decode>::decode(&mut &events[7].data[..]).unwrap();
+            assert!(!revoked.approved);
+        }
+
+        /// Tests the `approve_until` function.
+        /// - Verifies the approval works until just before its expiry.
+        /// - Verifies `get_approved` returns `None` and `transfer_from` fails with `ApprovalExpired` from the expiry on.
+        #[ink::test]
+        fn test_approve_until() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let market = accounts.eve;
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let first = contract.register_content(String::from("default_oracle_a")).unwrap();
+            let second = contract.register_content(String::from("default_oracle_b")).unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            contract.approve_until(first, market, 2_000).unwrap();
+            contract.approve_until(second, market, 2_000).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(market);
+            test::set_block_timestamp::<DefaultEnvironment>(1_999);
+            assert_eq!(contract.get_approved(first), Some(market));
+            contract.transfer_from(first, accounts.bob).unwrap();
+
+            test::set_block_timestamp::<DefaultEnvironment>(2_000);
+            assert_eq!(contract.get_approved(second), None);
+            assert_eq!(contract.transfer_from(second, accounts.bob), Err(Error::ApprovalExpired));
+            assert_eq!(contract.owner_of(second), Some(accounts.alice));
+        }
+
+        /// Tests the `permit` function.
+        /// - Verifies a valid owner signature grants the approval through a relayer and advances the nonce.
+        /// - Verifies a replayed signature and a tampered payload fail with `InvalidSignature`.
+        /// - Verifies an expired deadline fails with `PermitExpired`.
+        #[ink::test]
+        fn test_permit() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let (secret_key, owner) = ecdsa_account(7);
+            let relayer = accounts.django;
+            let market = accounts.eve;
+            test::set_caller::<DefaultEnvironment>(owner);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            let deadline = 5_000;
+
+            let signature = ecdsa_sign(&secret_key, contract.permit_hash(content_id, market, 0, deadline));
+            test::set_caller::<DefaultEnvironment>(relayer);
+            // Tampered payload: a different operator than the one signed for.
+            assert_eq!(contract.permit(content_id, relayer, deadline, signature), Err(Error::InvalidSignature));
+            contract.permit(content_id, market, deadline, signature).unwrap();
+            assert_eq!(contract.get_approved(content_id), Some(market));
+            assert_eq!(contract.nonce_of(owner), 1);
+
+            // Replay after the owner revoked.
+            test::set_caller::<DefaultEnvironment>(owner);
+            contract.revoke_approval(content_id).unwrap();
+            test::set_caller::<DefaultEnvironment>(relayer);
+            assert_eq!(contract.permit(content_id, market, deadline, signature), Err(Error::InvalidSignature));
+            assert_eq!(contract.get_approved(content_id), None);
+
+            // A signature by another key is rejected too.
+            let (other_key, _) = ecdsa_account(8);
+            let forged = ecdsa_sign(&other_key, contract.permit_hash(content_id, market, 1, deadline));
+            assert_eq!(contract.permit(content_id, market, deadline, forged), Err(Error::InvalidSignature));
+
+            let signature = ecdsa_sign(&secret_key, contract.permit_hash(content_id, market, 1, deadline));
+            test::set_block_timestamp::<DefaultEnvironment>(deadline + 1);
+            assert_eq!(contract.permit(content_id, market, deadline, signature), Err(Error::PermitExpired));
+            assert_eq!(contract.nonce_of(owner), 1);
+        }
+
+        /// Tests the `execute_signed_transfer` function.
+        /// - Verifies a relayer can execute the owner's signed intent, advancing the nonce and recording itself in the event.
+        /// - Verifies replayed intents fail with `InvalidNonce` and intents signed by another key with `InvalidSignature`.
+        #[ink::test]
+        fn test_execute_signed_transfer() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let (secret_key, owner) = ecdsa_account(7);
+            let relayer = accounts.django;
+            let buyer = accounts.eve;
+            test::set_caller::<DefaultEnvironment>(owner);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            let deadline = 5_000;
+
+            let signature = ecdsa_sign(&secret_key, contract.transfer_hash(content_id, buyer, 0, deadline));
+            test::set_caller::<DefaultEnvironment>(relayer);
+            // Tampered payload: a different recipient than the one signed for.
+            assert_eq!(
+                contract.execute_signed_transfer(content_id, relayer, deadline, 0, signature),
+                Err(Error::InvalidSignature)
+            );
+            contract.execute_signed_transfer(content_id, buyer, deadline, 0, signature).unwrap();
+            assert_eq!(contract.owner_of(content_id), Some(buyer));
+            assert_eq!(contract.nonce_of(owner), 1);
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <OwnershipTransferred as scale::Decode>::decode(&mut &events[1].data[..]).unwrap();
+            assert_eq!((event.from, event.to, event.relayed_by), (owner, buyer, Some(relayer)));
+
+            // Replay, once the content is back with the signer.
+            test::set_caller::<DefaultEnvironment>(buyer);
+            contract.transfer_ownership(content_id, owner).unwrap();
+            test::set_caller::<DefaultEnvironment>(relayer);
+            assert_eq!(
+                contract.execute_signed_transfer(content_id, buyer, deadline, 0, signature),
+                Err(Error::InvalidNonce)
+            );
+
+            let (other_key, _) = ecdsa_account(8);
+            let forged = ecdsa_sign(&other_key, contract.transfer_hash(content_id, buyer, 1, deadline));
+            assert_eq!(
+                contract.execute_signed_transfer(content_id, buyer, deadline, 1, forged),
+                Err(Error::InvalidSignature)
+            );
+            assert_eq!(contract.nonce_of(owner), 1);
+
+            // A permit signature cannot be replayed as a transfer intent.
+            let permit = ecdsa_sign(&secret_key, contract.permit_hash(content_id, buyer, 1, deadline));
+            assert_eq!(
+                contract.execute_signed_transfer(content_id, buyer, deadline, 1, permit),
+                Err(Error::InvalidSignature)
+            );
+
+            let signature = ecdsa_sign(&secret_key, contract.transfer_hash(content_id, buyer, 1, deadline));
+            contract.execute_signed_transfer(content_id, buyer, deadline, 1, signature).unwrap();
+            assert_eq!(contract.nonce_of(owner), 2);
+        }
+
+        /// Tests the `approve_batch` and `revoke_batch` functions.
+        /// - Verifies owned items are approved and revoked while missing and not-owned items fail on their own.
+        /// - Verifies one `Approval` event is emitted per successful item and oversized batches are rejected.
+        #[ink::test]
+        fn test_approve_and_revoke_batch() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let first = contract.register_content(String::from("default_oracle_content_1")).unwrap();
+            let second = contract.register_content(String::from("default_oracle_content_2")).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let foreign = contract.register_content(String::from("default_oracle_content_3")).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let before = test::recorded_events().count();
+            let results = contract.approve_batch(vec![first, foreign, second, 99], accounts.eve).unwrap();
+            assert_eq!(results, vec![Ok(()), Err(Error::NotOwner), Ok(()), Err(Error::ContentNotFound)]);
+            assert_eq!(test::recorded_events().count(), before + 2);
+            assert_eq!(contract.get_approved(first), Some(accounts.eve));
+            assert_eq!(contract.get_approved(second), Some(accounts.eve));
+            assert_eq!(contract.get_approved(foreign), None);
+
+            let results = contract.revoke_batch(vec![foreign, first]).unwrap();
+            assert_eq!(results, vec![Err(Error::NotOwner), Ok(())]);
+            assert_eq!(test::recorded_events().count(), before + 3);
+            assert_eq!(contract.get_approved(first), None);
+            assert_eq!(contract.get_approved(second), Some(accounts.eve));
+
+            let oversized = vec![first; MAX_BATCH_SIZE as usize + 1];
+            assert_eq!(contract.approve_batch(oversized.clone(), accounts.eve), Err(Error::BatchTooLarge));
+            assert_eq!(contract.revoke_batch(oversized), Err(Error::BatchTooLarge));
+        }
+
+        /// Tests the `approvals_of` and `operators_of` functions.
+        /// - Verifies the approval index lists granted approvals and shrinks on revoke, re-approval, and `transfer_from`.
+        /// - Verifies the operator index follows `set_approval_for_all`, including repeated and revoked approvals.
+        #[ink::test]
+        fn test_approval_introspection() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let first = contract.register_content(String::from("default_oracle_content_1")).unwrap();
+            let second = contract.register_content(String::from("default_oracle_content_2")).unwrap();
+            let third = contract.register_content(String::from("default_oracle_content_3")).unwrap();
+            contract.approve(first, accounts.eve).unwrap();
+            contract.approve_until(second, accounts.frank, 5_000).unwrap();
+            contract.approve(third, accounts.eve).unwrap();
+            // Replacing an approval does not add a second index entry.
+            contract.approve(first, accounts.frank).unwrap();
+            assert_eq!(
+                contract.approvals_of(accounts.alice, 0, 10),
+                vec![(first, accounts.frank, None), (second, accounts.frank, Some(5_000)), (third, accounts.eve, None)]
+            );
+            assert_eq!(contract.approvals_of(accounts.alice, 1, 1), vec![(second, accounts.frank, Some(5_000))]);
+
+            contract.revoke_approval(first).unwrap();
+            assert_eq!(
+                contract.approvals_of(accounts.alice, 0, 10),
+                vec![(third, accounts.eve, None), (second, accounts.frank, Some(5_000))]
+            );
+
+            test::set_caller::<DefaultEnvironment>(accounts.eve);
+            contract.transfer_from(third, accounts.bob).unwrap();
+            assert_eq!(contract.approvals_of(accounts.alice, 0, 10), vec![(second, accounts.frank, Some(5_000))]);
+            assert!(contract.approvals_of(accounts.bob, 0, 10).is_empty());
+            test::set_caller::<DefaultEnvironment>(accounts.frank);
+            contract.transfer_from(second, accounts.bob).unwrap();
+            assert!(contract.approvals_of(accounts.alice, 0, 10).is_empty());
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_approval_for_all(accounts.charlie, true).unwrap();
+            contract.set_approval_for_all(accounts.django, true).unwrap();
+            contract.set_approval_for_all(accounts.charlie, true).unwrap();
+            contract.set_approval_for_all(accounts.eve, true).unwrap();
+            assert_eq!(contract.operators_of(accounts.alice), vec![accounts.charlie, accounts.django, accounts.eve]);
+            contract.set_approval_for_all(accounts.charlie, false).unwrap();
+            contract.set_approval_for_all(accounts.frank, false).unwrap();
+            assert_eq!(contract.operators_of(accounts.alice), vec![accounts.eve, accounts.django]);
+            assert!(contract.is_approved_for_all(accounts.alice, accounts.eve));
+            assert!(!contract.is_approved_for_all(accounts.alice, accounts.charlie));
+            assert!(contract.operators_of(accounts.bob).is_empty());
+        }
+
+        /// Tests the `burn_content` function.
+        /// - Verifies only the owner can burn, and burned content is gone from lookups, indexes, and the supply.
+        /// - Verifies transfers of burned content fail, IDs are not reused, and anyone can re-register the hash.
+        #[ink::test]
+        fn test_burn_content() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let hash = String::from("default_oracle_content_1");
+            let first = contract.register_content(hash.clone()).unwrap();
+            let second = contract.register_content(String::from("default_oracle_content_2")).unwrap();
+            contract.approve(first, accounts.eve).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.burn_content(first), Err(Error::NotOwner));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.burn_content(first).unwrap();
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <ContentBurned as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.content_id, event.owner, event.content_hash), (first, accounts.alice, hash.clone()));
+
+            assert_eq!(contract.get_content(first), None);
+            assert_eq!(contract.get_content_id_by_hash(hash.clone()), None);
+            assert_eq!(contract.owner_of(first), None);
+            assert_eq!(contract.total_supply(), 1);
+            assert_eq!(contract.balance_of(accounts.alice), 1);
+            assert_eq!(contract.contents_of(accounts.alice, 0, 10), vec![second]);
+            assert_eq!(contract.content_by_index(0), Some(second));
+            assert_eq!(contract.content_by_index(1), None);
+            assert!(contract.approvals_of(accounts.alice, 0, 10).is_empty());
+            assert_eq!(contract.transfer_ownership(first, accounts.bob), Err(Error::ContentNotFound));
+            assert_eq!(contract.burn_content(first), Err(Error::ContentNotFound));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let reregistered = contract.register_content(hash.clone()).unwrap();
+            assert!(reregistered > second);
+            assert_eq!(contract.get_content_id_by_hash(hash), Some(reregistered));
+            assert_eq!(contract.total_supply(), 2);
+        }
+
+        /// Tests that burning content ends the rights other accounts held on it.
+        /// - Verifies licenses, the licensee index, purchased access, transfer exemptions, and the
+        ///   derivative consent requirement are all gone after a burn.
+        #[ink::test]
+        fn test_burn_content_ends_rights() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_burned_rights")).unwrap();
+            contract.grant_license(content_id, accounts.bob, String::from("terms"), None, true, false).unwrap();
+            contract.set_marketplace_only(content_id, true).unwrap();
+            contract.add_transfer_exemption(content_id, accounts.django).unwrap();
+            contract.set_derivative_consent_required(content_id, true).unwrap();
+            contract.set_access_price(content_id, 50, None).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.eve);
+            send_value(50);
+            contract.purchase_access(content_id).unwrap();
+            send_value(0);
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.burn_content(content_id).unwrap();
+            assert_eq!(contract.license_of(content_id, accounts.bob), None);
+            assert!(!contract.is_license_active(content_id, accounts.bob));
+            assert!(!contract.has_exclusive_license(content_id));
+            assert_eq!(contract.licensees_of(content_id, 0, 10), Vec::<AccountId>::new());
+            assert!(!contract.has_access(content_id, accounts.eve));
+            assert_eq!(contract.access_expires_at(content_id, accounts.eve), None);
+            assert!(!contract.is_transfer_exempt(content_id, accounts.django));
+            assert!(!contract.requires_derivative_consent(content_id));
+        }
+
+        /// Tests that `burn_content` respects owner locks and scheduled forced transfers.
+        /// - Verifies locked content and content with a pending forced transfer cannot be burned.
+        #[ink::test]
+        fn test_burn_content_blocked() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let other = contract.register_content(String::from("default_oracle_content_2")).unwrap();
+            contract.lock_content(other).unwrap();
+            assert_eq!(contract.burn_content(other), Err(Error::ContentLocked));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.admin_schedule_transfer(content_id, accounts.charlie, String::from("court_order")).unwrap();
+            assert_eq!(contract.burn_content(content_id), Err(Error::ForcedTransferPending));
+            contract.admin_cancel_transfer(content_id).unwrap();
+            contract.burn_content(content_id).unwrap();
+        }
+
+        /// Tests the content status lifecycle driven by `set_content_status`.
+        /// - Verifies new content is `Active`, `Disputed` can return to `Active`, and `Revoked` is terminal.
+        /// - Verifies only admins can change the status and `status_of` reports `Burned` for burned IDs.
+        #[ink::test]
+        fn test_content_status_transitions() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            assert_eq!(contract.get_content(content_id).unwrap().status(), ContentStatus::Active);
+            assert_eq!(contract.status_of(content_id), Some(ContentStatus::Active));
+            assert_eq!(contract.status_of(0), None);
+            assert_eq!(contract.status_of(content_id + 1), None);
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_content_status(content_id, ContentStatus::Disputed), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.set_content_status(99, ContentStatus::Disputed), Err(Error::ContentNotFound));
+            assert_eq!(
+                contract.set_content_status(content_id, ContentStatus::Active),
+                Err(Error::InvalidStatusTransition)
+            );
+            assert_eq!(
+                contract.set_content_status(content_id, ContentStatus::Burned),
+                Err(Error::InvalidStatusTransition)
+            );
+
+            contract.set_content_status(content_id, ContentStatus::Disputed).unwrap();
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <ContentStatusChanged as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.old_status, event.new_status, event.changed_by), (ContentStatus::Active, ContentStatus::Disputed, accounts.alice));
+            contract.set_content_status(content_id, ContentStatus::Active).unwrap();
+            contract.set_content_status(content_id, ContentStatus::Disputed).unwrap();
+            contract.set_content_status(content_id, ContentStatus::Revoked).unwrap();
+            assert_eq!(contract.status_of(content_id), Some(ContentStatus::Revoked));
+            for status in [ContentStatus::Active, ContentStatus::Disputed, ContentStatus::Burned] {
+                assert_eq!(contract.set_content_status(content_id, status), Err(Error::InvalidStatusTransition));
+            }
+
+            let other = contract.register_content(String::from("default_oracle_content_2")).unwrap();
+            contract.set_content_status(other, ContentStatus::Revoked).unwrap();
+            let burned = contract.register_content(String::from("default_oracle_content_3")).unwrap();
+            contract.burn_content(burned).unwrap();
+            assert_eq!(contract.status_of(burned), Some(ContentStatus::Burned));
+        }
+
+        /// Tests that content which is not `Active` cannot change hands or be burned.
+        /// - Verifies transfers, approvals, offers, and burning fail with `ContentNotActive` for disputed and revoked content.
+        /// - Verifies `contents_of_with_status` filters the owner's content by status.
+        #[ink::test]
+        fn test_content_status_blocks_operations() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let active = contract.register_content(String::from("default_oracle_content_1")).unwrap();
+            let disputed = contract.register_content(String::from("default_oracle_content_2")).unwrap();
+            let revoked = contract.register_content(String::from("default_oracle_content_3")).unwrap();
+            contract.set_content_status(disputed, ContentStatus::Disputed).unwrap();
+            contract.set_content_status(revoked, ContentStatus::Revoked).unwrap();
+
+            let bob = accounts.bob;
+            for content_id in [disputed, revoked] {
+                assert_eq!(contract.transfer_ownership(content_id, bob), Err(Error::ContentNotActive));
+                assert_eq!(contract.transfer_ownership_with_memo(content_id, bob, String::from("memo")), Err(Error::ContentNotActive));
+                assert_eq!(contract.safe_transfer_ownership(content_id, bob, Vec::new()), Err(Error::ContentNotActive));
+                assert_eq!(contract.approve(content_id, bob), Err(Error::ContentNotActive));
+                assert_eq!(contract.offer_transfer(content_id, bob), Err(Error::ContentNotActive));
+                assert_eq!(contract.admin_schedule_transfer(content_id, bob, String::from("order")), Err(Error::ContentNotActive));
+                assert_eq!(contract.burn_content(content_id), Err(Error::ContentNotActive));
+            }
+            assert_eq!(
+                contract.transfer_batch(vec![disputed, active], bob),
+                Ok(vec![Err(Error::ContentNotActive), Ok(())])
+            );
+
+            assert_eq!(contract.contents_of_with_status(accounts.alice, ContentStatus::Disputed, 0, 10), vec![disputed]);
+            assert_eq!(contract.contents_of_with_status(accounts.alice, ContentStatus::Revoked, 0, 10), vec![revoked]);
+            assert!(contract.contents_of_with_status(accounts.alice, ContentStatus::Active, 0, 10).is_empty());
+            assert_eq!(contract.contents_of_with_status(bob, ContentStatus::Active, 0, 10), vec![active]);
+
+            contract.set_content_status(disputed, ContentStatus::Active).unwrap();
+            contract.transfer_ownership(disputed, bob).unwrap();
+        }
+
+        /// Tests the `revoke_content` and `unrevoke_content` functions.
+        /// - Verifies admins and moderators can revoke with a reason hash, and other accounts cannot.
+        /// - Verifies revoked content cannot be transferred and its hash cannot be claimed by another account.
+        /// - Verifies an appeal through `unrevoke_content` makes the content transferable again.
+        #[ink::test]
+        fn test_revoke_content() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.grant_role(Role::Moderator, accounts.charlie).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let hash = String::from("default_oracle_content");
+            let content_id = contract.register_content(hash.clone()).unwrap();
+            let reason = || String::from("infringement_report");
+
+            assert_eq!(contract.revoke_content(content_id, reason()), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(contract.revoke_content(99, reason()), Err(Error::ContentNotFound));
+            assert_eq!(contract.unrevoke_content(content_id), Err(Error::InvalidStatusTransition));
+            contract.revoke_content(content_id, reason()).unwrap();
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <ContentRevoked as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.content_id, event.reason_hash, event.revoked_by), (content_id, reason(), accounts.charlie));
+            assert_eq!(contract.status_of(content_id), Some(ContentStatus::Revoked));
+            assert_eq!(contract.revoke_content(content_id, reason()), Err(Error::InvalidStatusTransition));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.transfer_ownership(content_id, accounts.django), Err(Error::ContentNotActive));
+            assert_eq!(contract.unrevoke_content(content_id), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(contract.register_content(hash.clone()), Err(Error::AlreadyRegistered));
+            assert_eq!(contract.get_content_id_by_hash(hash), Some(content_id));
+            assert_eq!(contract.owner_of(content_id), Some(accounts.bob));
+            assert_eq!(contract.total_supply(), 1);
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.unrevoke_content(content_id).unwrap();
+            assert_eq!(contract.status_of(content_id), Some(ContentStatus::Active));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.transfer_ownership(content_id, accounts.django).unwrap();
+        }
+
+        /// Tests the `freeze_content` and `unfreeze_content` functions.
+        /// - Verifies only admins and moderators can freeze, and freezing twice keeps the original timestamp.
+        /// - Verifies frozen content cannot be transferred, approved, offered, burned, or force-transferred.
+        /// - Verifies the owner regains full control after unfreezing.
+        #[ink::test]
+        fn test_freeze_content() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.grant_role(Role::Moderator, accounts.charlie).unwrap();
+            contract.set_forced_transfer_delay(0).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            contract.approve(content_id, accounts.eve).unwrap();
+
+            assert_eq!(contract.freeze_content(content_id), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(contract.freeze_content(99), Err(Error::ContentNotFound));
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            contract.freeze_content(content_id).unwrap();
+            let events_after_freeze = test::recorded_events().count();
+            test::set_block_timestamp::<DefaultEnvironment>(2_000);
+            contract.freeze_content(content_id).unwrap();
+            assert_eq!(test::recorded_events().count(), events_after_freeze);
+            assert_eq!(contract.frozen_since(content_id), Some(1_000));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let django = accounts.django;
+            assert_eq!(contract.transfer_ownership(content_id, django), Err(Error::ContentFrozen));
+            assert_eq!(contract.approve(content_id, django), Err(Error::ContentFrozen));
+            assert_eq!(contract.offer_transfer(content_id, django), Err(Error::ContentFrozen));
+            assert_eq!(contract.burn_content(content_id), Err(Error::ContentFrozen));
+            assert_eq!(contract.unfreeze_content(content_id), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(accounts.eve);
+            assert_eq!(contract.transfer_from(content_id, django), Err(Error::ContentFrozen));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.admin_schedule_transfer(content_id, django, String::from("court_order")).unwrap();
+            assert_eq!(contract.admin_execute_transfer(content_id), Err(Error::ContentFrozen));
+            contract.admin_cancel_transfer(content_id).unwrap();
+
+            contract.unfreeze_content(content_id).unwrap();
+            contract.unfreeze_content(content_id).unwrap();
+            assert_eq!(contract.frozen_since(content_id), None);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.approve(content_id, accounts.frank).unwrap();
+            contract.transfer_ownership(content_id, django).unwrap();
+            assert_eq!(contract.owner_of(content_id), Some(django));
+        }
+
+        /// Tests the `update_content_hash` function.
+        /// - Verifies only the owner can update, and the new hash must pass the oracle and be unregistered.
+        /// - Verifies the hash lookup follows the update, the old hash is released, and `hash_history` accumulates.
+        #[ink::test]
+        fn test_update_content_hash() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let original = String::from("default_oracle_cid_v0");
+            let content_id = contract.register_content(original.clone()).unwrap();
+            let other = contract.register_content(String::from("default_oracle_other")).unwrap();
+            let repinned = String::from("default_oracle_cid_v1");
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.update_content_hash(content_id, repinned.clone()), Err(Error::NotOwner));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.update_content_hash(content_id, String::from("unvalidated")), Err(Error::InvalidContent));
+            assert_eq!(
+                contract.update_content_hash(content_id, String::from("default_oracle_other")),
+                Err(Error::AlreadyRegistered)
+            );
+            assert_eq!(contract.update_content_hash(content_id, original.clone()), Err(Error::AlreadyRegistered));
+            assert_eq!(contract.hash_history_length(content_id), 0);
+
+            contract.update_content_hash(content_id, repinned.clone()).unwrap();
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <ContentHashUpdated as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.old_hash, event.new_hash), (original.clone(), repinned.clone()));
+            assert_eq!(contract.get_content(content_id).unwrap().content_hash(), repinned);
+            assert_eq!(contract.get_content_id_by_hash(repinned.clone()), Some(content_id));
+            assert_eq!(contract.get_content_id_by_hash(original.clone()), None);
+
+            let latest = String::from("default_oracle_cid_v2");
+            contract.update_content_hash(content_id, latest.clone()).unwrap();
+            assert_eq!(contract.hash_history(content_id, 0, 10), vec![original.clone(), repinned.clone()]);
+            assert_eq!(contract.hash_history(content_id, 1, 10), vec![repinned]);
+            assert_eq!(contract.hash_history_length(content_id), 2);
+            assert!(contract.hash_history(other, 0, 10).is_empty());
+            assert_eq!(contract.owner_of(content_id), Some(accounts.alice));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let reclaimed = contract.register_content(original).unwrap();
+            assert_ne!(reclaimed, content_id);
+        }
+
+        /// Tests the `register_new_version` function.
+        /// - Verifies a three-deep chain is linked both ways and `latest_version` and `version_chain` follow it.
+        /// - Verifies only the owner of the previous version can version it, and only once.
+        /// - Verifies old versions remain transferable on their own.
+        /// - Verifies only the latest version can be burned, and burning it unlinks it from the chain.
+        #[ink::test]
+        fn test_register_new_version() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let v1 = contract.register_content(String::from("default_oracle_doc_v1")).unwrap();
+            let v2 = contract.register_new_version(v1, String::from("default_oracle_doc_v2")).unwrap();
+            let v3 = contract.register_new_version(v2, String::from("default_oracle_doc_v3")).unwrap();
+
+            assert_eq!((contract.superseded_by(v1), contract.supersedes(v2)), (Some(v2), Some(v1)));
+            assert_eq!((contract.superseded_by(v2), contract.supersedes(v3)), (Some(v3), Some(v2)));
+            assert_eq!((contract.superseded_by(v3), contract.supersedes(v1)), (None, None));
+            for version in [v1, v2, v3] {
+                assert_eq!(contract.latest_version(version), Some(v3));
+            }
+            assert_eq!(contract.latest_version(99), None);
+            assert_eq!(contract.version_chain(v1, 10), vec![v1, v2, v3]);
+            assert_eq!(contract.version_chain(v2, 10), vec![v2, v3]);
+            assert_eq!(contract.version_chain(v1, 2), vec![v1, v2]);
+            assert!(contract.version_chain(99, 10).is_empty());
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <NewVersionRegistered as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.content_id, event.supersedes), (v3, v2));
+
+            assert_eq!(
+                contract.register_new_version(v2, String::from("default_oracle_doc_fork")),
+                Err(Error::AlreadySuperseded)
+            );
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.register_new_version(v3, String::from("default_oracle_doc_v4")),
+                Err(Error::NotOwner)
+            );
+            assert_eq!(contract.get_content_id_by_hash(String::from("default_oracle_doc_v4")), None);
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.transfer_ownership(v1, accounts.bob).unwrap();
+            assert_eq!(contract.owner_of(v1), Some(accounts.bob));
+            assert_eq!(contract.owner_of(v3), Some(accounts.alice));
+            assert_eq!(contract.latest_version(v1), Some(v3));
+
+            // Only the latest version can be burned, which unlinks it from the chain.
+            assert_eq!(contract.burn_content(v2), Err(Error::AlreadySuperseded));
+            contract.burn_content(v3).unwrap();
+            assert_eq!((contract.superseded_by(v2), contract.supersedes(v3)), (None, None));
+            assert_eq!(contract.latest_version(v1), Some(v2));
+            assert_eq!(contract.version_chain(v1, 10), vec![v1, v2]);
+            contract.burn_content(v2).unwrap();
+            assert_eq!(contract.superseded_by(v1), None);
+            assert_eq!(contract.latest_version(v1), Some(v1));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let fork = contract.register_new_version(v1, String::from("default_oracle_doc_fork")).unwrap();
+            assert_eq!(contract.latest_version(v1), Some(fork));
+        }
+
+        /// Tests the `register_content_with_metadata`, `set_metadata`, and `get_metadata` functions.
+        /// - Verifies metadata is stored at registration, and content registered without it has none.
+        /// - Verifies each field's byte limit is enforced and only the owner can update the metadata.
+        #[ink::test]
+        fn test_content_metadata() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let metadata = ContentMetadata {
+                title: String::from("Sunrise"),
+                description: String::from("A photo of the sunrise"),
+                uri: String::from("ipfs://sunrise"),
+            };
+            let legacy = contract.register_content(String::from("default_oracle_legacy")).unwrap();
+            let content_id = contract
+                .register_content_with_metadata(String::from("default_oracle_sunrise"), metadata.clone())
+                .unwrap();
+            assert_eq!(contract.get_metadata(content_id), Some(metadata.clone()));
+            assert_eq!(contract.get_metadata(legacy), None);
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <MetadataUpdated as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.content_id, event.metadata, event.updated_by), (content_id, metadata.clone(), accounts.alice));
+
+            let at_limits = ContentMetadata {
+                title: "t".repeat(MAX_TITLE_LENGTH),
+                description: "d".repeat(MAX_DESCRIPTION_LENGTH),
+                uri: "u".repeat(MAX_URI_LENGTH),
+            };
+            contract.set_metadata(legacy, at_limits.clone()).unwrap();
+            assert_eq!(contract.get_metadata(legacy), Some(at_limits.clone()));
+            let oversized = [
+                ContentMetadata { title: "t".repeat(MAX_TITLE_LENGTH + 1), ..at_limits.clone() },
+                ContentMetadata { description: "d".repeat(MAX_DESCRIPTION_LENGTH + 1), ..at_limits.clone() },
+                ContentMetadata { uri: "u".repeat(MAX_URI_LENGTH + 1), ..at_limits.clone() },
+            ];
+            for metadata in oversized {
+                assert_eq!(contract.set_metadata(legacy, metadata.clone()), Err(Error::MetadataTooLarge));
+                assert_eq!(
+                    contract.register_content_with_metadata(String::from("default_oracle_big"), metadata),
+                    Err(Error::MetadataTooLarge)
+                );
+            }
+            assert_eq!(contract.get_content_id_by_hash(String::from("default_oracle_big")), None);
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_metadata(content_id, ContentMetadata::default()), Err(Error::NotOwner));
+            assert_eq!(contract.set_metadata(99, ContentMetadata::default()), Err(Error::ContentNotFound));
+            assert_eq!(contract.get_metadata(content_id), Some(metadata));
+        }
+
+        /// Tests the `lock_metadata` function.
+        /// - Verifies only the owner can lock, and locked metadata can no longer be set or locked again.
+        /// - Verifies the lock survives a transfer, so the new owner cannot modify the metadata.
+        #[ink::test]
+        fn test_lock_metadata() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let metadata = ContentMetadata {
+                title: String::from("Sunrise"),
+                description: String::from("A photo of the sunrise"),
+                uri: String::from("ipfs://sunrise"),
+            };
+            let content_id = contract
+                .register_content_with_metadata(String::from("default_oracle_sunrise"), metadata.clone())
+                .unwrap();
+            let replacement = || ContentMetadata { title: String::from("Sunset"), ..metadata.clone() };
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.lock_metadata(content_id), Err(Error::NotOwner));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert!(!contract.is_metadata_locked(content_id));
+            contract.lock_metadata(content_id).unwrap();
+            assert!(contract.is_metadata_locked(content_id));
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <MetadataLocked as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.content_id, event.locked_by), (content_id, accounts.alice));
+            assert_eq!(contract.set_metadata(content_id, replacement()), Err(Error::MetadataLocked));
+            assert_eq!(contract.lock_metadata(content_id), Err(Error::MetadataLocked));
+
+            contract.transfer_ownership(content_id, accounts.bob).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert!(contract.is_metadata_locked(content_id));
+            assert_eq!(contract.set_metadata(content_id, replacement()), Err(Error::MetadataLocked));
+            assert_eq!(contract.get_metadata(content_id), Some(metadata));
+        }
+
+        /// Tests the `registration_info` function.
+        /// - Verifies the block number and timestamp of the registration are recorded and carried by `ContentRegistered`.
+        /// - Verifies unknown and burned content has no registration info.
+        #[ink::test]
+        fn test_registration_info() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            test::set_caller::<DefaultEnvironment>(alice);
+            test::advance_block::<DefaultEnvironment>();
+            test::advance_block::<DefaultEnvironment>();
+            test::set_block_timestamp::<DefaultEnvironment>(1_718_000_000_000);
+            let block = ink::env::block_number::<DefaultEnvironment>();
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+
+            let expected = RegistrationInfo { registered_at_block: block, registered_at_time: 1_718_000_000_000, oracle_version: 0 };
+            assert_eq!(contract.registration_info(content_id), Some(expected));
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <ContentRegistered as scale::Decode>::decode(&mut &events[0].data[..]).unwrap();
+            assert_eq!((event.registered_at_block, event.registered_at_time), (block, 1_718_000_000_000));
+
+            test::advance_block::<DefaultEnvironment>();
+            test::set_block_timestamp::<DefaultEnvironment>(1_718_000_006_000);
+            let later = contract.register_content(String::from("default_oracle_later")).unwrap();
+            let info = contract.registration_info(later).unwrap();
+            assert_eq!((info.registered_at_block, info.registered_at_time), (block + 1, 1_718_000_006_000));
+
+            assert_eq!(contract.registration_info(99), None);
+            contract.burn_content(later).unwrap();
+            assert_eq!(contract.registration_info(later), None);
+        }
+
+        /// Tests the `creator_of` and `created_by` functions.
+        /// - Verifies the creator stays constant over two transfers while the owner changes.
+        /// - Verifies content registered on a creator's behalf credits the creator, and burning removes it from `created_by`.
+        #[ink::test]
+        fn test_creator() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.grant_role(Role::Registrar, accounts.django).unwrap();
+            let first = contract.register_content(String::from("default_oracle_content_1")).unwrap();
+            let second = contract.register_content(String::from("default_oracle_content_2")).unwrap();
+
+            contract.transfer_ownership(first, accounts.bob).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.transfer_ownership(first, accounts.charlie).unwrap();
+            assert_eq!(contract.owner_of(first), Some(accounts.charlie));
+            assert_eq!(contract.creator_of(first), Some(accounts.alice));
+            assert_eq!(contract.get_content(first).unwrap().creator(), accounts.alice);
+            assert_eq!(contract.created_by(accounts.alice, 0, 10), vec![first, second]);
+            assert!(contract.created_by(accounts.charlie, 0, 10).is_empty());
+            assert_eq!(contract.creator_of(99), None);
+
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            let on_behalf = contract.register_content_for(String::from("default_oracle_content_3"), accounts.eve).unwrap();
+            assert_eq!(contract.creator_of(on_behalf), Some(accounts.eve));
+            assert!(contract.created_by(accounts.django, 0, 10).is_empty());
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.burn_content(second).unwrap();
+            assert_eq!(contract.created_by(accounts.alice, 0, 10), vec![first]);
+        }
+
+        /// Tests the `set_tags`, `tags_of`, and `content_with_tag` functions.
+        /// - Verifies tags are normalized so differently cased tags collide, and only the owner can tag.
+        /// - Verifies re-tagging removes the old index entries and burning removes the content from every tag.
+        #[ink::test]
+        fn test_tags() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let song = contract.register_content(String::from("default_oracle_song")).unwrap();
+            let photo = contract.register_content(String::from("default_oracle_photo")).unwrap();
+            let tags = |list: &[&str]| list.iter().map(|tag| String::from(*tag)).collect::<Vec<_>>();
+
+            contract.set_tags(song, tags(&[" Music", "live", "music "])).unwrap();
+            contract.set_tags(photo, tags(&["photo", "LIVE"])).unwrap();
+            assert_eq!(contract.tags_of(song), tags(&["music", "live"]));
+            assert_eq!(contract.content_with_tag(String::from("Live"), 0, 10), vec![song, photo]);
+            assert_eq!(contract.content_with_tag(String::from("music"), 0, 10), vec![song]);
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <TagsUpdated as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.content_id, event.tags), (photo, tags(&["photo", "live"])));
+
+            contract.set_tags(song, tags(&["podcast"])).unwrap();
+            assert_eq!(contract.tags_of(song), tags(&["podcast"]));
+            assert!(contract.content_with_tag(String::from("music"), 0, 10).is_empty());
+            assert_eq!(contract.content_with_tag(String::from("live"), 0, 10), vec![photo]);
+            assert_eq!(contract.content_with_tag(String::from("podcast"), 0, 10), vec![song]);
+
+            contract.burn_content(photo).unwrap();
+            assert!(contract.content_with_tag(String::from("live"), 0, 10).is_empty());
+            assert!(contract.tags_of(photo).is_empty());
+            contract.set_tags(song, Vec::new()).unwrap();
+            assert!(contract.tags_of(song).is_empty());
+            assert!(contract.content_with_tag(String::from("podcast"), 0, 10).is_empty());
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_tags(song, tags(&["stolen"])), Err(Error::NotOwner));
+        }
+
+        /// Tests the tag limits of `set_tags`.
+        /// - Verifies more than `MAX_TAGS_PER_CONTENT` distinct tags, empty tags, and overlong tags are rejected.
+        /// - Verifies duplicates after normalization do not count towards the limit.
+        #[ink::test]
+        fn test_tag_limits() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_content")).unwrap();
+            contract.set_tags(content_id, vec![String::from("original")]).unwrap();
+
+            let mut tags: Vec<String> = (0..MAX_TAGS_PER_CONTENT).map(|i| format!("tag{i}")).collect();
+            tags.push(String::from("TAG0"));
+            contract.set_tags(content_id, tags.clone()).unwrap();
+            assert_eq!(contract.tags_of(content_id).len(), MAX_TAGS_PER_CONTENT);
+
+            tags.push(String::from("one_too_many"));
+            assert_eq!(contract.set_tags(content_id, tags), Err(Error::TooManyTags));
+            assert_eq!(contract.set_tags(content_id, vec![String::from("   ")]), Err(Error::InvalidTag));
+            assert_eq!(contract.set_tags(content_id, vec!["x".repeat(MAX_TAG_LENGTH + 1)]), Err(Error::InvalidTag));
+            assert_eq!(contract.tags_of(content_id).len(), MAX_TAGS_PER_CONTENT);
+        }
+
+        /// Tests the `content_uri` resolution.
+        /// - Verifies content resolves to the base URI followed by its hash, and follows base URI changes.
+        /// - Verifies an owner override takes precedence until it is cleared, and setters enforce access and byte limits.
+        #[ink::test]
+        fn test_content_uri() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_cid")).unwrap();
+            let other = contract.register_content(String::from("default_oracle_other")).unwrap();
+            assert_eq!(contract.content_uri(content_id), Some(String::from("default_oracle_cid")));
+            assert_eq!(contract.content_uri(99), None);
+
+            contract.set_base_uri(String::from("https://gateway.example/ipfs/")).unwrap();
+            assert_eq!(contract.content_uri(content_id), Some(String::from("https://gateway.example/ipfs/default_oracle_cid")));
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <BaseUriUpdated as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.old_uri, event.new_uri), (String::new(), String::from("https://gateway.example/ipfs/")));
+
+            contract.set_content_uri_override(content_id, String::from("ar://custom")).unwrap();
+            assert_eq!(contract.content_uri(content_id), Some(String::from("ar://custom")));
+            contract.set_base_uri(String::from("https://cdn.example/")).unwrap();
+            assert_eq!(contract.content_uri(content_id), Some(String::from("ar://custom")));
+            assert_eq!(contract.content_uri(other), Some(String::from("https://cdn.example/default_oracle_other")));
+            contract.set_content_uri_override(content_id, String::new()).unwrap();
+            assert_eq!(contract.content_uri(content_id), Some(String::from("https://cdn.example/default_oracle_cid")));
+
+            let too_long = "u".repeat(MAX_URI_LENGTH + 1);
+            assert_eq!(contract.set_base_uri(too_long.clone()), Err(Error::UriTooLong));
+            assert_eq!(contract.set_content_uri_override(content_id, too_long), Err(Error::UriTooLong));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_base_uri(String::from("https://evil.example/")), Err(Error::NotAdmin));
+            assert_eq!(contract.set_content_uri_override(content_id, String::from("ar://evil")), Err(Error::NotOwner));
+            assert_eq!(contract.base_uri(), String::from("https://cdn.example/"));
+        }
+
+        /// Tests the `register_content_with_scheme` function and `check_hash_format`.
+        /// - Verifies a well-formed hash of each scheme registers and its scheme is stored and queryable.
+        /// - Verifies malformed hashes fail with `InvalidContent`, and `check_hash_format` reports the reason.
+        #[ink::test]
+        fn test_register_content_with_scheme() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            test::set_caller::<DefaultEnvironment>(alice);
+            let valid = [
+                (HashScheme::IpfsCidV0, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG"),
+                (HashScheme::IpfsCidV1, "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"),
+                (HashScheme::Sha256Hex, "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"),
+                (HashScheme::Other, "default_oracle_content"),
+            ];
+            for (scheme, hash) in valid {
+                let content_id = contract.register_content_with_scheme(String::from(hash), scheme).unwrap();
+                assert_eq!(contract.hash_scheme_of(content_id), Some(scheme));
+                assert_eq!(contract.get_content(content_id).unwrap().scheme(), scheme);
+            }
+
+            let malformed = [
+                (HashScheme::IpfsCidV0, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd", HashFormatError::WrongLength),
+                (HashScheme::IpfsCidV0, "XmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG", HashFormatError::WrongPrefix),
+                (HashScheme::IpfsCidV0, "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd0", HashFormatError::InvalidCharacter),
+                (HashScheme::IpfsCidV1, "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3e", HashFormatError::WrongLength),
+                (HashScheme::IpfsCidV1, "Bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi", HashFormatError::WrongPrefix),
+                (HashScheme::IpfsCidV1, "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbz01", HashFormatError::InvalidCharacter),
+                (HashScheme::Sha256Hex, "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b8", HashFormatError::WrongLength),
+                (HashScheme::Sha256Hex, "E3B0C44298FC1C149AFBF4C8996FB92427AE41E4649B934CA495991B7852B855", HashFormatError::InvalidCharacter),
+                (HashScheme::Other, "unvalidated_content", HashFormatError::OracleMismatch),
+            ];
+            for (scheme, hash, reason) in malformed {
+                assert_eq!(contract.register_content_with_scheme(String::from(hash), scheme), Err(Error::InvalidContent));
+                assert_eq!(contract.check_hash_format(String::from(hash), scheme), Err(reason));
+            }
+            assert_eq!(contract.total_supply(), 4);
+            assert_eq!(contract.hash_scheme_of(99), None);
+        }
+
+        /// Tests the `register_derivative` function for open parents.
+        /// - Verifies anyone can register a derivative, which points at its parent and is listed by `derivatives_of`.
+        /// - Verifies nonexistent and revoked parents are rejected, and burning a derivative unlists it.
+        #[ink::test]
+        fn test_register_derivative() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let original = contract.register_content(String::from("default_oracle_song")).unwrap();
+            let revoked = contract.register_content(String::from("default_oracle_banned")).unwrap();
+            contract.revoke_content(revoked, String::from("infringement_report")).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let remix = contract.register_derivative(original, String::from("default_oracle_remix")).unwrap();
+            let cover = contract.register_derivative(original, String::from("default_oracle_cover")).unwrap();
+            assert_eq!(contract.owner_of(remix), Some(accounts.bob));
+            assert_eq!(contract.parent_of(remix), Some(original));
+            assert_eq!(contract.get_content(cover).unwrap().parent(), Some(original));
+            assert_eq!(contract.parent_of(original), None);
+            assert_eq!(contract.derivatives_of(original, 0, 10), vec![remix, cover]);
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <DerivativeRegistered as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.content_id, event.parent_id), (cover, original));
+
+            assert_eq!(contract.register_derivative(99, String::from("default_oracle_orphan")), Err(Error::ContentNotFound));
+            assert_eq!(
+                contract.register_derivative(revoked, String::from("default_oracle_banned_remix")),
+                Err(Error::ContentNotActive)
+            );
+
+            contract.burn_content(remix).unwrap();
+            assert_eq!(contract.derivatives_of(original, 0, 10), vec![cover]);
+        }
+
+        /// Tests `register_derivative` for parents that require consent.
+        /// - Verifies only the owner can require consent and approve, and unapproved accounts are rejected.
+        /// - Verifies an approval is consumed by one registration and lapses when the parent changes hands.
+        #[ink::test]
+        fn test_register_derivative_consent() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let original = contract.register_content(String::from("default_oracle_novel")).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_derivative_consent_required(original, true), Err(Error::NotOwner));
+            assert_eq!(contract.approve_derivative(original, accounts.bob), Err(Error::NotOwner));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_derivative_consent_required(original, true).unwrap();
+            assert!(contract.requires_derivative_consent(original));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let hash = |name: &str| format!("default_oracle_{name}");
+            assert_eq!(contract.register_derivative(original, hash("translation")), Err(Error::DerivativeNotApproved));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.approve_derivative(original, accounts.bob).unwrap();
+            contract.approve_derivative(original, accounts.charlie).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let translation = contract.register_derivative(original, hash("translation")).unwrap();
+            assert_eq!(contract.parent_of(translation), Some(original));
+            assert_eq!(contract.register_derivative(original, hash("second_translation")), Err(Error::DerivativeNotApproved));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.transfer_ownership(original, accounts.django).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(contract.register_derivative(original, hash("adaptation")), Err(Error::DerivativeNotApproved));
+            assert!(contract.requires_derivative_consent(original));
+
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            contract.set_derivative_consent_required(original, false).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            contract.register_derivative(original, hash("adaptation")).unwrap();
+            assert_eq!(contract.derivatives_of(original, 0, 10).len(), 2);
+        }
+
+        /// Tests owner-defined collections.
+        /// - Verifies only the owner of both the collection and the content can add or remove items.
+        /// - Verifies `transfer_collection` moves the record and its items, skipping an item sold off separately.
+        #[ink::test]
+        fn test_transfer_collection() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let album = contract.create_collection(String::from("First Album")).unwrap();
+            let tracks: Vec<u64> = (0..3)
+                .map(|i| contract.register_content(format!("default_oracle_track_{i}")).unwrap())
+                .collect();
+            for &track in &tracks {
+                contract.add_to_collection(album, track).unwrap();
+            }
+            assert_eq!(contract.add_to_collection(album, tracks[0]), Err(Error::AlreadyInCollection));
+            assert_eq!(contract.add_to_collection(7, tracks[0]), Err(Error::CollectionNotFound));
+            assert_eq!(contract.create_collection("x".repeat(MAX_COLLECTION_NAME_LENGTH + 1)), Err(Error::CollectionNameTooLong));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let bobs = contract.register_content(String::from("default_oracle_bob_track")).unwrap();
+            assert_eq!(contract.add_to_collection(album, bobs), Err(Error::NotOwner));
+            assert_eq!(contract.remove_from_collection(album, tracks[0]), Err(Error::NotOwner));
+
+            // One track is sold off on its own and leaves the collection.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.transfer_ownership(tracks[1], accounts.charlie).unwrap();
+            assert_eq!(contract.collection_of(tracks[1]), None);
+            assert_eq!(contract.collection_size(album), 2);
+            assert_eq!(contract.remove_from_collection(album, bobs), Err(Error::NotOwner));
+
+            assert_eq!(contract.transfer_collection(album, accounts.bob), Ok(2));
+            assert_eq!(contract.get_collection(album).unwrap().owner, accounts.bob);
+            assert_eq!(contract.owner_of(tracks[0]), Some(accounts.bob));
+            assert_eq!(contract.owner_of(tracks[2]), Some(accounts.bob));
+            assert_eq!(contract.owner_of(tracks[1]), Some(accounts.charlie));
+            let mut items = contract.collection_items(album, 0, 10);
+            items.sort();
+            assert_eq!(items, vec![tracks[0], tracks[2]]);
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <CollectionTransferred as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.from, event.to, event.items), (accounts.alice, accounts.bob, 2));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.remove_from_collection(album, tracks[0]).unwrap();
+            assert_eq!(contract.collection_items(album, 0, 10), vec![tracks[2]]);
+            assert_eq!(contract.remove_from_collection(album, tracks[0]), Err(Error::NotInCollection));
+        }
+
+        /// Tests that `transfer_collection` is all-or-nothing.
+        /// - Verifies a locked item makes the whole call fail without moving the collection or any item.
+        #[ink::test]
+        fn test_transfer_collection_with_locked_item() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let set = contract.create_collection(String::from("Photo Set")).unwrap();
+            let first = contract.register_content(String::from("default_oracle_photo_1")).unwrap();
+            let second = contract.register_content(String::from("default_oracle_photo_2")).unwrap();
+            contract.add_to_collection(set, first).unwrap();
+            contract.add_to_collection(set, second).unwrap();
+            contract.lock_content(second).unwrap();
+            assert_eq!(contract.transfer_collection(set, accounts.bob), Err(Error::ContentLocked));
+            assert_eq!(contract.get_collection(set).unwrap().owner, accounts.alice);
+            assert_eq!(contract.owner_of(first), Some(accounts.alice));
+            assert_eq!(contract.transfer_collection(set, accounts.alice), Err(Error::SelfTransfer));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.transfer_collection(set, accounts.bob), Err(Error::NotOwner));
+        }
+
+        /// Tests the commit-reveal registration flow.
+        /// - Verifies reveals fail with a wrong salt, too early, or after expiry, and succeed in between.
+        #[ink::test]
+        fn test_commit_reveal_registration() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let hash = String::from("default_oracle_secret_song");
+            let salt = [7u8; 32];
+            let commitment = Hash::from(contract.registration_commitment(hash.clone(), accounts.alice, salt));
+            contract.commit_registration(commitment).unwrap();
+            assert_eq!(contract.commit_registration(commitment), Err(Error::CommitmentExists));
+            assert_eq!(contract.reveal_registration(hash.clone(), salt), Err(Error::CommitmentTooRecent));
+            test::advance_block::<DefaultEnvironment>();
+            assert_eq!(contract.reveal_registration(hash.clone(), [8u8; 32]), Err(Error::CommitmentNotFound));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.reveal_registration(hash.clone(), salt), Err(Error::CommitmentNotFound));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.reveal_registration(hash.clone(), salt).unwrap();
+            assert_eq!(contract.owner_of(content_id), Some(accounts.alice));
+            assert_eq!(contract.commitment_block(accounts.alice, commitment), None);
+            assert_eq!(contract.reveal_registration(hash, salt), Err(Error::CommitmentNotFound));
+
+            let late = String::from("default_oracle_late_song");
+            let commitment = Hash::from(contract.registration_commitment(late.clone(), accounts.alice, salt));
+            contract.commit_registration(commitment).unwrap();
+            for _ in 0..=COMMITMENT_EXPIRY_BLOCKS {
+                test::advance_block::<DefaultEnvironment>();
+            }
+            assert_eq!(contract.reveal_registration(late, salt), Err(Error::CommitmentExpired));
+            // An expired commitment can be made again.
+            contract.commit_registration(commitment).unwrap();
+        }
+
+        /// Tests that an older commitment wins a registration race.
+        /// - Verifies a front-runner's plain registration made after the commitment is reclaimed on reveal,
+        ///   crediting back the bid on its auction.
+        /// - Verifies a registration made before the commitment keeps priority.
+        #[ink::test]
+        fn test_reveal_registration_reclaims_front_run() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let hash = String::from("default_oracle_leaked_song");
+            let salt = [1u8; 32];
+            contract.commit_registration(Hash::from(contract.registration_commitment(hash.clone(), accounts.alice, salt))).unwrap();
+            test::advance_block::<DefaultEnvironment>();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let copied = contract.register_content(hash.clone()).unwrap();
+            contract.approve(copied, accounts.charlie).unwrap();
+            contract.start_auction(copied, 0, 60_000, 1).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(40);
+            contract.bid(copied).unwrap();
+            send_value(0);
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.reveal_registration(hash.clone(), salt), Ok(copied));
+            assert_eq!(contract.owner_of(copied), Some(accounts.alice));
+            assert_eq!(contract.creator_of(copied), Some(accounts.alice));
+            assert_eq!(contract.balance_of(accounts.bob), 0);
+            assert_eq!(contract.created_by(accounts.bob, 0, 10), Vec::<u64>::new());
+            assert_eq!(contract.get_approved(copied), None);
+            // The front-runner's auction is dropped and its bid credited back.
+            assert_eq!(contract.get_auction(copied), None);
+            assert_eq!(contract.credit_of(accounts.charlie), 40);
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <RegistrationRevealed as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!(event.reclaimed_from, Some(accounts.bob));
+
+            // Bob registered first here, so Alice's later commitment loses.
+            let early = String::from("default_oracle_public_song");
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let original = contract.register_content(early.clone()).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.commit_registration(Hash::from(contract.registration_commitment(early.clone(), accounts.alice, salt))).unwrap();
+            test::advance_block::<DefaultEnvironment>();
+            assert_eq!(contract.reveal_registration(early, salt), Err(Error::AlreadyRegistered));
+            assert_eq!(contract.owner_of(original), Some(accounts.bob));
+        }
+
+        /// Tests that reclaiming a front-run registration clears the front-runner's creator settings.
+        /// - Verifies the royalty, its split and lock, marketplace-only mode and its exemptions, the
+        ///   license-terms lock, derivative consent, and granted licenses are all cleared.
+        /// - Verifies a later sale pays the front-runner's split recipient nothing.
+        #[ink::test]
+        fn test_reclaim_clears_creator_settings() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let hash = String::from("default_oracle_leaked_album");
+            let salt = [2u8; 32];
+            contract.commit_registration(Hash::from(contract.registration_commitment(hash.clone(), accounts.alice, salt))).unwrap();
+            test::advance_block::<DefaultEnvironment>();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let copied = contract.register_content(hash.clone()).unwrap();
+            contract.set_royalty(copied, 1_000).unwrap();
+            contract.set_royalty_split(copied, vec![(accounts.django, 1_000)]).unwrap();
+            contract.lock_royalty(copied).unwrap();
+            contract.set_marketplace_only(copied, true).unwrap();
+            contract.add_transfer_exemption(copied, accounts.eve).unwrap();
+            contract.set_license_terms(copied, String::from("terms")).unwrap();
+            contract.lock_license_terms(copied).unwrap();
+            contract.grant_license(copied, accounts.django, String::from("terms"), None, true, false).unwrap();
+            contract.set_derivative_consent_required(copied, true).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.reveal_registration(hash, salt), Ok(copied));
+            assert_eq!(contract.royalty_bps_of(copied), 0);
+            assert_eq!(contract.royalty_split_of(copied), vec![]);
+            assert!(!contract.is_royalty_locked(copied));
+            assert!(!contract.is_marketplace_only(copied));
+            assert!(!contract.is_transfer_exempt(copied, accounts.eve));
+            assert!(!contract.is_license_terms_locked(copied));
+            assert!(!contract.requires_derivative_consent(copied));
+            assert_eq!(contract.license_of(copied, accounts.django), None);
+            assert_eq!(contract.licensees_of(copied, 0, 10), Vec::<AccountId>::new());
+
+            // The real creator sets their own terms, and a sale pays the old split nothing.
+            contract.set_royalty(copied, 500).unwrap();
+            contract.list_for_sale(copied, 1_000).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(1_000);
+            contract.buy(copied).unwrap();
+            send_value(0);
+            assert_eq!(contract.credit_of(accounts.django), 0);
+        }
+
+        /// Tests that a front-run registration securing a funded loan or rented out is not reclaimed.
+        /// - Verifies a funded loan blocks the reclaim with `LoanActive`, and an unfunded offer is cancelled.
+        /// - Verifies a running rental blocks the reclaim with `RentalActive` until it expires.
+        #[ink::test]
+        fn test_reclaim_blocked_by_loan_and_rental() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let hash = String::from("default_oracle_leaked_film");
+            let salt = [3u8; 32];
+            contract.commit_registration(Hash::from(contract.registration_commitment(hash.clone(), accounts.alice, salt))).unwrap();
+            test::advance_block::<DefaultEnvironment>();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let copied = contract.register_content(hash.clone()).unwrap();
+            contract.create_loan_offer(copied, 100, 10, 5_000).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(100);
+            contract.fund_loan(copied).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            send_value(0);
+            assert_eq!(contract.reveal_registration(hash.clone(), salt), Err(Error::LoanActive));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(110);
+            contract.repay_loan(copied).unwrap();
+            send_value(0);
+            contract.set_rental_terms(copied, 10, 1).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(10);
+            let expires_at = contract.rent(copied, 1).unwrap();
+            send_value(0);
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.reveal_registration(hash.clone(), salt), Err(Error::RentalActive));
+
+            test::set_block_timestamp::<DefaultEnvironment>(expires_at);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.create_loan_offer(copied, 100, 10, 5_000).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.reveal_registration(hash, salt), Ok(copied));
+            assert_eq!(contract.get_loan(copied), None);
+            assert_eq!(contract.owner_of(copied), Some(accounts.alice));
+        }
+
+        /// Tests registration under each `ValidationStrategy`.
+        /// - Verifies prefix, length, charset, disabled, and combined strategies accept and reject the expected hashes.
+        #[ink::test]
+        fn test_validation_strategies() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let sha = "ab".repeat(32);
+            assert_eq!(contract.get_validation_strategy(), ValidationStrategy::PrefixMatch);
+            assert_eq!(contract.register_content(sha.clone()), Err(Error::InvalidContent));
+            contract.register_content(String::from("default_oracle_prefixed")).unwrap();
+
+            contract.set_validation_strategy(ValidationStrategy::ExactLength(64)).unwrap();
+            assert_eq!(contract.check_hash_format(String::from("short"), HashScheme::Other), Err(HashFormatError::WrongLength));
+            contract.register_content(sha.clone()).unwrap();
+
+            contract.set_validation_strategy(ValidationStrategy::CharsetHex).unwrap();
+            assert_eq!(contract.register_content(String::from("xyz")), Err(Error::InvalidContent));
+            assert_eq!(contract.check_hash_format(String::new(), HashScheme::Other), Err(HashFormatError::InvalidCharacter));
+            contract.register_content(String::from("DEADbeef")).unwrap();
+
+            contract.set_validation_strategy(ValidationStrategy::Disabled).unwrap();
+            contract.register_content(String::from("anything_goes")).unwrap();
+
+            contract.update_oracle_data(String::from("f00d")).unwrap();
+            contract.set_validation_strategy(ValidationStrategy::Combined { prefix: true, length: Some(8), hex: true }).unwrap();
+            assert_eq!(contract.register_content(String::from("cafe0001")), Err(Error::InvalidContent));
+            assert_eq!(contract.check_hash_format(String::from("f00d001"), HashScheme::Other), Err(HashFormatError::WrongLength));
+            assert_eq!(contract.check_hash_format(String::from("f00d00zz"), HashScheme::Other), Err(HashFormatError::InvalidCharacter));
+            contract.register_content(String::from("f00d0001")).unwrap();
+            // Structured schemes keep their own checks whatever the strategy.
+            contract.register_content_with_scheme("cd".repeat(32), HashScheme::Sha256Hex).unwrap();
+        }
+
+        /// Tests the `set_validation_strategy` function for access control and events.
+        /// - Verifies only an admin can change the strategy, and the change emits `ValidationStrategyChanged`.
+        #[ink::test]
+        fn test_set_validation_strategy() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_validation_strategy(ValidationStrategy::Disabled), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_validation_strategy(ValidationStrategy::ExactLength(46)).unwrap();
+            assert_eq!(contract.get_validation_strategy(), ValidationStrategy::ExactLength(46));
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <ValidationStrategyChanged as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.by, event.strategy), (accounts.alice, ValidationStrategy::ExactLength(46)));
+        }
+
+        /// Tests validation against multiple oracle prefixes.
+        /// - Verifies a hash matching any accepted prefix registers, and removal stops accepting it.
+        /// - Verifies an empty prefix list rejects every hash, and `update_oracle_data` replaces the whole list.
+        #[ink::test]
+        fn test_multiple_oracle_prefixes() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.add_oracle_prefix(String::from("Qm")).unwrap();
+            assert_eq!(contract.add_oracle_prefix(String::from("Qm")), Err(Error::DuplicateOraclePrefix));
+            assert_eq!(contract.get_oracle_prefixes(), vec![String::from("default_oracle"), String::from("Qm")]);
+            contract.register_content(String::from("default_oracle_one")).unwrap();
+            contract.register_content(String::from("QmTwo")).unwrap();
+            assert_eq!(contract.register_content(String::from("bafyThree")), Err(Error::InvalidContent));
+
+            contract.remove_oracle_prefix(String::from("default_oracle")).unwrap();
+            assert_eq!(contract.remove_oracle_prefix(String::from("default_oracle")), Err(Error::OraclePrefixNotFound));
+            assert_eq!(contract.get_oracle_data(), "Qm");
+            assert_eq!(contract.register_content(String::from("default_oracle_four")), Err(Error::InvalidContent));
+
+            contract.remove_oracle_prefix(String::from("Qm")).unwrap();
+            assert_eq!(contract.get_oracle_prefixes(), Vec::<String>::new());
+            assert_eq!(contract.get_oracle_data(), "");
+            assert_eq!(contract.check_hash_format(String::from("QmFive"), HashScheme::Other), Err(HashFormatError::OracleMismatch));
+
+            for i in 0..MAX_ORACLE_PREFIXES {
+                contract.add_oracle_prefix(format!("net{i}")).unwrap();
             }
+            assert_eq!(contract.add_oracle_prefix(String::from("one_too_many")), Err(Error::TooManyOraclePrefixes));
+            contract.update_oracle_data(String::from("bafy")).unwrap();
+            assert_eq!(contract.get_oracle_prefixes(), vec![String::from("bafy")]);
+            contract.register_content(String::from("bafyThree")).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.add_oracle_prefix(String::from("Qm")), Err(Error::NotAdmin));
+            assert_eq!(contract.remove_oracle_prefix(String::from("bafy")), Err(Error::NotAdmin));
         }
-    }
 
-    //----------------------------------
-    // Contract Implementation
-    //----------------------------------
+        /// Tests the `push_oracle_data` function.
+        /// - Verifies only the configured oracle account can push, and admins keep `update_oracle_data`.
+        /// - Verifies `oracle_updated_at` tracks the last push or admin update.
+        #[ink::test]
+        fn test_push_oracle_data() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.oracle_updated_at(), 0);
+            assert_eq!(contract.push_oracle_data(String::from("admin_cannot_push")), Err(Error::NotOracle));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_oracle_account(Some(accounts.bob)), Err(Error::NotAdmin));
+            assert_eq!(contract.push_oracle_data(String::from("no_oracle_yet")), Err(Error::NotOracle));
 
-    impl ContentOwnership {
-        /// Constructor: Initializes the contract with the deployer as the admin and sets the initial oracle data.
-        #[ink(constructor)]
-        pub fn new() -> Self {
-            Self {
-                admin: Self::env().caller(),
-                ..Default::default()
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_oracle_account(Some(accounts.eve)).unwrap();
+            assert_eq!(contract.oracle_account(), Some(accounts.eve));
+            test::set_caller::<DefaultEnvironment>(accounts.eve);
+            test::set_block_timestamp::<DefaultEnvironment>(5_000);
+            contract.push_oracle_data(String::from("pushed_oracle")).unwrap();
+            assert_eq!(contract.get_oracle_data(), "pushed_oracle");
+            assert_eq!(contract.oracle_updated_at(), 5_000);
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <OracleDataPushed as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.oracle, event.old_data, event.new_data), (accounts.eve, String::from("default_oracle"), String::from("pushed_oracle")));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            test::set_block_timestamp::<DefaultEnvironment>(9_000);
+            contract.update_oracle_data(String::from("override_oracle")).unwrap();
+            assert_eq!(contract.oracle_updated_at(), 9_000);
+            contract.set_oracle_account(None).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.eve);
+            assert_eq!(contract.push_oracle_data(String::from("revoked_oracle")), Err(Error::NotOracle));
+            assert_eq!(contract.get_oracle_data(), "override_oracle");
+        }
+
+        /// Tests oracle data updates through a member quorum.
+        /// - Verifies data is adopted once two of three members agree, and non-members cannot submit.
+        /// - Verifies a split vote keeps the previous data and starts a new round.
+        #[ink::test]
+        fn test_oracle_quorum() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            for member in [accounts.bob, accounts.charlie, accounts.django] {
+                contract.add_oracle_member(member).unwrap();
+            }
+            assert_eq!(contract.add_oracle_member(accounts.bob), Err(Error::AlreadyOracleMember));
+            assert_eq!(contract.set_oracle_quorum(4), Err(Error::InvalidThreshold));
+            contract.set_oracle_quorum(2).unwrap();
+            assert_eq!(contract.oracle_members(), vec![accounts.bob, accounts.charlie, accounts.django]);
+
+            test::set_caller::<DefaultEnvironment>(accounts.eve);
+            assert_eq!(contract.submit_oracle_data(String::from("eve_oracle")), Err(Error::NotOracle));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.submit_oracle_data(String::from("Qm")).unwrap();
+            assert_eq!(contract.get_oracle_data(), "default_oracle");
+            assert_eq!(contract.pending_oracle_round(), OracleRound { round: 0, submissions: vec![(accounts.bob, String::from("Qm"))] });
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            contract.submit_oracle_data(String::from("Qm")).unwrap();
+            assert_eq!(contract.get_oracle_data(), "Qm");
+            assert_eq!(contract.pending_oracle_round(), OracleRound { round: 1, submissions: Vec::new() });
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <OracleQuorumReached as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.round, event.data), (0, String::from("Qm")));
+
+            // Every member disagrees: round 1 fails and the data is kept.
+            for (member, data) in [(accounts.bob, "a"), (accounts.charlie, "b"), (accounts.django, "c")] {
+                test::set_caller::<DefaultEnvironment>(member);
+                contract.submit_oracle_data(String::from(data)).unwrap();
             }
+            assert_eq!(contract.get_oracle_data(), "Qm");
+            assert_eq!(contract.pending_oracle_round().round, 2);
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <OracleRoundFailed as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!(event.round, 1);
         }
 
-        /// Updates the oracle data stored in the contract.
-        /// Only the admin can call this function.
-        ///
-        /// # Arguments
-        /// - `new_data`: The new oracle data to be stored.
-        ///
-        /// # Errors
-        /// - Returns `Error::NotAdmin` if the caller is not the admin.
-        #[ink(message)]
-        pub fn update_oracle_data(&mut self, new_data: String) -> Result<()> {
-            if self.env().caller() != self.admin {
-                return Err(Error::NotAdmin);
+        /// Tests removing an oracle member in the middle of a round.
+        /// - Verifies the removed member's submission is discarded and no longer counts towards quorum.
+        /// - Verifies removal is rejected when fewer members than the quorum would remain.
+        #[ink::test]
+        fn test_remove_oracle_member_mid_round() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            for member in [accounts.bob, accounts.charlie, accounts.django] {
+                contract.add_oracle_member(member).unwrap();
             }
-            self.oracle_data = new_data;
-            Ok(())
+            contract.set_oracle_quorum(2).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.submit_oracle_data(String::from("bob_oracle")).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            contract.submit_oracle_data(String::from("charlie_oracle")).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.remove_oracle_member(accounts.charlie), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.remove_oracle_member(accounts.bob).unwrap();
+            assert_eq!(contract.remove_oracle_member(accounts.bob), Err(Error::NotOracleMember));
+            assert_eq!(contract.pending_oracle_round().submissions, vec![(accounts.charlie, String::from("charlie_oracle"))]);
+            assert_eq!(contract.remove_oracle_member(accounts.charlie), Err(Error::InvalidThreshold));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.submit_oracle_data(String::from("charlie_oracle")), Err(Error::NotOracle));
+
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            contract.submit_oracle_data(String::from("charlie_oracle")).unwrap();
+            assert_eq!(contract.get_oracle_data(), "charlie_oracle");
         }
 
-        /// Registers new digital content on-chain.
-        /// The caller provides a content hash, which is validated against the oracle data.
-        /// If valid, the content is stored with the caller as the owner.
-        ///
-        /// # Arguments
-        /// - `content_hash`: The unique hash representing the content (e.g., an IPFS hash).
-        ///
-        /// # Returns
-        /// - A unique content ID for the registered content.
-        ///
-        /// # Errors
-        /// - Returns `Error::InvalidContent` if the content hash is invalid.
-        /// - Returns `Error::CounterOverflow` if the content ID counter overflows.
-        #[ink(message)]
-        pub fn register_content(&mut self, content_hash: String) -> Result<u64> {
-            if !self.validate_content_with_oracle(&content_hash) {
-                return Err(Error::InvalidContent);
+        /// Tests timelocked oracle data updates.
+        /// - Verifies `update_oracle_data` is disabled while a delay is set, and proposals apply only from `not_before`.
+        /// - Verifies any account can apply, and only an admin can cancel.
+        #[ink::test]
+        fn test_timelocked_oracle_data() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            contract.set_oracle_delay(500).unwrap();
+            assert_eq!(contract.update_oracle_data(String::from("instant")), Err(Error::OracleDelayActive));
+            assert_eq!(contract.apply_oracle_data(), Err(Error::NoPendingOracleData));
+            contract.propose_oracle_data(String::from("delayed_oracle")).unwrap();
+            let pending = contract.pending_oracle_data().unwrap();
+            assert_eq!(
+                (pending.change, pending.proposed_by, pending.not_before),
+                (OracleChange::ReplaceData(String::from("delayed_oracle")), accounts.alice, 1_500)
+            );
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.propose_oracle_data(String::from("bob_oracle")), Err(Error::NotAdmin));
+            test::set_block_timestamp::<DefaultEnvironment>(1_499);
+            assert_eq!(contract.apply_oracle_data(), Err(Error::OracleDelayNotElapsed));
+            assert_eq!(contract.get_oracle_data(), "default_oracle");
+            test::set_block_timestamp::<DefaultEnvironment>(1_500);
+            contract.apply_oracle_data().unwrap();
+            assert_eq!(contract.get_oracle_data(), "delayed_oracle");
+            assert_eq!(contract.pending_oracle_data(), None);
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <OracleProposalApplied as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.applied_by, event.proposed_by), (accounts.bob, accounts.alice));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.propose_oracle_data(String::from("malicious_oracle")).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.cancel_oracle_proposal(), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.cancel_oracle_proposal().unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(5_000);
+            assert_eq!(contract.apply_oracle_data(), Err(Error::NoPendingOracleData));
+            assert_eq!(contract.get_oracle_data(), "delayed_oracle");
+        }
+
+        /// Tests that no path changes validation immediately while an oracle delay is set.
+        /// - Verifies direct prefix, strategy, and validator changes fail, and approved admin actions are proposed instead.
+        /// - Verifies `propose_oracle_change` timelocks every kind of change.
+        #[ink::test]
+        fn test_timelocked_oracle_changes() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            contract.set_oracle_delay(500).unwrap();
+            assert_eq!(contract.add_oracle_prefix(String::from("extra")), Err(Error::OracleDelayActive));
+            assert_eq!(contract.remove_oracle_prefix(String::from("default_oracle")), Err(Error::OracleDelayActive));
+            assert_eq!(contract.set_validation_strategy(ValidationStrategy::Disabled), Err(Error::OracleDelayActive));
+            assert_eq!(contract.set_validator(Some(accounts.django)), Err(Error::OracleDelayActive));
+
+            // A single approval proposes the data rather than applying it.
+            contract.propose_admin_action(AdminAction::UpdateOracle(String::from("approved_oracle"))).unwrap();
+            assert_eq!(contract.get_oracle_data(), "default_oracle");
+            assert_eq!(
+                contract.pending_oracle_data().unwrap().change,
+                OracleChange::ReplaceData(String::from("approved_oracle"))
+            );
+            contract.propose_admin_action(AdminAction::SetValidator(Some(accounts.django))).unwrap();
+            assert_eq!(contract.validator(), None);
+            assert_eq!(contract.pending_oracle_data().unwrap().change, OracleChange::SetValidator(Some(accounts.django)));
+
+            contract.propose_oracle_change(OracleChange::SetValidationStrategy(ValidationStrategy::Disabled)).unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(1_499);
+            assert_eq!(contract.apply_oracle_data(), Err(Error::OracleDelayNotElapsed));
+            test::set_block_timestamp::<DefaultEnvironment>(1_500);
+            contract.apply_oracle_data().unwrap();
+            assert_eq!(contract.get_validation_strategy(), ValidationStrategy::Disabled);
+            assert_eq!(contract.validator(), None);
+
+            contract.propose_oracle_change(OracleChange::AddPrefix(String::from("extra"))).unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(2_000);
+            contract.apply_oracle_data().unwrap();
+            assert_eq!(contract.get_oracle_prefixes(), vec![String::from("default_oracle"), String::from("extra")]);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(
+                contract.propose_oracle_change(OracleChange::RemovePrefix(String::from("extra"))),
+                Err(Error::NotAdmin)
+            );
+        }
+
+        /// Tests that lowering the oracle delay is itself timelocked.
+        /// - Verifies a lower delay applies only once the current delay has passed, and a higher one at once.
+        #[ink::test]
+        fn test_set_oracle_delay() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            contract.set_oracle_delay(500).unwrap();
+            assert_eq!(contract.oracle_delay(), 500);
+            contract.set_oracle_delay(0).unwrap();
+            assert_eq!(contract.oracle_delay(), 500);
+            assert_eq!(contract.update_oracle_data(String::from("too_soon")), Err(Error::OracleDelayActive));
+            test::set_block_timestamp::<DefaultEnvironment>(1_500);
+            assert_eq!(contract.oracle_delay(), 0);
+            contract.update_oracle_data(String::from("instant_again")).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_oracle_delay(0), Err(Error::NotAdmin));
+        }
+
+        /// Tests that registrations record the oracle data version in force.
+        /// - Verifies every oracle change bumps the version and archives the data under it.
+        #[ink::test]
+        fn test_registration_oracle_version() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.current_oracle_version(), 0);
+            let before = contract.register_content(String::from("default_oracle_before")).unwrap();
+            contract.update_oracle_data(String::from("Qm")).unwrap();
+            contract.add_oracle_prefix(String::from("bafy")).unwrap();
+            assert_eq!(contract.current_oracle_version(), 2);
+            let after = contract.register_content(String::from("QmAfter")).unwrap();
+
+            assert_eq!(contract.registration_info(before).unwrap().oracle_version, 0);
+            assert_eq!(contract.registration_info(after).unwrap().oracle_version, 2);
+            assert_eq!(contract.oracle_data_at_version(0), Some(vec![String::from("default_oracle")]));
+            assert_eq!(contract.oracle_data_at_version(1), Some(vec![String::from("Qm")]));
+            assert_eq!(contract.oracle_data_at_version(2), Some(vec![String::from("Qm"), String::from("bafy")]));
+            assert_eq!(contract.oracle_data_at_version(3), None);
+        }
+
+        /// Tests the `set_validator` function.
+        /// - Verifies only an admin can set or clear the validator, and the change emits `ValidatorChanged`.
+        /// - Verifies clearing it falls back to the internal validation strategy.
+        #[ink::test]
+        fn test_set_validator() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_validator(Some(accounts.django)), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_validator(Some(accounts.django)).unwrap();
+            assert_eq!(contract.validator(), Some(accounts.django));
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <ValidatorChanged as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.by, event.validator), (accounts.alice, Some(accounts.django)));
+
+            contract.set_validator(None).unwrap();
+            assert_eq!(contract.validator(), None);
+            contract.register_content(String::from("default_oracle_internal")).unwrap();
+            assert_eq!(contract.register_content(String::from("QmInternal")), Err(Error::InvalidContent));
+        }
+
+        /// Tests the `register_with_attestation` function.
+        /// - Verifies a valid attestation registers content without on-chain validation, and cannot be replayed.
+        /// - Verifies expired attestations and signatures from another key are rejected.
+        #[ink::test]
+        fn test_register_with_attestation() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let (attestor_key, _) = ecdsa_account(5);
+            let (other_key, _) = ecdsa_account(6);
+            let attestor = PublicKey::from_secret_key(SECP256K1, &attestor_key).serialize();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let hash = String::from("QmAttested");
+            let signature = ecdsa_sign(&attestor_key, contract.attestation_hash(hash.clone(), accounts.alice, 2_000));
+            assert_eq!(contract.register_with_attestation(hash.clone(), 2_000, signature), Err(Error::InvalidSignature));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_attestor(Some(attestor)), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_attestor(Some(attestor)).unwrap();
+            assert_eq!(contract.attestor(), Some(attestor));
+
+            let forged = ecdsa_sign(&other_key, contract.attestation_hash(hash.clone(), accounts.alice, 2_000));
+            assert_eq!(contract.register_with_attestation(hash.clone(), 2_000, forged), Err(Error::InvalidSignature));
+            // The attestation is bound to its account.
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.register_with_attestation(hash.clone(), 2_000, signature), Err(Error::InvalidSignature));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            test::set_block_timestamp::<DefaultEnvironment>(2_000);
+            let content_id = contract.register_with_attestation(hash.clone(), 2_000, signature).unwrap();
+            assert_eq!(contract.owner_of(content_id), Some(accounts.alice));
+            contract.burn_content(content_id).unwrap();
+            assert_eq!(contract.register_with_attestation(hash.clone(), 2_000, signature), Err(Error::AttestationUsed));
+
+            test::set_block_timestamp::<DefaultEnvironment>(2_001);
+            let expired = ecdsa_sign(&attestor_key, contract.attestation_hash(String::from("QmLate"), accounts.alice, 2_000));
+            assert_eq!(contract.register_with_attestation(String::from("QmLate"), 2_000, expired), Err(Error::AttestationExpired));
+        }
+
+        /// Tests registering allowlisted hashes with Merkle proofs, and that root updates keep existing content.
+        #[ink::test]
+        fn test_register_with_proof() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let hashes = ["QmAllowedA", "QmAllowedB", "QmAllowedC", "QmAllowedD", "QmAllowedE"];
+            let leaves: Vec<[u8; 32]> = hashes.iter().map(|h| crate::merkle::leaf(h.as_bytes())).collect();
+            let proof_of = |index: usize| {
+                let (root, proof) = crate::merkle::tests::root_and_proof(&leaves, index);
+                (Hash::from(root), proof.into_iter().map(Hash::from).collect::<Vec<_>>())
+            };
+            let (root, proof) = proof_of(4);
+            assert_eq!(contract.register_with_proof(String::from("QmAllowedE"), proof.clone()), Err(Error::AllowlistNotSet));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_allowlist_root(root), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_allowlist_root(root).unwrap();
+            assert_eq!(contract.allowlist_root(), Some(root));
+
+            // The hash skips on-chain validation: it does not match the oracle prefix.
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let content_id = contract.register_with_proof(String::from("QmAllowedE"), proof.clone()).unwrap();
+            assert_eq!(contract.owner_of(content_id), Some(accounts.bob));
+            assert_eq!(contract.register_with_proof(String::from("QmAllowedE"), proof.clone()), Err(Error::AlreadyRegistered));
+            assert_eq!(contract.register_with_proof(String::from("QmAllowedA"), proof), Err(Error::InvalidProof));
+            let (_, proof_b) = proof_of(1);
+            assert_eq!(contract.register_with_proof(String::from("QmOther"), proof_b.clone()), Err(Error::InvalidProof));
+            assert_eq!(contract.register_with_proof(String::from("QmAllowedB"), vec![Hash::from([0u8; 32]); MAX_PROOF_LENGTH + 1]), Err(Error::InvalidProof));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_allowlist_root(Hash::from([7u8; 32])).unwrap();
+            assert_eq!(contract.owner_of(content_id), Some(accounts.bob));
+            assert_eq!(contract.register_with_proof(String::from("QmAllowedB"), proof_b), Err(Error::InvalidProof));
+        }
+
+        /// Tests the `register_preimage` function.
+        /// - Verifies the stored and emitted hash is the hex BLAKE2-256 digest of the preimage.
+        /// - Verifies oversized preimages are rejected and the hash deduplicates against `register_content`.
+        #[ink::test]
+        fn test_register_preimage() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            test::set_caller::<DefaultEnvironment>(alice);
+            let empty_digest = "0e5751c026e543b2e8ab2eb06099daa1d1e5df47778f7787faab45cdf12fe3a8";
+            let content_id = contract.register_preimage(Vec::new()).unwrap();
+            let record = contract.get_content(content_id).unwrap();
+            assert_eq!(record.content_hash(), empty_digest);
+            assert_eq!(record.scheme(), HashScheme::Blake2x256Hex);
+            let events: Vec<_> = test::recorded_events().collect();
+            let event = <ContentRegistered as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!(event.content_hash, empty_digest);
+            assert_eq!(contract.register_preimage(Vec::new()), Err(Error::AlreadyRegistered));
+
+            assert_eq!(contract.register_preimage(vec![0u8; MAX_PREIMAGE_SIZE + 1]), Err(Error::PreimageTooLarge));
+            let full = contract.register_preimage(vec![0u8; MAX_PREIMAGE_SIZE]).unwrap();
+            assert_eq!(contract.get_content(full).unwrap().content_hash().len(), 64);
+
+            // The same hash registered without the preimage blocks the preimage path.
+            contract.set_validation_strategy(ValidationStrategy::CharsetHex).unwrap();
+            let mut digest = [0u8; 32];
+            ink::env::hash_bytes::<ink::env::hash::Blake2x256>(b"document", &mut digest);
+            let hex: String = digest.iter().map(|byte| format!("{byte:02x}")).collect();
+            contract.register_content(hex).unwrap();
+            assert_eq!(contract.register_preimage(b"document".to_vec()), Err(Error::AlreadyRegistered));
+        }
+
+        /// Tests proof-of-possession registration and the `require_pop` mode.
+        /// - Verifies plain and preimage registration are rejected while the mode is on, and allowed again once it is off.
+        /// - Verifies signatures by another key, or over another hash, are rejected.
+        #[ink::test]
+        fn test_register_content_with_pop() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let (key, signer) = ecdsa_account(7);
+            let (other_key, _) = ecdsa_account(8);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.require_pop(true), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.require_pop(true).unwrap();
+            assert!(contract.pop_required());
+
+            test::set_caller::<DefaultEnvironment>(signer);
+            let hash = String::from("default_oracle_owned");
+            assert_eq!(contract.register_content(hash.clone()), Err(Error::ProofRequired));
+            assert_eq!(contract.register_content_soulbound(hash.clone()), Err(Error::ProofRequired));
+            let forged = ecdsa_sign(&other_key, contract.pop_hash(hash.clone(), signer));
+            assert_eq!(contract.register_content_with_pop(hash.clone(), forged), Err(Error::InvalidSignature));
+            let signature = ecdsa_sign(&key, contract.pop_hash(hash.clone(), signer));
+            // The signature is bound to the hash it was made for.
+            assert_eq!(
+                contract.register_content_with_pop(String::from("default_oracle_other"), signature),
+                Err(Error::InvalidSignature)
+            );
+            // And to the caller.
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.register_content_with_pop(hash.clone(), signature), Err(Error::InvalidSignature));
+            test::set_caller::<DefaultEnvironment>(signer);
+            let content_id = contract.register_content_with_pop(hash.clone(), signature).unwrap();
+            assert_eq!(contract.owner_of(content_id), Some(signer));
+            assert_eq!(contract.register_content_with_pop(hash, signature), Err(Error::AlreadyRegistered));
+            assert_eq!(contract.register_preimage(b"inline".to_vec()), Err(Error::ProofRequired));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.require_pop(false).unwrap();
+            contract.register_content(String::from("default_oracle_plain")).unwrap();
+        }
+
+        /// Tests re-validation at transfer time after the oracle rules change.
+        /// - Verifies content registered under the old oracle value still transfers while the flag is off.
+        /// - Verifies `transfer_ownership` and `transfer_from` fail with `InvalidContent` once it is on.
+        #[ink::test]
+        fn test_revalidate_on_transfer() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let old = contract.register_content(String::from("default_oracle_old")).unwrap();
+            contract.update_oracle_data(String::from("new_oracle")).unwrap();
+            let current = contract.register_content(String::from("new_oracle_current")).unwrap();
+            assert!(!contract.revalidate(old));
+            assert!(contract.revalidate(current));
+            assert!(!contract.revalidate(99));
+
+            contract.transfer_ownership(old, accounts.bob).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_revalidate_on_transfer(true), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_revalidate_on_transfer(true).unwrap();
+            assert!(contract.revalidate_on_transfer());
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.transfer_ownership(old, accounts.charlie), Err(Error::InvalidContent));
+            contract.approve(old, accounts.charlie).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(contract.transfer_from(old, accounts.charlie), Err(Error::InvalidContent));
+            assert_eq!(contract.owner_of(old), Some(accounts.bob));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.transfer_ownership(current, accounts.bob).unwrap();
+
+            contract.set_revalidate_on_transfer(false).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.transfer_ownership(old, accounts.charlie).unwrap();
+        }
+
+        /// Tests the `set_license_terms` and `lock_license_terms` functions.
+        /// - Verifies only the owner can set terms, and locked terms can no longer change.
+        /// - Verifies the terms and their lock carry over to the new owner unchanged.
+        #[ink::test]
+        fn test_license_terms() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_licensed")).unwrap();
+            assert_eq!(contract.license_terms_of(content_id), None);
+            assert_eq!(contract.lock_license_terms(content_id), Err(Error::LicenseTermsNotSet));
+            assert_eq!(
+                contract.set_license_terms(content_id, "a".repeat(MAX_LICENSE_TERMS_LENGTH + 1)),
+                Err(Error::LicenseTermsTooLong)
+            );
+            contract.set_license_terms(content_id, String::from("QmTermsV1")).unwrap();
+            let events: Vec<_> = test::recorded_events().collect();
+            let event = <LicenseTermsUpdated as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!(event.terms_hash, Some(String::from("QmTermsV1")));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_license_terms(content_id, String::from("QmTermsBob")), Err(Error::NotOwner));
+            assert_eq!(contract.lock_license_terms(content_id), Err(Error::NotOwner));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_license_terms(content_id, String::from("QmTermsV2")).unwrap();
+            contract.lock_license_terms(content_id).unwrap();
+            assert!(contract.is_license_terms_locked(content_id));
+            assert_eq!(contract.set_license_terms(content_id, String::new()), Err(Error::LicenseTermsLocked));
+            assert_eq!(contract.lock_license_terms(content_id), Err(Error::LicenseTermsLocked));
+
+            contract.transfer_ownership(content_id, accounts.bob).unwrap();
+            assert_eq!(contract.license_terms_of(content_id), Some(String::from("QmTermsV2")));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_license_terms(content_id, String::from("QmTermsBob")), Err(Error::LicenseTermsLocked));
+            contract.burn_content(content_id).unwrap();
+            assert_eq!(contract.license_terms_of(content_id), None);
+            assert!(!contract.is_license_terms_locked(content_id));
+        }
+
+        /// Tests the byte-length caps on oracle data and content hashes, and the content hash charset.
+        /// - Verifies inputs at exactly the cap are accepted and one byte over is rejected.
+        /// - Verifies hashes with whitespace or non-ASCII characters are rejected.
+        #[ink::test]
+        fn test_input_bounds() {
+            let mut contract = ContentOwnership::new();
+            let alice = test::default_accounts::<DefaultEnvironment>().alice;
+            test::set_caller::<DefaultEnvironment>(alice);
+            assert_eq!(
+                contract.update_oracle_data("o".repeat(MAX_ORACLE_DATA_LENGTH + 1)),
+                Err(Error::OracleDataTooLong)
+            );
+            assert_eq!(contract.add_oracle_prefix("o".repeat(MAX_ORACLE_DATA_LENGTH + 1)), Err(Error::OracleDataTooLong));
+            assert_eq!(
+                contract.propose_admin_action(AdminAction::UpdateOracle("o".repeat(MAX_ORACLE_DATA_LENGTH + 1))),
+                Err(Error::OracleDataTooLong)
+            );
+            assert_eq!(contract.get_oracle_prefixes(), vec![String::from("default_oracle")]);
+            contract.update_oracle_data("o".repeat(MAX_ORACLE_DATA_LENGTH)).unwrap();
+
+            contract.set_validation_strategy(ValidationStrategy::Disabled).unwrap();
+            assert_eq!(
+                contract.register_content("h".repeat(MAX_CONTENT_HASH_LENGTH + 1)),
+                Err(Error::ContentHashTooLong)
+            );
+            assert_eq!(
+                contract.check_hash_format("h".repeat(MAX_CONTENT_HASH_LENGTH + 1), HashScheme::Other),
+                Err(HashFormatError::WrongLength)
+            );
+            contract.register_content("h".repeat(MAX_CONTENT_HASH_LENGTH)).unwrap();
+            for hash in ["with space", "tab\there", "trailing\n", "caf\u{e9}"] {
+                assert_eq!(contract.register_content(String::from(hash)), Err(Error::InvalidContent));
+                assert_eq!(contract.check_hash_format(String::from(hash), HashScheme::Other), Err(HashFormatError::InvalidCharacter));
             }
+            assert_eq!(contract.total_supply(), 1);
+        }
 
-            if self.content_hash_to_id.contains_key(&content_hash) {
-                return Ok(*self.content_hash_to_id.get(&content_hash).unwrap());
+        /// Tests the registration fee for underpayment, exact payment, overpayment, and free mode.
+        /// - Verifies underpaying fails with `InsufficientFee` and registers nothing.
+        /// - Verifies the fee stays in the contract and any excess is credited to the caller.
+        #[ink::test]
+        fn test_registration_fee() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let balance_of = |account| test::get_account_balance::<DefaultEnvironment>(account).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.registration_fee(), 0);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_registration_fee(100), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_registration_fee(100).unwrap();
+            assert_eq!(contract.registration_fee(), 100);
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let contract_account = test::callee::<DefaultEnvironment>();
+            let initial = balance_of(contract_account);
+            assert_eq!(contract.register_content(String::from("default_oracle_free")), Err(Error::InsufficientFee));
+            send_value(99);
+            assert_eq!(contract.register_content(String::from("default_oracle_cheap")), Err(Error::InsufficientFee));
+            assert_eq!(contract.total_supply(), 0);
+            test::set_account_balance::<DefaultEnvironment>(contract_account, initial);
+
+            send_value(100);
+            contract.register_content(String::from("default_oracle_exact")).unwrap();
+            assert_eq!(balance_of(contract_account), initial + 100);
+
+            send_value(250);
+            contract.register_content_soulbound(String::from("default_oracle_over")).unwrap();
+            assert_eq!(balance_of(contract_account), initial + 350);
+            assert_eq!(contract.credit_of(accounts.bob), 150);
+            assert_eq!(contract.collected_fees(), 200);
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_registration_fee(0).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(0);
+            contract.register_content(String::from("default_oracle_gratis")).unwrap();
+            assert_eq!(balance_of(contract_account), initial + 350);
+            assert_eq!(contract.total_supply(), 3);
+        }
+
+        /// Tests the `withdraw_fees` function with fee income mixed with funds held for users.
+        /// - Verifies only collected fees can be withdrawn, to the treasury, leaving other funds in place.
+        /// - Verifies `NothingToWithdraw` and `InsufficientContractBalance` are returned as appropriate.
+        #[ink::test]
+        fn test_withdraw_fees() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let balance_of = |account| test::get_account_balance::<DefaultEnvironment>(account).unwrap();
+            let contract_account = test::callee::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.treasury(), accounts.alice);
+            assert_eq!(contract.withdraw_fees(1), Err(Error::NothingToWithdraw));
+            contract.set_registration_fee(100).unwrap();
+            for hash in ["default_oracle_one", "default_oracle_two", "default_oracle_three"] {
+                send_value(100);
+                contract.register_content(String::from(hash)).unwrap();
             }
-            let caller = self.env().caller();
-            let content_id = self.next_content_id;
-            self.next_content_id = self.next_content_id
-                .checked_add(1)
-                .ok_or(Error::CounterOverflow)?;
-            let record = Content {
-                content_hash: content_hash.clone(),
-                owner: caller,
-            };
-            self.contents.insert(content_id, &record);
-            self.content_hash_to_id.insert(content_hash, content_id);
-            Ok(content_id)
+            send_value(0);
+            assert_eq!(contract.collected_fees(), 300);
+            // Funds held for users, e.g. escrowed offers, sit in the same balance.
+            let escrow = 1_000;
+            test::set_account_balance::<DefaultEnvironment>(contract_account, balance_of(contract_account) + escrow);
+            let held = balance_of(contract_account);
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_treasury(accounts.django), Err(Error::NotAdmin));
+            assert_eq!(contract.withdraw_fees(100), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_treasury(accounts.django).unwrap();
+            assert_eq!(contract.treasury(), accounts.django);
+
+            let treasury_before = balance_of(accounts.django);
+            assert_eq!(contract.withdraw_fees(0), Err(Error::NothingToWithdraw));
+            assert_eq!(contract.withdraw_fees(301), Err(Error::InsufficientContractBalance));
+            contract.withdraw_fees(120).unwrap();
+            let events: Vec<_> = test::recorded_events().collect();
+            let event = <FeesWithdrawn as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.treasury, event.amount), (accounts.django, 120));
+            contract.withdraw_fees(180).unwrap();
+            assert_eq!(contract.collected_fees(), 0);
+            assert_eq!(balance_of(accounts.django), treasury_before + 300);
+            assert_eq!(balance_of(contract_account), held - 300);
+
+            // The escrowed funds can never be drained as fees.
+            assert_eq!(contract.withdraw_fees(escrow), Err(Error::NothingToWithdraw));
+            assert_eq!(balance_of(contract_account), held - 300);
+        }
+
+        /// Tests listing content for sale and buying it.
+        /// - Verifies underpayment and buying your own listing are rejected, and delisting works.
+        /// - Verifies a purchase moves ownership, credits the seller and any overpayment, and clears the listing.
+        #[ink::test]
+        fn test_list_and_buy() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_for_sale")).unwrap();
+            assert_eq!(contract.list_for_sale(content_id, 0), Err(Error::InvalidPrice));
+            assert_eq!(contract.delist(content_id), Err(Error::NotListed));
+            contract.approve(content_id, accounts.charlie).unwrap();
+            contract.list_for_sale(content_id, 500).unwrap();
+            assert_eq!(contract.get_listing(content_id), Some(Listing { price: 500, currency: None }));
+            send_value(500);
+            assert_eq!(contract.buy(content_id), Err(Error::SelfTransfer));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.list_for_sale(content_id, 1), Err(Error::NotOwner));
+            assert_eq!(contract.delist(content_id), Err(Error::NotOwner));
+            send_value(499);
+            assert_eq!(contract.buy(content_id), Err(Error::InsufficientPayment));
+            assert_eq!(contract.buy(99), Err(Error::ContentNotFound));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.delist(content_id).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(500);
+            assert_eq!(contract.buy(content_id), Err(Error::NotListed));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.list_for_sale(content_id, 500).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(600);
+            contract.buy(content_id).unwrap();
+            let events: Vec<_> = test::recorded_events().collect();
+            let event = <ContentSold as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.seller, event.buyer, event.price), (accounts.alice, accounts.bob, 500));
+            assert_eq!(contract.owner_of(content_id), Some(accounts.bob));
+            assert_eq!(contract.get_listing(content_id), None);
+            assert_eq!(contract.get_approved(content_id), None);
+            assert_eq!(contract.credit_of(accounts.alice), 500);
+            assert_eq!(contract.credit_of(accounts.bob), 100);
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let alice_before = test::get_account_balance::<DefaultEnvironment>(accounts.alice).unwrap();
+            assert_eq!(contract.withdraw(), Ok(500));
+            assert_eq!(test::get_account_balance::<DefaultEnvironment>(accounts.alice).unwrap(), alice_before + 500);
+            assert_eq!(contract.withdraw(), Err(Error::NothingToWithdraw));
+        }
+
+        /// Tests that a plain transfer of listed content clears its listing.
+        #[ink::test]
+        fn test_transfer_clears_listing() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_listed")).unwrap();
+            contract.list_for_sale(content_id, 500).unwrap();
+            contract.transfer_ownership(content_id, accounts.bob).unwrap();
+            assert_eq!(contract.get_listing(content_id), None);
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(500);
+            assert_eq!(contract.buy(content_id), Err(Error::NotListed));
+        }
+
+        /// Tests escrowed purchase offers from several buyers.
+        /// - Verifies offers top up, and rejection and withdrawal credit the escrow back to the buyer.
+        /// - Verifies acceptance moves ownership, credits the seller, and leaves other offers open.
+        #[ink::test]
+        fn test_purchase_offers() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_wanted")).unwrap();
+            send_value(10);
+            assert_eq!(contract.make_offer(content_id, OFFER_EXPIRY), Err(Error::SelfTransfer));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(0);
+            assert_eq!(contract.make_offer(content_id, OFFER_EXPIRY), Err(Error::InvalidPrice));
+            send_value(100);
+            contract.make_offer(content_id, OFFER_EXPIRY).unwrap();
+            send_value(50);
+            contract.make_offer(content_id, OFFER_EXPIRY).unwrap();
+            assert_eq!(contract.offer_of(content_id, accounts.bob).map(|offer| offer.amount), Some(150));
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(120);
+            contract.make_offer(content_id, OFFER_EXPIRY).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            send_value(90);
+            contract.make_offer(content_id, OFFER_EXPIRY).unwrap();
+            send_value(0);
+            assert_eq!(contract.offer_count(content_id), 3);
+
+            assert_eq!(contract.reject_offer(content_id, accounts.bob), Err(Error::NotOwner));
+            assert_eq!(contract.accept_offer(content_id, accounts.django), Err(Error::NotOwner));
+            contract.withdraw_offer(content_id).unwrap();
+            assert_eq!(contract.credit_of(accounts.django), 90);
+            assert_eq!(contract.withdraw_offer(content_id), Err(Error::OfferNotFound));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.reject_offer(content_id, accounts.charlie).unwrap();
+            assert_eq!(contract.credit_of(accounts.charlie), 120);
+            assert_eq!(contract.offer_of(content_id, accounts.charlie), None);
+            assert_eq!(contract.accept_offer(content_id, accounts.charlie), Err(Error::OfferNotFound));
+
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(200);
+            contract.make_offer(content_id, OFFER_EXPIRY).unwrap();
+            send_value(0);
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.accept_offer(content_id, accounts.bob).unwrap();
+            let events: Vec<_> = test::recorded_events().collect();
+            let event = <OfferAccepted as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.seller, event.buyer, event.amount), (accounts.alice, accounts.bob, 150));
+            assert_eq!(contract.owner_of(content_id), Some(accounts.bob));
+            assert_eq!(contract.credit_of(accounts.alice), 150);
+            assert_eq!(contract.offer_of(content_id, accounts.bob), None);
+            // Charlie's offer stays open for the new owner.
+            assert_eq!(contract.offer_count(content_id), 1);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.accept_offer(content_id, accounts.charlie).unwrap();
+            assert_eq!(contract.owner_of(content_id), Some(accounts.charlie));
+            assert_eq!(contract.credit_of(accounts.bob), 200);
+            assert_eq!(contract.offer_count(content_id), 0);
+        }
+
+        /// Tests that offers on content burned in the meantime can still be withdrawn but not accepted.
+        #[ink::test]
+        fn test_offer_on_burned_content() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_doomed")).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(100);
+            contract.make_offer(content_id, OFFER_EXPIRY).unwrap();
+            send_value(0);
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.burn_content(content_id).unwrap();
+            assert_eq!(contract.accept_offer(content_id, accounts.bob), Err(Error::ContentNotFound));
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(100);
+            assert_eq!(contract.make_offer(content_id, OFFER_EXPIRY), Err(Error::ContentNotFound));
+            send_value(0);
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.offer_of(content_id, accounts.bob).map(|offer| offer.amount), Some(100));
+            contract.withdraw_offer(content_id).unwrap();
+            assert_eq!(contract.withdraw(), Ok(100));
+        }
+
+        /// Tests purchase offer expiry across the expiry timestamp.
+        /// - Verifies expired offers cannot be accepted, drop out of `offers_for`, and can be swept by
+        ///   anyone, with the escrow only ever credited to the original buyer.
+        #[ink::test]
+        fn test_offer_expiry() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_expiring")).unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(100);
+            assert_eq!(contract.make_offer(content_id, 1_000), Err(Error::OfferExpired));
+            contract.make_offer(content_id, 2_000).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(80);
+            contract.make_offer(content_id, 3_000).unwrap();
+            send_value(0);
+            assert_eq!(
+                contract.offers_for(content_id, 0, 10),
+                vec![
+                    (accounts.bob, PurchaseOffer { amount: 100, expires_at: 2_000 }),
+                    (accounts.charlie, PurchaseOffer { amount: 80, expires_at: 3_000 }),
+                ]
+            );
+
+            test::set_block_timestamp::<DefaultEnvironment>(1_999);
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(contract.reclaim_expired_offer(content_id, accounts.bob), Err(Error::OfferNotExpired));
+
+            test::set_block_timestamp::<DefaultEnvironment>(2_000);
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.accept_offer(content_id, accounts.bob), Err(Error::OfferExpired));
+            assert_eq!(contract.owner_of(content_id), Some(accounts.alice));
+            assert_eq!(
+                contract.offers_for(content_id, 0, 10),
+                vec![(accounts.charlie, PurchaseOffer { amount: 80, expires_at: 3_000 })]
+            );
+            // Expired offers stay open until swept, so they still count.
+            assert_eq!(contract.offer_count(content_id), 2);
+
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            contract.reclaim_expired_offer(content_id, accounts.bob).unwrap();
+            let events: Vec<_> = test::recorded_events().collect();
+            let event = <OfferReclaimed as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.buyer, event.amount, event.reclaimed_by), (accounts.bob, 100, accounts.django));
+            assert_eq!(contract.credit_of(accounts.bob), 100);
+            assert_eq!(contract.credit_of(accounts.django), 0);
+            assert_eq!(contract.reclaim_expired_offer(content_id, accounts.bob), Err(Error::OfferNotFound));
+
+            // A top-up replaces the expiry, and the offer stays acceptable until then.
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(20);
+            contract.make_offer(content_id, 4_000).unwrap();
+            send_value(0);
+            assert_eq!(contract.offer_of(content_id, accounts.charlie), Some(PurchaseOffer { amount: 100, expires_at: 4_000 }));
+            test::set_block_timestamp::<DefaultEnvironment>(3_500);
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.reclaim_expired_offer(content_id, accounts.charlie), Err(Error::OfferNotExpired));
+            contract.accept_offer(content_id, accounts.charlie).unwrap();
+            assert_eq!(contract.credit_of(accounts.alice), 100);
+            assert_eq!(contract.offer_count(content_id), 0);
+        }
+
+        /// Tests an English auction with several bidders.
+        /// - Verifies bids must clear the minimum increment, outbid bidders are credited back,
+        ///   and settlement moves the content to the top bidder and credits the seller once.
+        #[ink::test]
+        fn test_auction_outbid_and_settle() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_auctioned")).unwrap();
+            contract.list_for_sale(content_id, 500).unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            contract.set_auction_extension(0).unwrap();
+            assert_eq!(contract.start_auction(content_id, 100, 0, 10), Err(Error::InvalidDuration));
+            contract.start_auction(content_id, 100, 10_000, 10).unwrap();
+            assert_eq!(contract.get_listing(content_id), None);
+            assert_eq!(contract.start_auction(content_id, 100, 10_000, 10), Err(Error::AuctionActive));
+            assert_eq!(contract.transfer_ownership(content_id, accounts.eve), Err(Error::AuctionActive));
+            assert_eq!(contract.burn_content(content_id), Err(Error::AuctionActive));
+            assert_eq!(contract.lock_content(content_id), Err(Error::AuctionActive));
+            send_value(100);
+            assert_eq!(contract.bid(content_id), Err(Error::SelfTransfer));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(0);
+            assert_eq!(contract.bid(content_id), Err(Error::BidTooLow));
+            send_value(80);
+            contract.bid(content_id).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(89);
+            assert_eq!(contract.bid(content_id), Err(Error::BidTooLow));
+            send_value(90);
+            contract.bid(content_id).unwrap();
+            assert_eq!(contract.credit_of(accounts.bob), 80);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(150);
+            contract.bid(content_id).unwrap();
+            send_value(0);
+            assert_eq!(contract.credit_of(accounts.charlie), 90);
+            assert_eq!(contract.credit_of(accounts.bob), 80);
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.cancel_auction(content_id), Err(Error::AuctionHasBids));
+
+            test::set_block_timestamp::<DefaultEnvironment>(10_999);
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(contract.settle_auction(content_id), Err(Error::AuctionNotEnded));
+            test::set_block_timestamp::<DefaultEnvironment>(11_000);
+            send_value(200);
+            assert_eq!(contract.bid(content_id), Err(Error::AuctionEnded));
+            send_value(0);
+            assert_eq!(contract.settle_auction(content_id), Ok(Some(accounts.bob)));
+            let events: Vec<_> = test::recorded_events().collect();
+            let event = <AuctionSettled as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.seller, event.winner, event.amount), (accounts.alice, Some(accounts.bob), 150));
+            assert_eq!(contract.owner_of(content_id), Some(accounts.bob));
+            assert_eq!(contract.credit_of(accounts.alice), 150);
+            assert_eq!(contract.settle_auction(content_id), Err(Error::NoAuction));
+            assert_eq!(contract.credit_of(accounts.alice), 150);
+            assert_eq!(contract.credit_of(accounts.bob), 80);
+        }
+
+        /// Tests that an auction whose highest bid stays below the reserve returns the item.
+        /// - Verifies the content stays with the seller and the bid is credited back to the bidder.
+        /// - Verifies an auction can only be cancelled before the first bid.
+        #[ink::test]
+        fn test_auction_reserve_not_met() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_reserved")).unwrap();
+            contract.set_auction_extension(0).unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            contract.start_auction(content_id, 1_000, 60_000, 1).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.cancel_auction(content_id), Err(Error::NotOwner));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.cancel_auction(content_id).unwrap();
+            assert_eq!(contract.get_auction(content_id), None);
+
+            contract.start_auction(content_id, 1_000, 60_000, 1).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(999);
+            contract.bid(content_id).unwrap();
+            send_value(0);
+            test::set_block_timestamp::<DefaultEnvironment>(61_000);
+            assert_eq!(contract.settle_auction(content_id), Ok(None));
+            assert_eq!(contract.owner_of(content_id), Some(accounts.alice));
+            assert_eq!(contract.credit_of(accounts.bob), 999);
+            assert_eq!(contract.credit_of(accounts.alice), 0);
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.transfer_ownership(content_id, accounts.charlie).unwrap();
+        }
+
+        /// Tests the anti-sniping window of auctions.
+        /// - Verifies a bid close to the deadline extends it, and an early bid leaves it unchanged.
+        #[ink::test]
+        fn test_auction_anti_snipe() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_sniped")).unwrap();
+            assert_eq!(contract.auction_extension(), DEFAULT_AUCTION_EXTENSION);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_auction_extension(1_000), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_auction_extension(1_000).unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(0);
+            contract.start_auction(content_id, 0, 10_000, 1).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            test::set_block_timestamp::<DefaultEnvironment>(5_000);
+            send_value(10);
+            contract.bid(content_id).unwrap();
+            assert_eq!(contract.get_auction(content_id).unwrap().ends_at, 10_000);
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            test::set_block_timestamp::<DefaultEnvironment>(9_500);
+            send_value(20);
+            contract.bid(content_id).unwrap();
+            send_value(0);
+            assert_eq!(contract.get_auction(content_id).unwrap().ends_at, 10_500);
+
+            test::set_block_timestamp::<DefaultEnvironment>(10_000);
+            assert_eq!(contract.settle_auction(content_id), Err(Error::AuctionNotEnded));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(30);
+            contract.bid(content_id).unwrap();
+            send_value(0);
+            assert_eq!(contract.get_auction(content_id).unwrap().ends_at, 11_000);
+            test::set_block_timestamp::<DefaultEnvironment>(11_000);
+            assert_eq!(contract.settle_auction(content_id), Ok(Some(accounts.bob)));
+            assert_eq!(contract.credit_of(accounts.alice), 30);
+            assert_eq!(contract.credit_of(accounts.bob), 10);
+            assert_eq!(contract.credit_of(accounts.charlie), 20);
+        }
+
+        /// Tests the price curve of a Dutch auction.
+        /// - Verifies the price at the start, the midpoint, and just before the end, and that none is
+        ///   quoted after expiry.
+        /// - Verifies buying at exactly the computed price works at a timestamp where it is rounded.
+        #[ink::test]
+        fn test_dutch_auction_price() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_declining")).unwrap();
+            assert_eq!(contract.start_dutch_auction(content_id, 100, 0, 3_000), Err(Error::InvalidPrice));
+            assert_eq!(contract.start_dutch_auction(content_id, 100, 101, 3_000), Err(Error::InvalidPrice));
+            assert_eq!(contract.start_dutch_auction(content_id, 1_000, 100, 0), Err(Error::InvalidDuration));
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            contract.start_dutch_auction(content_id, 1_000, 100, 3_000).unwrap();
+            assert_eq!(contract.current_dutch_price(content_id), Some(1_000));
+            test::set_block_timestamp::<DefaultEnvironment>(2_500);
+            assert_eq!(contract.current_dutch_price(content_id), Some(550));
+            test::set_block_timestamp::<DefaultEnvironment>(3_999);
+            assert_eq!(contract.current_dutch_price(content_id), Some(101));
+            test::set_block_timestamp::<DefaultEnvironment>(4_000);
+            assert_eq!(contract.current_dutch_price(content_id), None);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(1_000);
+            assert_eq!(contract.buy_dutch(content_id), Err(Error::AuctionEnded));
+            send_value(0);
+
+            // 900 over 7 ms drops by 128.57 per ms, so the price after 1 ms rounds up to 872.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.start_dutch_auction(content_id, 1_000, 100, 7).unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(4_001);
+            assert_eq!(contract.current_dutch_price(content_id), Some(872));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(871);
+            assert_eq!(contract.buy_dutch(content_id), Err(Error::InsufficientPayment));
+            send_value(872);
+            contract.buy_dutch(content_id).unwrap();
+            send_value(0);
+            assert_eq!(contract.owner_of(content_id), Some(accounts.bob));
+            assert_eq!(contract.credit_of(accounts.alice), 872);
+            assert_eq!(contract.credit_of(accounts.bob), 0);
+            assert_eq!(contract.get_dutch_auction(content_id), None);
+        }
+
+        /// Tests how a Dutch auction ends.
+        /// - Verifies overpayment is credited back, and that cancellation and a direct transfer end the sale.
+        #[ink::test]
+        fn test_dutch_auction_lifecycle() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_dutch")).unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(0);
+            contract.start_dutch_auction(content_id, 200, 100, 1_000).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.cancel_dutch_auction(content_id), Err(Error::NotOwner));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.cancel_dutch_auction(content_id).unwrap();
+            assert_eq!(contract.cancel_dutch_auction(content_id), Err(Error::NoAuction));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(200);
+            assert_eq!(contract.buy_dutch(content_id), Err(Error::NoAuction));
+            send_value(0);
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.start_dutch_auction(content_id, 200, 100, 1_000).unwrap();
+            contract.transfer_ownership(content_id, accounts.charlie).unwrap();
+            assert_eq!(contract.current_dutch_price(content_id), None);
+
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            contract.start_dutch_auction(content_id, 200, 100, 1_000).unwrap();
+            assert_eq!(contract.start_auction(content_id, 0, 1_000, 1), Ok(()));
+            assert_eq!(contract.get_dutch_auction(content_id), None);
+            contract.cancel_auction(content_id).unwrap();
+            contract.start_dutch_auction(content_id, 200, 100, 1_000).unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(500);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(300);
+            contract.buy_dutch(content_id).unwrap();
+            send_value(0);
+            let events: Vec<_> = test::recorded_events().collect();
+            let event = <ContentSold as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.seller, event.buyer, event.price), (accounts.charlie, accounts.bob, 150));
+            assert_eq!(contract.credit_of(accounts.charlie), 150);
+            assert_eq!(contract.credit_of(accounts.bob), 150);
+        }
+
+        /// Tests the pull-payment ledger with credits from several sources.
+        /// - Verifies a registration overpayment, an outbid auction bid, a rejected offer, and sale
+        ///   proceeds add up in one credit, paid out in full by a single `withdraw`.
+        /// - Verifies withdrawing a zero credit fails and moves nothing.
+        #[ink::test]
+        fn test_credit_ledger() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let balance_of = |account| test::get_account_balance::<DefaultEnvironment>(account).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_registration_fee(10).unwrap();
+            contract.set_auction_extension(0).unwrap();
+            send_value(10);
+            let auctioned = contract.register_content(String::from("default_oracle_ledger_auction")).unwrap();
+            send_value(10);
+            let offered = contract.register_content(String::from("default_oracle_ledger_offer")).unwrap();
+            send_value(0);
+            test::set_block_timestamp::<DefaultEnvironment>(0);
+            contract.start_auction(auctioned, 0, 1_000, 1).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(15);
+            let sold = contract.register_content(String::from("default_oracle_ledger_sale")).unwrap();
+            send_value(0);
+            contract.list_for_sale(sold, 40).unwrap();
+            send_value(20);
+            contract.bid(auctioned).unwrap();
+            send_value(30);
+            contract.make_offer(offered, OFFER_EXPIRY).unwrap();
+            send_value(0);
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(25);
+            contract.bid(auctioned).unwrap();
+            send_value(40);
+            contract.buy(sold).unwrap();
+            send_value(0);
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.reject_offer(offered, accounts.bob).unwrap();
+            assert_eq!(contract.credit_of(accounts.bob), 5 + 20 + 30 + 40);
+            assert_eq!(contract.credit_of(accounts.charlie), 0);
+
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(contract.withdraw(), Err(Error::NothingToWithdraw));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            let bob_before = balance_of(accounts.bob);
+            assert_eq!(contract.withdraw(), Ok(95));
+            assert_eq!(balance_of(accounts.bob), bob_before + 95);
+            assert_eq!(contract.credit_of(accounts.bob), 0);
+            assert_eq!(contract.withdraw(), Err(Error::NothingToWithdraw));
+            assert_eq!(balance_of(accounts.bob), bob_before + 95);
+        }
+
+        /// Tests the platform fee on every sale path.
+        /// - Verifies only an admin can set the fee, capped at `MAX_PLATFORM_FEE_BPS`.
+        /// - Verifies fees are rounded down in the seller's favour and reported in the sale events.
+        /// - Verifies no funds are created or destroyed: credits, collected fees, and open escrow add
+        ///   up to exactly what the contract received.
+        #[ink::test]
+        fn test_platform_fee() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let contract_account = test::callee::<DefaultEnvironment>();
+            let initial = test::get_account_balance::<DefaultEnvironment>(contract_account).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!((contract.primary_fee_bps(), contract.secondary_fee_bps()), (0, 0));
+            assert_eq!(contract.set_platform_fee_bps(MAX_PLATFORM_FEE_BPS + 1), Err(Error::FeeTooHigh));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_platform_fee_bps(250), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_platform_fee_bps(250).unwrap();
+            assert_eq!((contract.primary_fee_bps(), contract.secondary_fee_bps()), (250, 250));
+            contract.set_auction_extension(0).unwrap();
+            let listed = contract.register_content(String::from("default_oracle_fee_listed")).unwrap();
+            let offered = contract.register_content(String::from("default_oracle_fee_offered")).unwrap();
+            let auctioned = contract.register_content(String::from("default_oracle_fee_auctioned")).unwrap();
+            contract.list_for_sale(listed, 101).unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(0);
+            contract.start_auction(auctioned, 0, 1_000, 1).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(110);
+            contract.buy(listed).unwrap();
+            let events: Vec<_> = test::recorded_events().collect();
+            let event = <ContentSold as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            // 2.5% of 101 is 2.525, rounded down to 2.
+            assert_eq!((event.price, event.fee), (101, 2));
+            send_value(40);
+            contract.bid(auctioned).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(333);
+            contract.make_offer(offered, OFFER_EXPIRY).unwrap();
+            send_value(77);
+            contract.bid(auctioned).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            send_value(50);
+            contract.make_offer(offered, OFFER_EXPIRY).unwrap();
+            send_value(0);
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.accept_offer(offered, accounts.charlie).unwrap();
+            let events: Vec<_> = test::recorded_events().collect();
+            let event = <OfferAccepted as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.amount, event.fee), (333, 8));
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            assert_eq!(contract.settle_auction(auctioned), Ok(Some(accounts.charlie)));
+            let events: Vec<_> = test::recorded_events().collect();
+            let event = <AuctionSettled as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.amount, event.fee), (77, 1));
+
+            assert_eq!(contract.collected_fees(), 2 + 8 + 1);
+            assert_eq!(contract.credit_of(accounts.alice), 99 + 325 + 76);
+            assert_eq!(contract.credit_of(accounts.bob), 9 + 40);
+            let credits: Balance = [accounts.alice, accounts.bob, accounts.charlie, accounts.django]
+                .into_iter()
+                .map(|account| contract.credit_of(account))
+                .sum();
+            let escrow = contract.offer_of(offered, accounts.django).unwrap().amount;
+            let received = test::get_account_balance::<DefaultEnvironment>(contract_account).unwrap() - initial;
+            assert_eq!(credits + contract.collected_fees() + escrow, received);
         }
 
-        /// Validates a content hash against the oracle data.
-        ///
-        /// # Arguments
-        /// - `content_hash`: The hash to validate.
-        ///
-        /// # Returns
-        /// - `true` if the content hash is valid, `false` otherwise.
-        fn validate_content_with_oracle(&self, content_hash: &str) -> bool {
-            content_hash.starts_with(&self.oracle_data)
+        /// Tests tipping content owners.
+        /// - Verifies zero-value tips, long messages, and tips on revoked or burned content are rejected.
+        /// - Verifies tips after a transfer go to the new owner while the total keeps running.
+        #[ink::test]
+        fn test_tips() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_tipped")).unwrap();
+            let revoked = contract.register_content(String::from("default_oracle_tip_revoked")).unwrap();
+            let burned = contract.register_content(String::from("default_oracle_tip_burned")).unwrap();
+            contract.set_content_status(revoked, ContentStatus::Revoked).unwrap();
+            contract.burn_content(burned).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(contract.tip(content_id, None), Err(Error::InvalidPrice));
+            send_value(30);
+            assert_eq!(contract.tip(content_id, Some("x".repeat(MAX_TIP_MESSAGE_LENGTH + 1))), Err(Error::TipMessageTooLong));
+            assert_eq!(contract.tip(revoked, None), Err(Error::ContentNotActive));
+            assert_eq!(contract.tip(burned, None), Err(Error::ContentNotFound));
+            contract.tip(content_id, Some(String::from("great track"))).unwrap();
+            let events: Vec<_> = test::recorded_events().collect();
+            let event = <Tipped as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.from, event.to, event.amount), (accounts.charlie, accounts.alice, 30));
+            assert_eq!(event.message, Some(String::from("great track")));
+            send_value(0);
+            assert_eq!(contract.credit_of(accounts.alice), 30);
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.transfer_ownership(content_id, accounts.bob).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            send_value(20);
+            contract.tip(content_id, None).unwrap();
+            send_value(0);
+            assert_eq!(contract.credit_of(accounts.alice), 30);
+            assert_eq!(contract.credit_of(accounts.bob), 20);
+            assert_eq!(contract.total_tips(content_id), 50);
+            assert_eq!(contract.total_tips(revoked), 0);
         }
 
-        /// Transfers ownership of a registered content item to a new owner.
-        /// Only the current owner can authorize the transfer.
-        ///
-        /// # Arguments
-        /// - `content_id`: The unique ID of the content to transfer.
-        /// - `new_owner`: The AccountId of the new owner.
-        ///
-        /// # Errors
-        /// - Returns `Error::ContentNotFound` if the content ID is not found.
-        /// - Returns `Error::NotOwner` if the caller is not the current owner.
-        #[ink(message)]
-        pub fn transfer_ownership(&mut self, content_id: u64, new_owner: AccountId) -> Result<()> {
-            let mut record = self.contents.get(content_id).ok_or(Error::ContentNotFound)?;
-            if self.env().caller() != record.owner {
-                return Err(Error::NotOwner);
-            }
-            record.owner = new_owner;
-            self.contents.insert(content_id, &record);
-            Ok(())
+        /// Tests pay-per-view access purchases.
+        /// - Verifies the owner always has access and cannot purchase it, and underpayment is rejected.
+        /// - Verifies access expires, a repurchase extends it from its current end, and a price change
+        ///   leaves granted access alone.
+        /// - Verifies the owner is credited the price minus the platform fee.
+        #[ink::test]
+        fn test_access_purchases() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_platform_fee_bps(1_000).unwrap();
+            let content_id = contract.register_content(String::from("default_oracle_streamed")).unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            assert!(contract.has_access(content_id, accounts.alice));
+            assert!(!contract.has_access(content_id, accounts.bob));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(50);
+            assert_eq!(contract.purchase_access(content_id), Err(Error::AccessNotForSale));
+            assert_eq!(contract.set_access_price(content_id, 50, Some(1_000)), Err(Error::NotOwner));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.set_access_price(content_id, 50, Some(0)), Err(Error::InvalidDuration));
+            contract.set_access_price(content_id, 50, Some(1_000)).unwrap();
+            assert_eq!(contract.purchase_access(content_id), Err(Error::OwnerHasAccess));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(49);
+            assert_eq!(contract.purchase_access(content_id), Err(Error::InsufficientPayment));
+            send_value(60);
+            assert_eq!(contract.purchase_access(content_id), Ok(2_000));
+            send_value(0);
+            assert!(contract.has_access(content_id, accounts.bob));
+            assert_eq!(contract.credit_of(accounts.alice), 45);
+            assert_eq!(contract.credit_of(accounts.bob), 10);
+            assert_eq!(contract.collected_fees(), 5);
+
+            // Repurchasing before the end extends access from the end, not from now.
+            test::set_block_timestamp::<DefaultEnvironment>(1_500);
+            send_value(50);
+            assert_eq!(contract.purchase_access(content_id), Ok(3_000));
+            send_value(0);
+            test::set_block_timestamp::<DefaultEnvironment>(2_999);
+            assert!(contract.has_access(content_id, accounts.bob));
+            test::set_block_timestamp::<DefaultEnvironment>(3_000);
+            assert!(!contract.has_access(content_id, accounts.bob));
+            assert_eq!(contract.access_expires_at(content_id, accounts.bob), Some(3_000));
+
+            // A price change only applies to later purchases.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_access_price(content_id, 80, None).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(80);
+            assert_eq!(contract.purchase_access(content_id), Ok(Timestamp::MAX));
+            send_value(0);
+            assert_eq!(contract.access_expires_at(content_id, accounts.bob), Some(3_000));
+            assert_eq!(contract.credit_of(accounts.alice), 45 + 45 + 72);
+
+            // Grants survive a transfer, while the terms are cleared.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.transfer_ownership(content_id, accounts.django).unwrap();
+            assert!(contract.has_access(content_id, accounts.charlie));
+            assert!(!contract.has_access(content_id, accounts.alice));
+            assert_eq!(contract.access_terms_of(content_id), None);
         }
 
-        /// Retrieves a content record by its unique identifier.
-        ///
-        /// # Arguments
-        /// - `content_id`: The unique ID of the content to retrieve.
-        ///
-        /// # Returns
-        /// - An `Option` containing the content record if found, or `None` if not found.
-        #[ink(message)]
-        pub fn get_content(&self, content_id: u64) -> Option<Content> {
-            self.contents.get(content_id)
+        /// Tests per-content revenue and per-account earnings.
+        /// - Verifies a sale and two tips are counted per source, net of the platform fee.
+        /// - Verifies the history stays with the content while later income goes to the new owner.
+        #[ink::test]
+        fn test_revenue_accounting() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_platform_fee_bps(1_000).unwrap();
+            let content_id = contract.register_content(String::from("default_oracle_earning")).unwrap();
+            assert_eq!(contract.revenue_of(content_id), RevenueStats::default());
+            contract.list_for_sale(content_id, 200).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(30);
+            contract.tip(content_id, None).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(250);
+            contract.buy(content_id).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(40);
+            contract.tip(content_id, None).unwrap();
+            send_value(0);
+
+            assert_eq!(contract.revenue_of(content_id), RevenueStats { sales: 180, tips: 70, access: 0, rentals: 0, licenses: 0, royalties: 0 });
+            assert_eq!(contract.earnings_of(accounts.alice), 30 + 180);
+            // Bob's refunded overpayment is credit, not income.
+            assert_eq!(contract.earnings_of(accounts.bob), 40);
+            assert_eq!(contract.credit_of(accounts.bob), 50 + 40);
+            assert_eq!(contract.earnings_of(accounts.charlie), 0);
         }
 
-        /// Returns the current oracle data stored in the contract.
-        ///
-        /// # Returns
-        /// - A `String` containing the oracle data.
-        #[ink(message)]
-        pub fn get_oracle_data(&self) -> String {
-            self.oracle_data.clone()
+        /// Tests listing content in a PSP22 token.
+        /// - Verifies the listing records its currency and each buy path rejects the other currency.
+        /// - Verifies the seller cannot buy their own token listing. Token transfers are covered end to end.
+        #[ink::test]
+        fn test_token_listing() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            let token = accounts.frank;
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_stable")).unwrap();
+            assert_eq!(contract.buy_with_token(content_id), Err(Error::NotListed));
+            assert_eq!(contract.list_for_sale_in_token(content_id, token, 0), Err(Error::InvalidPrice));
+            contract.list_for_sale_in_token(content_id, token, 300).unwrap();
+            let events: Vec<_> = test::recorded_events().collect();
+            let event = <ContentListed as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.price, event.currency), (300, Some(token)));
+            assert_eq!(contract.get_listing(content_id), Some(Listing { price: 300, currency: Some(token) }));
+            assert_eq!(contract.buy_with_token(content_id), Err(Error::SelfTransfer));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(300);
+            assert_eq!(contract.buy(content_id), Err(Error::WrongCurrency));
+            send_value(0);
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.list_for_sale(content_id, 300).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.buy_with_token(content_id), Err(Error::WrongCurrency));
+            assert_eq!(contract.owner_of(content_id), Some(accounts.alice));
         }
-    }
 
-    //----------------------------------
-    // Unit Tests
-    //----------------------------------
+        /// Tests the sale history of a content item across sale paths.
+        /// - Verifies a purchase and an accepted offer are both recorded, oldest first, with price and time.
+        /// - Verifies only the latest `MAX_SALE_HISTORY` sales are kept while the count keeps growing.
+        #[ink::test]
+        fn test_sale_history() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_resold")).unwrap();
+            assert_eq!(contract.last_sale(content_id), None);
+            contract.list_for_sale(content_id, 100).unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(10);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(100);
+            contract.buy(content_id).unwrap();
 
-    /// # ContentOwnership Contract Test Suite
-    ///
-    /// This module contains comprehensive tests for the `ContentOwnership` contract,
-    /// covering core functionality, edge cases, and security requirements.
-    ///
-    /// ## Test Accounts Convention:
-    /// - **Alice**: Default caller/initiator (ink! default account)
-    /// - **Bob**: Counterparty account
-    /// - **Charlie**: Unauthorized third party
-    #[cfg(test)]
-    mod tests {
-        use super::*;
-        use ink::env::{test, DefaultEnvironment};
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(250);
+            contract.make_offer(content_id, OFFER_EXPIRY).unwrap();
+            send_value(0);
+            test::set_block_timestamp::<DefaultEnvironment>(20);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.accept_offer(content_id, accounts.charlie).unwrap();
 
-        /// Tests the `new` constructor to ensure the contract initializes correctly.
-        /// - Verifies that the admin is set to the caller.
-        /// - Verifies that the default oracle data is initialized.
+            let bought = SaleRecord { buyer: accounts.bob, seller: accounts.alice, price: 100, currency: None, timestamp: 10 };
+            let accepted = SaleRecord { buyer: accounts.charlie, seller: accounts.bob, price: 250, currency: None, timestamp: 20 };
+            assert_eq!(contract.sale_history(content_id, 0, 10), vec![bought, accepted]);
+            assert_eq!(contract.sale_history(content_id, 1, 10), vec![accepted]);
+            assert!(contract.sale_history(content_id, 2, 10).is_empty());
+            assert_eq!(contract.last_sale(content_id), Some(accepted));
+            assert_eq!(contract.sale_count(content_id), 2);
+            assert_eq!(contract.last_sale(content_id + 1), None);
+
+            let (mut seller, mut buyer) = (accounts.charlie, accounts.bob);
+            for i in 0..MAX_SALE_HISTORY {
+                test::set_caller::<DefaultEnvironment>(seller);
+                contract.list_for_sale(content_id, 1_000 + i as Balance).unwrap();
+                test::set_caller::<DefaultEnvironment>(buyer);
+                send_value(1_000 + i as Balance);
+                contract.buy(content_id).unwrap();
+                (seller, buyer) = (buyer, seller);
+            }
+            send_value(0);
+            assert_eq!(contract.sale_count(content_id), MAX_SALE_HISTORY + 2);
+            let history = contract.sale_history(content_id, 0, MAX_PAGE_SIZE);
+            assert_eq!(history.len(), MAX_SALE_HISTORY as usize);
+            assert_eq!(history[0].price, 1_000);
+            assert_eq!(contract.last_sale(content_id).unwrap().price, 1_000 + MAX_SALE_HISTORY as Balance - 1);
+        }
+
+        /// Tests selling a collection as a bundle.
+        /// - Verifies invalid bundles are rejected, and only the seller can cancel.
+        /// - Verifies locking or transferring away an item invalidates the bundle until it is sellable again.
+        /// - Verifies a purchase moves every item, splits the price per item, and sums the fees.
         #[ink::test]
-        fn test_new() {
-            let contract = ContentOwnership::new();
-            let caller = test::default_accounts::<DefaultEnvironment>().alice;
-            assert_eq!(contract.admin, caller);
-            assert_eq!(contract.get_oracle_data(), "default_oracle");
+        fn test_bundle_sale() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_platform_fee_bps(1_000).unwrap();
+            let collection_id = contract.create_collection(String::from("Album")).unwrap();
+            let mut items = Vec::new();
+            for hash in ["default_oracle_track_a", "default_oracle_track_b", "default_oracle_track_c"] {
+                let content_id = contract.register_content(String::from(hash)).unwrap();
+                contract.add_to_collection(collection_id, content_id).unwrap();
+                items.push(content_id);
+            }
+            let (a, b, c) = (items[0], items[1], items[2]);
+            let album = contract.collection_items(collection_id, 0, MAX_PAGE_SIZE);
+            assert_eq!(contract.list_bundle(album.clone(), 0), Err(Error::InvalidPrice));
+            assert_eq!(contract.list_bundle(Vec::new(), 301), Err(Error::InvalidBundle));
+            assert_eq!(contract.list_bundle(vec![a, b, a], 301), Err(Error::InvalidBundle));
+            let bundle_id = contract.list_bundle(album.clone(), 301).unwrap();
+            assert_eq!(contract.get_bundle(bundle_id).unwrap().content_ids, album);
+
+            contract.lock_content_until(a, 2_000).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.list_bundle(vec![a], 301), Err(Error::NotOwner));
+            send_value(350);
+            assert_eq!(contract.buy_bundle(bundle_id), Err(Error::BundleInvalidated));
+            assert_eq!(contract.cancel_bundle(bundle_id), Err(Error::NotOwner));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.cancel_bundle(bundle_id).unwrap();
+            assert_eq!(contract.get_bundle(bundle_id), None);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.buy_bundle(bundle_id), Err(Error::BundleNotFound));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let bundle_id = contract.list_bundle(vec![b, c], 301).unwrap();
+            assert_eq!(contract.buy_bundle(bundle_id), Err(Error::SelfTransfer));
+            contract.transfer_ownership(c, accounts.charlie).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.buy_bundle(bundle_id), Err(Error::BundleInvalidated));
+            assert_eq!(contract.owner_of(b), Some(accounts.alice));
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            contract.transfer_ownership(c, accounts.alice).unwrap();
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(300);
+            assert_eq!(contract.buy_bundle(bundle_id), Err(Error::InsufficientPayment));
+            send_value(350);
+            contract.buy_bundle(bundle_id).unwrap();
+            send_value(0);
+            let events: Vec<_> = test::recorded_events().collect();
+            let event = <BundleSold as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.seller, event.buyer, event.price, event.fee), (accounts.alice, accounts.bob, 301, 30));
+            assert_eq!(contract.owner_of(b), Some(accounts.bob));
+            assert_eq!(contract.owner_of(c), Some(accounts.bob));
+            assert_eq!(contract.owner_of(a), Some(accounts.alice));
+            assert_eq!(contract.last_sale(b).unwrap().price, 151);
+            assert_eq!(contract.last_sale(c).unwrap().price, 150);
+            assert_eq!(contract.credit_of(accounts.alice), 301 - 30);
+            assert_eq!(contract.credit_of(accounts.bob), 49);
+            assert_eq!(contract.collected_fees(), 30);
+            assert_eq!(contract.get_bundle(bundle_id), None);
         }
 
-        /// Tests the `update_oracle_data` function for successful updates by the admin.
-        /// - Verifies that the oracle data is updated when called by the admin.
+        /// Tests renting content: terms, overlapping rentals, transfers during a rental, and reclaiming.
+        /// - Verifies a running rental blocks new rentals and survives an ownership change.
+        /// - Verifies an expired slot can be reclaimed by anyone and rented again on the new owner's terms.
         #[ink::test]
-        fn test_update_oracle_data() {
+        fn test_rentals() {
             let mut contract = ContentOwnership::new();
-            let caller = test::default_accounts::<DefaultEnvironment>().alice;
-            test::set_caller::<DefaultEnvironment>(caller);
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_rented")).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.rent(content_id, 1), Err(Error::RentalNotAvailable));
+            assert_eq!(contract.set_rental_terms(content_id, 10, 7), Err(Error::NotOwner));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.set_rental_terms(content_id, 10, 0), Err(Error::InvalidDuration));
+            contract.set_rental_terms(content_id, 10, 7).unwrap();
+            assert_eq!(contract.rental_terms_of(content_id), Some(RentalTerms { price_per_day: 10, max_days: 7 }));
+            send_value(10);
+            assert_eq!(contract.rent(content_id, 1), Err(Error::OwnerHasAccess));
 
-            assert!(contract.update_oracle_data(String::from("new_oracle")).is_ok());
-            assert_eq!(contract.get_oracle_data(), "new_oracle");
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.rent(content_id, 0), Err(Error::InvalidDuration));
+            assert_eq!(contract.rent(content_id, 8), Err(Error::InvalidDuration));
+            send_value(29);
+            assert_eq!(contract.rent(content_id, 3), Err(Error::InsufficientPayment));
+            send_value(35);
+            let expires_at = contract.rent(content_id, 3).unwrap();
+            assert_eq!(expires_at, 1_000 + 3 * RENTAL_DAY);
+            assert!(contract.is_rented(content_id));
+            assert_eq!(contract.renter_of(content_id), Some(accounts.bob));
+            assert_eq!(contract.credit_of(accounts.alice), 30);
+            assert_eq!(contract.credit_of(accounts.bob), 5);
+
+            // A second rental cannot start while the first runs.
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(10);
+            assert_eq!(contract.rent(content_id, 1), Err(Error::RentalActive));
+            assert_eq!(contract.reclaim_rental(content_id), Err(Error::RentalActive));
+
+            // The rental survives a transfer, which clears the terms.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.transfer_ownership(content_id, accounts.django).unwrap();
+            assert_eq!(contract.renter_of(content_id), Some(accounts.bob));
+            assert_eq!(contract.rental_terms_of(content_id), None);
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            contract.set_rental_terms(content_id, 20, 5).unwrap();
+            // Nor can the owner burn the content while it is rented out.
+            assert_eq!(contract.burn_content(content_id), Err(Error::RentalActive));
+
+            test::set_block_timestamp::<DefaultEnvironment>(expires_at);
+            assert!(!contract.is_rented(content_id));
+            assert_eq!(contract.renter_of(content_id), None);
+            assert_eq!(contract.get_rental(content_id), Some(Rental { renter: accounts.bob, expires_at }));
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            contract.reclaim_rental(content_id).unwrap();
+            let events: Vec<_> = test::recorded_events().collect();
+            let event = <RentalReclaimed as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.renter, event.reclaimed_by), (accounts.bob, accounts.charlie));
+            assert_eq!(contract.get_rental(content_id), None);
+            assert_eq!(contract.reclaim_rental(content_id), Err(Error::NoRental));
+
+            send_value(40);
+            contract.rent(content_id, 2).unwrap();
+            send_value(0);
+            assert_eq!(contract.renter_of(content_id), Some(accounts.charlie));
+            assert_eq!(contract.credit_of(accounts.django), 40);
+            assert_eq!(contract.revenue_of(content_id).rentals, 70);
         }
 
-        /// Tests the `update_oracle_data` function for unauthorized access.
-        /// - Verifies that a non-admin user cannot update the oracle data.
+        /// Tests borrowing against content: funding, early repayment, defaulting, and expired offers.
+        /// - Verifies pledged content cannot be transferred, locked, or listed, and is released on repayment.
+        /// - Verifies the lender can only claim a funded loan once due, and an expired offer cannot be funded.
         #[ink::test]
-        fn test_update_oracle_data_not_admin() {
+        fn test_collateralized_loans() {
             let mut contract = ContentOwnership::new();
-            let caller = test::default_accounts::<DefaultEnvironment>().bob;
-            test::set_caller::<DefaultEnvironment>(caller);
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_pledged")).unwrap();
+            assert_eq!(contract.create_loan_offer(content_id, 0, 10, 1_000), Err(Error::InvalidPrice));
+            assert_eq!(contract.create_loan_offer(content_id, 100, 10, 0), Err(Error::InvalidDuration));
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            contract.create_loan_offer(content_id, 100, 10, 5_000).unwrap();
+            assert_eq!(contract.get_loan(content_id).unwrap().due_at, 6_000);
+            assert_eq!(contract.create_loan_offer(content_id, 100, 10, 5_000), Err(Error::ContentPledged));
+            assert_eq!(contract.transfer_ownership(content_id, accounts.eve), Err(Error::ContentPledged));
+            assert_eq!(contract.lock_content(content_id), Err(Error::ContentPledged));
+            assert_eq!(contract.list_for_sale(content_id, 500), Err(Error::ContentPledged));
+            assert_eq!(contract.repay_loan(content_id), Err(Error::LoanNotFunded));
+
+            // Funding moves the principal to the borrower's credit.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            send_value(100);
+            assert_eq!(contract.fund_loan(content_id), Err(Error::SelfTransfer));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(99);
+            assert_eq!(contract.fund_loan(content_id), Err(Error::InsufficientPayment));
+            send_value(120);
+            contract.fund_loan(content_id).unwrap();
+            assert_eq!(contract.credit_of(accounts.alice), 100);
+            assert_eq!(contract.credit_of(accounts.bob), 20);
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(100);
+            assert_eq!(contract.fund_loan(content_id), Err(Error::LoanAlreadyFunded));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.claim_collateral(content_id), Err(Error::LoanNotDue));
+
+            // Early repayment owes the full interest and releases the content.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.cancel_loan_offer(content_id), Err(Error::LoanAlreadyFunded));
+            send_value(109);
+            assert_eq!(contract.repay_loan(content_id), Err(Error::InsufficientPayment));
+            send_value(110);
+            contract.repay_loan(content_id).unwrap();
+            send_value(0);
+            assert_eq!(contract.credit_of(accounts.bob), 20 + 110);
+            assert_eq!(contract.get_loan(content_id), None);
+            assert_eq!(contract.repay_loan(content_id), Err(Error::NoLoan));
+            contract.lock_content_until(content_id, 0).unwrap();
+
+            // A funded loan that is not repaid in time can be claimed by the lender only.
+            contract.create_loan_offer(content_id, 50, 5, 1_000).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(50);
+            contract.fund_loan(content_id).unwrap();
+            send_value(0);
+            test::set_block_timestamp::<DefaultEnvironment>(2_000);
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            send_value(55);
+            assert_eq!(contract.repay_loan(content_id), Err(Error::LoanOverdue));
+            send_value(0);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.claim_collateral(content_id), Err(Error::NotOwner));
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            contract.claim_collateral(content_id).unwrap();
+            let events: Vec<_> = test::recorded_events().collect();
+            let event = <CollateralClaimed as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.lender, event.borrower), (accounts.charlie, accounts.alice));
+            assert_eq!(contract.owner_of(content_id), Some(accounts.charlie));
+            assert_eq!(contract.get_loan(content_id), None);
+
+            // An offer cannot be funded from its due time on, but can still be cancelled.
+            contract.create_loan_offer(content_id, 70, 0, 500).unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(2_500);
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            send_value(70);
+            assert_eq!(contract.fund_loan(content_id), Err(Error::LoanOfferExpired));
+            send_value(0);
+            assert_eq!(contract.cancel_loan_offer(content_id), Err(Error::NotOwner));
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            contract.cancel_loan_offer(content_id).unwrap();
+            contract.transfer_ownership(content_id, accounts.django).unwrap();
+        }
+
+        /// Tests creator royalties on resales.
+        /// - Verifies only the creator can set a royalty, and only up to the configurable cap.
+        /// - Verifies the creator's own sale pays no royalty, while resales through `buy` and
+        ///   `accept_offer` split the price into royalty, platform fee, and seller proceeds.
+        /// - Verifies the royalty and the fee never add up to more than the price.
+        #[ink::test]
+        fn test_royalties() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_platform_fee_bps(250).unwrap();
+            let content_id = contract.register_content(String::from("default_oracle_royalty")).unwrap();
+            assert_eq!(contract.set_royalty(content_id, DEFAULT_MAX_ROYALTY_BPS + 1), Err(Error::RoyaltyTooHigh));
+            contract.set_royalty(content_id, 1_000).unwrap();
+            assert_eq!(contract.royalty_bps_of(content_id), 1_000);
+            assert_eq!(contract.royalty_info(content_id, 333), (accounts.alice, 33));
+            assert_eq!(contract.royalty_info(content_id + 1, 333), (AccountId::from([0u8; 32]), 0));
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let set = <RoyaltySet as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((set.content_id, set.creator, set.bps), (content_id, accounts.alice, 1_000));
 
+            // The creator's own sale pays no royalty.
+            contract.list_for_sale(content_id, 999).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(999);
+            contract.buy(content_id).unwrap();
+            assert_eq!(contract.credit_of(accounts.alice), 999 - 24);
+            assert_eq!(contract.set_royalty(content_id, 0), Err(Error::NotCreator));
+
+            // 333 splits into a royalty of 33, a fee of 8, and 292 for the seller.
+            contract.list_for_sale(content_id, 333).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(333);
+            contract.buy(content_id).unwrap();
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let paid = <RoyaltyPaid as scale::Decode>::decode(&mut &events[events.len() - 2].data[..]).unwrap();
+            assert_eq!((paid.recipient, paid.amount, paid.currency), (accounts.alice, 33, None));
+            assert_eq!(contract.credit_of(accounts.bob), 292);
+            assert_eq!(contract.credit_of(accounts.alice), 999 - 24 + 33);
+            assert_eq!(contract.earnings_of(accounts.alice), 999 - 24 + 33);
+            assert_eq!(contract.collected_fees(), 24 + 8);
             assert_eq!(
-                contract.update_oracle_data(String::from("new_oracle")),
-                Err(Error::NotAdmin)
+                contract.revenue_of(content_id),
+                RevenueStats { sales: 975 + 292, tips: 0, access: 0, rentals: 0, licenses: 0, royalties: 33 }
             );
+
+            // Accepting an offer pays the royalty too.
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            send_value(100);
+            contract.make_offer(content_id, OFFER_EXPIRY).unwrap();
+            send_value(0);
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            contract.accept_offer(content_id, accounts.django).unwrap();
+            assert_eq!(contract.credit_of(accounts.charlie), 100 - 10 - 2);
+            assert_eq!(contract.credit_of(accounts.alice), 999 - 24 + 33 + 10);
+
+            // Even at the highest cap and fee, rounding never takes more than the price.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.set_max_royalty_bps(MAX_ROYALTY_CAP_BPS + 1), Err(Error::RoyaltyTooHigh));
+            contract.set_max_royalty_bps(MAX_ROYALTY_CAP_BPS).unwrap();
+            assert_eq!(contract.max_royalty_bps(), MAX_ROYALTY_CAP_BPS);
+            contract.set_platform_fee_bps(MAX_PLATFORM_FEE_BPS).unwrap();
+            contract.set_royalty(content_id, MAX_ROYALTY_CAP_BPS).unwrap();
+            for price in [0, 1, 7, 9_999, 10_001, 123_456_789, Balance::MAX] {
+                let (_, royalty) = contract.royalty_info(content_id, price);
+                assert!(royalty + contract.platform_fee(content_id, price, RevenueSource::Sale) <= price);
+            }
         }
 
-        /// Tests the `register_content` function for successful content registration.
-        /// - Verifies that valid content is registered with a unique ID.
-        /// - Verifies that the caller becomes the owner of the content.
+        /// Tests a creator buying their work back with an overpayment.
+        /// - Verifies the creator is credited both the royalty and the excess, through `buy` and `buy_dutch`.
         #[ink::test]
-        fn test_register_content() {
+        fn test_royalty_buy_back_with_excess() {
             let mut contract = ContentOwnership::new();
-            let caller = test::default_accounts::<DefaultEnvironment>().alice;
-            test::set_caller::<DefaultEnvironment>(caller);
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_buy_back")).unwrap();
+            contract.set_royalty(content_id, 1_000).unwrap();
+            contract.transfer_ownership(content_id, accounts.bob).unwrap();
 
-            let content_hash = String::from("default_oracle_content");
-            let content_id = contract.register_content(content_hash.clone()).unwrap();
-            assert_eq!(content_id, 1);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.list_for_sale(content_id, 1_000).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            send_value(1_500);
+            contract.buy(content_id).unwrap();
+            assert_eq!(contract.credit_of(accounts.alice), 100 + 500);
+            assert_eq!(contract.credit_of(accounts.bob), 900);
+            assert_eq!(contract.earnings_of(accounts.alice), 100);
 
-            let content = contract.get_content(content_id).unwrap();
-            assert_eq!(content.content_hash, content_hash);
-            assert_eq!(content.owner, caller);
+            contract.transfer_ownership(content_id, accounts.charlie).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(0);
+            contract.start_dutch_auction(content_id, 2_000, 2_000, 1_000).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            send_value(2_300);
+            contract.buy_dutch(content_id).unwrap();
+            assert_eq!(contract.credit_of(accounts.alice), 600 + 200 + 300);
+            assert_eq!(contract.royalties_earned(accounts.alice), 300);
         }
 
-        /// Tests the `register_content` function for invalid content.
-        /// - Verifies that content with an invalid hash is rejected.
+        /// Tests splitting a royalty among several recipients.
+        /// - Verifies only the creator can split it, and only into valid shares summing to the royalty.
+        /// - Verifies an uneven split credits each recipient their share, with the dust to the first.
+        /// - Verifies `set_royalty` clears the split.
         #[ink::test]
-        fn test_register_content_invalid() {
+        fn test_royalty_split() {
             let mut contract = ContentOwnership::new();
-            let caller = test::default_accounts::<DefaultEnvironment>().alice;
-            test::set_caller::<DefaultEnvironment>(caller);
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_split")).unwrap();
+            contract.set_royalty(content_id, 1_000).unwrap();
+            assert_eq!(contract.royalty_split_of(content_id), vec![(accounts.alice, 1_000)]);
+            for recipients in [
+                vec![],
+                vec![(accounts.charlie, 333), (accounts.django, 666)],
+                vec![(accounts.charlie, 500), (accounts.charlie, 500)],
+                vec![(accounts.charlie, 1_000), (accounts.django, 0)],
+                vec![(accounts.charlie, 100); MAX_ROYALTY_RECIPIENTS + 1],
+            ] {
+                assert_eq!(contract.set_royalty_split(content_id, recipients), Err(Error::InvalidRoyaltySplit));
+            }
+            let split = vec![(accounts.charlie, 333), (accounts.django, 667)];
+            contract.set_royalty_split(content_id, split.clone()).unwrap();
+            assert_eq!(contract.royalty_split_of(content_id), split);
+            assert_eq!(contract.royalty_info(content_id, 1_000), (accounts.charlie, 100));
 
-            let content_hash = String::from("invalid_content");
-            assert_eq!(contract.register_content(content_hash), Err(Error::InvalidContent));
+            contract.list_for_sale(content_id, 1_000).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(1_000);
+            contract.buy(content_id).unwrap();
+            assert_eq!(contract.set_royalty_split(content_id, split), Err(Error::NotCreator));
+
+            // 33.3 and 66.7 round down to 33 and 66, and the dust of 1 goes to Charlie.
+            contract.list_for_sale(content_id, 1_000).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.eve);
+            send_value(1_000);
+            contract.buy(content_id).unwrap();
+            send_value(0);
+            assert_eq!(contract.credit_of(accounts.charlie), 34);
+            assert_eq!(contract.credit_of(accounts.django), 66);
+            assert_eq!(contract.credit_of(accounts.bob), 900);
+            assert_eq!(contract.revenue_of(content_id).royalties, 100);
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_royalty(content_id, 500).unwrap();
+            assert_eq!(contract.royalty_split_of(content_id), vec![(accounts.alice, 500)]);
         }
 
-        /// Tests the `transfer_ownership` function for successful ownership transfer.
-        /// - Verifies that the current owner can transfer ownership to another account.
+        /// Tests permanently locking a royalty.
+        /// - Verifies neither the creator nor the current owner can change a locked royalty or its split.
+        /// - Verifies the lock survives transfers and sales keep paying the locked rate, even below a lowered cap.
         #[ink::test]
-        fn test_transfer_ownership() {
+        fn test_lock_royalty() {
             let mut contract = ContentOwnership::new();
-            let alice = test::default_accounts::<DefaultEnvironment>().alice;
-            let bob = test::default_accounts::<DefaultEnvironment>().bob;
-            test::set_caller::<DefaultEnvironment>(alice);
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_royalty_lock")).unwrap();
+            contract.set_royalty(content_id, 500).unwrap();
+            assert!(!contract.is_royalty_locked(content_id));
+            contract.lock_royalty(content_id).unwrap();
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <RoyaltyLocked as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.content_id, event.locked_by, event.bps), (content_id, accounts.alice, 500));
+            assert!(contract.is_royalty_locked(content_id));
+            assert_eq!(contract.set_royalty(content_id, 1_000), Err(Error::RoyaltyLocked));
+            assert_eq!(contract.set_royalty_split(content_id, vec![(accounts.bob, 500)]), Err(Error::RoyaltyLocked));
+            assert_eq!(contract.lock_royalty(content_id), Err(Error::RoyaltyLocked));
 
-            let content_hash = String::from("default_oracle_content");
-            let content_id = contract.register_content(content_hash.clone()).unwrap();
+            contract.transfer_ownership(content_id, accounts.bob).unwrap();
+            assert!(contract.is_royalty_locked(content_id));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_royalty(content_id, 0), Err(Error::NotCreator));
+            assert_eq!(contract.set_royalty_split(content_id, vec![(accounts.bob, 500)]), Err(Error::NotCreator));
 
-            test::set_caller::<DefaultEnvironment>(alice);
-            assert!(contract.transfer_ownership(content_id, bob).is_ok());
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_max_royalty_bps(100).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.list_for_sale(content_id, 1_000).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(1_000);
+            contract.buy(content_id).unwrap();
+            send_value(0);
+            assert_eq!(contract.credit_of(accounts.alice), 50);
+            assert_eq!(contract.credit_of(accounts.bob), 950);
+        }
 
-            let content = contract.get_content(content_id).unwrap();
-            assert_eq!(content.owner, bob);
+        /// Tests separate platform fees for first sales and resales.
+        /// - Verifies only an admin can set the schedule, with both fees capped.
+        /// - Verifies a first sale pays the primary fee and flips `is_primary_sold`, whatever the sale
+        ///   path, while resales and access purchases pay the secondary fee.
+        #[ink::test]
+        fn test_fee_schedule() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            assert_eq!(contract.set_fee_schedule(MAX_PLATFORM_FEE_BPS + 1, 250), Err(Error::FeeTooHigh));
+            assert_eq!(contract.set_fee_schedule(1_000, MAX_PLATFORM_FEE_BPS + 1), Err(Error::FeeTooHigh));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_fee_schedule(1_000, 250), Err(Error::NotAdmin));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_fee_schedule(1_000, 250).unwrap();
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <PlatformFeeChanged as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.primary_bps, event.secondary_bps), (1_000, 250));
+            assert_eq!((contract.primary_fee_bps(), contract.secondary_fee_bps()), (1_000, 250));
+            let listed = contract.register_content(String::from("default_oracle_fee_primary")).unwrap();
+            let offered = contract.register_content(String::from("default_oracle_fee_offered")).unwrap();
+            assert!(!contract.is_primary_sold(listed));
+
+            // Access purchases pay the secondary fee and are not sales.
+            contract.set_access_price(offered, 1_000, None).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            send_value(1_000);
+            contract.purchase_access(offered).unwrap();
+            assert_eq!(contract.credit_of(accounts.alice), 975);
+            assert!(!contract.is_primary_sold(offered));
+
+            // The creator's first sale pays the primary fee.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.list_for_sale(listed, 1_000).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(1_000);
+            contract.buy(listed).unwrap();
+            assert!(contract.is_primary_sold(listed));
+            assert_eq!(contract.credit_of(accounts.alice), 975 + 900);
+            assert_eq!(contract.collected_fees(), 25 + 100);
+
+            // The resale pays the secondary fee.
+            contract.list_for_sale(listed, 1_000).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(1_000);
+            contract.buy(listed).unwrap();
+            assert_eq!(contract.credit_of(accounts.bob), 975);
+            assert_eq!(contract.collected_fees(), 25 + 100 + 25);
+
+            // An accepted offer is a first sale too.
+            send_value(1_000);
+            contract.make_offer(offered, OFFER_EXPIRY).unwrap();
+            send_value(0);
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.accept_offer(offered, accounts.charlie).unwrap();
+            assert!(contract.is_primary_sold(offered));
+            assert_eq!(contract.credit_of(accounts.alice), 975 + 900 + 900);
         }
 
-        /// Tests the `transfer_ownership` function for unauthorized access.
-        /// - Verifies that a non-owner cannot transfer ownership.
+        /// Tests the royalty earnings report.
+        /// - Verifies royalties from two works of the same creator, sold through `buy` and through
+        ///   `settle_auction`, add up per recipient and per content.
         #[ink::test]
-        fn test_transfer_ownership_not_owner() {
+        fn test_royalty_earnings() {
             let mut contract = ContentOwnership::new();
-            let alice = test::default_accounts::<DefaultEnvironment>().alice;
-            let bob = test::default_accounts::<DefaultEnvironment>().bob;
-            test::set_caller::<DefaultEnvironment>(alice);
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_auction_extension(0).unwrap();
+            let listed = contract.register_content(String::from("default_oracle_royalty_listed")).unwrap();
+            let auctioned = contract.register_content(String::from("default_oracle_royalty_auctioned")).unwrap();
+            contract.set_royalty(listed, 1_000).unwrap();
+            contract.set_royalty(auctioned, 1_000).unwrap();
+            contract.set_royalty_split(auctioned, vec![(accounts.alice, 600), (accounts.django, 400)]).unwrap();
+            contract.transfer_ownership(listed, accounts.bob).unwrap();
+            contract.transfer_ownership(auctioned, accounts.bob).unwrap();
+            assert_eq!(contract.royalties_earned(accounts.alice), 0);
 
-            let content_hash = String::from("default_oracle_content");
-            let content_id = contract.register_content(content_hash.clone()).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.list_for_sale(listed, 500).unwrap();
+            test::set_block_timestamp::<DefaultEnvironment>(0);
+            contract.start_auction(auctioned, 0, 1_000, 1).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(500);
+            contract.buy(listed).unwrap();
+            send_value(300);
+            contract.bid(auctioned).unwrap();
+            send_value(0);
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            contract.settle_auction(auctioned).unwrap();
 
-            test::set_caller::<DefaultEnvironment>(bob);
-            assert_eq!(contract.transfer_ownership(content_id, alice), Err(Error::NotOwner));
+            assert_eq!(contract.royalties_paid_for(listed), 50);
+            assert_eq!(contract.royalties_paid_for(auctioned), 30);
+            assert_eq!(contract.royalties_earned(accounts.alice), 50 + 18);
+            assert_eq!(contract.royalties_earned(accounts.django), 12);
+            assert_eq!(contract.earnings_of(accounts.alice), 50 + 18);
+            assert_eq!(contract.credit_of(accounts.bob), 450 + 270);
         }
 
-        /// Tests the `transfer_ownership` function for unauthorized access by Charlie.
-        /// - Verifies that a third party (Charlie) cannot transfer ownership of content they do not own.
+        /// Tests marketplace-only content.
+        /// - Verifies plain and approved transfers fail while sales still change ownership.
+        /// - Verifies only the creator can opt in, before the first transfer, and exempt recipients.
+        /// - Verifies transfers to an exempted recipient go through.
+        /// - Verifies only exempted lenders can fund a loan against it, and pledged content cannot opt in.
         #[ink::test]
-        fn test_transfer_ownership_unauthorized_charlie() {
+        fn test_marketplace_only() {
             let mut contract = ContentOwnership::new();
-            let alice = test::default_accounts::<DefaultEnvironment>().alice;
-            let charlie = test::default_accounts::<DefaultEnvironment>().charlie;
-            test::set_caller::<DefaultEnvironment>(alice);
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let content_id = contract.register_content(String::from("default_oracle_marketplace")).unwrap();
+            let moved = contract.register_content(String::from("default_oracle_marketplace_moved")).unwrap();
+            contract.transfer_ownership(moved, accounts.bob).unwrap();
+            assert_eq!(contract.set_marketplace_only(moved, true), Err(Error::AlreadyTransferred));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.set_marketplace_only(content_id, true), Err(Error::NotCreator));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_marketplace_only(content_id, true).unwrap();
+            assert!(contract.is_marketplace_only(content_id));
+            assert_eq!(contract.transfer_ownership(content_id, accounts.bob), Err(Error::MarketplaceOnly));
 
-            let content_hash = String::from("default_oracle_content");
-            let content_id = contract.register_content(content_hash.clone()).unwrap();
+            // A sale goes through.
+            contract.set_royalty(content_id, 1_000).unwrap();
+            contract.list_for_sale(content_id, 100).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(100);
+            contract.buy(content_id).unwrap();
+            send_value(0);
+            assert_eq!(contract.owner_of(content_id), Some(accounts.bob));
 
-            test::set_caller::<DefaultEnvironment>(charlie);
+            // Neither the owner nor an approved operator can skip the marketplace.
+            assert_eq!(contract.transfer_ownership(content_id, accounts.charlie), Err(Error::MarketplaceOnly));
+            contract.approve(content_id, accounts.charlie).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(contract.transfer_from(content_id, accounts.charlie), Err(Error::MarketplaceOnly));
+            assert_eq!(contract.add_transfer_exemption(content_id, accounts.django), Err(Error::NotCreator));
+
+            // An exempted recipient can receive it.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.add_transfer_exemption(content_id, accounts.django).unwrap();
+            assert!(contract.is_transfer_exempt(content_id, accounts.django));
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.transfer_ownership(content_id, accounts.django).unwrap();
+            assert_eq!(contract.owner_of(content_id), Some(accounts.django));
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.remove_transfer_exemption(content_id, accounts.django).unwrap();
+            contract.add_transfer_exemption(content_id, accounts.bob).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            contract.transfer_ownership(content_id, accounts.bob).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.transfer_ownership(content_id, accounts.django), Err(Error::MarketplaceOnly));
+
+            // Only an exempted lender can fund a loan, so a collateral claim cannot skip the royalty.
+            contract.create_loan_offer(content_id, 100, 10, 5_000).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(100);
+            assert_eq!(contract.fund_loan(content_id), Err(Error::MarketplaceOnly));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.add_transfer_exemption(content_id, accounts.charlie).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            contract.fund_loan(content_id).unwrap();
+            send_value(0);
+
+            // Pledged content cannot be made marketplace-only afterwards.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            let pledged = contract.register_content(String::from("default_oracle_marketplace_pledged")).unwrap();
+            contract.create_loan_offer(pledged, 100, 10, 5_000).unwrap();
+            assert_eq!(contract.set_marketplace_only(pledged, true), Err(Error::ContentPledged));
+        }
+
+        /// Tests granting and revoking licenses.
+        /// - Verifies only the owner can grant, and only to others and with a future expiry.
+        /// - Verifies expired licenses read as inactive but stay listed until revoked.
+        /// - Verifies revocation removes the license and a revoked licensee can be granted one again.
+        #[ink::test]
+        fn test_licenses() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            let content_id = contract.register_content(String::from("default_oracle_license")).unwrap();
+            let terms = || String::from("license_terms_hash");
+            assert_eq!(contract.grant_license(content_id, accounts.alice, terms(), None, false, false), Err(Error::OwnerHasAccess));
+            assert_eq!(contract.grant_license(content_id, accounts.bob, terms(), Some(1_000), false, false), Err(Error::LicenseExpired));
             assert_eq!(
-                contract.transfer_ownership(content_id, alice),
-                Err(Error::NotOwner)
+                contract.grant_license(content_id, accounts.bob, "a".repeat(MAX_LICENSE_TERMS_LENGTH + 1), None, false, false),
+                Err(Error::LicenseTermsTooLong)
             );
+            contract.grant_license(content_id, accounts.bob, terms(), Some(2_000), false, false).unwrap();
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <LicenseGranted as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.licensee, event.expires_at), (accounts.bob, Some(2_000)));
+            contract.grant_license(content_id, accounts.charlie, terms(), None, false, false).unwrap();
+            assert_eq!(
+                contract.license_of(content_id, accounts.bob),
+                Some(License { terms_hash: terms(), granted_by: accounts.alice, granted_at: 1_000, expires_at: Some(2_000), exclusive: false, transferable: false })
+            );
+            assert!(contract.is_license_active(content_id, accounts.bob));
+            assert_eq!(contract.licensees_of(content_id, 0, 10), vec![accounts.bob, accounts.charlie]);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.grant_license(content_id, accounts.django, terms(), None, false, false), Err(Error::NotOwner));
+            assert_eq!(contract.revoke_license(content_id, accounts.charlie, String::new()), Err(Error::NotOwner));
+
+            // Expiry needs no cleanup.
+            test::set_block_timestamp::<DefaultEnvironment>(2_000);
+            assert!(!contract.is_license_active(content_id, accounts.bob));
+            assert!(contract.is_license_active(content_id, accounts.charlie));
+            assert_eq!(contract.licensees_of(content_id, 0, 10), vec![accounts.bob, accounts.charlie]);
+
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.revoke_license(content_id, accounts.bob, String::from("expired")).unwrap();
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <LicenseRevoked as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.licensee, event.revoked_by, event.reason), (accounts.bob, accounts.alice, String::from("expired")));
+            contract.revoke_license(content_id, accounts.charlie, String::from("breach")).unwrap();
+            assert_eq!(contract.revoke_license(content_id, accounts.charlie, String::new()), Err(Error::NoLicense));
+            assert_eq!(contract.license_of(content_id, accounts.charlie), None);
+            assert!(!contract.is_license_active(content_id, accounts.charlie));
+            assert_eq!(contract.licensees_of(content_id, 0, 10), vec![]);
+
+            // A revoked licensee can be licensed again, and licenses survive transfers.
+            contract.grant_license(content_id, accounts.charlie, terms(), None, false, false).unwrap();
+            contract.transfer_ownership(content_id, accounts.django).unwrap();
+            assert!(contract.is_license_active(content_id, accounts.charlie));
+            assert_eq!(contract.licensees_of(content_id, 0, 10), vec![accounts.charlie]);
         }
 
-        /// Tests the `get_content` function for non-existent content.
-        /// - Verifies that querying a non-existent content ID returns `None`.
+        /// Tests selling standard licenses at an owner-set price.
+        /// - Verifies underpayment fails and a price change only applies to later purchases.
+        /// - Verifies withdrawing the offer keeps sold licenses, and revoked content cannot be licensed.
         #[ink::test]
-        fn test_get_content_not_found() {
-            let contract = ContentOwnership::new();
-            assert!(contract.get_content(999).is_none());
+        fn test_buy_license() {
+            let mut contract = ContentOwnership::new();
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            let content_id = contract.register_content(String::from("default_oracle_license_offer")).unwrap();
+            let terms = || String::from("standard_license_hash");
+            assert_eq!(contract.set_license_offer(content_id, 100, 0, terms()), Err(Error::InvalidDuration));
+            contract.set_license_offer(content_id, 100, 5_000, terms()).unwrap();
+            assert_eq!(contract.license_offer_of(content_id), Some(LicenseOffer { price: 100, duration: 5_000, terms_hash: terms() }));
+            send_value(100);
+            assert_eq!(contract.buy_license(content_id), Err(Error::OwnerHasAccess));
+
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            send_value(99);
+            assert_eq!(contract.buy_license(content_id), Err(Error::InsufficientPayment));
+            send_value(100);
+            assert_eq!(contract.buy_license(content_id), Ok(6_000));
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <LicensePurchased as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.licensee, event.owner, event.price, event.expires_at), (accounts.bob, accounts.alice, 100, 6_000));
+            assert!(contract.is_license_active(content_id, accounts.bob));
+
+            // A new price applies to the next purchase only.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_license_offer(content_id, 150, 1_000, String::from("updated_license_hash")).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            send_value(100);
+            assert_eq!(contract.buy_license(content_id), Err(Error::InsufficientPayment));
+            send_value(200);
+            assert_eq!(contract.buy_license(content_id), Ok(2_000));
+            send_value(0);
+            assert_eq!(contract.credit_of(accounts.alice), 250);
+            assert_eq!(contract.credit_of(accounts.charlie), 50);
+            assert_eq!(contract.revenue_of(content_id).licenses, 250);
+            let bob_license = contract.license_of(content_id, accounts.bob).unwrap();
+            assert_eq!((bob_license.terms_hash, bob_license.expires_at), (terms(), Some(6_000)));
+
+            // Withdrawing the offer keeps the sold licenses.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_license_offer(content_id, 0, 0, String::new()).unwrap();
+            assert_eq!(contract.license_offer_of(content_id), None);
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            send_value(150);
+            assert_eq!(contract.buy_license(content_id), Err(Error::LicenseNotOffered));
+            assert!(contract.is_license_active(content_id, accounts.bob));
+            assert!(contract.is_license_active(content_id, accounts.charlie));
+
+            // Revoked content cannot be licensed.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_license_offer(content_id, 150, 1_000, terms()).unwrap();
+            contract.revoke_content(content_id, String::from("infringement_report")).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            assert_eq!(contract.buy_license(content_id), Err(Error::ContentNotActive));
+            send_value(0);
         }
 
-        /// Tests the `register_content` function for duplicate content registration.
-        /// - Verifies that registering the same content hash returns the same content ID.
+        /// Tests exclusive licenses.
+        /// - Verifies an exclusive license needs no other active license and blocks granting and selling others.
+        /// - Verifies the exclusive holder cannot replace it by buying a license.
+        /// - Verifies exclusivity lapses on expiry and on revocation.
         #[ink::test]
-        fn test_register_content_duplicate() {
+        fn test_exclusive_license() {
             let mut contract = ContentOwnership::new();
-            let caller = test::default_accounts::<DefaultEnvironment>().alice;
-            test::set_caller::<DefaultEnvironment>(caller);
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            let content_id = contract.register_content(String::from("default_oracle_exclusive")).unwrap();
+            let terms = || String::from("exclusive_terms_hash");
+            contract.grant_license(content_id, accounts.bob, terms(), Some(2_000), false, false).unwrap();
+            assert_eq!(contract.grant_license(content_id, accounts.charlie, terms(), Some(5_000), true, false), Err(Error::ExclusiveLicenseActive));
+            assert!(!contract.has_exclusive_license(content_id));
 
-            let content_hash = String::from("default_oracle_content");
-            let content_id_1 = contract.register_content(content_hash.clone()).unwrap();
-            let content_id_2 = contract.register_content(content_hash).unwrap();
+            // Once the other license expires, an exclusive one can be granted and blocks everyone else.
+            test::set_block_timestamp::<DefaultEnvironment>(2_000);
+            contract.grant_license(content_id, accounts.charlie, terms(), Some(5_000), true, false).unwrap();
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <LicenseGranted as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert!(event.exclusive);
+            assert!(contract.has_exclusive_license(content_id));
+            assert_eq!(contract.grant_license(content_id, accounts.bob, terms(), None, false, false), Err(Error::ExclusiveLicenseActive));
+            contract.set_license_offer(content_id, 100, 1_000, terms()).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.django);
+            send_value(100);
+            assert_eq!(contract.buy_license(content_id), Err(Error::ExclusiveLicenseActive));
+            // Nor can the exclusive holder swap it for a bought, non-exclusive one.
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(contract.buy_license(content_id), Err(Error::AlreadyLicensed));
+            assert!(contract.license_of(content_id, accounts.charlie).unwrap().exclusive);
+            test::set_caller::<DefaultEnvironment>(accounts.django);
 
-            assert_eq!(content_id_1, content_id_2);
+            // Exclusivity lapses on expiry.
+            test::set_block_timestamp::<DefaultEnvironment>(5_000);
+            assert!(!contract.has_exclusive_license(content_id));
+            assert_eq!(contract.buy_license(content_id), Ok(6_000));
+
+            // The holder can be renewed exclusively only once no one else is licensed, and revocation ends it.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            send_value(0);
+            assert_eq!(contract.grant_license(content_id, accounts.charlie, terms(), None, true, false), Err(Error::ExclusiveLicenseActive));
+            contract.revoke_license(content_id, accounts.django, String::new()).unwrap();
+            contract.grant_license(content_id, accounts.charlie, terms(), None, true, false).unwrap();
+            assert!(contract.has_exclusive_license(content_id));
+            contract.revoke_license(content_id, accounts.charlie, String::from("breach")).unwrap();
+            assert!(!contract.has_exclusive_license(content_id));
+            contract.grant_license(content_id, accounts.bob, terms(), None, false, false).unwrap();
         }
 
-        /// Tests the `register_content` function for counter overflow.
-        /// - Verifies that attempting to register content when the counter is at its maximum value results in an error.
+        /// Tests license transfers.
+        /// - Verifies a transferable license moves with its terms, expiry and exclusivity.
+        /// - Verifies non-transferable, expired and revoked licenses cannot be transferred, nor any while transfers are paused.
         #[ink::test]
-        fn test_counter_overflow() {
+        fn test_transfer_license() {
             let mut contract = ContentOwnership::new();
-            contract.next_content_id = u64::MAX;
+            let accounts = test::default_accounts::<DefaultEnvironment>();
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            test::set_block_timestamp::<DefaultEnvironment>(1_000);
+            let content_id = contract.register_content(String::from("default_oracle_license_transfer")).unwrap();
+            let terms = || String::from("license_terms_hash");
+            contract.grant_license(content_id, accounts.bob, terms(), Some(3_000), true, true).unwrap();
+            let license = contract.license_of(content_id, accounts.bob).unwrap();
 
-            let caller = test::default_accounts::<DefaultEnvironment>().alice;
-            test::set_caller::<DefaultEnvironment>(caller);
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.transfer_license(content_id, accounts.bob), Err(Error::SelfTransfer));
+            assert_eq!(contract.transfer_license(content_id, accounts.alice), Err(Error::OwnerHasAccess));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_paused(PausableOp::Transfers, true).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.transfer_license(content_id, accounts.charlie), Err(Error::ContractPaused));
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.set_paused(PausableOp::Transfers, false).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            contract.transfer_license(content_id, accounts.charlie).unwrap();
+            let events = test::recorded_events().collect::<Vec<_>>();
+            let event = <LicenseTransferred as scale::Decode>::decode(&mut &events.last().unwrap().data[..]).unwrap();
+            assert_eq!((event.from, event.to), (accounts.bob, accounts.charlie));
+            assert_eq!(contract.license_of(content_id, accounts.bob), None);
+            assert_eq!(contract.license_of(content_id, accounts.charlie), Some(license));
+            assert_eq!(contract.licensees_of(content_id, 0, 10), vec![accounts.charlie]);
+            assert!(contract.has_exclusive_license(content_id));
+            assert_eq!(contract.transfer_license(content_id, accounts.django), Err(Error::NoLicense));
 
-            let content_hash = String::from("default_oracle_content");
-            assert_eq!(contract.register_content(content_hash), Err(Error::CounterOverflow));
+            // The owner can veto by revoking the moved license.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.revoke_license(content_id, accounts.charlie, String::from("veto")).unwrap();
+            assert!(!contract.has_exclusive_license(content_id));
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(contract.transfer_license(content_id, accounts.django), Err(Error::NoLicense));
+
+            // Non-transferable licenses stay put, and a recipient cannot hold two licenses.
+            test::set_caller::<DefaultEnvironment>(accounts.alice);
+            contract.grant_license(content_id, accounts.bob, terms(), Some(3_000), false, false).unwrap();
+            contract.grant_license(content_id, accounts.charlie, terms(), Some(2_000), false, true).unwrap();
+            test::set_caller::<DefaultEnvironment>(accounts.bob);
+            assert_eq!(contract.transfer_license(content_id, accounts.django), Err(Error::LicenseNotTransferable));
+            test::set_caller::<DefaultEnvironment>(accounts.charlie);
+            assert_eq!(contract.transfer_license(content_id, accounts.bob), Err(Error::AlreadyLicensed));
+
+            // Expired licenses cannot be transferred.
+            test::set_block_timestamp::<DefaultEnvironment>(2_000);
+            assert_eq!(contract.transfer_license(content_id, accounts.django), Err(Error::LicenseExpired));
+            assert!(contract.license_of(content_id, accounts.django).is_none());
+        }
+    }
+}
+
+//----------------------------------
+// End-to-end Tests
+//----------------------------------
+
+/// End-to-end tests for flows that need real cross-contract calls.
+/// Run with `cargo test --features e2e-tests` against a running contracts node.
+#[cfg(all(test, feature = "e2e-tests"))]
+mod e2e_tests {
+    use super::content_ownership::*;
+    use ink::prelude::string::String;
+    use ink_e2e::ContractsBackend;
+    use mock_psp22::mock_psp22::{MockPsp22, MockPsp22Ref, PSP22 as _};
+    use mock_receiver::mock_receiver::{MockReceiver, MockReceiverRef};
+    use mock_validator::mock_validator::MockValidatorRef;
+
+    type E2EResult<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+    /// Tests `safe_transfer_ownership` to a receiver contract that accepts the content.
+    /// - Verifies the content moves to the receiver and the hook sees the ID, sender, and data.
+    #[ink_e2e::test]
+    async fn e2e_safe_transfer_accepted<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+        let mut constructor = ContentOwnershipRef::new();
+        let contract = client
+            .instantiate("content_ownership", &ink_e2e::alice(), &mut constructor)
+            .submit()
+            .await
+            .expect("content_ownership instantiate failed");
+        let mut call_builder = contract.call_builder::<ContentOwnership>();
+        let mut receiver_constructor = MockReceiverRef::new(true);
+        let receiver = client
+            .instantiate("mock_receiver", &ink_e2e::alice(), &mut receiver_constructor)
+            .submit()
+            .await
+            .expect("mock_receiver instantiate failed");
+
+        let register = call_builder.register_content(String::from("default_oracle_content"));
+        let content_id = client
+            .call(&ink_e2e::alice(), &register)
+            .submit()
+            .await
+            .expect("register_content failed")
+            .return_value()
+            .expect("register_content returned an error");
+
+        let transfer = call_builder.safe_transfer_ownership(content_id, receiver.account_id, vec![7]);
+        client
+            .call(&ink_e2e::alice(), &transfer)
+            .submit()
+            .await
+            .expect("safe_transfer_ownership failed");
+
+        let owner_of = call_builder.owner_of(content_id);
+        let owner = client.call(&ink_e2e::alice(), &owner_of).dry_run().await?.return_value();
+        assert_eq!(owner, Some(receiver.account_id));
+
+        let last_received = receiver.call_builder::<MockReceiver>().last_received();
+        let received = client.call(&ink_e2e::alice(), &last_received).dry_run().await?.return_value();
+        let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+        assert_eq!(received, Some((content_id, alice, vec![7])));
+        Ok(())
+    }
+
+    /// Tests `safe_transfer_ownership` to a receiver contract that rejects the content.
+    /// - Verifies the call fails with `ReceiverRejected` and the owner is unchanged.
+    #[ink_e2e::test]
+    async fn e2e_safe_transfer_rejected<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+        let mut constructor = ContentOwnershipRef::new();
+        let contract = client
+            .instantiate("content_ownership", &ink_e2e::alice(), &mut constructor)
+            .submit()
+            .await
+            .expect("content_ownership instantiate failed");
+        let mut call_builder = contract.call_builder::<ContentOwnership>();
+        let mut receiver_constructor = MockReceiverRef::new(false);
+        let receiver = client
+            .instantiate("mock_receiver", &ink_e2e::alice(), &mut receiver_constructor)
+            .submit()
+            .await
+            .expect("mock_receiver instantiate failed");
+
+        let register = call_builder.register_content(String::from("default_oracle_content"));
+        let content_id = client
+            .call(&ink_e2e::alice(), &register)
+            .submit()
+            .await
+            .expect("register_content failed")
+            .return_value()
+            .expect("register_content returned an error");
+
+        let transfer = call_builder.safe_transfer_ownership(content_id, receiver.account_id, Vec::new());
+        let result = client.call(&ink_e2e::alice(), &transfer).dry_run().await?.return_value();
+        assert_eq!(result, Err(Error::ReceiverRejected));
+
+        let owner_of = call_builder.owner_of(content_id);
+        let owner = client.call(&ink_e2e::alice(), &owner_of).dry_run().await?.return_value();
+        let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+        assert_eq!(owner, Some(alice));
+        Ok(())
+    }
+
+    /// Tests registration through an external validator contract.
+    /// - Verifies hashes the validator accepts register even without an oracle prefix, and others fail with `InvalidContent`.
+    #[ink_e2e::test]
+    async fn e2e_external_validator<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+        let mut constructor = ContentOwnershipRef::new();
+        let contract = client
+            .instantiate("content_ownership", &ink_e2e::alice(), &mut constructor)
+            .submit()
+            .await
+            .expect("content_ownership instantiate failed");
+        let mut call_builder = contract.call_builder::<ContentOwnership>();
+        let mut validator_constructor = MockValidatorRef::new(String::from("Qm"), 8);
+        let validator = client
+            .instantiate("mock_validator", &ink_e2e::alice(), &mut validator_constructor)
+            .submit()
+            .await
+            .expect("mock_validator instantiate failed");
+
+        let set_validator = call_builder.set_validator(Some(validator.account_id));
+        client
+            .call(&ink_e2e::alice(), &set_validator)
+            .submit()
+            .await
+            .expect("set_validator failed");
+
+        let accepted = call_builder.register_content(String::from("QmAccepted"));
+        let content_id = client
+            .call(&ink_e2e::alice(), &accepted)
+            .submit()
+            .await
+            .expect("register_content failed")
+            .return_value()
+            .expect("register_content returned an error");
+        assert_eq!(content_id, 1);
+
+        for rejected in ["default_oracle_content", "QmShort"] {
+            let register = call_builder.register_content(String::from(rejected));
+            let result = client.call(&ink_e2e::alice(), &register).dry_run().await?.return_value();
+            assert_eq!(result, Err(Error::InvalidContent));
+        }
+        Ok(())
+    }
+
+    /// Tests registration when the validator call fails.
+    /// - Verifies a contract without `is_valid` makes registration fail with `InvalidContent` instead of trapping.
+    #[ink_e2e::test]
+    async fn e2e_failing_validator<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+        let mut constructor = ContentOwnershipRef::new();
+        let contract = client
+            .instantiate("content_ownership", &ink_e2e::alice(), &mut constructor)
+            .submit()
+            .await
+            .expect("content_ownership instantiate failed");
+        let mut call_builder = contract.call_builder::<ContentOwnership>();
+        let mut receiver_constructor = MockReceiverRef::new(true);
+        let not_a_validator = client
+            .instantiate("mock_receiver", &ink_e2e::alice(), &mut receiver_constructor)
+            .submit()
+            .await
+            .expect("mock_receiver instantiate failed");
+
+        let set_validator = call_builder.set_validator(Some(not_a_validator.account_id));
+        client
+            .call(&ink_e2e::alice(), &set_validator)
+            .submit()
+            .await
+            .expect("set_validator failed");
+
+        let register = call_builder.register_content(String::from("default_oracle_content"));
+        let result = client.call(&ink_e2e::alice(), &register).dry_run().await?.return_value();
+        assert_eq!(result, Err(Error::InvalidContent));
+        Ok(())
+    }
+
+    /// Tests `buy_with_token` against a reference PSP22 token.
+    /// - Verifies buying without enough allowance fails with `TokenTransferFailed` and changes nothing.
+    /// - Verifies an approved purchase moves ownership, pays the seller, and sends the fee to the treasury.
+    #[ink_e2e::test]
+    async fn e2e_buy_with_token<Client: E2EBackend>(mut client: Client) -> E2EResult<()> {
+        let mut constructor = ContentOwnershipRef::new();
+        let contract = client
+            .instantiate("content_ownership", &ink_e2e::alice(), &mut constructor)
+            .submit()
+            .await
+            .expect("content_ownership instantiate failed");
+        let mut call_builder = contract.call_builder::<ContentOwnership>();
+        let mut token_constructor = MockPsp22Ref::new(1_000);
+        let token = client
+            .instantiate("mock_psp22", &ink_e2e::bob(), &mut token_constructor)
+            .submit()
+            .await
+            .expect("mock_psp22 instantiate failed");
+        let mut token_builder = token.call_builder::<MockPsp22>();
+        let alice = ink_e2e::account_id(ink_e2e::AccountKeyring::Alice);
+        let bob = ink_e2e::account_id(ink_e2e::AccountKeyring::Bob);
+        let charlie = ink_e2e::account_id(ink_e2e::AccountKeyring::Charlie);
+
+        let set_fee = call_builder.set_platform_fee_bps(500);
+        client.call(&ink_e2e::alice(), &set_fee).submit().await.expect("set_platform_fee_bps failed");
+        let set_treasury = call_builder.set_treasury(charlie);
+        client.call(&ink_e2e::alice(), &set_treasury).submit().await.expect("set_treasury failed");
+        let register = call_builder.register_content(String::from("default_oracle_content"));
+        let content_id = client
+            .call(&ink_e2e::alice(), &register)
+            .submit()
+            .await
+            .expect("register_content failed")
+            .return_value()
+            .expect("register_content returned an error");
+        let list = call_builder.list_for_sale_in_token(content_id, token.account_id, 100);
+        client.call(&ink_e2e::alice(), &list).submit().await.expect("list_for_sale_in_token failed");
+
+        let approve = token_builder.approve(contract.account_id, 99);
+        client.call(&ink_e2e::bob(), &approve).submit().await.expect("approve failed");
+        let buy = call_builder.buy_with_token(content_id);
+        let result = client.call(&ink_e2e::bob(), &buy).dry_run().await?.return_value();
+        assert_eq!(result, Err(Error::TokenTransferFailed));
+        let owner_of = call_builder.owner_of(content_id);
+        let owner = client.call(&ink_e2e::alice(), &owner_of).dry_run().await?.return_value();
+        assert_eq!(owner, Some(alice));
+
+        let approve = token_builder.approve(contract.account_id, 100);
+        client.call(&ink_e2e::bob(), &approve).submit().await.expect("approve failed");
+        client.call(&ink_e2e::bob(), &buy).submit().await.expect("buy_with_token failed");
+        let owner = client.call(&ink_e2e::alice(), &owner_of).dry_run().await?.return_value();
+        assert_eq!(owner, Some(bob));
+        for (account, expected) in [(bob, 900), (alice, 95), (charlie, 5)] {
+            let balance_of = token_builder.balance_of(account);
+            let balance = client.call(&ink_e2e::alice(), &balance_of).dry_run().await?.return_value();
+            assert_eq!(balance, expected);
         }
+        let revenue_of = call_builder.revenue_of(content_id);
+        let revenue = client.call(&ink_e2e::alice(), &revenue_of).dry_run().await?.return_value();
+        assert_eq!(revenue.sales, 95);
+        let earnings_of = call_builder.earnings_of(alice);
+        let earnings = client.call(&ink_e2e::alice(), &earnings_of).dry_run().await?.return_value();
+        assert_eq!(earnings, 95);
+        Ok(())
     }
 }
+
@@ -0,0 +1,79 @@
+/// Returns the leaf of an allowlisted item: the BLAKE2-256 hash of its bytes.
+pub fn leaf(data: &[u8]) -> [u8; 32] {
+    let mut output = [0u8; 32];
+    ink::env::hash_bytes::<ink::env::hash::Blake2x256>(data, &mut output);
+    output
+}
+
+/// Returns the parent of two nodes, hashing them in ascending byte order.
+pub fn hash_pair(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut payload = [0u8; 64];
+    payload[..32].copy_from_slice(first);
+    payload[32..].copy_from_slice(second);
+    leaf(&payload)
+}
+
+/// Checks that `proof` links `leaf` to `root`. The proof lists the sibling on each level
+/// from the leaf upwards, leaving out levels where the node was promoted.
+pub fn verify(root: &[u8; 32], leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| hash_pair(&node, sibling));
+    computed == *root
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Builds the root of a tree over `leaves` and the proof of the leaf at `index`.
+    pub(crate) fn root_and_proof(leaves: &[[u8; 32]], index: usize) -> ([u8; 32], Vec<[u8; 32]>) {
+        let mut level = leaves.to_vec();
+        let mut index = index;
+        let mut proof = Vec::new();
+        while level.len() > 1 {
+            let sibling = index ^ 1;
+            if sibling < level.len() {
+                proof.push(level[sibling]);
+            }
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => hash_pair(a, b),
+                    [single] => *single,
+                    _ => unreachable!(),
+                })
+                .collect();
+            index /= 2;
+        }
+        (level[0], proof)
+    }
+
+    fn leaves(count: u8) -> Vec<[u8; 32]> {
+        (0..count).map(|i| leaf(&[i])).collect()
+    }
+
+    /// Tests that every leaf of trees with odd and even sizes verifies against the root.
+    #[test]
+    fn test_verify_odd_and_even_tree_sizes() {
+        for count in [1, 2, 3, 5, 6, 7] {
+            let leaves = leaves(count);
+            for (index, &item) in leaves.iter().enumerate() {
+                let (root, proof) = root_and_proof(&leaves, index);
+                assert!(verify(&root, item, &proof), "leaf {index} of {count}");
+            }
+        }
+    }
+
+    /// Tests that a proof fails for another leaf, a tampered sibling, or another root.
+    #[test]
+    fn test_verify_rejects_wrong_inputs() {
+        let leaves = leaves(5);
+        let (root, mut proof) = root_and_proof(&leaves, 2);
+        assert!(!verify(&root, leaves[3], &proof));
+        assert!(!verify(&leaf(b"other"), leaves[2], &proof));
+        proof[0][0] ^= 1;
+        assert!(!verify(&root, leaves[2], &proof));
+        // A single-leaf tree has the leaf as root and an empty proof.
+        assert!(verify(&leaves[0], leaves[0], &[]));
+    }
+}
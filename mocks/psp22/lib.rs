@@ -0,0 +1,122 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// A reference PSP22 token used by the `content_ownership` end-to-end tests.
+/// It keeps balances and allowances like a standard fungible token, with the whole
+/// supply minted to the instantiating account.
+#[ink::contract]
+pub mod mock_psp22 {
+    use ink::prelude::string::String;
+    use ink::prelude::vec::Vec;
+    use ink::storage::Mapping;
+
+    /// Mirrors `content_ownership::PSP22Error`, the error type of the PSP22 standard.
+    #[derive(scale::Encode, scale::Decode, Clone, Debug, PartialEq, Eq)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum PSP22Error {
+        /// A custom error type for implementation-specific errors.
+        Custom(String),
+        /// Returned when the sender's balance is below the transferred value.
+        InsufficientBalance,
+        /// Returned when the caller's allowance is below the transferred value.
+        InsufficientAllowance,
+        /// Returned when the recipient is the zero address.
+        ZeroRecipientAddress,
+        /// Returned when the sender is the zero address.
+        ZeroSenderAddress,
+        /// Returned when a safe transfer check fails.
+        SafeTransferCheckFailed(String),
+    }
+
+    /// The subset of the PSP22 standard the tests use. The trait name and message names
+    /// determine the selectors, so they must stay identical to the standard.
+    #[ink::trait_definition]
+    pub trait PSP22 {
+        /// Returns the token balance of `owner`.
+        #[ink(message)]
+        fn balance_of(&self, owner: AccountId) -> u128;
+
+        /// Returns how much `spender` may still transfer on behalf of `owner`.
+        #[ink(message)]
+        fn allowance(&self, owner: AccountId, spender: AccountId) -> u128;
+
+        /// Transfers `value` from the caller to `to`.
+        #[ink(message)]
+        fn transfer(&mut self, to: AccountId, value: u128, data: Vec<u8>) -> Result<(), PSP22Error>;
+
+        /// Transfers `value` from `from` to `to` out of the caller's allowance.
+        #[ink(message)]
+        fn transfer_from(&mut self, from: AccountId, to: AccountId, value: u128, data: Vec<u8>) -> Result<(), PSP22Error>;
+
+        /// Sets the allowance of `spender` over the caller's tokens to `value`.
+        #[ink(message)]
+        fn approve(&mut self, spender: AccountId, value: u128) -> Result<(), PSP22Error>;
+    }
+
+    /// Holds the token balances and allowances.
+    #[ink(storage)]
+    pub struct MockPsp22 {
+        /// The balance of each account.
+        balances: Mapping<AccountId, u128>,
+        /// The allowance of each `(owner, spender)` pair.
+        allowances: Mapping<(AccountId, AccountId), u128>,
+    }
+
+    impl MockPsp22 {
+        /// Constructor: mints `supply` tokens to the caller.
+        #[ink(constructor)]
+        pub fn new(supply: u128) -> Self {
+            let mut balances = Mapping::default();
+            balances.insert(Self::env().caller(), &supply);
+            Self { balances, allowances: Mapping::default() }
+        }
+
+        /// Moves `value` from `from` to `to` if `from` holds enough.
+        fn move_tokens(&mut self, from: AccountId, to: AccountId, value: u128) -> Result<(), PSP22Error> {
+            let from_balance = self.balance_of(from);
+            if from_balance < value {
+                return Err(PSP22Error::InsufficientBalance);
+            }
+            self.balances.insert(from, &(from_balance - value));
+            let to_balance = self.balance_of(to);
+            self.balances.insert(to, &to_balance.saturating_add(value));
+            Ok(())
+        }
+    }
+
+    impl PSP22 for MockPsp22 {
+        #[ink(message)]
+        fn balance_of(&self, owner: AccountId) -> u128 {
+            self.balances.get(owner).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        fn allowance(&self, owner: AccountId, spender: AccountId) -> u128 {
+            self.allowances.get((owner, spender)).unwrap_or(0)
+        }
+
+        #[ink(message)]
+        fn transfer(&mut self, to: AccountId, value: u128, _data: Vec<u8>) -> Result<(), PSP22Error> {
+            let caller = self.env().caller();
+            self.move_tokens(caller, to, value)
+        }
+
+        #[ink(message)]
+        fn transfer_from(&mut self, from: AccountId, to: AccountId, value: u128, _data: Vec<u8>) -> Result<(), PSP22Error> {
+            let spender = self.env().caller();
+            let allowance = self.allowance(from, spender);
+            if allowance < value {
+                return Err(PSP22Error::InsufficientAllowance);
+            }
+            self.move_tokens(from, to, value)?;
+            self.allowances.insert((from, spender), &(allowance - value));
+            Ok(())
+        }
+
+        #[ink(message)]
+        fn approve(&mut self, spender: AccountId, value: u128) -> Result<(), PSP22Error> {
+            let owner = self.env().caller();
+            self.allowances.insert((owner, spender), &value);
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,51 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// A receiver contract used by the `content_ownership` end-to-end tests.
+/// It implements the `ContentReceiver` hook and accepts or rejects incoming content
+/// depending on how it was instantiated.
+#[ink::contract]
+pub mod mock_receiver {
+    use ink::prelude::vec::Vec;
+
+    /// Mirrors `content_ownership::ContentReceiver`. The trait name and message name
+    /// determine the selector, so they must stay identical to the original.
+    #[ink::trait_definition]
+    pub trait ContentReceiver {
+        /// Called after a content item was transferred to this contract.
+        #[ink(message)]
+        fn on_content_received(&mut self, content_id: u64, from: AccountId, data: Vec<u8>) -> bool;
+    }
+
+    /// Records the last content received so tests can inspect the hook arguments.
+    #[ink(storage)]
+    pub struct MockReceiver {
+        /// Whether `on_content_received` accepts the content.
+        accept: bool,
+        /// The arguments of the last accepted call, if any.
+        last_received: Option<(u64, AccountId, Vec<u8>)>,
+    }
+
+    impl MockReceiver {
+        /// Constructor: `accept` decides the answer of every `on_content_received` call.
+        #[ink(constructor)]
+        pub fn new(accept: bool) -> Self {
+            Self { accept, last_received: None }
+        }
+
+        /// Returns the arguments of the last accepted `on_content_received` call.
+        #[ink(message)]
+        pub fn last_received(&self) -> Option<(u64, AccountId, Vec<u8>)> {
+            self.last_received.clone()
+        }
+    }
+
+    impl ContentReceiver for MockReceiver {
+        #[ink(message)]
+        fn on_content_received(&mut self, content_id: u64, from: AccountId, data: Vec<u8>) -> bool {
+            if self.accept {
+                self.last_received = Some((content_id, from, data));
+            }
+            self.accept
+        }
+    }
+}
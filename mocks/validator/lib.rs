@@ -0,0 +1,53 @@
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+/// A reference validator contract used by the `content_ownership` end-to-end tests.
+/// It implements the `ContentValidator` hook and accepts content hashes that start with
+/// the prefix it was instantiated with and are at least `min_length` bytes long.
+#[ink::contract]
+pub mod mock_validator {
+    use ink::prelude::string::String;
+
+    /// Mirrors `content_ownership::ContentValidator`. The trait name and message name
+    /// determine the selector, so they must stay identical to the original.
+    #[ink::trait_definition]
+    pub trait ContentValidator {
+        /// Returns whether `content_hash` may be registered.
+        #[ink(message)]
+        fn is_valid(&self, content_hash: String) -> bool;
+    }
+
+    /// Holds the validation rules; its owner can replace them without touching the registry.
+    #[ink(storage)]
+    pub struct MockValidator {
+        /// The account allowed to change the rules.
+        owner: AccountId,
+        /// The prefix every valid hash starts with.
+        prefix: String,
+        /// The minimum length, in bytes, of a valid hash.
+        min_length: u32,
+    }
+
+    impl MockValidator {
+        /// Constructor: sets the initial rules, with the caller as owner.
+        #[ink(constructor)]
+        pub fn new(prefix: String, min_length: u32) -> Self {
+            Self { owner: Self::env().caller(), prefix, min_length }
+        }
+
+        /// Replaces the rules. Calls from any account other than the owner are ignored.
+        #[ink(message)]
+        pub fn set_rules(&mut self, prefix: String, min_length: u32) {
+            if self.env().caller() == self.owner {
+                self.prefix = prefix;
+                self.min_length = min_length;
+            }
+        }
+    }
+
+    impl ContentValidator for MockValidator {
+        #[ink(message)]
+        fn is_valid(&self, content_hash: String) -> bool {
+            content_hash.starts_with(&self.prefix) && content_hash.len() >= self.min_length as usize
+        }
+    }
+}